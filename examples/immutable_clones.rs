@@ -8,7 +8,7 @@ struct MyTimeKeeper {
 }
 
 fn main() {
-  let event_sync = EventSync::new(10);
+  let event_sync = EventSync::new_millis(10);
   let mut time_keeper = MyTimeKeeper {
     immutable: event_sync.clone_immutable(),
     mutable: event_sync,
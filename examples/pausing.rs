@@ -3,14 +3,14 @@
 use event_sync::EventSync;
 
 /// 500ms between ticks.
-const TICKRATE: u32 = 500;
+const TICKRATE: u64 = 500;
 
 fn main() -> anyhow::Result<()> {
   // Create the EventSync with 500ms tickrate.
-  let mut event_sync = EventSync::new(TICKRATE);
+  let mut event_sync = EventSync::new_millis(TICKRATE);
   let copied_event_sync = event_sync.clone();
   // Create another instance of EventSync to wait while the other one is paused.
-  let other_event_sync = EventSync::new(TICKRATE);
+  let other_event_sync = EventSync::new_millis(TICKRATE);
 
   // Add some time to the first EventSync, then pause it.
   event_sync.wait_for_x_ticks(3)?;
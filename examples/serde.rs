@@ -4,11 +4,11 @@
 use event_sync::EventSync;
 
 /// 500ms between ticks.
-const TICKRATE: u32 = 500;
+const TICKRATE: u64 = 500;
 
 fn main() {
   // Create the EventSync with 500ms tickrate.
-  let event_sync = EventSync::new(TICKRATE);
+  let event_sync = EventSync::new_millis(TICKRATE);
 
   // Add some time from creation.
   event_sync.wait_for_x_ticks(2).unwrap();
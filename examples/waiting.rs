@@ -7,13 +7,13 @@ use std::thread;
 use std::thread::JoinHandle;
 
 /// 500ms between ticks.
-const TICKRATE: u32 = 500;
+const TICKRATE: u64 = 500;
 
 fn main() -> anyhow::Result<()> {
   let thread_count = 5;
 
   // Create an EventSync with a tick lasting 500ms.
-  let event_sync = EventSync::new(TICKRATE);
+  let event_sync = EventSync::new_millis(TICKRATE);
   let mut thread_handles: Vec<JoinHandle<()>> = vec![];
 
   // Spawn 5 threads that will all print which thread they are around the same time (every 2 ticks).
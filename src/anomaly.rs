@@ -0,0 +1,168 @@
+//! Noticing when the process was stalled while waiting for a tick, such as by a debugger breakpoint or a virtual
+//! machine being migrated, and deciding what an EventSync does about it.
+
+use crate::errors::TimeError;
+use crate::tick_math;
+use crate::{EventSync, Mutable};
+use std::time::Duration;
+
+/// What an [`AnomalyDetector`](AnomalyDetector) does to its EventSync after noticing a stall.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AnomalyPolicy {
+  /// Moves the EventSync back by the gap, as if it was paused for the stall, so the stall never happened as far as
+  /// its ticks are concerned.
+  #[default]
+  Absorb,
+
+  /// Leaves the EventSync as it is, only reporting the stall.
+  Report,
+}
+
+/// A stall noticed by an [`AnomalyDetector`](AnomalyDetector).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AnomalyDetected {
+  /// How long after the tick the wait woke up, before the policy was applied.
+  pub gap: Duration,
+  /// The tick that was waited for.
+  pub expected_tick: u64,
+  /// The tick the EventSync was on when the wait woke up, before the policy was applied.
+  pub woke_on_tick: u64,
+}
+
+/// Waits for the ticks of an EventSync, noticing when a wait woke up far later than the tick it was for, and
+/// applying an [`AnomalyPolicy`](AnomalyPolicy) whenever it did.
+///
+/// The host scheduler can stop the whole process, such as when a debugger hits a breakpoint or a virtual machine is
+/// migrated, while the clock carries on. Without absorbing the gap, a few minutes at a breakpoint would show up as
+/// thousands of missed ticks once the process carries on.
+///
+/// A stall is noticed when the wait woke up more than the threshold after the tick it was for. Time spent paused is
+/// never treated as a stall, as an EventSync doesn't move while paused.
+///
+/// # Example
+///
+/// ```
+/// use event_sync::*;
+///
+/// let tickrate = 10; // 10ms between every tick.
+/// let event_sync = EventSync::new_millis(tickrate);
+/// let mut detector = AnomalyDetector::new(event_sync.clone(), AnomalyPolicy::Absorb);
+///
+/// for _ in 0..3 {
+///   if let Some(anomaly) = detector.wait_for_tick().unwrap() {
+///     println!("Stalled for {:?}", anomaly.gap);
+///   }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct AnomalyDetector {
+  event_sync: EventSync<Mutable>,
+  policy: AnomalyPolicy,
+  threshold: Duration,
+}
+
+impl AnomalyDetector {
+  /// The default amount of time a wait can wake up after its tick before it's treated as a stall.
+  pub const DEFAULT_THRESHOLD: Duration = Duration::from_millis(250);
+
+  /// Creates a detector applying the policy to the EventSync.
+  pub fn new(event_sync: EventSync<Mutable>, policy: AnomalyPolicy) -> Self {
+    Self {
+      event_sync,
+      policy,
+      threshold: Self::DEFAULT_THRESHOLD,
+    }
+  }
+
+  /// Sets the amount of time a wait can wake up after its tick before it's treated as a stall.
+  ///
+  /// Should be well above the tickrate and how late the scheduler usually wakes threads, so normal jitter isn't
+  /// treated as a stall.
+  pub fn with_threshold(mut self, threshold: Duration) -> Self {
+    self.threshold = threshold;
+
+    self
+  }
+
+  /// Returns the policy applied to stalls.
+  pub fn policy(&self) -> AnomalyPolicy {
+    self.policy
+  }
+
+  /// [`wait_for_tick()`](EventSync::wait_for_tick), returning the stall if the wait woke up too long after the tick.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if the EventSync is paused.
+  pub fn wait_for_tick(&mut self) -> Result<Option<AnomalyDetected>, TimeError> {
+    let next_tick = self.event_sync.ticks_since_started() + 1;
+
+    self.wait_until(next_tick)
+  }
+
+  /// [`wait_until()`](EventSync::wait_until), returning the stall if the wait woke up too long after the tick.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned when the given tick occurred before the current one.
+  /// - An error is returned if the EventSync is paused.
+  pub fn wait_until(&mut self, tick: u64) -> Result<Option<AnomalyDetected>, TimeError> {
+    self.event_sync.wait_until(tick)?;
+
+    Ok(self.check(tick))
+  }
+
+  /// Checks how long after the tick the EventSync is, applying the policy if it's more than the threshold.
+  fn check(&mut self, expected_tick: u64) -> Option<AnomalyDetected> {
+    let tick_time = tick_math::time_of_tick(expected_tick, self.event_sync.get_tickrate_millis());
+    let gap = self
+      .event_sync
+      .time_since_started()
+      .checked_sub(tick_time)?;
+
+    if gap <= self.threshold {
+      return None;
+    }
+
+    let anomaly = AnomalyDetected {
+      gap,
+      expected_tick,
+      woke_on_tick: self.event_sync.ticks_since_started(),
+    };
+
+    if self.policy == AnomalyPolicy::Absorb {
+      // Moving back by the gap lands on the tick waited for, however long the absorbing took.
+      let _ = self.event_sync.sync_to(tick_time);
+    }
+
+    Some(anomaly)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  #[test]
+  fn stalls_are_absorbed() {
+    let event_sync = EventSync::new_millis(TEST_TICKRATE);
+    let mut detector = AnomalyDetector::new(event_sync.clone(), AnomalyPolicy::Absorb)
+      .with_threshold(Duration::from_millis(50));
+
+    // Stands in for the process being stopped right as the tick occurred.
+    std::thread::sleep(Duration::from_millis(200));
+
+    let anomaly = detector.check(1).unwrap();
+
+    assert_eq!(anomaly.expected_tick, 1);
+    assert!(anomaly.woke_on_tick >= 20);
+    assert!(anomaly.gap >= Duration::from_millis(190));
+    assert!(event_sync.ticks_since_started() < 5);
+
+    let mut detector = AnomalyDetector::new(event_sync.clone(), AnomalyPolicy::Report);
+
+    assert_eq!(detector.wait_for_tick(), Ok(None));
+  }
+}
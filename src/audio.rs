@@ -0,0 +1,150 @@
+//! Counting an EventSync's time in audio samples.
+
+use crate::errors::TimeError;
+use crate::{EventSync, Mutable};
+
+impl EventSync<Mutable> {
+  /// Creates a new instance of [`EventSync`](EventSync) where every tick lasts the given amount of samples at the
+  /// sample rate, so an audio thread can work in samples and ticks interchangeably.
+  ///
+  /// Samples are counted from the time passed rather than added up tick by tick, so the count doesn't drift from the
+  /// tick timeline over long sessions.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if the sample rate or samples per tick is 0.
+  /// - An error is returned if the samples per tick don't last a whole amount of milliseconds, or last longer than a
+  ///   day.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::*;
+  ///
+  /// // 480 samples at 48kHz is 10ms between every tick.
+  /// let event_sync = EventSync::from_sample_rate(48_000, 480).unwrap();
+  ///
+  /// assert_eq!(event_sync.get_tickrate_millis(), 10);
+  /// assert_eq!(event_sync.sample_of_tick(3), Some(1440));
+  ///
+  /// // 512 samples at 44.1kHz isn't a whole amount of milliseconds.
+  /// assert!(EventSync::from_sample_rate(44_100, 512).is_err());
+  /// ```
+  pub fn from_sample_rate(sample_rate: u32, samples_per_tick: u32) -> Result<Self, TimeError> {
+    if sample_rate == 0 {
+      return Err(TimeError::InvalidState("the sample rate is 0"));
+    }
+
+    let milliseconds = samples_per_tick as u64 * 1000;
+
+    if !milliseconds.is_multiple_of(sample_rate as u64) {
+      return Err(TimeError::InvalidState(
+        "the samples per tick aren't a whole amount of milliseconds",
+      ));
+    }
+
+    let mut event_sync = Self::try_from(milliseconds / sample_rate as u64)?;
+    event_sync.write_inner().set_sample_rate(sample_rate);
+
+    Ok(event_sync)
+  }
+}
+
+impl<T> EventSync<T> {
+  /// Returns the sample rate this EventSync was created with by
+  /// [`from_sample_rate()`](EventSync::from_sample_rate), or None if it wasn't.
+  pub fn sample_rate(&self) -> Option<u32> {
+    self.read_inner().sample_rate()
+  }
+
+  /// Returns the amount of whole samples that have passed since this EventSync started, or None if it wasn't
+  /// created with [`from_sample_rate()`](EventSync::from_sample_rate).
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::*;
+  /// use std::time::Duration;
+  ///
+  /// let mut event_sync = EventSync::from_sample_rate(44_100, 441).unwrap();
+  ///
+  /// event_sync.pause();
+  /// event_sync.sync_to(Duration::from_millis(1500)).unwrap();
+  ///
+  /// assert_eq!(event_sync.samples_elapsed(), Some(66_150));
+  /// ```
+  pub fn samples_elapsed(&self) -> Option<u64> {
+    let inner = self.read_inner();
+    let sample_rate = inner.sample_rate()?;
+
+    Some((inner.time_since_started().as_nanos() * sample_rate as u128 / 1_000_000_000) as u64)
+  }
+
+  /// Returns the sample the given tick occurs on, or None if this EventSync wasn't created with
+  /// [`from_sample_rate()`](EventSync::from_sample_rate).
+  ///
+  /// If the tickrate was changed to one that isn't a whole amount of samples, the sample is rounded down.
+  pub fn sample_of_tick(&self, tick: u64) -> Option<u64> {
+    let inner = self.read_inner();
+    let sample_rate = inner.sample_rate()?;
+    let milliseconds = tick as u128 * inner.get_tickrate() as u128;
+
+    Some((milliseconds * sample_rate as u128 / 1000) as u64)
+  }
+
+  /// Returns the tick the given sample is in, or None if this EventSync wasn't created with
+  /// [`from_sample_rate()`](EventSync::from_sample_rate).
+  pub fn tick_of_sample(&self, sample: u64) -> Option<u64> {
+    let inner = self.read_inner();
+    let sample_rate = inner.sample_rate()?;
+    let milli_samples_per_tick = sample_rate as u128 * inner.get_tickrate() as u128;
+
+    Some((sample as u128 * 1000 / milli_samples_per_tick) as u64)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::time::Duration;
+
+  #[test]
+  fn samples_dont_drift_over_long_sessions() {
+    let mut event_sync = EventSync::from_sample_rate(44_100, 441).unwrap();
+    let ten_hours = Duration::from_secs(10 * 60 * 60);
+
+    event_sync.pause();
+    event_sync.sync_to(ten_hours).unwrap();
+
+    let tick = event_sync.ticks_since_started();
+
+    assert_eq!(event_sync.samples_elapsed(), Some(44_100 * 10 * 60 * 60));
+    assert_eq!(
+      event_sync.sample_of_tick(tick),
+      event_sync.samples_elapsed()
+    );
+    assert_eq!(
+      event_sync.tick_of_sample(44_100 * 10 * 60 * 60 + 440),
+      Some(tick)
+    );
+  }
+
+  #[test]
+  fn sample_rates_survive_serialization() {
+    let mut event_sync = EventSync::from_sample_rate(48_000, 480).unwrap();
+    event_sync.pause();
+
+    let serialized_event_sync = serde_json::to_string(&event_sync).unwrap();
+    let deserialized_event_sync: EventSync = serde_json::from_str(&serialized_event_sync).unwrap();
+
+    assert_eq!(deserialized_event_sync.sample_rate(), Some(48_000));
+    assert_eq!(deserialized_event_sync, event_sync);
+    assert_eq!(EventSync::new_millis(10).samples_elapsed(), None);
+
+    let mut without_sample_rate = EventSync::new_paused_millis(10);
+    event_sync.restart_paused();
+    without_sample_rate.restart_paused();
+
+    assert_ne!(without_sample_rate, event_sync);
+  }
+}
@@ -0,0 +1,171 @@
+use crate::errors::TimeError;
+use crate::EventSync;
+use std::hash::{BuildHasher, Hasher};
+
+/// An endless series of tick delays, each one growing from the last.
+///
+/// Every delay can be waited on with [`wait_next()`](BackoffSchedule::wait_next), or read with
+/// [`next()`](Iterator::next) so the timing can be tested separately from whatever is being retried.
+///
+/// # Example
+///
+/// ```
+/// use event_sync::BackoffSchedule;
+///
+/// let schedule = BackoffSchedule::exponential(1, 2).with_cap(10);
+///
+/// assert_eq!(schedule.take(6).collect::<Vec<_>>(), [1, 2, 4, 8, 10, 10]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackoffSchedule {
+  backoff: Backoff,
+  cap: u32,
+  jitter: Option<u64>,
+  delays_produced: u32,
+}
+
+/// How the delay grows with every step of the schedule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Backoff {
+  Fixed(u32),
+  Linear { initial: u32, step: u32 },
+  Exponential { initial: u32, factor: u32 },
+}
+
+impl BackoffSchedule {
+  /// Produces the same amount of ticks every time.
+  pub fn fixed(ticks: u32) -> Self {
+    Self::from_backoff(Backoff::Fixed(ticks))
+  }
+
+  /// Starts at initial ticks, adding step ticks every time after.
+  pub fn linear(initial: u32, step: u32) -> Self {
+    Self::from_backoff(Backoff::Linear { initial, step })
+  }
+
+  /// Starts at initial ticks, multiplying by factor every time after.
+  pub fn exponential(initial: u32, factor: u32) -> Self {
+    Self::from_backoff(Backoff::Exponential { initial, factor })
+  }
+
+  fn from_backoff(backoff: Backoff) -> Self {
+    Self {
+      backoff,
+      cap: u32::MAX,
+      jitter: None,
+      delays_produced: 0,
+    }
+  }
+
+  /// Sets the longest delay that will be produced.
+  pub fn with_cap(mut self, cap: u32) -> Self {
+    self.cap = cap;
+
+    self
+  }
+
+  /// Randomly shortens every delay by up to half, so many clients backing off at once spread out their retries.
+  pub fn with_jitter(self) -> Self {
+    let seed = std::collections::hash_map::RandomState::new()
+      .build_hasher()
+      .finish();
+
+    self.with_jitter_seed(seed)
+  }
+
+  /// Randomly shortens every delay by up to half, producing the same delays for the same seed.
+  pub fn with_jitter_seed(mut self, seed: u64) -> Self {
+    // Xorshift gets stuck at 0.
+    self.jitter = Some(seed.max(1));
+
+    self
+  }
+
+  /// Returns the delay at the given step of the schedule, counting from 0, before any jitter.
+  pub fn delay_at(&self, step: u32) -> u32 {
+    let delay = match self.backoff {
+      Backoff::Fixed(ticks) => ticks,
+      Backoff::Linear {
+        initial,
+        step: growth,
+      } => initial.saturating_add(growth.saturating_mul(step)),
+      Backoff::Exponential { initial, factor } => {
+        initial.saturating_mul(factor.saturating_pow(step))
+      }
+    };
+
+    delay.min(self.cap)
+  }
+
+  /// Waits on the EventSync for the next delay in the schedule.
+  ///
+  /// Like [`wait_for_x_ticks()`](EventSync::wait_for_x_ticks), the wait ends on a tick. A delay of 0 doesn't wait.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if the EventSync is paused.
+  pub fn wait_next<T>(&mut self, event_sync: &EventSync<T>) -> Result<(), TimeError> {
+    event_sync
+      .wait_for_x_ticks(self.next().unwrap_or(0))
+      .map(|_| ())
+  }
+
+  /// Moves the jitter state forward, returning a random number.
+  fn next_random(state: &mut u64) -> u64 {
+    *state ^= *state << 13;
+    *state ^= *state >> 7;
+    *state ^= *state << 17;
+
+    *state
+  }
+}
+
+impl Iterator for BackoffSchedule {
+  type Item = u32;
+
+  fn next(&mut self) -> Option<u32> {
+    let delay = self.delay_at(self.delays_produced);
+    self.delays_produced = self.delays_produced.saturating_add(1);
+
+    let Some(state) = &mut self.jitter else {
+      return Some(delay);
+    };
+
+    let reduction = Self::next_random(state) % (delay as u64 / 2 + 1);
+
+    Some(delay - reduction as u32)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn delays_follow_backoff() {
+    let delays = |schedule: BackoffSchedule| schedule.take(4).collect::<Vec<_>>();
+
+    assert_eq!(delays(BackoffSchedule::fixed(3)), [3, 3, 3, 3]);
+    assert_eq!(delays(BackoffSchedule::linear(1, 2)), [1, 3, 5, 7]);
+    assert_eq!(
+      delays(BackoffSchedule::exponential(1, 3).with_cap(20)),
+      [1, 3, 9, 20]
+    );
+  }
+
+  #[test]
+  fn jitter_stays_within_half_of_delay() {
+    let schedule = BackoffSchedule::exponential(4, 2).with_jitter_seed(7);
+
+    for (step, delay) in schedule.clone().take(10).enumerate() {
+      let unjittered = schedule.delay_at(step as u32);
+
+      assert!(delay <= unjittered && delay >= unjittered - unjittered / 2);
+    }
+
+    assert_eq!(
+      schedule.clone().take(10).collect::<Vec<_>>(),
+      schedule.take(10).collect::<Vec<_>>()
+    );
+  }
+}
@@ -0,0 +1,159 @@
+//! Driving Bevy schedules with an EventSync.
+//!
+//! Enabled with the `bevy` feature.
+
+use crate::{EventSync, Immutable, Mutable};
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_ecs::prelude::*;
+use bevy_ecs::schedule::ScheduleLabel;
+
+impl Resource for EventSync<Mutable> {}
+impl Resource for EventSync<Immutable> {}
+
+/// The schedule run once for every tick of the [`EventSync`](EventSync) resource.
+///
+/// Systems added to this schedule run at a fixed timestep, which stops while the EventSync is paused.
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct EventSyncTick;
+
+/// The last tick the [`EventSyncTick`](EventSyncTick) schedule was run for.
+///
+/// While the schedule is running, this is the tick it's being run for.
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LastEventSyncTick {
+  /// The absolute tick, counted from when the EventSync was started.
+  pub tick: u64,
+}
+
+/// Inserts an [`EventSync`](EventSync) as a resource, and runs the [`EventSyncTick`](EventSyncTick) schedule once
+/// for every tick that has passed since the last frame.
+///
+/// As the resource is a regular EventSync, it can be paused, serialized, or shared with code outside of the app.
+///
+/// # Example
+///
+/// ```
+/// use bevy_app::App;
+/// use event_sync::*;
+///
+/// fn fixed_update(tick: bevy_ecs::prelude::Res<LastEventSyncTick>) {
+///   println!("Running tick {}", tick.tick);
+/// }
+///
+/// let tickrate = 10; // 10ms between every tick.
+/// let event_sync = EventSync::new_millis(tickrate);
+///
+/// let mut app = App::new();
+/// app.add_plugins(EventSyncPlugin::from_event_sync(event_sync.clone()));
+/// app.add_systems(EventSyncTick, fixed_update);
+///
+/// event_sync.wait_for_x_ticks(2).unwrap();
+/// app.update();
+///
+/// assert_eq!(app.world().resource::<LastEventSyncTick>().tick, 2);
+/// ```
+pub struct EventSyncPlugin {
+  event_sync: EventSync<Mutable>,
+}
+
+impl EventSyncPlugin {
+  /// Creates a plugin driven by a new EventSync with the given tickrate in milliseconds.
+  pub fn new_millis(tickrate_in_milliseconds: u64) -> Self {
+    Self::from_event_sync(EventSync::new_millis(tickrate_in_milliseconds))
+  }
+
+  /// [`new_millis()`](EventSyncPlugin::new_millis), taking the tickrate as a `u32`.
+  #[deprecated(
+    since = "0.4.5",
+    note = "use `new_millis()`, as tickrates are `u64` milliseconds"
+  )]
+  pub fn new(tickrate_in_milliseconds: u32) -> Self {
+    Self::new_millis(tickrate_in_milliseconds.into())
+  }
+
+  /// Creates a plugin driven by an existing EventSync, sharing its clock with the app.
+  pub fn from_event_sync(event_sync: EventSync<Mutable>) -> Self {
+    Self { event_sync }
+  }
+}
+
+impl Plugin for EventSyncPlugin {
+  fn build(&self, app: &mut App) {
+    app
+      .insert_resource(self.event_sync.clone())
+      .insert_resource(LastEventSyncTick {
+        tick: self.event_sync.ticks_since_started(),
+      })
+      .init_schedule(EventSyncTick)
+      .add_systems(PreUpdate, run_event_sync_ticks);
+  }
+}
+
+/// Runs the [`EventSyncTick`](EventSyncTick) schedule for every tick that passed since it was last run.
+///
+/// If the EventSync was restarted or moved back, the schedule picks up from the new tick without running.
+fn run_event_sync_ticks(world: &mut World) {
+  let ticks_since_started = world.resource::<EventSync>().ticks_since_started();
+  let last_tick = world.resource::<LastEventSyncTick>().tick;
+
+  if ticks_since_started < last_tick {
+    world.resource_mut::<LastEventSyncTick>().tick = ticks_since_started;
+
+    return;
+  }
+
+  for tick in last_tick + 1..=ticks_since_started {
+    world.resource_mut::<LastEventSyncTick>().tick = tick;
+    world.run_schedule(EventSyncTick);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  #[derive(Resource, Default)]
+  struct TickCounter(u64);
+
+  fn count_ticks(mut counter: ResMut<TickCounter>) {
+    counter.0 += 1;
+  }
+
+  fn test_app(event_sync: &EventSync) -> App {
+    let mut app = App::new();
+
+    app
+      .add_plugins(EventSyncPlugin::from_event_sync(event_sync.clone()))
+      .init_resource::<TickCounter>()
+      .add_systems(EventSyncTick, count_ticks);
+
+    app
+  }
+
+  #[test]
+  fn schedule_runs_once_per_tick() {
+    let event_sync = EventSync::new_millis(TEST_TICKRATE);
+    let mut app = test_app(&event_sync);
+
+    event_sync.wait_until(3).unwrap();
+    app.update();
+
+    assert_eq!(app.world().resource::<TickCounter>().0, 3);
+  }
+
+  #[test]
+  fn schedule_stops_while_paused() {
+    let mut event_sync = EventSync::new_millis(TEST_TICKRATE);
+    let mut app = test_app(&event_sync);
+
+    event_sync.wait_until(1).unwrap();
+    event_sync.pause();
+    app.update();
+    std::thread::sleep(std::time::Duration::from_millis(TEST_TICKRATE * 2));
+    app.update();
+
+    assert_eq!(app.world().resource::<TickCounter>().0, 1);
+  }
+}
@@ -0,0 +1,295 @@
+//! Constructors that reject invalid values instead of clamping them.
+
+use crate::clock::{ClockSource, SystemTime};
+use crate::errors::TimeError;
+use crate::inner::validate;
+use crate::tick_math;
+use crate::{EventSync, Mutable};
+use std::time::Duration;
+
+impl EventSync<Mutable> {
+  /// [`try_new_millis()`](EventSync::try_new_millis), taking the tickrate as a `u32`.
+  #[deprecated(
+    since = "0.4.5",
+    note = "use `try_new_millis()`, as tickrates are `u64` milliseconds"
+  )]
+  pub fn try_new(tickrate_in_milliseconds: u32) -> Result<Self, TimeError> {
+    Self::try_new_millis(tickrate_in_milliseconds.into())
+  }
+
+  /// Creates a new instance of [`EventSync`](EventSync), returning an error instead of clamping an invalid tickrate.
+  ///
+  /// Unlike [`new_millis()`](EventSync::new_millis), a tickrate of 0 is rejected rather than set to 1.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if the tickrate is 0, or longer than 100 years.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::*;
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let event_sync = EventSync::try_new_millis(tickrate).unwrap();
+  ///
+  /// assert_eq!(event_sync.get_tickrate_millis(), tickrate);
+  /// assert!(EventSync::try_new_millis(0).is_err());
+  /// ```
+  pub fn try_new_millis(tickrate_in_milliseconds: u64) -> Result<Self, TimeError> {
+    validate(tickrate_in_milliseconds, Duration::ZERO)?;
+
+    Ok(Self::new_millis(tickrate_in_milliseconds))
+  }
+
+  /// [`try_new_with_clock_millis()`](EventSync::try_new_with_clock_millis), taking the tickrate as a `u32`.
+  #[deprecated(
+    since = "0.4.5",
+    note = "use `try_new_with_clock_millis()`, as tickrates are `u64` milliseconds"
+  )]
+  pub fn try_new_with_clock(
+    tickrate_in_milliseconds: u32,
+    clock: ClockSource,
+  ) -> Result<Self, TimeError> {
+    Self::try_new_with_clock_millis(tickrate_in_milliseconds.into(), clock)
+  }
+
+  /// [`new_with_clock_millis()`](EventSync::new_with_clock_millis), returning an error instead of clamping an invalid tickrate.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if the tickrate is 0, or longer than 100 years.
+  pub fn try_new_with_clock_millis(
+    tickrate_in_milliseconds: u64,
+    clock: ClockSource,
+  ) -> Result<Self, TimeError> {
+    validate(tickrate_in_milliseconds, Duration::ZERO)?;
+
+    Ok(Self::new_with_clock_millis(tickrate_in_milliseconds, clock))
+  }
+
+  /// [`try_new_paused_millis()`](EventSync::try_new_paused_millis), taking the tickrate as a `u32`.
+  #[deprecated(
+    since = "0.4.5",
+    note = "use `try_new_paused_millis()`, as tickrates are `u64` milliseconds"
+  )]
+  pub fn try_new_paused(tickrate_in_milliseconds: u32) -> Result<Self, TimeError> {
+    Self::try_new_paused_millis(tickrate_in_milliseconds.into())
+  }
+
+  /// [`new_paused_millis()`](EventSync::new_paused_millis), returning an error instead of clamping an invalid tickrate.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if the tickrate is 0, or longer than 100 years.
+  pub fn try_new_paused_millis(tickrate_in_milliseconds: u64) -> Result<Self, TimeError> {
+    validate(tickrate_in_milliseconds, Duration::ZERO)?;
+
+    Ok(Self::new_paused_millis(tickrate_in_milliseconds))
+  }
+
+  /// [`try_from_starting_time_millis()`](EventSync::try_from_starting_time_millis), taking the tickrate as a `u32`.
+  #[deprecated(
+    since = "0.4.5",
+    note = "use `try_from_starting_time_millis()`, as tickrates are `u64` milliseconds"
+  )]
+  pub fn try_from_starting_time(
+    tickrate_in_milliseconds: u32,
+    elapsed_time: Duration,
+    start_paused: bool,
+  ) -> Result<Self, TimeError> {
+    Self::try_from_starting_time_millis(tickrate_in_milliseconds.into(), elapsed_time, start_paused)
+  }
+
+  /// [`from_starting_time_millis()`](EventSync::from_starting_time_millis), returning an error instead of clamping an invalid
+  /// tickrate.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if the tickrate is 0, or longer than 100 years.
+  /// - An error is returned if the starting time is more than 100 years.
+  pub fn try_from_starting_time_millis(
+    tickrate_in_milliseconds: u64,
+    elapsed_time: Duration,
+    start_paused: bool,
+  ) -> Result<Self, TimeError> {
+    validate(tickrate_in_milliseconds, elapsed_time)?;
+
+    Ok(Self::from_starting_time_millis(
+      tickrate_in_milliseconds,
+      elapsed_time,
+      start_paused,
+    ))
+  }
+
+  /// [`try_from_starting_tick_millis()`](EventSync::try_from_starting_tick_millis), taking the tickrate as a `u32`.
+  #[deprecated(
+    since = "0.4.5",
+    note = "use `try_from_starting_tick_millis()`, as tickrates are `u64` milliseconds"
+  )]
+  pub fn try_from_starting_tick(
+    tickrate_in_milliseconds: u32,
+    starting_tick: u64,
+    start_paused: bool,
+  ) -> Result<Self, TimeError> {
+    Self::try_from_starting_tick_millis(
+      tickrate_in_milliseconds.into(),
+      starting_tick,
+      start_paused,
+    )
+  }
+
+  /// [`from_starting_tick_millis()`](EventSync::from_starting_tick_millis), returning an error instead of clamping an invalid
+  /// tickrate.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if the tickrate is 0, or longer than 100 years.
+  /// - An error is returned if the starting tick is more than 100 years in.
+  pub fn try_from_starting_tick_millis(
+    tickrate_in_milliseconds: u64,
+    starting_tick: u64,
+    start_paused: bool,
+  ) -> Result<Self, TimeError> {
+    let elapsed_time = tick_math::checked_time_of_tick(starting_tick, tickrate_in_milliseconds)
+      .ok_or(TimeError::InvalidState(
+        "more than 100 years have passed on it",
+      ))?;
+
+    Self::try_from_starting_time_millis(tickrate_in_milliseconds, elapsed_time, start_paused)
+  }
+
+  /// [`try_from_system_epoch_millis()`](EventSync::try_from_system_epoch_millis), taking the tickrate as a `u32`.
+  #[deprecated(
+    since = "0.4.5",
+    note = "use `try_from_system_epoch_millis()`, as tickrates are `u64` milliseconds"
+  )]
+  pub fn try_from_system_epoch(
+    tickrate_in_milliseconds: u32,
+    anchor: SystemTime,
+  ) -> Result<Self, TimeError> {
+    Self::try_from_system_epoch_millis(tickrate_in_milliseconds.into(), anchor)
+  }
+
+  /// [`from_system_epoch_millis()`](EventSync::from_system_epoch_millis), returning an error instead of clamping an invalid
+  /// tickrate.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if the tickrate is 0, or longer than 100 years.
+  /// - An error is returned if the anchor hasn't happened yet, or was more than 100 years ago.
+  pub fn try_from_system_epoch_millis(
+    tickrate_in_milliseconds: u64,
+    anchor: SystemTime,
+  ) -> Result<Self, TimeError> {
+    let time_since_anchor = SystemTime::now()
+      .duration_since(anchor)
+      .map_err(|_| TimeError::StartTimeInFuture)?;
+
+    validate(tickrate_in_milliseconds, time_since_anchor)?;
+
+    Self::from_system_epoch_millis(tickrate_in_milliseconds, anchor)
+  }
+}
+
+/// Creates an EventSync with the given tickrate in milliseconds, see [`EventSync::try_new_millis()`](EventSync::try_new_millis).
+impl TryFrom<u32> for EventSync<Mutable> {
+  type Error = TimeError;
+
+  fn try_from(tickrate_in_milliseconds: u32) -> Result<Self, Self::Error> {
+    Self::try_new_millis(tickrate_in_milliseconds.into())
+  }
+}
+
+/// Creates an EventSync with the given tickrate in milliseconds, see [`EventSync::try_new_millis()`](EventSync::try_new_millis).
+impl TryFrom<u64> for EventSync<Mutable> {
+  type Error = TimeError;
+
+  fn try_from(tickrate_in_milliseconds: u64) -> Result<Self, Self::Error> {
+    Self::try_new_millis(tickrate_in_milliseconds)
+  }
+}
+
+/// Creates an EventSync where each tick lasts the given duration.
+///
+/// The duration must be a whole amount of milliseconds.
+///
+/// # Example
+///
+/// ```
+/// use event_sync::*;
+/// use std::time::Duration;
+///
+/// let event_sync = EventSync::try_from(Duration::from_millis(16)).unwrap();
+///
+/// assert_eq!(event_sync.get_tickrate_millis(), 16);
+/// assert!(EventSync::try_from(Duration::from_micros(16_500)).is_err());
+/// ```
+impl TryFrom<Duration> for EventSync<Mutable> {
+  type Error = TimeError;
+
+  fn try_from(tickrate: Duration) -> Result<Self, Self::Error> {
+    if !tickrate.subsec_nanos().is_multiple_of(1_000_000) {
+      return Err(TimeError::InvalidState(
+        "the tickrate isn't a whole amount of milliseconds",
+      ));
+    }
+
+    let tickrate_in_milliseconds = u64::try_from(tickrate.as_millis())
+      .map_err(|_| TimeError::InvalidState("the tickrate is longer than 100 years"))?;
+
+    Self::try_from(tickrate_in_milliseconds)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn invalid_tickrates_are_rejected() {
+    assert!(matches!(
+      EventSync::try_new_paused_millis(0),
+      Err(TimeError::InvalidState("the tickrate is 0"))
+    ));
+    assert!(EventSync::try_from(101 * 365 * 24 * 60 * 60 * 1000_u64).is_err());
+    assert!(EventSync::try_new_with_clock_millis(1, ClockSource::WallClock).is_ok());
+  }
+
+  #[test]
+  fn absurd_starting_points_are_rejected() {
+    assert!(EventSync::try_from_starting_tick_millis(10, u64::MAX, true).is_err());
+    assert!(EventSync::try_from_starting_time_millis(10, Duration::MAX, true).is_err());
+    assert_eq!(
+      EventSync::try_from_starting_tick_millis(10, 3, true)
+        .unwrap()
+        .ticks_since_started(),
+      3
+    );
+    assert!(EventSync::try_from_system_epoch_millis(10, SystemTime::UNIX_EPOCH).is_ok());
+  }
+
+  #[test]
+  fn conversions_validate_the_tickrate() {
+    assert!(EventSync::try_from(Duration::ZERO).is_err());
+    assert!(EventSync::try_from(Duration::MAX).is_err());
+    assert!(EventSync::try_from(u64::MAX).is_err());
+    assert!(EventSync::try_from(0_u32).is_err());
+    assert_eq!(
+      EventSync::try_from(20_u64).unwrap().get_tickrate_millis(),
+      20
+    );
+    assert_eq!(
+      EventSync::try_from(Duration::from_secs(60 * 24 * 60 * 60))
+        .unwrap()
+        .get_tickrate_millis(),
+      60 * 24 * 60 * 60 * 1000
+    );
+    assert_eq!(
+      EventSync::try_from(Duration::from_secs(1))
+        .unwrap()
+        .get_tickrate_millis(),
+      1000
+    );
+  }
+}
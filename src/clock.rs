@@ -0,0 +1,218 @@
+use crate::errors::TimeError;
+use core::time::Duration;
+use serde::{Deserialize, Serialize};
+
+#[cfg(all(feature = "std", not(feature = "wasm")))]
+pub(crate) use std::time::{Instant, SystemTime};
+// std's clocks panic on wasm32-unknown-unknown, web-time backs them with performance.now() and Date.now() instead.
+#[cfg(feature = "wasm")]
+pub(crate) use web_time::{Instant, SystemTime};
+
+/// The clock an EventSync measures elapsed time against.
+///
+/// # Example
+///
+/// ```
+/// use event_sync::*;
+///
+/// let tickrate = 10; // 10ms between every tick.
+/// let event_sync = EventSync::new_with_clock_millis(tickrate, ClockSource::WallClock);
+///
+/// assert_eq!(event_sync.clock_source(), ClockSource::WallClock);
+/// ```
+#[derive(Debug, Clone, Copy, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "std", derive(Default))]
+pub enum ClockSource {
+  /// Measures time with [`Instant`](std::time::Instant).
+  ///
+  /// This clock never goes backwards, but will drift from civil time over long periods.
+  #[cfg(feature = "std")]
+  #[default]
+  Monotonic,
+
+  /// Measures time with [`SystemTime`](std::time::SystemTime).
+  ///
+  /// This clock follows any adjustments made to the system's clock, such as NTP corrections.
+  /// If the system's clock goes back to before the EventSync was started, methods that return a Result will return
+  /// [`TimeError::SystemTimeWentBackwards`](TimeError::SystemTimeWentBackwards), and all other methods will treat
+  /// the elapsed time as 0.
+  #[cfg(feature = "std")]
+  WallClock,
+
+  /// Measures time with Linux's `CLOCK_BOOTTIME`.
+  ///
+  /// Unlike [`Monotonic`](ClockSource::Monotonic), which stands still on Linux while the system is suspended, this
+  /// clock carries on counting through suspends, so ticks keep following real-world schedules across them.
+  /// Like [`Monotonic`](ClockSource::Monotonic), it's unaffected by adjustments made to the system's clock.
+  ///
+  /// Enabled with the `boottime` feature, on Linux only.
+  #[cfg(all(feature = "boottime", target_os = "linux"))]
+  Boottime,
+
+  /// Measures time with the given function, which returns the time passed since an arbitrary fixed point.
+  ///
+  /// This is how time is measured without std, such as by reading the counter of a hardware timer.
+  /// The function must never go backwards.
+  ///
+  /// As a function can't be serialized, an EventSync using this clock will fail to serialize.
+  #[serde(skip)]
+  Custom(fn() -> Duration),
+}
+
+// Custom clocks are compared by address. The same function may end up at different addresses, in which case the
+// clocks are treated as different, which is fine for telling whether two EventSyncs are in the same state.
+impl PartialEq for ClockSource {
+  fn eq(&self, other: &Self) -> bool {
+    match (self, other) {
+      (ClockSource::Custom(clock), ClockSource::Custom(other_clock)) => {
+        core::ptr::fn_addr_eq(*clock, *other_clock)
+      }
+      #[allow(unreachable_patterns)]
+      _ => core::mem::discriminant(self) == core::mem::discriminant(other),
+    }
+  }
+}
+
+impl core::hash::Hash for ClockSource {
+  fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+    core::mem::discriminant(self).hash(state);
+
+    #[allow(irrefutable_let_patterns)]
+    if let ClockSource::Custom(clock) = self {
+      (*clock as usize).hash(state);
+    }
+  }
+}
+
+/// Reads the time since the system booted, including any time it spent suspended.
+#[cfg(all(feature = "boottime", target_os = "linux"))]
+fn read_boottime() -> Duration {
+  let mut time = libc::timespec {
+    tv_sec: 0,
+    tv_nsec: 0,
+  };
+
+  // Safety: The pointer is to a valid timespec, and CLOCK_BOOTTIME is supported by every Linux since 2.6.39.
+  unsafe { libc::clock_gettime(libc::CLOCK_BOOTTIME, &mut time) };
+
+  Duration::new(time.tv_sec as u64, time.tv_nsec as u32)
+}
+
+/// A point in time read from a [`ClockSource`](ClockSource).
+#[derive(Clone, Copy, Eq)]
+pub(crate) enum Timestamp {
+  #[cfg(feature = "std")]
+  Monotonic(Instant),
+  #[cfg(feature = "std")]
+  WallClock(SystemTime),
+  /// The reading of a custom clock, and any time this timestamp has been moved back by.
+  ///
+  /// `ClockSource::Boottime` is read as a custom clock.
+  Custom {
+    clock: fn() -> Duration,
+    reading: Duration,
+    offset: Duration,
+  },
+}
+
+impl PartialEq for Timestamp {
+  fn eq(&self, other: &Self) -> bool {
+    match (self, other) {
+      #[cfg(feature = "std")]
+      (Timestamp::Monotonic(instant), Timestamp::Monotonic(other_instant)) => {
+        instant == other_instant
+      }
+      #[cfg(feature = "std")]
+      (Timestamp::WallClock(system_time), Timestamp::WallClock(other_system_time)) => {
+        system_time == other_system_time
+      }
+      (
+        Timestamp::Custom {
+          clock,
+          reading,
+          offset,
+        },
+        Timestamp::Custom {
+          clock: other_clock,
+          reading: other_reading,
+          offset: other_offset,
+        },
+      ) => {
+        ClockSource::Custom(*clock) == ClockSource::Custom(*other_clock)
+          && reading == other_reading
+          && offset == other_offset
+      }
+      #[allow(unreachable_patterns)]
+      _ => false,
+    }
+  }
+}
+
+impl Timestamp {
+  /// Reads the current time from the given clock.
+  pub(crate) fn now(clock: ClockSource) -> Self {
+    match clock {
+      #[cfg(feature = "std")]
+      ClockSource::Monotonic => Timestamp::Monotonic(Instant::now()),
+      #[cfg(feature = "std")]
+      ClockSource::WallClock => Timestamp::WallClock(SystemTime::now()),
+      #[cfg(all(feature = "boottime", target_os = "linux"))]
+      ClockSource::Boottime => Timestamp::now(ClockSource::Custom(read_boottime)),
+      ClockSource::Custom(clock) => Timestamp::Custom {
+        clock,
+        reading: clock(),
+        offset: Duration::ZERO,
+      },
+    }
+  }
+
+  /// Returns the time that has passed since this timestamp.
+  ///
+  /// # Errors
+  ///
+  /// - If the wall clock has gone back to before this timestamp.
+  pub(crate) fn elapsed(&self) -> Result<Duration, TimeError> {
+    match self {
+      #[cfg(feature = "std")]
+      Timestamp::Monotonic(instant) => Ok(instant.elapsed()),
+      #[cfg(feature = "std")]
+      Timestamp::WallClock(system_time) => system_time
+        .elapsed()
+        .map_err(|_| TimeError::SystemTimeWentBackwards),
+      Timestamp::Custom {
+        clock,
+        reading,
+        offset,
+      } => Ok(clock().saturating_sub(*reading) + *offset),
+    }
+  }
+
+  /// Returns the time that has passed since this timestamp, or 0 if the wall clock has gone backwards.
+  pub(crate) fn saturating_elapsed(&self) -> Duration {
+    self.elapsed().unwrap_or_default()
+  }
+
+  /// Returns a timestamp the given duration before this one.
+  ///
+  /// None is returned if the result cannot be represented.
+  pub(crate) fn checked_sub(&self, duration: Duration) -> Option<Self> {
+    match self {
+      #[cfg(feature = "std")]
+      Timestamp::Monotonic(instant) => instant.checked_sub(duration).map(Timestamp::Monotonic),
+      #[cfg(feature = "std")]
+      Timestamp::WallClock(system_time) => {
+        system_time.checked_sub(duration).map(Timestamp::WallClock)
+      }
+      // A custom clock may start at 0, so the reading can't be moved back. The difference is tracked instead.
+      Timestamp::Custom {
+        clock,
+        reading,
+        offset,
+      } => Some(Timestamp::Custom {
+        clock: *clock,
+        reading: *reading,
+        offset: offset.checked_add(duration)?,
+      }),
+    }
+  }
+}
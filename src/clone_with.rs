@@ -0,0 +1,229 @@
+use crate::errors::TimeError;
+use crate::inner::{validate, InnerEventSync};
+use crate::{EventSync, Immutable, Mutable};
+use std::marker::PhantomData;
+use std::time::Duration;
+
+/// Options for cloning an EventSync, created with [`EventSync::clone_with()`](EventSync::clone_with).
+///
+/// Without any options, the clone is connected to the EventSync like one made with `clone()`.
+/// Offsetting, pausing, or dividing the clone gives it its own copy of the timeline instead, starting from where the
+/// EventSync is, so the changes stay local to it.
+/// Nothing done to either afterwards is shared with the other.
+///
+/// # Example
+///
+/// ```
+/// use event_sync::*;
+///
+/// let tickrate = 10; // 10ms between every tick.
+/// let event_sync = EventSync::from_starting_tick_millis(tickrate, 12, true);
+///
+/// // A paused copy, ticking every 4 ticks of the original, 2 of its own ticks ahead.
+/// let divided = event_sync
+///   .clone_with()
+///   .rate_divided(4)
+///   .offset_by_ticks(2)
+///   .paused()
+///   .build()
+///   .unwrap();
+///
+/// assert_eq!(divided.get_tickrate_millis(), 40);
+/// assert_eq!(divided.ticks_since_started(), 5);
+/// assert_eq!(event_sync.ticks_since_started(), 12);
+/// ```
+#[derive(Debug)]
+pub struct CloneWith<'a, T> {
+  event_sync: &'a EventSync<T>,
+  offset_ticks: u64,
+  rate_divisor: u64,
+  is_paused: bool,
+}
+
+impl<'a, T> CloneWith<'a, T> {
+  pub(crate) fn new(event_sync: &'a EventSync<T>) -> Self {
+    Self {
+      event_sync,
+      offset_ticks: 0,
+      rate_divisor: 1,
+      is_paused: false,
+    }
+  }
+
+  /// Moves the clone the given amount of its own ticks ahead of the EventSync.
+  pub fn offset_by_ticks(mut self, ticks: u64) -> Self {
+    self.offset_ticks = ticks;
+
+    self
+  }
+
+  /// Makes every tick of the clone last the given amount of the EventSync's ticks, like a clock divider.
+  ///
+  /// The divisor cannot go below 1. If 0 is passed in, 1 is used.
+  pub fn rate_divided(mut self, divisor: u32) -> Self {
+    self.rate_divisor = divisor.max(1).into();
+
+    self
+  }
+
+  /// Pauses the clone, leaving the EventSync running.
+  pub fn paused(mut self) -> Self {
+    self.is_paused = true;
+
+    self
+  }
+
+  /// Creates the clone, keeping the access of the EventSync.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if the tickrate or offset take the clone more than 100 years in.
+  /// - An error is returned if the offset is earlier than the clock can represent.
+  pub fn build(self) -> Result<EventSync<T>, TimeError> {
+    if !self.is_local() {
+      return Ok(EventSync {
+        inner: self.event_sync.inner.clone(),
+        stats: self.event_sync.stats.clone(),
+        driver: self.event_sync.driver.clone(),
+        mutable_handle: self.event_sync.mutable_handle.clone(),
+        change_access: PhantomData,
+      });
+    }
+
+    let inner = self.local_inner()?;
+
+    Ok(EventSync::from_inner(
+      inner,
+      self.event_sync.mutable_handle.is_some(),
+    ))
+  }
+
+  /// Creates the clone as an Immutable EventSync.
+  ///
+  /// # Errors
+  ///
+  /// - The same as [`build()`](CloneWith::build).
+  pub fn immutable(self) -> Result<EventSync<Immutable>, TimeError> {
+    self.build().map(|event_sync| event_sync.to_immutable())
+  }
+
+  /// Returns true if the clone needs its own copy of the timeline.
+  fn is_local(&self) -> bool {
+    self.offset_ticks != 0 || self.rate_divisor != 1 || self.is_paused
+  }
+
+  /// Copies the EventSync's timeline with the options applied.
+  fn local_inner(&self) -> Result<InnerEventSync, TimeError> {
+    let mut inner = self.event_sync.read_inner().clone();
+
+    if self.is_paused {
+      inner.pause();
+    }
+
+    let tickrate = inner.get_tickrate().saturating_mul(self.rate_divisor);
+    let time_since_started = self
+      .offset_ticks
+      .checked_mul(tickrate)
+      .map(Duration::from_millis)
+      .and_then(|offset| inner.time_since_started().checked_add(offset))
+      .ok_or(TimeError::InvalidState(
+        "more than 100 years have passed on it",
+      ))?;
+
+    validate(tickrate, time_since_started)?;
+
+    inner.change_tickrate(tickrate);
+
+    if self.offset_ticks != 0 {
+      inner.set_time_since_started(time_since_started)?;
+    }
+
+    Ok(inner)
+  }
+}
+
+impl<T> EventSync<T> {
+  /// Returns a [`CloneWith`](CloneWith) to clone this EventSync with options, such as offsetting or pausing the
+  /// clone, in one expression.
+  pub fn clone_with(&self) -> CloneWith<'_, T> {
+    CloneWith::new(self)
+  }
+
+  /// Returns a paused copy of this EventSync, frozen at the time that has passed and the tickrate it has now.
+  ///
+  /// The copy isn't connected to this EventSync, so it can be kept as a record of when something happened, and
+  /// inspected or unpaused later without affecting this one.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::*;
+  /// use std::time::Duration;
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let mut event_sync = EventSync::from_starting_tick_millis(tickrate, 7, false);
+  ///
+  /// let incident = event_sync.clone_detached_paused();
+  /// event_sync.sync_to(Duration::from_secs(1)).unwrap();
+  ///
+  /// assert!(incident.is_paused());
+  /// assert_eq!(incident.ticks_since_started(), 7);
+  /// assert!(!event_sync.is_paused());
+  /// ```
+  pub fn clone_detached_paused(&self) -> EventSync<Mutable> {
+    let mut inner = self.read_inner().clone();
+    inner.pause();
+
+    EventSync::from_inner(inner, true)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  #[test]
+  fn plain_clones_stay_connected() {
+    let mut event_sync = EventSync::new_millis(TEST_TICKRATE);
+    let clone: EventSync<Mutable> = event_sync.clone_with().build().unwrap();
+    let immutable_clone = event_sync.clone_with().immutable().unwrap();
+
+    event_sync.pause();
+
+    assert!(clone.is_paused());
+    assert!(immutable_clone.is_paused());
+  }
+
+  #[test]
+  fn local_changes_are_not_shared() {
+    let event_sync = EventSync::from_starting_tick_millis(TEST_TICKRATE, 3, true);
+    let mut offset = event_sync.clone_with().offset_by_ticks(4).build().unwrap();
+
+    assert_eq!(offset.ticks_since_started(), 7);
+
+    offset.unpause().unwrap();
+
+    assert!(event_sync.is_paused());
+    assert_eq!(event_sync.ticks_since_started(), 3);
+    assert!(event_sync
+      .clone_with()
+      .offset_by_ticks(u64::MAX)
+      .build()
+      .is_err());
+  }
+
+  #[test]
+  fn detached_copies_stay_frozen() {
+    let mut event_sync = EventSync::from_starting_tick_millis(TEST_TICKRATE, 5, true);
+    let mut frozen = event_sync.clone_immutable().clone_detached_paused();
+
+    event_sync.restart();
+    frozen.change_tickrate_millis(TEST_TICKRATE / 2);
+
+    assert!(frozen.is_paused());
+    assert_eq!(frozen.ticks_since_started(), 10);
+    assert_eq!(event_sync.get_tickrate_millis(), TEST_TICKRATE);
+  }
+}
@@ -0,0 +1,91 @@
+//! The commands shared by every remote control channel.
+
+use crate::{EventSync, Mutable};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// A request sent by a client to change or query a remote EventSync.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub(crate) enum ControlRequest {
+  Pause,
+  Unpause,
+  Restart,
+  ChangeTickrate(u64),
+  Status,
+}
+
+/// The answer to a [`ControlRequest`](ControlRequest).
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub(crate) enum ControlResponse {
+  Ok,
+  Status(ClockStatus),
+  Error(String),
+}
+
+/// The state of a remote EventSync, as reported by a control channel.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ClockStatus {
+  /// Whether the EventSync is paused.
+  pub is_paused: bool,
+  /// The tickrate in milliseconds.
+  pub tickrate: u64,
+  /// The amount of ticks that have passed.
+  pub ticks_since_started: u64,
+  /// The amount of time that has passed.
+  pub time_since_started: Duration,
+}
+
+impl ControlRequest {
+  /// Applies this request to the given EventSync, returning the response to send back.
+  pub(crate) fn apply(self, event_sync: &mut EventSync<Mutable>) -> ControlResponse {
+    match self {
+      ControlRequest::Pause => event_sync.pause(),
+      ControlRequest::Unpause => {
+        if let Err(error) = event_sync.unpause() {
+          return ControlResponse::Error(error.to_string());
+        }
+      }
+      ControlRequest::Restart => event_sync.restart(),
+      ControlRequest::ChangeTickrate(tickrate) => {
+        event_sync.change_tickrate_millis(tickrate);
+      }
+      ControlRequest::Status => {
+        return ControlResponse::Status(ClockStatus {
+          is_paused: event_sync.is_paused(),
+          tickrate: event_sync.get_tickrate_millis(),
+          ticks_since_started: event_sync.ticks_since_started(),
+          time_since_started: event_sync.time_since_started(),
+        })
+      }
+    }
+
+    ControlResponse::Ok
+  }
+}
+
+impl ControlResponse {
+  /// Converts the response into the result of a request that doesn't return a value.
+  pub(crate) fn into_unit(self) -> std::io::Result<()> {
+    match self {
+      ControlResponse::Ok => Ok(()),
+      ControlResponse::Status(_) => Err(unexpected_response()),
+      ControlResponse::Error(message) => Err(std::io::Error::other(message)),
+    }
+  }
+
+  /// Converts the response into the result of a status request.
+  pub(crate) fn into_status(self) -> std::io::Result<ClockStatus> {
+    match self {
+      ControlResponse::Status(status) => Ok(status),
+      ControlResponse::Ok => Err(unexpected_response()),
+      ControlResponse::Error(message) => Err(std::io::Error::other(message)),
+    }
+  }
+}
+
+fn unexpected_response() -> std::io::Error {
+  std::io::Error::new(
+    std::io::ErrorKind::InvalidData,
+    "Received an unexpected response from the server.",
+  )
+}
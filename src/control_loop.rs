@@ -0,0 +1,286 @@
+use crate::errors::TimeError;
+use crate::{EventSync, Immutable};
+use std::time::Duration;
+
+/// A loop running at a fixed rate on an EventSync's clock, keeping track of the deadlines it misses and how far off
+/// its wakes land, like ROS's `Rate` with diagnostics.
+///
+/// Periods are counted from when the EventSync started, so they stay in phase with its ticks, and with every other
+/// loop on the same EventSync running at the same rate.
+/// Pausing the EventSync holds the loop until it's unpaused, without counting the pause as missed deadlines.
+///
+/// # Example
+///
+/// ```
+/// use event_sync::*;
+///
+/// let tickrate = 10; // 10ms between every tick.
+/// let event_sync = EventSync::new_millis(tickrate);
+/// let mut control_loop = ControlLoop::new(&event_sync, 200.0).unwrap();
+///
+/// for _ in 0..10 {
+///   // Read sensors and drive the actuators.
+///
+///   control_loop.step();
+/// }
+///
+/// assert_eq!(control_loop.steps(), 10);
+/// assert!(control_loop.max_jitter() < std::time::Duration::from_secs(1));
+/// ```
+#[derive(Debug)]
+pub struct ControlLoop {
+  event_sync: EventSync<Immutable>,
+  period: Duration,
+  next_period: u64,
+  steps: u64,
+  missed_deadlines: u64,
+  total_jitter: Duration,
+  max_jitter: Duration,
+}
+
+impl ControlLoop {
+  /// Creates a loop running the given amount of times a second on the EventSync's clock.
+  ///
+  /// The first [`step()`](ControlLoop::step) sleeps until the start of the next period.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if the rate isn't a positive number, or is faster than once a nanosecond.
+  pub fn new<T>(event_sync: &EventSync<T>, rate_hz: f64) -> Result<Self, TimeError> {
+    if !(rate_hz.is_finite() && rate_hz > 0.0) {
+      return Err(TimeError::InvalidState("the rate isn't a positive number"));
+    }
+
+    let period = Duration::try_from_secs_f64(1.0 / rate_hz)
+      .ok()
+      .filter(|period| !period.is_zero())
+      .ok_or(TimeError::InvalidState(
+        "the rate is faster than a nanosecond",
+      ))?;
+
+    let mut control_loop = Self {
+      event_sync: event_sync.to_immutable(),
+      period,
+      next_period: 0,
+      steps: 0,
+      missed_deadlines: 0,
+      total_jitter: Duration::ZERO,
+      max_jitter: Duration::ZERO,
+    };
+    control_loop.next_period = period_at(control_loop.event_sync.time_since_started(), period) + 1;
+
+    Ok(control_loop)
+  }
+
+  /// Sleeps until the start of the next period, returning false if its deadline was already missed.
+  ///
+  /// When the work since the last step ran past the deadline, the periods that passed are counted as missed, and the
+  /// loop sleeps until the start of the next period still ahead, staying in phase rather than running late.
+  pub fn step(&mut self) -> bool {
+    let (deadline, met_deadline) = self.schedule_step(self.event_sync.time_since_started());
+
+    self
+      .event_sync
+      .sleep_until_elapsed_in_chunks(deadline, || false);
+
+    self.record_wake(deadline, self.event_sync.time_since_started());
+
+    met_deadline
+  }
+
+  /// Counts the deadlines missed by a step made at the given time since the EventSync started, returning when the
+  /// step should wake, and whether the deadline of the period it was for was met.
+  fn schedule_step(&mut self, now: Duration) -> (Duration, bool) {
+    let current_period = period_at(now, self.period);
+
+    // Moving the EventSync back starts counting periods again from where it was moved to.
+    let was_moved_back = current_period + 1 < self.next_period.saturating_sub(1);
+    let met_deadline = current_period < self.next_period;
+
+    if !met_deadline {
+      self.missed_deadlines += current_period + 1 - self.next_period;
+    }
+
+    if !met_deadline || was_moved_back {
+      self.next_period = current_period + 1;
+    }
+
+    (start_of(self.next_period, self.period), met_deadline)
+  }
+
+  /// Records a step waking at the given time since the EventSync started, for the deadline it was scheduled for.
+  fn record_wake(&mut self, deadline: Duration, woke_at: Duration) {
+    let jitter = woke_at.saturating_sub(deadline);
+    self.total_jitter += jitter;
+    self.max_jitter = self.max_jitter.max(jitter);
+    self.steps += 1;
+    self.next_period += 1;
+  }
+
+  /// Returns how long every period lasts.
+  pub fn period(&self) -> Duration {
+    self.period
+  }
+
+  /// Returns the amount of times [`step()`](ControlLoop::step) has been called.
+  pub fn steps(&self) -> u64 {
+    self.steps
+  }
+
+  /// Returns the amount of periods whose deadline passed before the loop got to them.
+  pub fn missed_deadlines(&self) -> u64 {
+    self.missed_deadlines
+  }
+
+  /// Returns the latest a step woke after the start of its period.
+  pub fn max_jitter(&self) -> Duration {
+    self.max_jitter
+  }
+
+  /// Returns how late after the start of their period steps woke on average.
+  pub fn average_jitter(&self) -> Duration {
+    self
+      .total_jitter
+      .checked_div(self.steps.min(u32::MAX as u64) as u32)
+      .unwrap_or_default()
+  }
+}
+
+/// Sleeps at a fixed rate on an EventSync's clock, for loops written in the `rate.sleep()` style of ROS.
+///
+/// Every sleep lasts until the start of the next period counted from when the EventSync started, rather than a
+/// fixed duration, so the time the work takes doesn't push the loop out of phase with the EventSync's ticks.
+/// Work that runs past a period skips to the start of the next one still ahead.
+///
+/// For counts of missed deadlines and jitter, use a [`ControlLoop`](ControlLoop).
+///
+/// Created with [`EventSync::rate()`](EventSync::rate).
+#[derive(Debug, Clone)]
+pub struct Rate {
+  event_sync: EventSync<Immutable>,
+  period: Duration,
+}
+
+impl Rate {
+  /// Sleeps until the start of the next period.
+  ///
+  /// While the EventSync is paused, the sleep carries on until it's unpaused.
+  pub fn sleep(&self) {
+    let next_period = period_at(self.event_sync.time_since_started(), self.period) + 1;
+
+    self
+      .event_sync
+      .sleep_until_elapsed_in_chunks(start_of(next_period, self.period), || false);
+  }
+
+  /// Returns how long every period lasts.
+  pub fn period(&self) -> Duration {
+    self.period
+  }
+}
+
+impl<T> EventSync<T> {
+  /// Creates a [`Rate`](Rate) sleeping the given amount of times a second, in phase with this EventSync's ticks.
+  ///
+  /// A rate that isn't a positive number is treated as once a second, and one faster than once a nanosecond is
+  /// treated as once a nanosecond.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::*;
+  /// use std::time::Duration;
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let event_sync = EventSync::new_millis(tickrate);
+  /// let rate = event_sync.rate(100.0);
+  ///
+  /// for _ in 0..3 {
+  ///   // Do the work.
+  ///   rate.sleep();
+  /// }
+  ///
+  /// assert!(event_sync.ticks_since_started() >= 3);
+  /// ```
+  pub fn rate(&self, rate_hz: f64) -> Rate {
+    let period = Duration::try_from_secs_f64(1.0 / rate_hz)
+      .unwrap_or(Duration::from_secs(1))
+      .max(Duration::from_nanos(1));
+
+    Rate {
+      event_sync: self.to_immutable(),
+      period,
+    }
+  }
+}
+
+/// Returns the period the given time since the EventSync started is in.
+fn period_at(time_since_started: Duration, period: Duration) -> u64 {
+  (time_since_started.as_nanos() / period.as_nanos()) as u64
+}
+
+/// Returns the time since the EventSync started that the period starts at.
+fn start_of(period: u64, period_length: Duration) -> Duration {
+  let nanos = period as u128 * period_length.as_nanos();
+
+  Duration::from_nanos(nanos.min(u64::MAX as u128) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  #[test]
+  fn overruns_skip_to_the_next_period() {
+    let mut event_sync = EventSync::from_starting_tick_millis(TEST_TICKRATE, 0, true);
+    let mut control_loop = ControlLoop::new(&event_sync, 100.0).unwrap();
+
+    assert_eq!(control_loop.next_period, 1);
+
+    // Work ran 3.5 periods past the deadline of the first period.
+    event_sync.sync_to(Duration::from_millis(45)).unwrap();
+
+    let (deadline, met_deadline) = control_loop.schedule_step(event_sync.time_since_started());
+
+    assert!(!met_deadline);
+    assert_eq!(deadline, Duration::from_millis(50));
+    assert_eq!(control_loop.missed_deadlines(), 4);
+
+    event_sync.sync_to(Duration::from_millis(52)).unwrap();
+    control_loop.record_wake(deadline, event_sync.time_since_started());
+
+    assert_eq!(control_loop.max_jitter(), Duration::from_millis(2));
+    assert_eq!(
+      control_loop.schedule_step(event_sync.time_since_started()),
+      (Duration::from_millis(60), true)
+    );
+    assert_eq!(control_loop.missed_deadlines(), 4);
+    assert_eq!(control_loop.steps(), 1);
+  }
+
+  #[test]
+  fn invalid_rates_are_rejected() {
+    let event_sync = EventSync::new_millis(TEST_TICKRATE);
+
+    assert!(ControlLoop::new(&event_sync, 0.0).is_err());
+    assert!(ControlLoop::new(&event_sync, f64::NAN).is_err());
+    assert!(ControlLoop::new(&event_sync, 1e12).is_err());
+
+    assert_eq!(event_sync.rate(-1.0).period(), Duration::from_secs(1));
+    assert_eq!(event_sync.rate(1e12).period(), Duration::from_nanos(1));
+  }
+
+  #[test]
+  fn rates_sleep_to_the_next_period_boundary() {
+    let event_sync = EventSync::new_millis(TEST_TICKRATE);
+    let rate = event_sync.rate(50.0);
+
+    std::thread::sleep(Duration::from_millis(7));
+    let period_before = period_at(event_sync.time_since_started(), rate.period());
+    rate.sleep();
+
+    assert!(period_at(event_sync.time_since_started(), rate.period()) > period_before);
+  }
+}
@@ -0,0 +1,128 @@
+//! Calendar-aware constructors and accessors through [`chrono`](https://docs.rs/chrono).
+//!
+//! Enabled with the `chrono` feature.
+
+use crate::clock::SystemTime;
+use crate::{EventSync, Mutable, TimeError};
+use chrono::{DateTime, Utc};
+
+impl EventSync<Mutable> {
+  /// Creates a new instance of [`EventSync`](EventSync) where tick 0 occurred at the given point in time.
+  ///
+  /// The time that's passed between `start` and now is applied to the EventSync as if it had been running since then.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if `start` hasn't happened yet.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use chrono::{TimeDelta, Utc};
+  /// use event_sync::*;
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let start = Utc::now() - TimeDelta::milliseconds(30); // Start 30ms ago.
+  /// let event_sync = EventSync::start_at_millis(tickrate, start).unwrap();
+  ///
+  /// assert_eq!(event_sync.ticks_since_started(), 3);
+  /// ```
+  pub fn start_at_millis(
+    tickrate_in_milliseconds: u64,
+    start: DateTime<Utc>,
+  ) -> Result<Self, TimeError> {
+    let elapsed_time = (Utc::now() - start)
+      .to_std()
+      .map_err(|_| TimeError::StartTimeInFuture)?;
+
+    Ok(Self::from_starting_time_millis(
+      tickrate_in_milliseconds,
+      elapsed_time,
+      false,
+    ))
+  }
+
+  /// [`start_at_millis()`](EventSync::start_at_millis), taking the tickrate as a `u32`.
+  #[deprecated(
+    since = "0.4.5",
+    note = "use `start_at_millis()`, as tickrates are `u64` milliseconds"
+  )]
+  pub fn start_at(tickrate_in_milliseconds: u32, start: DateTime<Utc>) -> Result<Self, TimeError> {
+    Self::start_at_millis(tickrate_in_milliseconds.into(), start)
+  }
+}
+
+impl<T> EventSync<T> {
+  /// Returns the point in time at which the given tick occurs, or occurred.
+  ///
+  /// Returns None if the EventSync is paused, or the time cannot be represented.
+  /// See [`system_time_of_tick()`](EventSync::system_time_of_tick) for more details.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use chrono::{TimeDelta, Utc};
+  /// use event_sync::*;
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let start = Utc::now() - TimeDelta::seconds(1);
+  /// let event_sync = EventSync::start_at_millis(tickrate, start).unwrap();
+  ///
+  /// let tick_zero = event_sync.datetime_of_tick(0).unwrap();
+  ///
+  /// assert!((tick_zero - start).abs() < TimeDelta::milliseconds(2));
+  /// ```
+  pub fn datetime_of_tick(&self, tick: u64) -> Option<DateTime<Utc>> {
+    self.system_time_of_tick(tick).and_then(to_datetime)
+  }
+
+  /// Returns the point in time at which the next tick will occur.
+  ///
+  /// Returns None if the EventSync is paused.
+  pub fn next_tick_datetime(&self) -> Option<DateTime<Utc>> {
+    self.next_tick_system_time().and_then(to_datetime)
+  }
+}
+
+/// Converts the time through the unix epoch, as the wall clock may not be std's [`SystemTime`](std::time::SystemTime).
+fn to_datetime(system_time: SystemTime) -> Option<DateTime<Utc>> {
+  let since_epoch = system_time.duration_since(SystemTime::UNIX_EPOCH).ok()?;
+
+  DateTime::UNIX_EPOCH.checked_add_signed(chrono::TimeDelta::from_std(since_epoch).ok()?)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use chrono::TimeDelta;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  #[test]
+  fn start_at_in_future_errors() {
+    let start = Utc::now() + TimeDelta::seconds(10);
+
+    assert_eq!(
+      EventSync::start_at_millis(TEST_TICKRATE, start),
+      Err(TimeError::StartTimeInFuture)
+    );
+  }
+
+  #[test]
+  fn datetime_of_tick_logic() {
+    let start = Utc::now() - TimeDelta::milliseconds(50);
+    let event_sync = EventSync::start_at_millis(TEST_TICKRATE, start).unwrap();
+
+    let tick_ten = event_sync.datetime_of_tick(10).unwrap();
+    let difference = tick_ten - (start + TimeDelta::milliseconds(100));
+
+    assert!(difference.abs() < TimeDelta::milliseconds(2));
+  }
+
+  #[test]
+  fn next_tick_datetime_is_none_when_paused() {
+    let event_sync = EventSync::new_paused_millis(TEST_TICKRATE);
+
+    assert!(event_sync.next_tick_datetime().is_none());
+  }
+}
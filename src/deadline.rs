@@ -0,0 +1,246 @@
+use crate::errors::TimeError;
+use crate::{EventSync, Immutable};
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+/// A point on an EventSync's timeline, stored as the tick it occurs on.
+///
+/// Unlike a raw tick number, a deadline states what it means, and can be checked or waited on against the
+/// EventSync it was made from.
+///
+/// # Example
+///
+/// ```
+/// use event_sync::*;
+///
+/// let tickrate = 10; // 10ms between every tick.
+/// let event_sync = EventSync::new_millis(tickrate);
+///
+/// let deadline = event_sync.deadline_in(2);
+///
+/// assert!(!deadline.has_passed(&event_sync));
+///
+/// deadline.wait(&event_sync).unwrap();
+///
+/// assert!(deadline.has_passed(&event_sync));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct TickDeadline {
+  tick: u64,
+}
+
+/// The result of running a closure with [`EventSync::run_with_tick_deadline()`](EventSync::run_with_tick_deadline).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeadlineOutcome<R> {
+  /// What the closure returned.
+  pub value: R,
+  /// Whether the closure returned before the deadline tick occurred.
+  pub met_deadline: bool,
+  /// The amount of ticks that passed while the closure ran.
+  pub ticks_taken: u64,
+}
+
+/// Set once the deadline of [`EventSync::run_with_cancellable_tick_deadline()`](EventSync::run_with_cancellable_tick_deadline)
+/// has passed, for the closure to check and stop early.
+#[derive(Debug, Default)]
+pub struct CancellationFlag {
+  cancelled: AtomicBool,
+}
+
+impl CancellationFlag {
+  /// Returns true once the deadline has passed.
+  pub fn is_cancelled(&self) -> bool {
+    self.cancelled.load(Ordering::Acquire)
+  }
+
+  fn cancel(&self) {
+    self.cancelled.store(true, Ordering::Release);
+  }
+}
+
+impl TickDeadline {
+  /// Creates a deadline at the given absolute tick.
+  pub fn at_tick(tick: u64) -> Self {
+    Self { tick }
+  }
+
+  /// Creates a deadline at the first tick on or after the given time since the EventSync started.
+  pub fn at_time(time_since_started: Duration, tickrate_in_milliseconds: u64) -> Self {
+    let tickrate = tickrate_in_milliseconds.max(1) as u128;
+
+    Self {
+      tick: time_since_started.as_millis().div_ceil(tickrate) as u64,
+    }
+  }
+
+  /// Returns the tick the deadline occurs on.
+  pub fn tick(&self) -> u64 {
+    self.tick
+  }
+
+  /// Returns true once the deadline's tick has occurred on the EventSync.
+  pub fn has_passed<T>(&self, event_sync: &EventSync<T>) -> bool {
+    event_sync.ticks_since_started() >= self.tick
+  }
+
+  /// Returns the time left until the deadline's tick, or 0 if it has passed.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if the EventSync is paused before reaching the deadline.
+  pub fn remaining<T>(&self, event_sync: &EventSync<T>) -> Result<Duration, TimeError> {
+    if self.has_passed(event_sync) {
+      return Ok(Duration::ZERO);
+    }
+
+    match event_sync.read_inner().time_until_tick_occurs(self.tick) {
+      Err(TimeError::ThatTimeHasAlreadyHappened) => Ok(Duration::ZERO),
+      result => result,
+    }
+  }
+
+  /// Waits until the deadline's tick, returning immediately if it has passed.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if the EventSync is paused before reaching the deadline.
+  pub fn wait<T>(&self, event_sync: &EventSync<T>) -> Result<(), TimeError> {
+    std::thread::sleep(self.remaining(event_sync)?);
+
+    Ok(())
+  }
+}
+
+/// Runs the closure, measuring whether it finished before the deadline tick.
+pub(crate) fn run_with_deadline<R, F: FnOnce() -> R>(
+  event_sync: &EventSync<Immutable>,
+  n_ticks: u32,
+  closure: F,
+) -> DeadlineOutcome<R> {
+  let starting_tick = event_sync.ticks_since_started();
+  let deadline_tick = starting_tick + n_ticks as u64;
+
+  let value = closure();
+
+  let ending_tick = event_sync.ticks_since_started();
+
+  DeadlineOutcome {
+    value,
+    met_deadline: ending_tick < deadline_tick,
+    ticks_taken: ending_tick.saturating_sub(starting_tick),
+  }
+}
+
+/// Runs the closure while a separate thread sets the flag once the deadline tick occurs.
+pub(crate) fn run_with_cancellable_deadline<R, F: FnOnce(&CancellationFlag) -> R>(
+  event_sync: &EventSync<Immutable>,
+  n_ticks: u32,
+  closure: F,
+) -> DeadlineOutcome<R> {
+  let deadline_tick = event_sync.ticks_since_started() + n_ticks as u64;
+  let flag = CancellationFlag::default();
+  let finished = AtomicBool::new(false);
+
+  std::thread::scope(|scope| {
+    let watcher = scope.spawn(|| watch_deadline(event_sync, deadline_tick, &flag, &finished));
+
+    let outcome = run_with_deadline(event_sync, n_ticks, || closure(&flag));
+
+    finished.store(true, Ordering::Release);
+    watcher.thread().unpark();
+
+    outcome
+  })
+}
+
+/// Sets the flag once the deadline tick occurs, returning early if the closure finishes first.
+///
+/// The deadline is re-checked at least every tick, so pausing or changing the tickrate is followed.
+fn watch_deadline(
+  event_sync: &EventSync<Immutable>,
+  deadline_tick: u64,
+  flag: &CancellationFlag,
+  finished: &AtomicBool,
+) {
+  while !finished.load(Ordering::Acquire) {
+    let inner = event_sync.read_inner();
+    let tickrate = Duration::from_millis(inner.get_tickrate());
+    let time_until_deadline = inner.time_until_tick_occurs(deadline_tick);
+    drop(inner);
+
+    match time_until_deadline {
+      Err(TimeError::ThatTimeHasAlreadyHappened) => {
+        flag.cancel();
+
+        return;
+      }
+      Ok(wait_time) => std::thread::park_timeout(wait_time.min(tickrate)),
+      Err(_) => std::thread::park_timeout(tickrate),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  #[test]
+  fn deadline_rounds_time_up_to_a_tick() {
+    let deadline = TickDeadline::at_time(Duration::from_millis(25), TEST_TICKRATE);
+
+    assert_eq!(deadline.tick(), 3);
+    assert_eq!(
+      TickDeadline::at_time(Duration::from_millis(20), TEST_TICKRATE).tick(),
+      2
+    );
+  }
+
+  #[test]
+  fn passed_deadlines_have_no_time_remaining() {
+    let event_sync = EventSync::from_starting_tick_millis(TEST_TICKRATE, 5, true);
+    let deadline = TickDeadline::at_tick(3);
+
+    assert!(deadline.has_passed(&event_sync));
+    assert_eq!(deadline.remaining(&event_sync), Ok(Duration::ZERO));
+    assert_eq!(
+      TickDeadline::at_tick(6).remaining(&event_sync),
+      Err(TimeError::EventSyncPaused)
+    );
+  }
+
+  #[test]
+  fn slow_closures_miss_the_deadline() {
+    let event_sync = EventSync::new_millis(TEST_TICKRATE);
+
+    let outcome = event_sync.run_with_tick_deadline(1, || event_sync.wait_for_x_ticks(2));
+
+    assert!(!outcome.met_deadline);
+    assert!(outcome.ticks_taken >= 2);
+  }
+
+  #[test]
+  fn flag_is_set_when_the_deadline_passes() {
+    let event_sync = EventSync::new_millis(TEST_TICKRATE);
+
+    let outcome = event_sync.run_with_cancellable_tick_deadline(2, |flag| {
+      while !flag.is_cancelled() {
+        std::thread::yield_now();
+      }
+    });
+
+    assert!(!outcome.met_deadline);
+  }
+
+  #[test]
+  fn fast_closures_are_not_cancelled() {
+    let event_sync = EventSync::new_millis(TEST_TICKRATE * 10);
+
+    let outcome = event_sync.run_with_cancellable_tick_deadline(5, |flag| flag.is_cancelled());
+
+    assert!(outcome.met_deadline);
+    assert!(!outcome.value);
+  }
+}
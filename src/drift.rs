@@ -0,0 +1,161 @@
+use crate::clock::SystemTime;
+use crate::errors::TimeError;
+use crate::EventSync;
+use std::time::Duration;
+
+/// How far the time on an EventSync has drifted from the wall clock.
+///
+/// Drift is measured since the EventSync first started running, over the time it spent running.
+/// Time spent paused, and changes made to the time such as by restarting or syncing it, aren't counted as drift.
+///
+/// Created with [`EventSync::drift_report()`](EventSync::drift_report).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DriftReport {
+  /// The wall clock time drift is measured since, when the EventSync first started running.
+  pub measured_since: SystemTime,
+  /// The time that passed on the wall clock while the EventSync was running.
+  pub wall_clock_elapsed: Duration,
+  /// The time that passed on the EventSync while it was running, leaving out any changes made to its time.
+  pub event_sync_elapsed: Duration,
+}
+
+impl DriftReport {
+  /// Returns the total difference between the time passed on the EventSync and on the wall clock.
+  pub fn drift(&self) -> Duration {
+    self.event_sync_elapsed.abs_diff(self.wall_clock_elapsed)
+  }
+
+  /// Returns true if more time has passed on the EventSync than on the wall clock.
+  pub fn is_ahead(&self) -> bool {
+    self.event_sync_elapsed > self.wall_clock_elapsed
+  }
+
+  /// Returns the rate of drift in parts per million, positive if the EventSync is ahead of the wall clock.
+  ///
+  /// 0 is returned if no time has passed on the wall clock.
+  pub fn drift_rate_ppm(&self) -> f64 {
+    if self.wall_clock_elapsed.is_zero() {
+      return 0.0;
+    }
+
+    let drift = self.drift().as_secs_f64() / self.wall_clock_elapsed.as_secs_f64() * 1_000_000.0;
+
+    if self.is_ahead() {
+      drift
+    } else {
+      -drift
+    }
+  }
+}
+
+impl<T> EventSync<T> {
+  /// Compares the time that has passed on the EventSync with the time that passed on the wall clock
+  /// ([`SystemTime`](std::time::SystemTime)).
+  ///
+  /// Over long runs this shows whether the clock and tick math are diverging from real time.
+  /// Drift is measured since the EventSync first started running, leaving out the time spent paused and any
+  /// changes made to its time, such as by restarting or syncing it.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if the EventSync is paused.
+  /// - An error is returned if the wall clock has gone back to before the EventSync was last unpaused, or last had
+  ///   its time changed.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::*;
+  /// use std::time::Duration;
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let event_sync = EventSync::new_millis(tickrate);
+  ///
+  /// event_sync.wait_for_x_ticks(5).unwrap();
+  ///
+  /// let report = event_sync.drift_report().unwrap();
+  ///
+  /// assert!(report.drift() < Duration::from_millis(10));
+  /// ```
+  pub fn drift_report(&self) -> Result<DriftReport, TimeError> {
+    let inner = self.read_inner();
+    inner.err_if_paused()?;
+
+    let totals = inner.drift_totals();
+    let (Some(measured_since), Some(anchor)) = (totals.measured_since, totals.current) else {
+      return Err(TimeError::EventSyncPaused);
+    };
+    let event_sync_elapsed = inner
+      .try_time_since_started()?
+      .saturating_sub(anchor.time_since_started);
+    let wall_clock_elapsed = anchor
+      .system_time
+      .elapsed()
+      .map_err(|_| TimeError::SystemTimeWentBackwards)?;
+
+    Ok(DriftReport {
+      measured_since,
+      wall_clock_elapsed: totals.wall_clock_elapsed + wall_clock_elapsed,
+      event_sync_elapsed: totals.event_sync_elapsed + event_sync_elapsed,
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  #[test]
+  fn drift_adds_up_over_pauses_and_time_changes() {
+    let started_at = SystemTime::now();
+    let mut event_sync = EventSync::from_starting_tick_millis(TEST_TICKRATE, 100, false);
+    let measured_since = event_sync.drift_report().unwrap().measured_since;
+
+    std::thread::sleep(Duration::from_millis(TEST_TICKRATE * 2));
+    event_sync
+      .sync_to(Duration::from_millis(TEST_TICKRATE * 200))
+      .unwrap();
+    event_sync.pause();
+    std::thread::sleep(Duration::from_millis(TEST_TICKRATE * 5));
+    event_sync.unpause().unwrap();
+    std::thread::sleep(Duration::from_millis(TEST_TICKRATE * 2));
+
+    let report = event_sync.drift_report().unwrap();
+    let wall_clock_elapsed = started_at.elapsed().unwrap();
+
+    assert_eq!(report.measured_since, measured_since);
+    // Both stretches of running are counted, but not the pause.
+    assert!(report.wall_clock_elapsed >= Duration::from_millis(TEST_TICKRATE * 4));
+    assert!(
+      report.wall_clock_elapsed < wall_clock_elapsed - Duration::from_millis(TEST_TICKRATE * 4)
+    );
+    assert!(report.drift() < Duration::from_millis(TEST_TICKRATE));
+  }
+
+  #[test]
+  fn paused_event_syncs_have_no_report() {
+    let mut event_sync = EventSync::new_millis(TEST_TICKRATE);
+    event_sync.pause();
+
+    assert_eq!(event_sync.drift_report(), Err(TimeError::EventSyncPaused));
+
+    event_sync.unpause().unwrap();
+
+    assert!(event_sync.drift_report().is_ok());
+  }
+
+  #[test]
+  fn drift_rate_is_signed() {
+    let report = DriftReport {
+      measured_since: SystemTime::UNIX_EPOCH,
+      wall_clock_elapsed: Duration::from_secs(1000),
+      event_sync_elapsed: Duration::from_millis(999_999),
+    };
+
+    assert_eq!(report.drift(), Duration::from_millis(1));
+    assert!(!report.is_ahead());
+    assert!((report.drift_rate_ppm() + 1.0).abs() < 1e-6);
+  }
+}
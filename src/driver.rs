@@ -0,0 +1,719 @@
+//! A single background thread per EventSync, notifying registered listeners and ordered waiters of every tick.
+
+use crate::clock::{ClockSource, Instant};
+use crate::orphan::Orphaning;
+use crate::sync::atomic::{AtomicU64, Ordering};
+use crate::sync::{thread, Arc, Condvar, Mutex, MutexGuard};
+use crate::view::EventSyncView;
+use crate::waiting::WaitTarget;
+use crate::{EventSync, Immutable, Mutable};
+use std::sync::OnceLock;
+use std::time::Duration;
+
+/// Something notified of every tick by an EventSync's driver thread.
+///
+/// Closures taking the tick implement this, so most listeners don't need their own type.
+pub trait TickListener: Send {
+  /// Called on the driver thread once for every tick that occurs.
+  ///
+  /// Every listener is called before the driver moves on to the next tick, so a slow listener delays the rest.
+  /// Listeners are called in the order of their [`TickPhase`](TickPhase).
+  fn on_tick(&mut self, tick: u64);
+}
+
+impl<F: FnMut(u64) + Send> TickListener for F {
+  fn on_tick(&mut self, tick: u64) {
+    self(tick)
+  }
+}
+
+/// The stage of a tick a listener runs in.
+///
+/// Every listener in a phase finishes before any listener in a later phase is called, so multi-stage pipelines run
+/// in the same order every tick. Within a phase, listeners are called in the order they were added.
+///
+/// The phases are spaced apart so custom ones can be placed between them, such as `TickPhase(150)` to run after
+/// [`UPDATE`](TickPhase::UPDATE) but before [`OUTPUT`](TickPhase::OUTPUT).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TickPhase(pub u32);
+
+impl TickPhase {
+  /// For gathering input, before anything is updated.
+  pub const INPUT: Self = Self(0);
+  /// The phase listeners are added to by default.
+  pub const UPDATE: Self = Self(100);
+  /// For presenting the results of the tick, after everything is updated.
+  pub const OUTPUT: Self = Self(200);
+}
+
+impl Default for TickPhase {
+  fn default() -> Self {
+    Self::UPDATE
+  }
+}
+
+/// The order threads waiting for the same tick wake up in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum WakeOrder {
+  /// Every waiting thread sleeps on its own, waking in whatever order the OS schedules them.
+  #[default]
+  Unordered,
+
+  /// Threads waiting for the same tick wake one at a time, in the order they started waiting.
+  ///
+  /// Waits are woken by the driver thread, and always wait for the tick with the number they started waiting for.
+  Fifo,
+}
+
+/// Identifies a listener added with [`EventSync::add_listener()`](EventSync::add_listener), to remove it later.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ListenerId(u64);
+
+/// The listeners of an EventSync and all of its clones, along with the state of the thread calling them.
+#[derive(Default)]
+pub(crate) struct Driver {
+  registry: Mutex<Registry>,
+  /// Notified on every tick, and whenever an ordered waiter wakes up.
+  tick_occurred: Condvar,
+  /// The tick the EventSync was on when the driver thread last saw one occur, plus 1, or 0 while there isn't one.
+  ///
+  /// Only set while holding the EventSync's lock, and cleared whenever the EventSync is changed, so it never comes
+  /// from before a pause, restart, or tickrate change.
+  cached_tick: AtomicU64,
+  /// When the tick after the cached one occurs, in nanoseconds since [`nanos_since_epoch()`](nanos_since_epoch)
+  /// started counting, or 0 while that isn't known. The cached tick is stale from then on, even if the driver thread
+  /// hasn't seen the next tick yet, such as while slow listeners are being called.
+  ///
+  /// Only ever earlier than or the same as the deadline of the cached tick it's read with.
+  cached_tick_deadline: AtomicU64,
+  /// Whether the EventSync pauses once no Mutable EventSyncs are left, and who is told when it does.
+  pub(crate) orphaning: Orphaning,
+}
+
+struct Listener {
+  id: ListenerId,
+  phase: TickPhase,
+  listener: Box<dyn TickListener>,
+}
+
+#[derive(Default)]
+struct Registry {
+  /// Kept sorted by phase, then in the order they were added, which is the order they're called in.
+  listeners: Vec<Listener>,
+  next_id: u64,
+  is_running: bool,
+  /// While dispatching, the listeners being called are taken out of the registry so they can add or remove
+  /// listeners themselves. Removals of those are applied once dispatching is done.
+  dispatching: Vec<ListenerId>,
+  pending_removals: Vec<ListenerId>,
+  wake_order: WakeOrder,
+  /// The tick each ordered waiter is waiting for and when it started waiting, in the order they wake up in.
+  waiters: Vec<(u64, u64)>,
+  next_waiter: u64,
+}
+
+impl Registry {
+  /// Adds the listener after every other listener in the same or an earlier phase.
+  fn insert(&mut self, listener: Listener) {
+    let index = self
+      .listeners
+      .partition_point(|existing| existing.phase <= listener.phase);
+
+    self.listeners.insert(index, listener);
+  }
+
+  /// Adds the waiter after every waiter for the same or an earlier tick that started waiting before it.
+  fn insert_waiter(&mut self, waiter: (u64, u64)) {
+    let index = self.waiters.partition_point(|other| *other <= waiter);
+
+    self.waiters.insert(index, waiter);
+  }
+}
+
+impl Driver {
+  /// Returns the tick the driver thread last saw occur, if it's keeping track of them.
+  ///
+  /// None is returned once the next tick is due, so the tick is never more out of date than the time it takes to read.
+  pub(crate) fn cached_tick(&self) -> Option<u64> {
+    let cached_tick = self.cached_tick.load(Ordering::Acquire);
+    let deadline = self.cached_tick_deadline.load(Ordering::Acquire);
+
+    if cached_tick == 0 || nanos_since_epoch(Instant::now()) >= deadline {
+      return None;
+    }
+
+    // A tick refreshed in between could have been paired with the deadline of the next one.
+    (self.cached_tick.load(Ordering::Acquire) == cached_tick).then(|| cached_tick - 1)
+  }
+
+  /// Forgets the cached tick, for when the EventSync is changed or the driver thread stops.
+  pub(crate) fn clear_cached_tick(&self) {
+    self.cached_tick.store(0, Ordering::Release);
+    self.cached_tick_deadline.store(0, Ordering::Release);
+  }
+
+  /// Moves the cached tick forward to the tick, if the driver thread is keeping one.
+  fn raise_cached_tick(&self, tick: u64) {
+    let _ = self
+      .cached_tick
+      .fetch_update(Ordering::AcqRel, Ordering::Acquire, |cached_tick| {
+        (cached_tick != 0).then_some(cached_tick.max(tick.saturating_add(1)))
+      });
+  }
+
+  /// Calls every listener with the tick, returning false if there were none to call.
+  fn dispatch(&self, tick: u64) -> bool {
+    let mut listeners = {
+      let mut registry = self.registry.lock().unwrap();
+
+      if registry.listeners.is_empty() {
+        return false;
+      }
+
+      registry.dispatching = registry.listeners.iter().map(|entry| entry.id).collect();
+      std::mem::take(&mut registry.listeners)
+    };
+
+    // Listeners are sorted by phase, so calling them in order finishes each phase before the next.
+    for entry in &mut listeners {
+      entry.listener.on_tick(tick);
+    }
+
+    let mut registry = self.registry.lock().unwrap();
+    let pending_removals = std::mem::take(&mut registry.pending_removals);
+
+    registry.dispatching.clear();
+    listeners.retain(|entry| !pending_removals.contains(&entry.id));
+
+    // Listeners added while dispatching go after the existing ones in their phase.
+    let added = std::mem::replace(&mut registry.listeners, listeners);
+
+    for listener in added {
+      registry.insert(listener);
+    }
+
+    true
+  }
+
+  /// Marks the driver thread as stopped if there's nothing left for it to do, returning true if it should stop.
+  ///
+  /// `references` is the amount of EventSyncs connected to the driver, including the one on the driver thread.
+  fn stop_if_unused(&self, references: usize) -> bool {
+    let mut registry = self.registry.lock().unwrap();
+
+    let is_unused = registry.listeners.is_empty() && registry.waiters.is_empty();
+
+    if is_unused || references == 1 {
+      registry.is_running = false;
+      registry.listeners.clear();
+      self.clear_cached_tick();
+
+      return true;
+    }
+
+    false
+  }
+}
+
+impl<T> EventSync<T> {
+  /// Registers a listener to be called with every tick that occurs, returning an id to remove it with.
+  ///
+  /// Every listener on an EventSync and its clones is called from one shared background thread, in the order they
+  /// were added. The thread is started with the first listener, and stops once there are none left or every
+  /// EventSync connected to this one has been dropped. A listener holding a clone of the EventSync keeps it alive.
+  ///
+  /// Ticks that occur while a listener is running are still delivered, one after another.
+  /// While paused, no ticks occur, so listeners aren't called.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::*;
+  /// use std::sync::mpsc;
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let event_sync = EventSync::new_millis(tickrate);
+  /// let (sender, receiver) = mpsc::channel();
+  ///
+  /// let listener = event_sync.add_listener(Box::new(move |tick| {
+  ///   let _ = sender.send(tick);
+  /// }));
+  ///
+  /// assert_eq!(receiver.recv().unwrap(), 1);
+  /// assert_eq!(receiver.recv().unwrap(), 2);
+  ///
+  /// assert!(event_sync.remove_listener(listener));
+  /// ```
+  pub fn add_listener(&self, listener: Box<dyn TickListener>) -> ListenerId {
+    self.add_listener_in_phase(TickPhase::default(), listener)
+  }
+
+  /// [`add_listener()`](EventSync::add_listener), calling the listener in the given phase of every tick.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::*;
+  /// use std::sync::mpsc;
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let event_sync = EventSync::new_millis(tickrate);
+  /// let (sender, receiver) = mpsc::channel();
+  ///
+  /// let output_sender = sender.clone();
+  /// event_sync.add_listener_in_phase(
+  ///   TickPhase::OUTPUT,
+  ///   Box::new(move |_| {
+  ///     let _ = output_sender.send("output");
+  ///   }),
+  /// );
+  /// event_sync.add_listener_in_phase(
+  ///   TickPhase::INPUT,
+  ///   Box::new(move |_| {
+  ///     let _ = sender.send("input");
+  ///   }),
+  /// );
+  ///
+  /// assert_eq!(receiver.recv().unwrap(), "input");
+  /// assert_eq!(receiver.recv().unwrap(), "output");
+  /// ```
+  pub fn add_listener_in_phase(
+    &self,
+    phase: TickPhase,
+    listener: Box<dyn TickListener>,
+  ) -> ListenerId {
+    let mut registry = self.driver.registry.lock().unwrap();
+    let id = ListenerId(registry.next_id);
+
+    registry.next_id += 1;
+    registry.insert(Listener {
+      id,
+      phase,
+      listener,
+    });
+    self.start_driver(&mut registry);
+
+    id
+  }
+
+  /// Unregisters a listener, returning true if it was registered.
+  ///
+  /// A listener removed while it's being called finishes that call, and isn't called again.
+  pub fn remove_listener(&self, id: ListenerId) -> bool {
+    let mut registry = self.driver.registry.lock().unwrap();
+
+    if let Some(index) = registry.listeners.iter().position(|entry| entry.id == id) {
+      registry.listeners.remove(index);
+
+      return true;
+    }
+
+    if registry.dispatching.contains(&id) && !registry.pending_removals.contains(&id) {
+      registry.pending_removals.push(id);
+
+      return true;
+    }
+
+    false
+  }
+
+  /// Returns the amount of listeners currently registered.
+  pub fn listener_count(&self) -> usize {
+    let registry = self.driver.registry.lock().unwrap();
+
+    registry.listeners.len()
+  }
+
+  /// Returns the order threads waiting for the same tick wake up in.
+  pub fn wake_order(&self) -> WakeOrder {
+    self.driver.registry.lock().unwrap().wake_order
+  }
+
+  /// Sleeps until the given tick occurs, by the current [`WakeOrder`](WakeOrder).
+  pub(crate) fn sleep_until_tick_occurs(&self, tick: u64, target: WaitTarget) {
+    self.record_wait(|| {
+      let tick = if self.wake_order() == WakeOrder::Unordered {
+        self
+          .sleep_until_tick_in_chunks(tick, target, || false)
+          .map_or(tick, |(tick, _)| tick)
+      } else {
+        self.wait_in_order(tick, target)
+      };
+
+      let view = {
+        let inner = self.read_inner();
+        let view = EventSyncView::from_inner(&inner);
+
+        // Saves readers of the cache waiting on the driver thread to see the tick this wait just saw.
+        self.driver.raise_cached_tick(view.tick);
+
+        view
+      };
+      let tick_time = Duration::from_millis(tick.saturating_mul(view.tickrate));
+
+      view.time_since_started.saturating_sub(tick_time)
+    });
+  }
+
+  /// Waits for the driver thread to reach the tick, and for every thread ahead of this one to wake first.
+  ///
+  /// Like [`sleep_until_tick_in_chunks()`](EventSync::sleep_until_tick_in_chunks), the target decides what changes to
+  /// the timeline mean for the tick waited for, which is returned once it's reached.
+  fn wait_in_order(&self, mut tick: u64, target: WaitTarget) -> u64 {
+    let mut registry = self.driver.registry.lock().unwrap();
+    let order = registry.next_waiter;
+
+    registry.next_waiter += 1;
+    registry.insert_waiter((tick, order));
+    self.start_driver(&mut registry);
+
+    let mut last_seen = None;
+
+    loop {
+      let (current_tick, tickrate, generation) = {
+        let inner = self.read_inner();
+
+        (
+          inner.ticks_since_started(),
+          inner.get_tickrate(),
+          inner.generation(),
+        )
+      };
+
+      if let Some((last_tick, last_tickrate, last_generation)) = last_seen {
+        let was_moved =
+          tickrate != last_tickrate || generation != last_generation || current_tick < last_tick;
+
+        if target == WaitTarget::Relative && was_moved {
+          let ticks_left = tick.saturating_sub(last_tick).max(1);
+
+          // Waiters are kept sorted by tick, so the new one takes its place among them.
+          registry.waiters.retain(|(_, other)| *other != order);
+          tick = current_tick + ticks_left;
+          registry.insert_waiter((tick, order));
+
+          // A waiter behind this one could be first now.
+          self.driver.tick_occurred.notify_all();
+        }
+      }
+
+      if registry.waiters.first() == Some(&(tick, order)) && current_tick >= tick {
+        break;
+      }
+
+      last_seen = Some((current_tick, tickrate, generation));
+      registry = self.driver.tick_occurred.wait(registry).unwrap();
+    }
+
+    registry.waiters.remove(0);
+    drop(registry);
+
+    // Lets the next waiter check if it's its turn.
+    self.driver.tick_occurred.notify_all();
+
+    tick
+  }
+
+  /// Starts the driver thread if it isn't already running.
+  fn start_driver(&self, registry: &mut MutexGuard<'_, Registry>) {
+    if !registry.is_running {
+      registry.is_running = true;
+
+      let event_sync = self.to_immutable();
+      thread::spawn(move || event_sync.drive_listeners());
+    }
+  }
+}
+
+impl EventSync<Mutable> {
+  /// Sets the order threads waiting for the same tick wake up in, for this EventSync and all of its clones.
+  ///
+  /// Applies to [`wait_until()`](EventSync::wait_until), [`wait_for_tick()`](EventSync::wait_for_tick),
+  /// [`wait_for_x_ticks()`](EventSync::wait_for_x_ticks), and everything built on them.
+  /// Waits already in progress keep the order they started with.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::*;
+  /// use std::sync::{Arc, Mutex};
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let mut event_sync = EventSync::new_millis(tickrate);
+  /// event_sync.set_wake_order(WakeOrder::Fifo);
+  ///
+  /// let woken = Arc::new(Mutex::new(Vec::new()));
+  /// let handles: Vec<_> = (0..3)
+  ///   .map(|thread| {
+  ///     let event_sync = event_sync.clone_immutable();
+  ///     let woken = woken.clone();
+  ///
+  ///     // Staggered, so they start waiting in order.
+  ///     std::thread::sleep(std::time::Duration::from_millis(1));
+  ///
+  ///     std::thread::spawn(move || {
+  ///       event_sync.wait_until(3).unwrap();
+  ///       woken.lock().unwrap().push(thread);
+  ///     })
+  ///   })
+  ///   .collect();
+  ///
+  /// handles.into_iter().for_each(|handle| handle.join().unwrap());
+  ///
+  /// assert_eq!(woken.lock().unwrap().len(), 3);
+  /// ```
+  pub fn set_wake_order(&mut self, wake_order: WakeOrder) {
+    self.driver.registry.lock().unwrap().wake_order = wake_order;
+  }
+}
+
+impl EventSync<Immutable> {
+  /// The driver thread, calling every listener with each tick until there's nothing left to call.
+  fn drive_listeners(self) {
+    let mut next_tick = self.ticks_since_started() + 1;
+
+    loop {
+      let Some((_, tick)) =
+        self.sleep_until_tick_in_chunks(next_tick, WaitTarget::Relative, || {
+          // Also catches the timeline being moved back between ticks, such as by the wall clock.
+          self.refresh_cached_tick();
+          self.driver.stop_if_unused(Arc::strong_count(&self.driver))
+        })
+      else {
+        return;
+      };
+
+      self.refresh_cached_tick();
+      self.driver.tick_occurred.notify_all();
+
+      // Moving back restarts delivery from where it was moved to.
+      for delivered_tick in next_tick.min(tick)..=tick {
+        if !self.driver.dispatch(delivered_tick) {
+          break;
+        }
+      }
+
+      next_tick = tick + 1;
+    }
+  }
+
+  /// Caches the tick the EventSync is on, along with when the next one is due, for
+  /// [`ticks_since_started()`](EventSync::ticks_since_started) to read.
+  ///
+  /// Deadlines are measured with [`Instant`](std::time::Instant), so only ticks of the monotonic clock are cached.
+  fn refresh_cached_tick(&self) {
+    // Taken before the EventSync is read, so the deadline is never later than the next tick.
+    let now = Instant::now();
+    let inner = self.read_inner();
+
+    if inner.clock_source() != ClockSource::Monotonic {
+      return;
+    }
+
+    let tick = inner.ticks_since_started();
+    let deadline = if inner.is_paused() {
+      // Nothing changes while paused without the cache being cleared.
+      u64::MAX
+    } else {
+      inner
+        .time_until_tick_occurs(tick.saturating_add(1))
+        .map_or(0, |wait_time| nanos_since_epoch(now + wait_time))
+    };
+
+    // The tick is stored first, so it's only ever paired with its own deadline or an earlier one.
+    self
+      .driver
+      .cached_tick
+      .store(tick.saturating_add(1), Ordering::Release);
+    self
+      .driver
+      .cached_tick_deadline
+      .store(deadline, Ordering::Release);
+  }
+}
+
+/// Returns the nanoseconds between the first time this was called and the instant, or 0 if the instant was before it.
+fn nanos_since_epoch(instant: Instant) -> u64 {
+  static EPOCH: OnceLock<Instant> = OnceLock::new();
+
+  let epoch = *EPOCH.get_or_init(Instant::now);
+
+  instant.saturating_duration_since(epoch).as_nanos() as u64
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::sync::mpsc;
+  use std::time::Duration;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  #[test]
+  fn listeners_are_called_in_order() {
+    let event_sync = EventSync::new_millis(TEST_TICKRATE);
+    let (sender, receiver) = mpsc::channel();
+
+    for name in ["first", "second"] {
+      let sender = sender.clone();
+
+      event_sync.add_listener(Box::new(move |tick| {
+        let _ = sender.send((tick, name));
+      }));
+    }
+
+    let deliveries: Vec<(u64, &str)> = receiver.iter().take(4).collect();
+
+    assert_eq!(deliveries[0].1, "first");
+    assert_eq!(deliveries[1], (deliveries[0].0, "second"));
+    assert_eq!(deliveries[2], (deliveries[0].0 + 1, "first"));
+  }
+
+  #[test]
+  fn phases_run_in_order() {
+    let event_sync = EventSync::new_millis(TEST_TICKRATE);
+    let (sender, receiver) = mpsc::channel();
+
+    for phase in [TickPhase::OUTPUT, TickPhase(150), TickPhase::INPUT] {
+      let sender = sender.clone();
+
+      event_sync.add_listener_in_phase(
+        phase,
+        Box::new(move |_| {
+          let _ = sender.send(phase);
+        }),
+      );
+    }
+
+    let phases: Vec<TickPhase> = receiver.iter().take(3).collect();
+
+    assert_eq!(
+      phases,
+      [TickPhase::INPUT, TickPhase(150), TickPhase::OUTPUT]
+    );
+  }
+
+  #[test]
+  fn ordered_waiters_wake_in_the_order_they_waited() {
+    let mut event_sync = EventSync::new_millis(TEST_TICKRATE);
+    event_sync.set_wake_order(WakeOrder::Fifo);
+
+    let handles: Vec<_> = (0..5)
+      .map(|thread| {
+        let waiter = event_sync.clone_immutable();
+
+        let handle = std::thread::spawn(move || {
+          waiter.wait_until(5).unwrap();
+
+          // Every thread that started waiting earlier has already woken up.
+          let registry = waiter.driver.registry.lock().unwrap();
+          registry.waiters.iter().all(|(_, order)| *order > thread)
+        });
+
+        while event_sync.driver.registry.lock().unwrap().waiters.len() <= thread as usize {
+          std::thread::yield_now();
+        }
+
+        handle
+      })
+      .collect();
+
+    for handle in handles {
+      assert!(handle.join().unwrap());
+    }
+
+    assert_eq!(event_sync.stats().wakes(), 5);
+  }
+
+  #[test]
+  fn ordered_waits_follow_restarts() {
+    let mut event_sync = EventSync::from_starting_tick_millis(TEST_TICKRATE, 100, false);
+    event_sync.set_wake_order(WakeOrder::Fifo);
+    let waiter = event_sync.clone_immutable();
+
+    let handle = std::thread::spawn(move || {
+      waiter.wait_for_tick().unwrap();
+
+      waiter.ticks_since_started()
+    });
+
+    while event_sync
+      .driver
+      .registry
+      .lock()
+      .unwrap()
+      .waiters
+      .is_empty()
+    {
+      std::thread::yield_now();
+    }
+    event_sync.restart();
+
+    // Waiting for tick 101 from before the restart would take 100 more ticks.
+    assert!(handle.join().unwrap() < 50);
+  }
+
+  #[test]
+  fn listeners_can_remove_themselves() {
+    let event_sync = EventSync::new_millis(TEST_TICKRATE);
+    let (sender, receiver) = mpsc::channel();
+    let (id_sender, id_receiver) = mpsc::channel();
+    let remover = event_sync.clone_immutable();
+
+    let id = event_sync.add_listener(Box::new(move |tick| {
+      let id = id_receiver.recv().unwrap();
+      assert!(remover.remove_listener(id));
+
+      let _ = sender.send(tick);
+    }));
+    id_sender.send(id).unwrap();
+
+    receiver.recv().unwrap();
+    std::thread::sleep(Duration::from_millis(TEST_TICKRATE * 3));
+
+    assert!(receiver.try_recv().is_err());
+    assert_eq!(event_sync.listener_count(), 0);
+    assert!(!event_sync.remove_listener(id));
+  }
+
+  #[test]
+  fn the_cached_tick_follows_changes() {
+    let mut event_sync = EventSync::from_starting_tick_millis(TEST_TICKRATE, 20, false);
+    let (sender, receiver) = mpsc::channel();
+
+    let id = event_sync.add_listener(Box::new(move |tick| {
+      let _ = sender.send(tick);
+    }));
+    receiver.recv().unwrap();
+
+    assert!(event_sync.driver.cached_tick().unwrap() >= 20);
+
+    event_sync.restart_paused();
+
+    assert_eq!(event_sync.driver.cached_tick(), None);
+    assert_eq!(event_sync.ticks_since_started(), 0);
+
+    event_sync.remove_listener(id);
+    event_sync.unpause().unwrap();
+    std::thread::sleep(Duration::from_millis(TEST_TICKRATE * 3));
+
+    // The driver thread stopped with nothing left to call.
+    assert_eq!(event_sync.driver.cached_tick(), None);
+  }
+
+  #[test]
+  fn slow_listeners_dont_hold_back_the_tick() {
+    let event_sync = EventSync::new_millis(TEST_TICKRATE);
+    let (sender, receiver) = mpsc::channel();
+
+    event_sync.add_listener(Box::new(move |tick| {
+      let _ = sender.send(tick);
+      std::thread::sleep(Duration::from_millis(TEST_TICKRATE * 20));
+    }));
+    let first_tick = receiver.recv().unwrap();
+    std::thread::sleep(Duration::from_millis(TEST_TICKRATE * 5));
+
+    // The driver thread is still in the listener, so it hasn't cached anything past the first tick.
+    assert!(event_sync.ticks_since_started() >= first_tick + 4);
+  }
+}
@@ -0,0 +1,185 @@
+//! Waiting on ticks with an `embedded-hal` delay, for bare-metal and RTIC applications.
+//!
+//! Enabled with the `embedded` feature, which doesn't require std.
+
+use crate::clock::ClockSource;
+use crate::errors::TimeError;
+use crate::event_sync_core::EventSyncCore;
+use core::time::Duration;
+use embedded_hal::delay::DelayNs;
+
+/// An [`EventSyncCore`](EventSyncCore) that waits using a hardware delay, measuring time with a hardware timer.
+///
+/// The clock is a function reading the timer's counter as the time passed since an arbitrary fixed point.
+///
+/// # Example
+///
+/// ```
+/// use core::sync::atomic::{AtomicU64, Ordering};
+/// use core::time::Duration;
+/// use embedded_hal::delay::DelayNs;
+/// use event_sync::*;
+///
+/// // Stands in for the counter of a hardware timer.
+/// static COUNTER_NANOS: AtomicU64 = AtomicU64::new(0);
+///
+/// fn read_counter() -> Duration {
+///   Duration::from_nanos(COUNTER_NANOS.load(Ordering::SeqCst))
+/// }
+///
+/// // Stands in for a delay provided by a HAL, which would busy-wait on the timer.
+/// struct Delay;
+///
+/// impl DelayNs for Delay {
+///   fn delay_ns(&mut self, ns: u32) {
+///     COUNTER_NANOS.fetch_add(ns as u64, Ordering::SeqCst);
+///   }
+/// }
+///
+/// let tickrate = 10; // 10ms between every tick.
+/// let mut event_sync = EmbeddedEventSync::new_millis(tickrate, read_counter, Delay);
+///
+/// event_sync.wait_for_x_ticks(3).unwrap();
+///
+/// assert_eq!(event_sync.core().ticks_since_started(), 3);
+/// ```
+pub struct EmbeddedEventSync<D: DelayNs> {
+  core: EventSyncCore,
+  delay: D,
+}
+
+impl<D: DelayNs> EmbeddedEventSync<D> {
+  /// Creates a running EmbeddedEventSync with the given tickrate in milliseconds, measuring time with the given
+  /// clock and waiting with the given delay.
+  ///
+  /// The tickrate cannot go below 1. If 0 is passed in, 1 is set as the tickrate.
+  pub fn new_millis(tickrate_in_milliseconds: u64, clock: fn() -> Duration, delay: D) -> Self {
+    Self::from_core(
+      EventSyncCore::new_millis(tickrate_in_milliseconds, ClockSource::Custom(clock)),
+      delay,
+    )
+  }
+
+  /// [`new_millis()`](EmbeddedEventSync::new_millis), taking the tickrate as a `u32`.
+  #[deprecated(
+    since = "0.4.5",
+    note = "use `new_millis()`, as tickrates are `u64` milliseconds"
+  )]
+  pub fn new(tickrate_in_milliseconds: u32, clock: fn() -> Duration, delay: D) -> Self {
+    Self::new_millis(tickrate_in_milliseconds.into(), clock, delay)
+  }
+
+  /// Creates an EmbeddedEventSync that waits on the timeline of an existing EventSyncCore.
+  pub fn from_core(core: EventSyncCore, delay: D) -> Self {
+    Self { core, delay }
+  }
+
+  /// Returns the underlying tick bookkeeping.
+  pub fn core(&self) -> &EventSyncCore {
+    &self.core
+  }
+
+  /// Returns the underlying tick bookkeeping, for pausing, restarting, or changing the tickrate.
+  pub fn core_mut(&mut self) -> &mut EventSyncCore {
+    &mut self.core
+  }
+
+  /// Returns the tick bookkeeping and the delay this was made with.
+  pub fn release(self) -> (EventSyncCore, D) {
+    (self.core, self.delay)
+  }
+
+  /// Waits until an absolute tick has occurred.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned when the given tick has already occurred.
+  /// - An error is returned if paused.
+  pub fn wait_until(&mut self, tick_to_wait_for: u64) -> Result<(), TimeError> {
+    let wait_time = self.core.time_until_tick_occurs(tick_to_wait_for)?;
+
+    self.delay_for(wait_time);
+
+    Ok(())
+  }
+
+  /// Waits until the next tick.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if paused.
+  pub fn wait_for_tick(&mut self) -> Result<(), TimeError> {
+    self.wait_for_x_ticks(1)
+  }
+
+  /// Waits for the passed in amount of ticks.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if paused.
+  pub fn wait_for_x_ticks(&mut self, ticks_to_wait: u32) -> Result<(), TimeError> {
+    let wait_time = self.core.time_for_x_ticks(ticks_to_wait)?;
+
+    self.delay_for(wait_time);
+
+    Ok(())
+  }
+
+  /// Delays for the given duration, which may be longer than a single call to the delay can handle.
+  fn delay_for(&mut self, duration: Duration) {
+    let mut milliseconds = duration.as_millis();
+
+    while milliseconds > 0 {
+      let chunk = milliseconds.min(u32::MAX as u128);
+
+      self.delay.delay_ms(chunk as u32);
+      milliseconds -= chunk;
+    }
+
+    self.delay.delay_ns(duration.subsec_nanos() % 1_000_000);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use core::sync::atomic::{AtomicU64, Ordering};
+
+  const TEST_TICKRATE: u64 = 10;
+
+  static TEST_COUNTER_NANOS: AtomicU64 = AtomicU64::new(0);
+
+  fn test_clock() -> Duration {
+    Duration::from_nanos(TEST_COUNTER_NANOS.load(Ordering::SeqCst))
+  }
+
+  struct TestDelay;
+
+  impl DelayNs for TestDelay {
+    fn delay_ns(&mut self, ns: u32) {
+      TEST_COUNTER_NANOS.fetch_add(ns as u64, Ordering::SeqCst);
+    }
+  }
+
+  // The counter is global, so everything is checked in one test to keep the readings predictable.
+  #[test]
+  fn delay_waits_on_custom_clock() {
+    let mut event_sync = EmbeddedEventSync::new_millis(TEST_TICKRATE, test_clock, TestDelay);
+
+    event_sync.wait_until(2).unwrap();
+    assert_eq!(
+      event_sync.core().time_since_started(),
+      Duration::from_millis(20)
+    );
+
+    TEST_COUNTER_NANOS.fetch_add(4_500_000, Ordering::SeqCst);
+    event_sync.wait_for_tick().unwrap();
+    assert_eq!(
+      event_sync.core().time_since_started(),
+      Duration::from_millis(30)
+    );
+
+    event_sync.core_mut().pause();
+    assert_eq!(event_sync.wait_for_tick(), Err(TimeError::EventSyncPaused));
+  }
+}
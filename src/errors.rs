@@ -5,6 +5,9 @@ use thiserror::Error;
 pub enum TimeError {
   /// This error is returned when the [`wait_until()`](crate::EventSync::wait_until) method has been
   /// called with a time that's already occurred.
+  ///
+  /// Waiting for the tick the EventSync is on isn't an error, and returns
+  /// [`NoWait`](crate::WaitOutcome::NoWait) instead.
   #[error("A method with a time input has been told to wait for a time that already happened.")]
   ThatTimeHasAlreadyHappened,
 
@@ -15,11 +18,42 @@ pub enum TimeError {
   /// Failed to subtract the passed pause time from an Instant when starting up an EventSync.
   #[error("Attempted to start an EventSync, but an unexpected error occurred.")]
   FailedToStartEventSync,
+
+  /// Attempted to create an EventSync anchored to a time that hasn't happened yet.
+  #[error("Attempted to start an EventSync from a time that hasn't happened yet.")]
+  StartTimeInFuture,
+
+  /// The system's clock was moved back to before an EventSync using
+  /// [`ClockSource::WallClock`](crate::ClockSource::WallClock) was started.
+  #[error("The system's clock went back to before the EventSync was started.")]
+  SystemTimeWentBackwards,
+
+  /// Every permit of a [`RateLimiter`](crate::RateLimiter) has been used up until the next tick.
+  #[error("No permits are left until the next tick.")]
+  RateLimited,
+
+  /// An EventSync couldn't be created or loaded, as the state it was given is invalid. The reason is given.
+  #[error("The EventSync's state is invalid: {0}.")]
+  InvalidState(&'static str),
+
+  /// [`EventSync::set_default_tickrate_millis()`](crate::EventSync::set_default_tickrate_millis) was called after the default
+  /// tickrate was already set.
+  #[error("The default tickrate has already been set.")]
+  DefaultTickrateAlreadySet,
+
+  /// A [`snapshot`](crate::EventSync::to_snapshot) couldn't be loaded, as it was truncated or corrupted. The reason
+  /// is given.
+  #[error("The snapshot is corrupt: {0}.")]
+  CorruptSnapshot(&'static str),
+
+  /// A [`LockstepSession`](crate::LockstepSession) couldn't take a peer's input. The reason is given.
+  #[error("The input was rejected: {0}.")]
+  InputRejected(&'static str),
 }
 
 impl PartialEq for TimeError {
   fn eq(&self, other: &Self) -> bool {
-    std::mem::discriminant(self) == std::mem::discriminant(other)
+    core::mem::discriminant(self) == core::mem::discriminant(other)
   }
 }
 
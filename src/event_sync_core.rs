@@ -0,0 +1,292 @@
+use crate::clock::ClockSource;
+use crate::errors::TimeError;
+use crate::inner::InnerEventSync;
+use crate::view::EventSyncView;
+use core::time::Duration;
+use serde::{Deserialize, Serialize};
+
+/// The tick bookkeeping of an [`EventSync`](crate::EventSync), without any locking or sleeping.
+///
+/// This is available without the `std` feature, making it usable on targets such as microcontrollers.
+/// Instead of waiting, methods return how long to wait for, leaving the actual waiting to the caller.
+///
+/// # Example
+///
+/// ```
+/// use core::sync::atomic::{AtomicU64, Ordering};
+/// use core::time::Duration;
+/// use event_sync::*;
+///
+/// // Stands in for the counter of a hardware timer.
+/// static COUNTER_NANOS: AtomicU64 = AtomicU64::new(0);
+///
+/// fn read_counter() -> Duration {
+///   Duration::from_nanos(COUNTER_NANOS.load(Ordering::SeqCst))
+/// }
+///
+/// let tickrate = 10; // 10ms between every tick.
+/// let event_sync = EventSyncCore::new_millis(tickrate, ClockSource::Custom(read_counter));
+///
+/// COUNTER_NANOS.store(25_000_000, Ordering::SeqCst); // 25ms pass.
+///
+/// assert_eq!(event_sync.ticks_since_started(), 2);
+/// assert_eq!(event_sync.time_for_x_ticks(1), Ok(Duration::from_millis(5)));
+/// ```
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct EventSyncCore {
+  inner: InnerEventSync,
+}
+
+impl EventSyncCore {
+  /// [`new_millis()`](EventSyncCore::new_millis), taking the tickrate as a `u32`.
+  #[deprecated(
+    since = "0.4.5",
+    note = "use `new_millis()`, as tickrates are `u64` milliseconds"
+  )]
+  pub fn new(tickrate_in_milliseconds: u32, clock: ClockSource) -> Self {
+    Self::new_millis(tickrate_in_milliseconds.into(), clock)
+  }
+
+  /// Creates a running EventSyncCore with the given tickrate in milliseconds, measuring time with the given clock.
+  ///
+  /// The tickrate is kept between 1ms and 100 years, so 0 is set as 1 and anything longer is set as 100 years.
+  pub fn new_millis(tickrate_in_milliseconds: u64, clock: ClockSource) -> Self {
+    Self::from_starting_time_millis(tickrate_in_milliseconds, Duration::ZERO, clock)
+  }
+
+  /// [`from_starting_time_millis()`](EventSyncCore::from_starting_time_millis), taking the tickrate as a `u32`.
+  #[deprecated(
+    since = "0.4.5",
+    note = "use `from_starting_time_millis()`, as tickrates are `u64` milliseconds"
+  )]
+  pub fn from_starting_time(
+    tickrate_in_milliseconds: u32,
+    starting_time: Duration,
+    clock: ClockSource,
+  ) -> Self {
+    Self::from_starting_time_millis(tickrate_in_milliseconds.into(), starting_time, clock)
+  }
+
+  /// Creates a running EventSyncCore where the given amount of time has already passed.
+  pub fn from_starting_time_millis(
+    tickrate_in_milliseconds: u64,
+    starting_time: Duration,
+    clock: ClockSource,
+  ) -> Self {
+    Self {
+      inner: InnerEventSync::new(tickrate_in_milliseconds, starting_time, false, clock),
+    }
+  }
+
+  /// Returns true if paused.
+  pub fn is_paused(&self) -> bool {
+    self.inner.is_paused()
+  }
+
+  /// Pauses time, retaining the time that had passed. Does nothing if already paused.
+  pub fn pause(&mut self) {
+    self.inner.pause()
+  }
+
+  /// Unpauses time, continuing from the time that had passed before pausing. Does nothing if already running.
+  ///
+  /// # Errors
+  ///
+  /// - If the time that had passed is earlier than the clock can represent.
+  pub fn unpause(&mut self) -> Result<(), TimeError> {
+    self.inner.unpause()
+  }
+
+  /// Restarts time from 0, unpausing if paused.
+  pub fn restart(&mut self) {
+    self.inner.restart()
+  }
+
+  /// [`change_tickrate_millis()`](EventSyncCore::change_tickrate_millis), taking and returning tickrates as `u32`s.
+  ///
+  /// A previous tickrate too long to fit in a `u32` is returned as `u32::MAX`.
+  #[deprecated(
+    since = "0.4.5",
+    note = "use `change_tickrate_millis()`, as tickrates are `u64` milliseconds"
+  )]
+  pub fn change_tickrate(&mut self, new_tickrate: u32) -> (u32, u64) {
+    let (old_tickrate, current_tick) = self.change_tickrate_millis(new_tickrate.into());
+
+    (
+      u32::try_from(old_tickrate).unwrap_or(u32::MAX),
+      current_tick,
+    )
+  }
+
+  /// Changes the tickrate, retaining the time that has passed.
+  ///
+  /// The tickrate is kept between 1ms and 100 years, so 0 is set as 1 and anything longer is set as 100 years.
+  ///
+  /// Returns the previous tickrate and the tick it's now on.
+  pub fn change_tickrate_millis(&mut self, new_tickrate: u64) -> (u64, u64) {
+    self.inner.change_tickrate(new_tickrate)
+  }
+
+  /// Moves the timeline so the given amount of time has passed, keeping the running or paused state.
+  ///
+  /// # Errors
+  ///
+  /// - If the given time is earlier than the clock can represent.
+  pub fn sync_to(&mut self, time_since_started: Duration) -> Result<(), TimeError> {
+    self.inner.set_time_since_started(time_since_started)
+  }
+
+  /// Gradually moves the timeline so the given amount of time has passed, spreading the correction over the given
+  /// amount of ticks instead of jumping.
+  ///
+  /// # Errors
+  ///
+  /// - If the given time is earlier than the clock can represent.
+  pub fn slew_to(
+    &mut self,
+    time_since_started: Duration,
+    over_ticks: u32,
+  ) -> Result<(), TimeError> {
+    let period =
+      Duration::from_millis(u64::from(over_ticks).saturating_mul(self.get_tickrate_millis()));
+
+    self
+      .inner
+      .slew_time_since_started(time_since_started, period)
+  }
+
+  /// Returns the tickrate in milliseconds, or `u32::MAX` if it's too long to fit in a `u32`.
+  #[deprecated(
+    since = "0.4.5",
+    note = "use `get_tickrate_millis()`, as tickrates are `u64` milliseconds"
+  )]
+  pub fn get_tickrate(&self) -> u32 {
+    u32::try_from(self.get_tickrate_millis()).unwrap_or(u32::MAX)
+  }
+
+  /// Returns the tickrate in milliseconds.
+  pub fn get_tickrate_millis(&self) -> u64 {
+    self.inner.get_tickrate()
+  }
+
+  /// Returns the clock time is measured against.
+  pub fn clock_source(&self) -> ClockSource {
+    self.inner.clock_source()
+  }
+
+  /// Returns the amount of times the timeline has been reset, by restarting or syncing to a time.
+  pub fn generation(&self) -> u64 {
+    self.inner.generation()
+  }
+
+  /// Returns the amount of time that has passed.
+  pub fn time_since_started(&self) -> Duration {
+    self.inner.time_since_started()
+  }
+
+  /// Returns the amount of ticks that have passed.
+  pub fn ticks_since_started(&self) -> u64 {
+    self.inner.ticks_since_started()
+  }
+
+  /// Returns the amount of time that has passed since the last tick.
+  pub fn time_since_last_tick(&self) -> Duration {
+    self.inner.time_since_last_tick()
+  }
+
+  /// Returns the amount of time until the next tick.
+  pub fn time_until_next_tick(&self) -> Duration {
+    self.inner.time_until_next_tick()
+  }
+
+  /// Returns the tick, time passed, tickrate, and pause state all read at the same instant.
+  pub fn view(&self) -> EventSyncView {
+    EventSyncView::from_inner(&self.inner)
+  }
+
+  /// Returns how long to wait until the given amount of time has passed, regardless of where the ticks fall.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if paused.
+  /// - An error is returned if that much time has already passed.
+  pub fn time_until_elapsed(&self, time_since_started: Duration) -> Result<Duration, TimeError> {
+    self.inner.time_until_elapsed(time_since_started)
+  }
+
+  /// Returns how long to wait until the given absolute tick occurs.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned when the given tick has already occurred.
+  /// - An error is returned if paused.
+  pub fn time_until_tick_occurs(&self, tick: u64) -> Result<Duration, TimeError> {
+    self.inner.time_until_tick_occurs(tick)
+  }
+
+  /// Returns how long to wait for the given amount of ticks to pass, counted from the last tick.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if paused.
+  pub fn time_for_x_ticks(&self, ticks_to_wait: u32) -> Result<Duration, TimeError> {
+    self.inner.time_for_x_ticks(ticks_to_wait)
+  }
+}
+
+impl core::fmt::Debug for EventSyncCore {
+  fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    write!(formatter, "{:?}", self.time_since_started())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use core::sync::atomic::{AtomicU64, Ordering};
+
+  const TEST_TICKRATE: u64 = 10;
+
+  static TEST_COUNTER_NANOS: AtomicU64 = AtomicU64::new(0);
+
+  fn test_clock() -> Duration {
+    Duration::from_nanos(TEST_COUNTER_NANOS.load(Ordering::SeqCst))
+  }
+
+  fn advance_test_clock(milliseconds: u64) {
+    TEST_COUNTER_NANOS.fetch_add(milliseconds * 1_000_000, Ordering::SeqCst);
+  }
+
+  // The counter is global, so everything is checked in one test to keep the readings predictable.
+  #[test]
+  fn custom_clock_drives_ticks() {
+    let mut event_sync = EventSyncCore::new_millis(TEST_TICKRATE, ClockSource::Custom(test_clock));
+
+    advance_test_clock(35);
+
+    assert_eq!(event_sync.ticks_since_started(), 3);
+    assert_eq!(
+      event_sync.time_until_tick_occurs(4),
+      Ok(Duration::from_millis(5))
+    );
+    assert_eq!(
+      event_sync.time_until_tick_occurs(3),
+      Err(TimeError::ThatTimeHasAlreadyHappened)
+    );
+
+    event_sync.pause();
+    advance_test_clock(100);
+
+    assert_eq!(event_sync.ticks_since_started(), 3);
+    assert_eq!(
+      event_sync.time_for_x_ticks(1),
+      Err(TimeError::EventSyncPaused)
+    );
+
+    event_sync.unpause().unwrap();
+    advance_test_clock(10);
+
+    assert_eq!(event_sync.time_since_started(), Duration::from_millis(45));
+  }
+}
@@ -0,0 +1,282 @@
+use crate::{EventSync, Immutable};
+use std::collections::VecDeque;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+/// A pool of worker threads that runs submitted jobs on the ticks of an EventSync.
+///
+/// Jobs are queued as they're submitted, and on every tick up to `jobs_per_tick` of them are handed to the
+/// workers, so bursts of submissions are spread out over the following ticks.
+/// Nothing is handed out while the EventSync is paused.
+///
+/// Dropping the executor waits for the jobs already handed out to finish, and discards the rest.
+///
+/// # Example
+///
+/// ```
+/// use event_sync::*;
+/// use std::sync::mpsc::channel;
+///
+/// let tickrate = 10; // 10ms between every tick.
+/// let event_sync = EventSync::new_millis(tickrate);
+///
+/// // 2 workers, running up to 4 jobs every tick.
+/// let executor = TickExecutor::new(event_sync.clone_immutable(), 2, 4);
+/// let (sender, receiver) = channel();
+///
+/// for job in 0..8 {
+///   let sender = sender.clone();
+///
+///   executor.submit(move || sender.send(job).unwrap());
+/// }
+///
+/// let mut finished: Vec<i32> = receiver.iter().take(8).collect();
+/// finished.sort();
+///
+/// assert_eq!(finished, (0..8).collect::<Vec<_>>());
+/// // The 8 jobs needed at least 2 ticks.
+/// assert!(event_sync.ticks_since_started() >= 2);
+/// ```
+pub struct TickExecutor {
+  shared: Arc<Shared>,
+  dispatcher: Option<JoinHandle<()>>,
+  workers: Vec<JoinHandle<()>>,
+}
+
+/// The state shared between the executor, its dispatcher, and its workers.
+struct Shared {
+  queues: Mutex<Queues>,
+  /// Notified when jobs are handed out, or the executor is shutting down.
+  job_ready: Condvar,
+}
+
+struct Queues {
+  /// Jobs waiting for a tick.
+  pending: VecDeque<Job>,
+  /// Jobs handed out on a tick, waiting for a worker.
+  ready: VecDeque<Job>,
+  shutting_down: bool,
+}
+
+impl TickExecutor {
+  /// Creates an executor with the given amount of worker threads, handing out up to `jobs_per_tick` jobs
+  /// every tick.
+  ///
+  /// At least 1 worker is always created, and at least 1 job is handed out every tick.
+  pub fn new(event_sync: EventSync<Immutable>, workers: usize, jobs_per_tick: usize) -> Self {
+    let shared = Arc::new(Shared {
+      queues: Mutex::new(Queues {
+        pending: VecDeque::new(),
+        ready: VecDeque::new(),
+        shutting_down: false,
+      }),
+      job_ready: Condvar::new(),
+    });
+
+    let workers = (0..workers.max(1))
+      .map(|_| {
+        let shared = Arc::clone(&shared);
+
+        std::thread::spawn(move || run_jobs(&shared))
+      })
+      .collect();
+
+    let dispatcher = {
+      let shared = Arc::clone(&shared);
+
+      std::thread::spawn(move || dispatch_jobs(event_sync, &shared, jobs_per_tick.max(1)))
+    };
+
+    Self {
+      shared,
+      dispatcher: Some(dispatcher),
+      workers,
+    }
+  }
+
+  /// Queues the job to be run on an upcoming tick.
+  pub fn submit<F: FnOnce() + Send + 'static>(&self, job: F) {
+    self.shared.lock().pending.push_back(Box::new(job));
+  }
+
+  /// Returns the amount of jobs that haven't been handed out to a worker yet.
+  pub fn pending_jobs(&self) -> usize {
+    self.shared.lock().pending.len()
+  }
+}
+
+impl Drop for TickExecutor {
+  fn drop(&mut self) {
+    self.shared.lock().shutting_down = true;
+    self.shared.job_ready.notify_all();
+
+    if let Some(dispatcher) = self.dispatcher.take() {
+      dispatcher.thread().unpark();
+      let _ = dispatcher.join();
+    }
+
+    for worker in self.workers.drain(..) {
+      let _ = worker.join();
+    }
+  }
+}
+
+impl std::fmt::Debug for TickExecutor {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let queues = self.shared.lock();
+
+    f.debug_struct("TickExecutor")
+      .field("workers", &self.workers.len())
+      .field("pending_jobs", &queues.pending.len())
+      .field("ready_jobs", &queues.ready.len())
+      .finish()
+  }
+}
+
+impl Shared {
+  fn lock(&self) -> MutexGuard<'_, Queues> {
+    self
+      .queues
+      .lock()
+      .unwrap_or_else(|poisoned| poisoned.into_inner())
+  }
+}
+
+/// Hands out up to `jobs_per_tick` pending jobs on every tick until the executor shuts down.
+///
+/// The EventSync is re-checked at least once every tick, so pausing, restarting, or changing the tickrate is
+/// followed.
+fn dispatch_jobs(event_sync: EventSync<Immutable>, shared: &Shared, jobs_per_tick: usize) {
+  let mut last_tick = event_sync.ticks_since_started();
+
+  loop {
+    let (current_tick, tickrate, is_paused) = {
+      let inner = event_sync.read_inner();
+
+      (
+        inner.ticks_since_started(),
+        Duration::from_millis(inner.get_tickrate()),
+        inner.is_paused(),
+      )
+    };
+
+    {
+      let mut queues = shared.lock();
+
+      if queues.shutting_down {
+        return;
+      }
+
+      // Ticks missed while the dispatcher was asleep still hand out their jobs.
+      if current_tick > last_tick {
+        let ticks_passed = (current_tick - last_tick).min(usize::MAX as u64) as usize;
+        let handed_out = jobs_per_tick
+          .saturating_mul(ticks_passed)
+          .min(queues.pending.len());
+
+        let jobs: Vec<Job> = queues.pending.drain(..handed_out).collect();
+        queues.ready.extend(jobs);
+
+        if handed_out > 0 {
+          shared.job_ready.notify_all();
+        }
+      }
+    }
+
+    // Restarted, or moved back.
+    last_tick = current_tick;
+
+    let wait_time = if is_paused {
+      tickrate
+    } else {
+      event_sync
+        .read_inner()
+        .time_until_tick_occurs(current_tick + 1)
+        .map_or(Duration::ZERO, |wait_time| wait_time.min(tickrate))
+    };
+
+    std::thread::park_timeout(wait_time);
+  }
+}
+
+/// Runs jobs as they're handed out, until the executor shuts down and no handed out jobs are left.
+fn run_jobs(shared: &Shared) {
+  loop {
+    let job = {
+      let mut queues = shared.lock();
+
+      loop {
+        if let Some(job) = queues.ready.pop_front() {
+          break job;
+        }
+
+        if queues.shutting_down {
+          return;
+        }
+
+        queues = shared
+          .job_ready
+          .wait(queues)
+          .unwrap_or_else(|poisoned| poisoned.into_inner());
+      }
+    };
+
+    job();
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::sync::mpsc::channel;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  #[test]
+  fn jobs_wait_for_a_tick() {
+    let mut event_sync = EventSync::new_paused_millis(TEST_TICKRATE);
+    let executor = TickExecutor::new(event_sync.clone_immutable(), 1, 2);
+
+    for _ in 0..5 {
+      executor.submit(|| ());
+    }
+
+    std::thread::sleep(Duration::from_millis(TEST_TICKRATE * 2));
+    assert_eq!(executor.pending_jobs(), 5);
+
+    event_sync
+      .sync_to(Duration::from_millis(TEST_TICKRATE))
+      .unwrap();
+    executor.dispatcher.as_ref().unwrap().thread().unpark();
+
+    // One tick hands out 2 jobs.
+    while executor.pending_jobs() > 3 {
+      std::thread::yield_now();
+    }
+    std::thread::sleep(Duration::from_millis(TEST_TICKRATE * 2));
+
+    assert_eq!(executor.pending_jobs(), 3);
+  }
+
+  #[test]
+  fn every_job_runs() {
+    let event_sync = EventSync::new_millis(TEST_TICKRATE);
+    let executor = TickExecutor::new(event_sync.clone_immutable(), 3, 2);
+    let (sender, receiver) = channel();
+
+    for job in 0..6 {
+      let sender = sender.clone();
+
+      executor.submit(move || sender.send(job).unwrap());
+    }
+
+    let mut finished: Vec<i32> = receiver.iter().take(6).collect();
+    finished.sort();
+
+    assert_eq!(finished, [0, 1, 2, 3, 4, 5]);
+    assert!(event_sync.ticks_since_started() >= 3);
+  }
+}
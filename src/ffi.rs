@@ -0,0 +1,363 @@
+//! A flat C API for sharing an EventSync with C and C++ code.
+//!
+//! Enabled with the `ffi` feature. To build a library C code can link against, run
+//! `cargo rustc --release --features ffi --crate-type cdylib`.
+//!
+//! Every EventSync is passed around as a handle made by [`event_sync_new_millis()`](event_sync_new_millis), and must be released
+//! with [`event_sync_free()`](event_sync_free).
+//! Functions that can fail return one of the `EVENT_SYNC_*` error codes, with 0 meaning success.
+//!
+//! The declarations for C look like this:
+//!
+//! ```c
+//! typedef struct EventSyncHandle EventSyncHandle;
+//!
+//! EventSyncHandle *event_sync_new_millis(uint64_t tickrate_in_milliseconds);
+//! EventSyncHandle *event_sync_clone(const EventSyncHandle *handle);
+//! void event_sync_free(EventSyncHandle *handle);
+//! int32_t event_sync_wait_until(const EventSyncHandle *handle, uint64_t tick);
+//! int32_t event_sync_wait_for_tick(const EventSyncHandle *handle);
+//! int32_t event_sync_wait_for_x_ticks(const EventSyncHandle *handle, uint32_t ticks);
+//! uint64_t event_sync_ticks_since_started(const EventSyncHandle *handle);
+//! uint64_t event_sync_get_tickrate_millis(const EventSyncHandle *handle);
+//! bool event_sync_is_paused(const EventSyncHandle *handle);
+//! int32_t event_sync_pause(EventSyncHandle *handle);
+//! int32_t event_sync_unpause(EventSyncHandle *handle);
+//! int32_t event_sync_restart(EventSyncHandle *handle);
+//! int32_t event_sync_change_tickrate_millis(EventSyncHandle *handle, uint64_t new_tickrate);
+//!
+//! // Deprecated, taking and returning tickrates as 32 bits. Use the _millis versions above instead.
+//! EventSyncHandle *event_sync_new(uint32_t tickrate_in_milliseconds);
+//! uint32_t event_sync_get_tickrate(const EventSyncHandle *handle);
+//! int32_t event_sync_change_tickrate(EventSyncHandle *handle, uint32_t new_tickrate);
+//! ```
+
+use crate::errors::TimeError;
+use crate::{EventSync, Mutable};
+
+/// The function succeeded.
+pub const EVENT_SYNC_OK: i32 = 0;
+/// A null handle was passed in.
+pub const EVENT_SYNC_NULL_HANDLE: i32 = -1;
+/// See [`TimeError::ThatTimeHasAlreadyHappened`](TimeError::ThatTimeHasAlreadyHappened).
+pub const EVENT_SYNC_TIME_ALREADY_HAPPENED: i32 = 1;
+/// See [`TimeError::EventSyncPaused`](TimeError::EventSyncPaused).
+pub const EVENT_SYNC_PAUSED: i32 = 2;
+/// See [`TimeError::FailedToStartEventSync`](TimeError::FailedToStartEventSync).
+pub const EVENT_SYNC_FAILED_TO_START: i32 = 3;
+/// See [`TimeError::StartTimeInFuture`](TimeError::StartTimeInFuture).
+pub const EVENT_SYNC_START_TIME_IN_FUTURE: i32 = 4;
+/// See [`TimeError::SystemTimeWentBackwards`](TimeError::SystemTimeWentBackwards).
+pub const EVENT_SYNC_SYSTEM_TIME_WENT_BACKWARDS: i32 = 5;
+/// See [`TimeError::RateLimited`](TimeError::RateLimited).
+pub const EVENT_SYNC_RATE_LIMITED: i32 = 6;
+/// See [`TimeError::InvalidState`](TimeError::InvalidState).
+pub const EVENT_SYNC_INVALID_STATE: i32 = 7;
+/// See [`TimeError::DefaultTickrateAlreadySet`](TimeError::DefaultTickrateAlreadySet).
+pub const EVENT_SYNC_DEFAULT_TICKRATE_ALREADY_SET: i32 = 8;
+/// See [`TimeError::CorruptSnapshot`](TimeError::CorruptSnapshot).
+pub const EVENT_SYNC_CORRUPT_SNAPSHOT: i32 = 9;
+/// See [`TimeError::InputRejected`](TimeError::InputRejected).
+pub const EVENT_SYNC_INPUT_REJECTED: i32 = 10;
+
+/// An EventSync owned by C code.
+///
+/// Every handle shares its clock with the handles it was cloned from.
+pub struct EventSyncHandle {
+  event_sync: EventSync<Mutable>,
+}
+
+impl TimeError {
+  /// Returns the `EVENT_SYNC_*` code C code receives for this error.
+  pub fn error_code(&self) -> i32 {
+    match self {
+      TimeError::ThatTimeHasAlreadyHappened => EVENT_SYNC_TIME_ALREADY_HAPPENED,
+      TimeError::EventSyncPaused => EVENT_SYNC_PAUSED,
+      TimeError::FailedToStartEventSync => EVENT_SYNC_FAILED_TO_START,
+      TimeError::StartTimeInFuture => EVENT_SYNC_START_TIME_IN_FUTURE,
+      TimeError::SystemTimeWentBackwards => EVENT_SYNC_SYSTEM_TIME_WENT_BACKWARDS,
+      TimeError::RateLimited => EVENT_SYNC_RATE_LIMITED,
+      TimeError::InvalidState(_) => EVENT_SYNC_INVALID_STATE,
+      TimeError::DefaultTickrateAlreadySet => EVENT_SYNC_DEFAULT_TICKRATE_ALREADY_SET,
+      TimeError::CorruptSnapshot(_) => EVENT_SYNC_CORRUPT_SNAPSHOT,
+      TimeError::InputRejected(_) => EVENT_SYNC_INPUT_REJECTED,
+    }
+  }
+}
+
+/// Converts the result of a call into an error code.
+fn to_error_code<T>(result: Result<T, TimeError>) -> i32 {
+  match result {
+    Ok(_) => EVENT_SYNC_OK,
+    Err(error) => error.error_code(),
+  }
+}
+
+/// Creates a running EventSync with the given tickrate in milliseconds.
+///
+/// The handle must be released with [`event_sync_free()`](event_sync_free).
+#[no_mangle]
+pub extern "C" fn event_sync_new_millis(tickrate_in_milliseconds: u64) -> *mut EventSyncHandle {
+  Box::into_raw(Box::new(EventSyncHandle {
+    event_sync: EventSync::new_millis(tickrate_in_milliseconds),
+  }))
+}
+
+/// [`event_sync_new_millis()`](event_sync_new_millis), taking the tickrate as a `uint32_t`.
+#[deprecated(
+  since = "0.4.5",
+  note = "use `event_sync_new_millis()`, as tickrates are `u64` milliseconds"
+)]
+#[no_mangle]
+pub extern "C" fn event_sync_new(tickrate_in_milliseconds: u32) -> *mut EventSyncHandle {
+  event_sync_new_millis(tickrate_in_milliseconds.into())
+}
+
+/// Creates a new handle sharing the clock of the given one. Returns null if the given handle is null.
+///
+/// The handle must be released with [`event_sync_free()`](event_sync_free).
+///
+/// # Safety
+///
+/// The handle must be null or have been made by this API and not yet freed.
+#[no_mangle]
+pub unsafe extern "C" fn event_sync_clone(handle: *const EventSyncHandle) -> *mut EventSyncHandle {
+  match handle.as_ref() {
+    Some(handle) => Box::into_raw(Box::new(EventSyncHandle {
+      event_sync: handle.event_sync.clone(),
+    })),
+    None => std::ptr::null_mut(),
+  }
+}
+
+/// Releases a handle. Does nothing if the handle is null.
+///
+/// # Safety
+///
+/// The handle must be null or have been made by this API and not yet freed.
+#[no_mangle]
+pub unsafe extern "C" fn event_sync_free(handle: *mut EventSyncHandle) {
+  if !handle.is_null() {
+    drop(Box::from_raw(handle));
+  }
+}
+
+/// Waits until an absolute tick has occurred.
+///
+/// # Safety
+///
+/// The handle must be null or have been made by this API and not yet freed.
+#[no_mangle]
+pub unsafe extern "C" fn event_sync_wait_until(handle: *const EventSyncHandle, tick: u64) -> i32 {
+  match handle.as_ref() {
+    Some(handle) => to_error_code(handle.event_sync.wait_until(tick)),
+    None => EVENT_SYNC_NULL_HANDLE,
+  }
+}
+
+/// Waits until the next tick.
+///
+/// # Safety
+///
+/// The handle must be null or have been made by this API and not yet freed.
+#[no_mangle]
+pub unsafe extern "C" fn event_sync_wait_for_tick(handle: *const EventSyncHandle) -> i32 {
+  event_sync_wait_for_x_ticks(handle, 1)
+}
+
+/// Waits for the given amount of ticks.
+///
+/// # Safety
+///
+/// The handle must be null or have been made by this API and not yet freed.
+#[no_mangle]
+pub unsafe extern "C" fn event_sync_wait_for_x_ticks(
+  handle: *const EventSyncHandle,
+  ticks_to_wait: u32,
+) -> i32 {
+  match handle.as_ref() {
+    Some(handle) => to_error_code(handle.event_sync.wait_for_x_ticks(ticks_to_wait)),
+    None => EVENT_SYNC_NULL_HANDLE,
+  }
+}
+
+/// Returns the amount of ticks that have passed, or 0 if the handle is null.
+///
+/// # Safety
+///
+/// The handle must be null or have been made by this API and not yet freed.
+#[no_mangle]
+pub unsafe extern "C" fn event_sync_ticks_since_started(handle: *const EventSyncHandle) -> u64 {
+  handle
+    .as_ref()
+    .map_or(0, |handle| handle.event_sync.ticks_since_started())
+}
+
+/// Returns the tickrate in milliseconds, or 0 if the handle is null.
+///
+/// # Safety
+///
+/// The handle must be null or have been made by this API and not yet freed.
+#[no_mangle]
+pub unsafe extern "C" fn event_sync_get_tickrate_millis(handle: *const EventSyncHandle) -> u64 {
+  handle
+    .as_ref()
+    .map_or(0, |handle| handle.event_sync.get_tickrate_millis())
+}
+
+/// Returns the tickrate in milliseconds, `UINT32_MAX` if it's longer than that, or 0 if the handle is null.
+///
+/// # Safety
+///
+/// The handle must be null or have been made by this API and not yet freed.
+#[deprecated(
+  since = "0.4.5",
+  note = "use `event_sync_get_tickrate_millis()`, as tickrates are `u64` milliseconds"
+)]
+#[no_mangle]
+pub unsafe extern "C" fn event_sync_get_tickrate(handle: *const EventSyncHandle) -> u32 {
+  u32::try_from(event_sync_get_tickrate_millis(handle)).unwrap_or(u32::MAX)
+}
+
+/// Returns true if paused, or false if the handle is null.
+///
+/// # Safety
+///
+/// The handle must be null or have been made by this API and not yet freed.
+#[no_mangle]
+pub unsafe extern "C" fn event_sync_is_paused(handle: *const EventSyncHandle) -> bool {
+  handle
+    .as_ref()
+    .is_some_and(|handle| handle.event_sync.is_paused())
+}
+
+/// Pauses the clock for every handle sharing it.
+///
+/// # Safety
+///
+/// The handle must be null or have been made by this API and not yet freed.
+#[no_mangle]
+pub unsafe extern "C" fn event_sync_pause(handle: *mut EventSyncHandle) -> i32 {
+  match handle.as_mut() {
+    Some(handle) => {
+      handle.event_sync.pause();
+
+      EVENT_SYNC_OK
+    }
+    None => EVENT_SYNC_NULL_HANDLE,
+  }
+}
+
+/// Unpauses the clock for every handle sharing it.
+///
+/// # Safety
+///
+/// The handle must be null or have been made by this API and not yet freed.
+#[no_mangle]
+pub unsafe extern "C" fn event_sync_unpause(handle: *mut EventSyncHandle) -> i32 {
+  match handle.as_mut() {
+    Some(handle) => to_error_code(handle.event_sync.unpause()),
+    None => EVENT_SYNC_NULL_HANDLE,
+  }
+}
+
+/// Restarts the clock for every handle sharing it.
+///
+/// # Safety
+///
+/// The handle must be null or have been made by this API and not yet freed.
+#[no_mangle]
+pub unsafe extern "C" fn event_sync_restart(handle: *mut EventSyncHandle) -> i32 {
+  match handle.as_mut() {
+    Some(handle) => {
+      handle.event_sync.restart();
+
+      EVENT_SYNC_OK
+    }
+    None => EVENT_SYNC_NULL_HANDLE,
+  }
+}
+
+/// Changes the tickrate for every handle sharing the clock.
+///
+/// # Safety
+///
+/// The handle must be null or have been made by this API and not yet freed.
+#[no_mangle]
+pub unsafe extern "C" fn event_sync_change_tickrate_millis(
+  handle: *mut EventSyncHandle,
+  new_tickrate: u64,
+) -> i32 {
+  match handle.as_mut() {
+    Some(handle) => {
+      handle.event_sync.change_tickrate_millis(new_tickrate);
+
+      EVENT_SYNC_OK
+    }
+    None => EVENT_SYNC_NULL_HANDLE,
+  }
+}
+
+/// [`event_sync_change_tickrate_millis()`](event_sync_change_tickrate_millis), taking the tickrate as a `uint32_t`.
+///
+/// # Safety
+///
+/// The handle must be null or have been made by this API and not yet freed.
+#[deprecated(
+  since = "0.4.5",
+  note = "use `event_sync_change_tickrate_millis()`, as tickrates are `u64` milliseconds"
+)]
+#[no_mangle]
+pub unsafe extern "C" fn event_sync_change_tickrate(
+  handle: *mut EventSyncHandle,
+  new_tickrate: u32,
+) -> i32 {
+  event_sync_change_tickrate_millis(handle, new_tickrate.into())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  #[test]
+  fn cloned_handles_share_a_clock() {
+    unsafe {
+      let handle = event_sync_new_millis(TEST_TICKRATE);
+      let other_handle = event_sync_clone(handle);
+
+      assert_eq!(event_sync_wait_until(handle, 2), EVENT_SYNC_OK);
+      assert_eq!(event_sync_pause(handle), EVENT_SYNC_OK);
+
+      assert!(event_sync_is_paused(other_handle));
+      assert_eq!(event_sync_ticks_since_started(other_handle), 2);
+      assert_eq!(event_sync_wait_for_tick(other_handle), EVENT_SYNC_PAUSED);
+      assert_eq!(
+        event_sync_change_tickrate_millis(other_handle, TEST_TICKRATE * 2),
+        EVENT_SYNC_OK
+      );
+      assert_eq!(event_sync_get_tickrate_millis(handle), TEST_TICKRATE * 2);
+
+      event_sync_free(handle);
+      event_sync_free(other_handle);
+    }
+  }
+
+  #[test]
+  fn null_handles_are_rejected() {
+    unsafe {
+      assert_eq!(
+        event_sync_wait_until(std::ptr::null(), 1),
+        EVENT_SYNC_NULL_HANDLE
+      );
+      assert_eq!(
+        event_sync_pause(std::ptr::null_mut()),
+        EVENT_SYNC_NULL_HANDLE
+      );
+      assert!(event_sync_clone(std::ptr::null()).is_null());
+
+      event_sync_free(std::ptr::null_mut());
+    }
+  }
+}
@@ -0,0 +1,75 @@
+use crate::EventSync;
+use std::time::Duration;
+
+/// How [`EventSync::format_elapsed()`](EventSync::format_elapsed) writes the time that has passed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ElapsedStyle {
+  /// Hours, minutes, seconds, and milliseconds, such as `01:02:03.456`.
+  ///
+  /// Hours keep counting past 24.
+  #[default]
+  Clock,
+
+  /// Only the amount of ticks, such as `423 ticks`.
+  Ticks,
+}
+
+impl ElapsedStyle {
+  /// Writes the time that has passed in this style.
+  fn format(&self, time_since_started: Duration, ticks_since_started: u64) -> String {
+    match self {
+      ElapsedStyle::Clock => {
+        let seconds = time_since_started.as_secs();
+
+        format!(
+          "{:02}:{:02}:{:02}.{:03}",
+          seconds / 3600,
+          seconds / 60 % 60,
+          seconds % 60,
+          time_since_started.subsec_millis()
+        )
+      }
+      ElapsedStyle::Ticks => format!("{ticks_since_started} ticks"),
+    }
+  }
+}
+
+impl<T> EventSync<T> {
+  /// Returns the time that has passed since the EventSync started, written in the given style for people to read.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::*;
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let event_sync = EventSync::from_starting_tick_millis(tickrate, 6_100, true);
+  ///
+  /// assert_eq!(event_sync.format_elapsed(ElapsedStyle::Clock), "00:01:01.000");
+  /// assert_eq!(event_sync.format_elapsed(ElapsedStyle::Ticks), "6100 ticks");
+  /// ```
+  pub fn format_elapsed(&self, style: ElapsedStyle) -> String {
+    let view = self.view();
+
+    style.format(view.time_since_started, view.tick)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn clock_style_counts_hours_past_a_day() {
+    let time = Duration::from_millis(((25 * 60 + 2) * 60 + 3) * 1000 + 45);
+
+    assert_eq!(ElapsedStyle::Clock.format(time, 0), "25:02:03.045");
+  }
+
+  #[test]
+  fn display_shows_tick_tickrate_and_state() {
+    let event_sync = EventSync::from_starting_tick_millis(10, 423, true);
+
+    assert_eq!(event_sync.to_string(), "tick 423 @ 10ms, paused");
+  }
+}
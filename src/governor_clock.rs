@@ -0,0 +1,83 @@
+//! Running [`governor`](https://docs.rs/governor) rate limiters on an EventSync's time.
+//!
+//! Enabled with the `governor` feature.
+
+use crate::{EventSync, Immutable};
+use governor::clock::{Clock, ReasonablyRealtime};
+use std::time::Duration;
+
+/// A governor [`Clock`](Clock) reading the time that has passed on an EventSync.
+///
+/// Rate limiters built on it refill as the EventSync's time passes, so they stop refilling while it's paused, and
+/// follow it when it's moved, restarted, or driven by a [`custom clock`](crate::ClockSource::Custom).
+///
+/// # Example
+///
+/// ```
+/// use event_sync::*;
+/// use governor::{Quota, RateLimiter};
+/// use std::num::NonZeroU32;
+/// use std::time::Duration;
+///
+/// let tickrate = 10; // 10ms between every tick.
+/// let mut event_sync = EventSync::new_paused_millis(tickrate);
+///
+/// let quota = Quota::per_second(NonZeroU32::new(1).unwrap());
+/// let limiter = RateLimiter::direct_with_clock(quota, GovernorClock::new(event_sync.clone_immutable()));
+///
+/// assert!(limiter.check().is_ok());
+/// assert!(limiter.check().is_err());
+///
+/// // A second passing on the EventSync lets another through.
+/// event_sync.sync_to(Duration::from_secs(1)).unwrap();
+///
+/// assert!(limiter.check().is_ok());
+/// ```
+#[derive(Debug, Clone)]
+pub struct GovernorClock {
+  event_sync: EventSync<Immutable>,
+}
+
+impl GovernorClock {
+  /// Creates a clock reading the time that has passed on the EventSync.
+  pub fn new(event_sync: EventSync<Immutable>) -> Self {
+    Self { event_sync }
+  }
+}
+
+impl Clock for GovernorClock {
+  type Instant = Duration;
+
+  fn now(&self) -> Duration {
+    self.event_sync.time_since_started()
+  }
+}
+
+impl ReasonablyRealtime for GovernorClock {}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use governor::{Quota, RateLimiter};
+
+  const TEST_TICKRATE: u64 = 10;
+
+  #[test]
+  fn paused_limiters_stay_limited() {
+    let event_sync = EventSync::from_starting_tick_millis(TEST_TICKRATE, 50, true);
+    let quota = Quota::with_period(Duration::from_millis(TEST_TICKRATE)).unwrap();
+    let limiter =
+      RateLimiter::direct_with_clock(quota, GovernorClock::new(event_sync.clone_immutable()));
+
+    assert!(limiter.check().is_ok());
+
+    std::thread::sleep(Duration::from_millis(TEST_TICKRATE * 3));
+
+    let not_until = limiter.check().unwrap_err();
+
+    assert_eq!(
+      not_until.wait_time_from(event_sync.time_since_started()),
+      Duration::from_millis(TEST_TICKRATE)
+    );
+  }
+}
@@ -1,39 +1,357 @@
+use crate::clock::{ClockSource, Timestamp};
+#[cfg(feature = "std")]
+use crate::clock::{Instant, SystemTime};
 use crate::errors::TimeError;
+use crate::tick_math;
+use core::time::Duration;
 use serde::{Deserialize, Serialize, Serializer};
-use std::time::{Duration, Instant};
+
+/// The version of the layout EventSyncs are serialized with.
+///
+/// Increase this when the layout changes in a way older versions of the crate can't read.
+const SERIALIZED_VERSION: u32 = 1;
+
+/// The most time an EventSync can be created or loaded with, as larger times can't be represented by every clock.
+pub(crate) const MAX_TIME_SINCE_STARTED: Duration = Duration::from_secs(100 * 365 * 24 * 60 * 60);
+
+/// The longest tickrate an EventSync can be created or loaded with, as long as it could ever run for.
+pub(crate) const MAX_TICKRATE: u64 = MAX_TIME_SINCE_STARTED.as_secs() * 1000;
+
+/// Brings a tickrate into the range an EventSync can run with, for the methods that can't return an error.
+pub(crate) fn clamp_tickrate(tickrate: u64) -> u64 {
+  tickrate.clamp(1, MAX_TICKRATE)
+}
+
+/// Checks that an EventSync can be created with the given tickrate and time passed, instead of clamping them.
+///
+/// # Errors
+///
+/// - If the tickrate is 0, or longer than [`MAX_TICKRATE`](MAX_TICKRATE).
+/// - If the time is longer than [`MAX_TIME_SINCE_STARTED`](MAX_TIME_SINCE_STARTED).
+pub(crate) fn validate(tickrate: u64, time_since_started: Duration) -> Result<(), TimeError> {
+  if tickrate == 0 {
+    return Err(TimeError::InvalidState("the tickrate is 0"));
+  }
+
+  if tickrate > MAX_TICKRATE {
+    return Err(TimeError::InvalidState(
+      "the tickrate is longer than 100 years",
+    ));
+  }
+
+  if time_since_started > MAX_TIME_SINCE_STARTED {
+    return Err(TimeError::InvalidState(
+      "more than 100 years have passed on it",
+    ));
+  }
+
+  Ok(())
+}
 
 /// The internal data for EventSync for threadsafe sharing of this value.
-#[derive(Clone, Eq, PartialEq, Serialize, Deserialize)]
+///
+/// The state is checked when deserializing, see [`SerializedInnerEventSync`](SerializedInnerEventSync).
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(
+  try_from = "SerializedInnerEventSync",
+  into = "SerializedInnerEventSync"
+)]
 pub(crate) struct InnerEventSync {
+  state: EventSyncState,
+  tickrate: u64,
+  clock: ClockSource,
+  /// The amount of times the timeline has been reset, so repeated tick numbers can be told apart.
+  generation: u64,
+  /// The audio sample rate the EventSync was created from, if it was, so time can be counted in samples.
+  sample_rate: Option<u32>,
+  /// The time that passed on the wall clock and on the EventSync while running, for reporting drift.
+  #[cfg(feature = "std")]
+  drift: DriftTotals,
+  /// Why the EventSync is paused, for the pauses that were given a reason.
+  #[cfg(feature = "std")]
+  pause_reasons: PauseReasons,
+}
+
+/// The layout InnerEventSync is stored as.
+///
+/// EventSyncs are always stored as paused, and anything missing from layouts saved by older versions of the crate is
+/// filled in with defaults.
+#[derive(Serialize, Deserialize)]
+struct SerializedInnerEventSync {
   #[serde(serialize_with = "serialize_paused")]
   state: EventSyncState,
-  tickrate: u32,
+  tickrate: u64,
+  #[cfg_attr(feature = "std", serde(default))]
+  clock: ClockSource,
+  #[serde(default)]
+  generation: u64,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  sample_rate: Option<u32>,
+  #[serde(default = "first_serialized_version")]
+  version: u32,
 }
 
+/// Layouts saved before the version was stored are all the first version.
+fn first_serialized_version() -> u32 {
+  1
+}
+
+impl From<InnerEventSync> for SerializedInnerEventSync {
+  fn from(inner: InnerEventSync) -> Self {
+    Self {
+      state: inner.state,
+      tickrate: inner.tickrate,
+      clock: inner.clock,
+      generation: inner.generation,
+      sample_rate: inner.sample_rate,
+      version: SERIALIZED_VERSION,
+    }
+  }
+}
+
+/// Stored state may come from an untrusted source, so anything that can't be loaded as is, is rejected instead of
+/// being corrected.
+impl TryFrom<SerializedInnerEventSync> for InnerEventSync {
+  type Error = TimeError;
+
+  fn try_from(serialized: SerializedInnerEventSync) -> Result<Self, Self::Error> {
+    if serialized.version > SERIALIZED_VERSION {
+      return Err(TimeError::InvalidState(
+        "it was saved by a newer version of event_sync",
+      ));
+    }
+
+    validate(serialized.tickrate, serialized.state.elapsed()?)?;
+
+    if serialized.sample_rate == Some(0) {
+      return Err(TimeError::InvalidState("the sample rate is 0"));
+    }
+
+    let mut inner = Self {
+      state: serialized.state,
+      tickrate: serialized.tickrate,
+      clock: serialized.clock,
+      generation: serialized.generation,
+      sample_rate: serialized.sample_rate,
+      #[cfg(feature = "std")]
+      drift: DriftTotals::default(),
+      #[cfg(feature = "std")]
+      pause_reasons: PauseReasons::default(),
+    };
+    inner.start_drift_stretch();
+
+    Ok(inner)
+  }
+}
+
+/// A reading of the wall clock, paired with the time that had passed on the EventSync at that moment.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy)]
+pub(crate) struct WallClockAnchor {
+  pub(crate) system_time: SystemTime,
+  pub(crate) time_since_started: Duration,
+}
+
+/// The time that passed on the wall clock and on the EventSync over every stretch the EventSync spent running.
+///
+/// A stretch ends whenever the EventSync is paused or has its time changed, so neither is counted as drift.
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Default)]
+pub(crate) struct DriftTotals {
+  /// When the EventSync first started running.
+  pub(crate) measured_since: Option<SystemTime>,
+  /// The time that passed on the wall clock in the stretches that have ended.
+  pub(crate) wall_clock_elapsed: Duration,
+  /// The time that passed on the EventSync in the stretches that have ended.
+  pub(crate) event_sync_elapsed: Duration,
+  /// Where the current stretch started, None while paused.
+  pub(crate) current: Option<WallClockAnchor>,
+}
+
+/// The reasons an EventSync was paused with, each counted for every time it was given.
+#[cfg(feature = "std")]
+#[derive(Clone, Default)]
+struct PauseReasons {
+  /// Each reason in the order it was first given, with how many times it's been given and not taken back.
+  held: Vec<(&'static str, usize)>,
+  /// True if the reasons paused a running EventSync, so it's unpaused once they're all taken back.
+  resume_when_released: bool,
+}
+
+/// Compares the fields that are saved when serializing. The drift totals and pause reasons are left out, as
+/// they're lost when deserializing.
+impl PartialEq for InnerEventSync {
+  fn eq(&self, other: &Self) -> bool {
+    self.state == other.state
+      && self.tickrate == other.tickrate
+      && self.clock == other.clock
+      && self.generation == other.generation
+      && self.sample_rate == other.sample_rate
+  }
+}
+
+impl Eq for InnerEventSync {}
+
 /// The states an EventSync could be in.
 ///
-/// When running, an [`Instant`](std::time::Instant) will be stored, tracking passed time whilst running.
+/// When running, a [`Timestamp`](Timestamp) will be stored, tracking passed time whilst running,
+/// along with any [`Slew`](Slew) currently being applied to it.
 /// When paused, the time that passed whilst running is stored as a [`Duration`](std::time::Duration).
 #[derive(Clone, Eq, PartialEq, Serialize, Deserialize)]
 enum EventSyncState {
   #[serde(skip_serializing)]
   #[serde(skip_deserializing)]
-  Running(Instant),
+  Running(Timestamp, Option<Slew>),
 
   Paused(Duration),
 }
 
+/// A correction to the time that has passed, spread over a period of time instead of applied all at once.
+///
+/// While the correction is being applied, time passes slightly faster or slower than it would otherwise.
+#[derive(Clone, Copy, Eq, PartialEq)]
+struct Slew {
+  /// The uncorrected time that had passed when the correction started.
+  started_at: Duration,
+  /// The uncorrected time the correction is spread over.
+  period: Duration,
+  /// The total amount of time being added or removed.
+  correction: Duration,
+  /// Whether the correction moves time forward or backward.
+  forward: bool,
+}
+
+impl Slew {
+  /// Creates a slew that moves the current time to the target over the given period.
+  ///
+  /// Backward corrections are spread over at least their own length, so time never runs backwards.
+  fn new(current: Duration, target: Duration, period: Duration) -> Self {
+    let forward = target >= current;
+    let correction = target.abs_diff(current);
+
+    Self {
+      started_at: current,
+      period: if forward {
+        period
+      } else {
+        period.max(correction)
+      },
+      correction,
+      forward,
+    }
+  }
+
+  /// Applies the portion of the correction that's due at the given uncorrected time.
+  fn apply(&self, time_passed: Duration) -> Duration {
+    let progress = time_passed.saturating_sub(self.started_at);
+
+    let applied = if progress >= self.period {
+      self.correction
+    } else {
+      Duration::from_nanos(
+        (self.correction.as_nanos() * progress.as_nanos() / self.period.as_nanos()) as u64,
+      )
+    };
+
+    self.shift(time_passed, applied)
+  }
+
+  /// Returns the uncorrected time at which the given corrected time is reached.
+  fn invert(&self, corrected_time: Duration) -> Duration {
+    let end = self.started_at + self.period;
+
+    if corrected_time >= self.apply_fully(end) {
+      return if self.forward {
+        corrected_time - self.correction
+      } else {
+        corrected_time + self.correction
+      };
+    }
+
+    if corrected_time <= self.started_at {
+      return corrected_time;
+    }
+
+    let corrected_period = if self.forward {
+      self.period + self.correction
+    } else {
+      self.period - self.correction
+    };
+    let progress = corrected_time - self.started_at;
+
+    self.started_at
+      + Duration::from_nanos(
+        (progress.as_nanos() * self.period.as_nanos() / corrected_period.as_nanos()) as u64,
+      )
+  }
+
+  /// Applies the entire correction to the given uncorrected time.
+  fn apply_fully(&self, time_passed: Duration) -> Duration {
+    self.shift(time_passed, self.correction)
+  }
+
+  /// Moves the time in the direction of the correction.
+  fn shift(&self, time_passed: Duration, amount: Duration) -> Duration {
+    if self.forward {
+      time_passed + amount
+    } else {
+      time_passed.saturating_sub(amount)
+    }
+  }
+}
+
 impl EventSyncState {
   /// Returns true if the state is EventSyncState::Paused().
   fn is_paused(&self) -> bool {
-    std::mem::discriminant(self)
-      == std::mem::discriminant(&EventSyncState::Paused(Duration::default()))
+    core::mem::discriminant(self)
+      == core::mem::discriminant(&EventSyncState::Paused(Duration::default()))
+  }
+
+  /// Returns the time that has passed, with any slew applied.
+  ///
+  /// # Errors
+  ///
+  /// - If the wall clock has gone back to before the EventSync was started.
+  fn elapsed(&self) -> Result<Duration, TimeError> {
+    match self {
+      EventSyncState::Running(time, slew) => {
+        let time_passed = time.elapsed()?;
+
+        Ok(slew.map_or(time_passed, |slew| slew.apply(time_passed)))
+      }
+      EventSyncState::Paused(time) => Ok(*time),
+    }
+  }
+
+  /// Returns how long until the given amount of time will have passed, accounting for any slew.
+  ///
+  /// # Errors
+  ///
+  /// - If the wall clock has gone back to before the EventSync was started.
+  fn time_until(&self, time_since_started: Duration) -> Result<Duration, TimeError> {
+    match self {
+      EventSyncState::Running(time, slew) => {
+        let time_passed = time.elapsed()?;
+        let target = slew.map_or(time_since_started, |slew| slew.invert(time_since_started));
+
+        Ok(target.saturating_sub(time_passed))
+      }
+      EventSyncState::Paused(time) => Ok(time_since_started.saturating_sub(*time)),
+    }
   }
 
   /// Changes the state to Paused, and stored the elapsed time while running.
+  ///
+  /// Any slew in progress stops where it is, so the time doesn't jump when pausing. The rest of the correction is
+  /// dropped.
   fn pause(&mut self) {
-    if let EventSyncState::Running(time) = self {
-      *self = EventSyncState::Paused(time.elapsed())
+    if let EventSyncState::Running(time, slew) = self {
+      let time_passed = time.saturating_elapsed();
+
+      *self = match slew {
+        Some(slew) => EventSyncState::Paused(slew.apply(time_passed)),
+        None => EventSyncState::Paused(time_passed),
+      }
     }
   }
 
@@ -42,11 +360,11 @@ impl EventSyncState {
   /// # Errors
   ///
   /// - If [`Instant::checked_sub`](https://doc.rust-lang.org/stable/std/time/struct.Instant.html#method.checked_sub) fails.
-  fn unpause(&mut self) -> Result<(), TimeError> {
+  fn unpause(&mut self, clock: ClockSource) -> Result<(), TimeError> {
     match self {
       EventSyncState::Paused(paused_duration) => {
-        if let Some(running_time) = Instant::now().checked_sub(*paused_duration) {
-          *self = EventSyncState::Running(running_time);
+        if let Some(running_time) = Timestamp::now(clock).checked_sub(*paused_duration) {
+          *self = EventSyncState::Running(running_time, None);
         } else {
           return Err(TimeError::FailedToStartEventSync);
         };
@@ -68,26 +386,88 @@ where
   S: Serializer,
 {
   match value {
-    EventSyncState::Running(time) => EventSyncState::Paused(time.elapsed()).serialize(serializer),
+    EventSyncState::Running(..) => {
+      let mut paused = value.clone();
+      paused.pause();
+
+      paused.serialize(serializer)
+    }
     EventSyncState::Paused(_) => value.serialize(serializer),
   }
 }
 
 impl InnerEventSync {
-  /// Creates an instance of InnerEventSync with the given tickrate, starting time, and whether or not it starts paused.
+  /// Creates an instance of InnerEventSync with the given tickrate, starting time, whether or not it starts paused,
+  /// and the clock to measure time against.
   ///
   /// Starting paused will store the passed in subtracted_time.
-  pub(crate) fn new(tickrate: u32, subtracted_time: Duration, is_paused: bool) -> Self {
+  pub(crate) fn new(
+    tickrate: u64,
+    subtracted_time: Duration,
+    is_paused: bool,
+    clock: ClockSource,
+  ) -> Self {
     let state = if is_paused {
       EventSyncState::Paused(subtracted_time)
     } else {
-      EventSyncState::Running(Instant::now().checked_sub(subtracted_time).unwrap())
+      EventSyncState::Running(
+        Timestamp::now(clock).checked_sub(subtracted_time).unwrap(),
+        None,
+      )
     };
 
-    Self {
+    let mut inner = Self {
       state,
-      tickrate: tickrate.max(1),
-    }
+      tickrate: clamp_tickrate(tickrate),
+      clock,
+      generation: 0,
+      sample_rate: None,
+      #[cfg(feature = "std")]
+      drift: DriftTotals::default(),
+      #[cfg(feature = "std")]
+      pause_reasons: PauseReasons::default(),
+    };
+    inner.start_drift_stretch();
+
+    inner
+  }
+
+  /// Creates a running instance of InnerEventSync measured against the wall clock, where tick 0 occurred at the
+  /// given anchor.
+  #[cfg(feature = "std")]
+  pub(crate) fn from_system_epoch(tickrate: u64, anchor: SystemTime) -> Self {
+    let mut inner = Self {
+      state: EventSyncState::Running(Timestamp::WallClock(anchor), None),
+      tickrate: clamp_tickrate(tickrate),
+      clock: ClockSource::WallClock,
+      generation: 0,
+      sample_rate: None,
+      drift: DriftTotals::default(),
+      pause_reasons: PauseReasons::default(),
+    };
+    inner.start_drift_stretch();
+
+    inner
+  }
+
+  /// Creates a paused instance of InnerEventSync from the parts of a saved one, checking them the same way as when
+  /// deserializing.
+  #[cfg(feature = "std")]
+  pub(crate) fn from_saved(
+    tickrate: u64,
+    time_since_started: Duration,
+    clock: ClockSource,
+    generation: u64,
+    sample_rate: Option<u32>,
+  ) -> Result<Self, TimeError> {
+    Self::try_from(SerializedInnerEventSync {
+      state: EventSyncState::Paused(time_since_started),
+      tickrate,
+      clock,
+      generation,
+      sample_rate,
+      version: SERIALIZED_VERSION,
+    })
   }
 
   // Not used at the moment, but the code will be kept here for if it's ever needed in the future.
@@ -98,16 +478,95 @@ impl InnerEventSync {
   ///
   /// Does nothing if already paused.
   pub(crate) fn pause(&mut self) {
+    self.end_drift_stretch();
     self.state.pause();
+
+    // Paused without a reason, so it's held until unpaused outright.
+    #[cfg(feature = "std")]
+    {
+      self.pause_reasons.resume_when_released = false;
+    }
+  }
+
+  /// Pauses the EventSync with the reason, counting it once more if it's already held.
+  #[cfg(feature = "std")]
+  pub(crate) fn pause_with_reason(&mut self, reason: &'static str) {
+    if !self.is_paused() {
+      self.end_drift_stretch();
+      self.state.pause();
+      self.pause_reasons.resume_when_released = true;
+    }
+
+    match self
+      .pause_reasons
+      .held
+      .iter_mut()
+      .find(|(held, _)| *held == reason)
+    {
+      Some((_, count)) => *count += 1,
+      None => self.pause_reasons.held.push((reason, 1)),
+    }
+  }
+
+  /// Takes back one pause with the reason, returning false if the reason wasn't held.
+  ///
+  /// Once no reasons are held, the EventSync is unpaused, unless it was paused without a reason as well.
+  ///
+  /// # Errors
+  ///
+  /// - If [`Instant::checked_sub`](https://doc.rust-lang.org/stable/std/time/struct.Instant.html#method.checked_sub) fails.
+  #[cfg(feature = "std")]
+  pub(crate) fn unpause_with_reason(&mut self, reason: &'static str) -> Result<bool, TimeError> {
+    let held = &mut self.pause_reasons.held;
+    let Some(index) = held.iter().position(|(held, _)| *held == reason) else {
+      return Ok(false);
+    };
+
+    held[index].1 -= 1;
+
+    if held[index].1 == 0 {
+      held.remove(index);
+    }
+
+    if held.is_empty() && core::mem::take(&mut self.pause_reasons.resume_when_released) {
+      self.state.unpause(self.clock)?;
+      self.start_drift_stretch();
+    }
+
+    Ok(true)
+  }
+
+  /// Returns the reasons the EventSync is paused with, in the order they were first given.
+  #[cfg(feature = "std")]
+  pub(crate) fn pause_reasons(&self) -> Vec<&'static str> {
+    self
+      .pause_reasons
+      .held
+      .iter()
+      .map(|(reason, _)| *reason)
+      .collect()
   }
 
-  /// Changes the internal state to Running and applies the time that occurred before pausing.
+  /// Changes the internal state to Running and applies the time that occurred before pausing, dropping any reasons
+  /// it was paused with.
   ///
   /// # Errors
   ///
   /// - If [`Instant::checked_sub`](https://doc.rust-lang.org/stable/std/time/struct.Instant.html#method.checked_sub) fails.
   pub(crate) fn unpause(&mut self) -> Result<(), TimeError> {
-    self.state.unpause()
+    let was_paused = self.is_paused();
+    self.state.unpause(self.clock)?;
+
+    #[cfg(feature = "std")]
+    {
+      self.pause_reasons = PauseReasons::default();
+    }
+
+    if was_paused {
+      self.start_drift_stretch();
+    }
+
+    Ok(())
   }
 
   /// Returns true if the current state of the EventSync is EventSyncState::Running().
@@ -130,24 +589,168 @@ impl InnerEventSync {
 
   /// Sets the EventSync state to Running, overwriting any data in the previous state.
   pub(crate) fn restart(&mut self) {
-    self.state = EventSyncState::Running(Instant::now());
+    self.end_drift_stretch();
+    self.state = EventSyncState::Running(Timestamp::now(self.clock), None);
+    self.generation += 1;
+    self.start_drift_stretch();
+
+    #[cfg(feature = "std")]
+    {
+      self.pause_reasons = PauseReasons::default();
+    }
   }
 
   /// Sets the EventSync state to Paused(Duration::default()), overwriting any data in the previous state.
+  #[cfg_attr(not(feature = "std"), allow(dead_code))]
   pub(crate) fn restart_paused(&mut self) {
+    self.end_drift_stretch();
     self.state = EventSyncState::Paused(Duration::default());
+    self.generation += 1;
+
+    #[cfg(feature = "std")]
+    {
+      self.pause_reasons = PauseReasons::default();
+    }
+  }
+
+  /// Changes the tickrate, then restarts the EventSync running from 0.
+  #[cfg(feature = "std")]
+  pub(crate) fn restart_with_tickrate(&mut self, new_tickrate: u64) {
+    self.change_tickrate(new_tickrate);
+    self.restart();
   }
 
-  /// Change the internally stored tickrate
-  pub(crate) fn change_tickrate(&mut self, new_tickrate: u32) {
-    self.tickrate = new_tickrate.max(1);
+  /// Pauses the EventSync, then changes the tickrate, returning the previous tickrate and the tick it's now on.
+  #[cfg(feature = "std")]
+  pub(crate) fn pause_and_change_tickrate(&mut self, new_tickrate: u64) -> (u64, u64) {
+    self.pause();
+    self.change_tickrate(new_tickrate)
+  }
+
+  /// Overwrites the time that has passed, keeping the current running or paused state.
+  ///
+  /// # Errors
+  ///
+  /// - If [`Instant::checked_sub`](https://doc.rust-lang.org/stable/std/time/struct.Instant.html#method.checked_sub) fails.
+  pub(crate) fn set_time_since_started(
+    &mut self,
+    time_since_started: Duration,
+  ) -> Result<(), TimeError> {
+    let state = match self.state {
+      EventSyncState::Running(..) => EventSyncState::Running(
+        Timestamp::now(self.clock)
+          .checked_sub(time_since_started)
+          .ok_or(TimeError::FailedToStartEventSync)?,
+        None,
+      ),
+      EventSyncState::Paused(_) => EventSyncState::Paused(time_since_started),
+    };
+    self.end_drift_stretch();
+    self.state = state;
+    self.generation += 1;
+    self.start_drift_stretch();
+
+    Ok(())
+  }
+
+  /// Gradually moves the time that has passed to the given time, spreading the correction over the given period.
+  ///
+  /// The target keeps moving forward while running, as if it were a second EventSync.
+  /// When paused, or if the period is 0, the time is moved immediately.
+  ///
+  /// # Errors
+  ///
+  /// - If [`Instant::checked_sub`](https://doc.rust-lang.org/stable/std/time/struct.Instant.html#method.checked_sub) fails.
+  pub(crate) fn slew_time_since_started(
+    &mut self,
+    time_since_started: Duration,
+    period: Duration,
+  ) -> Result<(), TimeError> {
+    if self.is_paused() || period.is_zero() {
+      return self.set_time_since_started(time_since_started);
+    }
+
+    let current = self.time_since_started();
+    let running_time = Timestamp::now(self.clock)
+      .checked_sub(current)
+      .ok_or(TimeError::FailedToStartEventSync)?;
+
+    self.end_drift_stretch();
+    self.state = EventSyncState::Running(
+      running_time,
+      Some(Slew::new(current, time_since_started, period)),
+    );
+    self.start_drift_stretch();
+
+    Ok(())
+  }
+
+  /// Starts a stretch of measuring drift from the current wall clock time, if running.
+  fn start_drift_stretch(&mut self) {
+    #[cfg(feature = "std")]
+    if !self.is_paused() {
+      let now = SystemTime::now();
+
+      self.drift.measured_since.get_or_insert(now);
+      self.drift.current = Some(WallClockAnchor {
+        system_time: now,
+        time_since_started: self.time_since_started(),
+      });
+    }
+  }
+
+  /// Adds the current stretch of measuring drift to the totals, before the EventSync is paused or has its time
+  /// changed.
+  fn end_drift_stretch(&mut self) {
+    #[cfg(feature = "std")]
+    if let Some(anchor) = self.drift.current.take() {
+      self.drift.wall_clock_elapsed += anchor.system_time.elapsed().unwrap_or_default();
+      self.drift.event_sync_elapsed += self
+        .time_since_started()
+        .saturating_sub(anchor.time_since_started);
+    }
+  }
+
+  /// Returns the time that passed on the wall clock and on the EventSync while running.
+  #[cfg(feature = "std")]
+  pub(crate) fn drift_totals(&self) -> DriftTotals {
+    self.drift
+  }
+
+  /// Change the internally stored tickrate, returning the previous tickrate and the tick it's now on.
+  pub(crate) fn change_tickrate(&mut self, new_tickrate: u64) -> (u64, u64) {
+    let old_tickrate = core::mem::replace(&mut self.tickrate, clamp_tickrate(new_tickrate));
+
+    (old_tickrate, self.ticks_since_started())
   }
 
   /// Returns the currently stored tickrate.
-  pub(crate) fn get_tickrate(&self) -> u32 {
+  pub(crate) fn get_tickrate(&self) -> u64 {
     self.tickrate
   }
 
+  /// Returns the amount of times the timeline has been reset by restarting or setting the time.
+  pub(crate) fn generation(&self) -> u64 {
+    self.generation
+  }
+
+  /// Returns the audio sample rate the EventSync was created from, if it was.
+  #[cfg(feature = "std")]
+  pub(crate) fn sample_rate(&self) -> Option<u32> {
+    self.sample_rate
+  }
+
+  /// Sets the audio sample rate time can be counted in.
+  #[cfg(feature = "std")]
+  pub(crate) fn set_sample_rate(&mut self, sample_rate: u32) {
+    self.sample_rate = Some(sample_rate);
+  }
+
+  /// Returns the clock this EventSync measures time against.
+  pub(crate) fn clock_source(&self) -> ClockSource {
+    self.clock
+  }
+
   /// Returns the exact amount of time to sleep to reach a specified tick.
   ///
   /// If 1.6 ticks have passed, and 3 is passed in, 1.4 * tickrate is returned.
@@ -157,11 +760,31 @@ impl InnerEventSync {
   ) -> Result<Duration, TimeError> {
     self.err_if_paused()?;
 
-    if self.ticks_since_started() < tick_to_wait_for {
-      Ok(
-        Duration::from_millis(tick_to_wait_for * self.get_tickrate() as u64)
-          - self.time_since_started(),
-      )
+    let time_since_started = self.try_time_since_started()?;
+
+    if self.ticks_in(time_since_started) < tick_to_wait_for {
+      self
+        .state
+        .time_until(tick_math::time_of_tick(tick_to_wait_for, self.tickrate))
+    } else {
+      Err(TimeError::ThatTimeHasAlreadyHappened)
+    }
+  }
+
+  /// Returns the exact amount of time to sleep until the given amount of time has passed, regardless of ticks.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if the EventSync is paused.
+  /// - An error is returned if that much time has already passed.
+  pub(crate) fn time_until_elapsed(
+    &self,
+    time_since_started: Duration,
+  ) -> Result<Duration, TimeError> {
+    self.err_if_paused()?;
+
+    if self.try_time_since_started()? < time_since_started {
+      self.state.time_until(time_since_started)
     } else {
       Err(TimeError::ThatTimeHasAlreadyHappened)
     }
@@ -175,6 +798,7 @@ impl InnerEventSync {
   /// # Errors
   ///
   /// - An error is returned if the EventSync is paused.
+  #[cfg_attr(not(feature = "std"), allow(dead_code))]
   pub(crate) fn time_for_tick(&self) -> Result<Duration, TimeError> {
     self.err_if_paused()?;
 
@@ -189,41 +813,158 @@ impl InnerEventSync {
   /// # Errors
   ///
   /// - An error is returned if the EventSync is paused.
+  /// - An error is returned if the wall clock has gone back to before the EventSync was started.
   pub(crate) fn time_for_x_ticks(&self, ticks_to_wait: u32) -> Result<Duration, TimeError> {
     self.err_if_paused()?;
 
-    let ticks_since_started = self.ticks_since_started();
+    let ticks_since_started = self.ticks_in(self.try_time_since_started()?);
 
     self.time_until_tick_occurs(ticks_since_started + ticks_to_wait as u64)
   }
 
   /// Returns the amount of time that has occurred since the creation of this instance of EventSync.
-  pub(crate) fn time_since_started(&self) -> std::time::Duration {
-    match self.state {
-      EventSyncState::Running(instant) => instant.elapsed(),
-      EventSyncState::Paused(time) => time,
-    }
+  ///
+  /// # Errors
+  ///
+  /// - If the wall clock has gone back to before the EventSync was started.
+  pub(crate) fn try_time_since_started(&self) -> Result<Duration, TimeError> {
+    self.state.elapsed()
+  }
+
+  /// Returns the amount of time that has occurred since the creation of this instance of EventSync.
+  ///
+  /// 0 is returned if the wall clock has gone back to before the EventSync was started.
+  pub(crate) fn time_since_started(&self) -> Duration {
+    self.try_time_since_started().unwrap_or_default()
   }
 
   /// Returns the amount of ticks that have occurred since the creation of this instance of EventSync.
   pub(crate) fn ticks_since_started(&self) -> u64 {
-    let time_passed = match self.state {
-      EventSyncState::Running(instant) => instant.elapsed().as_millis(),
-      EventSyncState::Paused(time) => time.as_millis(),
-    };
+    self.ticks_in(self.time_since_started())
+  }
+
+  /// Returns true if the given tick is the one the EventSync is on, so waiting for it wouldn't wait at all.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if the EventSync is paused.
+  #[cfg(feature = "std")]
+  pub(crate) fn is_current_tick(&self, tick: u64) -> Result<bool, TimeError> {
+    self.err_if_paused()?;
+
+    Ok(self.ticks_in(self.try_time_since_started()?) == tick)
+  }
 
-    (time_passed / self.tickrate as u128) as u64
+  /// Returns the amount of whole ticks that fit in the given duration.
+  fn ticks_in(&self, duration: Duration) -> u64 {
+    tick_math::ticks_in(duration, self.tickrate)
   }
 
   /// Returns the amount of time that has passed since the last tick
-  pub(crate) fn time_since_last_tick(&self) -> std::time::Duration {
-    Duration::from_nanos(
-      (self.time_since_started().as_nanos() % (self.get_tickrate() as u128 * 1000000)) as u64,
-    )
+  pub(crate) fn time_since_last_tick(&self) -> Duration {
+    tick_math::time_since_last_tick(self.time_since_started(), self.tickrate)
   }
 
   /// Returns the amount of time until the next tick will occur.
-  pub(crate) fn time_until_next_tick(&self) -> std::time::Duration {
-    Duration::from_millis(self.get_tickrate() as u64).saturating_sub(self.time_since_last_tick())
+  pub(crate) fn time_until_next_tick(&self) -> Duration {
+    tick_math::time_until_next_tick(self.time_since_started(), self.tickrate)
+  }
+
+  /// Projects the given tick onto the wall clock.
+  ///
+  /// Returns None if paused, as no tick has a point on the wall clock until the EventSync is running again.
+  /// None is also returned if the resulting time cannot be represented by [`SystemTime`](std::time::SystemTime).
+  #[cfg(feature = "std")]
+  pub(crate) fn system_time_of_tick(&self, tick: u64) -> Option<SystemTime> {
+    if self.is_paused() {
+      return None;
+    }
+
+    let time_of_tick = tick_math::checked_time_of_tick(tick, self.tickrate)?;
+    let time_since_started = self.time_since_started();
+    let now = SystemTime::now();
+
+    if time_of_tick >= time_since_started {
+      now.checked_add(time_of_tick - time_since_started)
+    } else {
+      now.checked_sub(time_since_started - time_of_tick)
+    }
+  }
+
+  /// Projects the given tick onto the monotonic clock.
+  ///
+  /// Returns None if paused, or if the resulting time cannot be represented by [`Instant`](std::time::Instant).
+  #[cfg(feature = "std")]
+  pub(crate) fn instant_of_tick(&self, tick: u64) -> Option<Instant> {
+    if self.is_paused() {
+      return None;
+    }
+
+    let time_of_tick = tick_math::checked_time_of_tick(tick, self.tickrate)?;
+    let time_since_started = self.time_since_started();
+    let now = Instant::now();
+
+    if time_of_tick >= time_since_started {
+      now.checked_add(time_of_tick - time_since_started)
+    } else {
+      now.checked_sub(time_since_started - time_of_tick)
+    }
+  }
+
+  /// Returns the tick that was, or will be, current at the given instant, projected from the current run.
+  ///
+  /// Returns None if paused, or if the instant is from before the EventSync started.
+  #[cfg(feature = "std")]
+  pub(crate) fn tick_at(&self, instant: Instant) -> Option<u64> {
+    if self.is_paused() {
+      return None;
+    }
+
+    self
+      .time_at_instant(instant)
+      .map(|time_at_instant| self.ticks_in(time_at_instant))
+  }
+
+  /// Returns the amount of tick boundaries between the two instants, in either order, projected from the current
+  /// run.
+  ///
+  /// Instants from before the EventSync started are treated as when it started.
+  /// Returns None if paused.
+  #[cfg(feature = "std")]
+  pub(crate) fn ticks_elapsed_between(&self, start: Instant, end: Instant) -> Option<u64> {
+    if self.is_paused() {
+      return None;
+    }
+
+    let tick_at = |instant| {
+      self
+        .time_at_instant(instant)
+        .map_or(0, |time_at_instant| self.ticks_in(time_at_instant))
+    };
+
+    Some(tick_at(start).abs_diff(tick_at(end)))
+  }
+
+  /// Returns the time that had passed on the EventSync at the given instant, projected from the current run.
+  ///
+  /// Returns None if the instant is from before the EventSync started.
+  #[cfg(feature = "std")]
+  fn time_at_instant(&self, instant: Instant) -> Option<Duration> {
+    let time_since_started = self.time_since_started();
+    let now = Instant::now();
+
+    if instant >= now {
+      time_since_started.checked_add(instant - now)
+    } else {
+      time_since_started.checked_sub(now - instant)
+    }
+  }
+
+  /// Projects the next tick onto the wall clock.
+  ///
+  /// Returns None under the same conditions as [`system_time_of_tick`](InnerEventSync::system_time_of_tick).
+  #[cfg(feature = "std")]
+  pub(crate) fn next_tick_system_time(&self) -> Option<SystemTime> {
+    self.system_time_of_tick(self.ticks_since_started() + 1)
   }
 }
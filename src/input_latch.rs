@@ -0,0 +1,235 @@
+use crate::{EventSync, Immutable};
+use std::collections::VecDeque;
+use std::sync::{Mutex, MutexGuard};
+
+/// Which of the values pushed to an [`InputLatch`](InputLatch) it latches on each tick.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LatchPolicy {
+  /// Latches the last value pushed before the tick, dropping the ones before it.
+  ///
+  /// When nothing was pushed, the value from the last tick is kept, like sampling the state of a controller.
+  #[default]
+  LastWriteWins,
+
+  /// Latches the values one per tick in the order they were pushed, so none are dropped.
+  ///
+  /// When nothing is queued, nothing is latched for the tick, like a stream of button presses.
+  Queued,
+}
+
+/// Latches values pushed at any time to the ticks of an EventSync, so consumers read exactly one value per tick.
+///
+/// Producers, such as an input thread, [`push()`](InputLatch::push) values whenever they arrive, while consumers,
+/// such as a fixed-timestep update, [`read()`](InputLatch::read) the value latched for the current tick. Every read
+/// within a tick sees the same value, and values pushed during a tick aren't seen until the next one.
+///
+/// The latch happens on the first push or read after a tick, so no thread is needed to drive it.
+/// With [`Queued`](LatchPolicy::Queued), one value is latched each time a tick is seen, so ticks that pass without
+/// a push or read don't use up any values.
+///
+/// # Example
+///
+/// ```
+/// use event_sync::*;
+/// use std::time::Duration;
+///
+/// let tickrate = 10; // 10ms between every tick.
+/// let mut event_sync = EventSync::new_paused_millis(tickrate);
+///
+/// let stick = InputLatch::new(event_sync.clone_immutable());
+///
+/// stick.push((0.0, 0.5));
+/// stick.push((0.0, 1.0));
+///
+/// // Nothing is latched until the next tick.
+/// assert_eq!(stick.read(), None);
+///
+/// event_sync.sync_to(Duration::from_millis(10)).unwrap();
+///
+/// assert_eq!(stick.read(), Some((0.0, 1.0)));
+///
+/// // With nothing new pushed, the last value is kept.
+/// event_sync.sync_to(Duration::from_millis(20)).unwrap();
+///
+/// assert_eq!(stick.read(), Some((0.0, 1.0)));
+/// ```
+#[derive(Debug)]
+pub struct InputLatch<T> {
+  event_sync: EventSync<Immutable>,
+  policy: LatchPolicy,
+  state: Mutex<LatchState<T>>,
+}
+
+#[derive(Debug)]
+struct LatchState<T> {
+  /// The value consumers read.
+  latched: Option<T>,
+  /// The values pushed since the last latch, or still queued.
+  pending: VecDeque<T>,
+  /// The tick the value was latched on.
+  latched_tick: u64,
+}
+
+impl<T> InputLatch<T> {
+  /// Creates a latch with nothing latched, latching the last value pushed every tick.
+  pub fn new(event_sync: EventSync<Immutable>) -> Self {
+    let latched_tick = event_sync.ticks_since_started();
+
+    Self {
+      event_sync,
+      policy: LatchPolicy::default(),
+      state: Mutex::new(LatchState {
+        latched: None,
+        pending: VecDeque::new(),
+        latched_tick,
+      }),
+    }
+  }
+
+  /// Sets which of the pushed values are latched on each tick.
+  pub fn with_policy(mut self, policy: LatchPolicy) -> Self {
+    self.policy = policy;
+
+    self
+  }
+
+  /// Returns which of the pushed values are latched on each tick.
+  pub fn policy(&self) -> LatchPolicy {
+    self.policy
+  }
+
+  /// Pushes a value to be latched on a later tick.
+  pub fn push(&self, value: T) {
+    let mut state = self.lock_latched();
+
+    if self.policy == LatchPolicy::LastWriteWins {
+      state.pending.clear();
+    }
+
+    state.pending.push_back(value);
+  }
+
+  /// Returns the value latched for the current tick, if there is one.
+  pub fn read(&self) -> Option<T>
+  where
+    T: Clone,
+  {
+    self.lock_latched().latched.clone()
+  }
+
+  /// Takes the value latched for the current tick, so later reads in the same tick see nothing.
+  pub fn take(&self) -> Option<T> {
+    self.lock_latched().latched.take()
+  }
+
+  /// Returns the tick the current value was latched on.
+  pub fn latched_tick(&self) -> u64 {
+    self.lock_latched().latched_tick
+  }
+
+  /// Returns the amount of values waiting to be latched.
+  pub fn pending(&self) -> usize {
+    self.lock_latched().pending.len()
+  }
+
+  /// Drops the latched value and every value waiting to be latched.
+  pub fn clear(&self) {
+    let mut state = self.lock_state();
+
+    state.latched = None;
+    state.pending.clear();
+  }
+
+  /// Locks the state, first latching a value if the EventSync has moved to another tick since the last latch.
+  fn lock_latched(&self) -> MutexGuard<'_, LatchState<T>> {
+    let current_tick = self.event_sync.ticks_since_started();
+    let mut state = self.lock_state();
+
+    // Moving back is a new tick to latch on as well.
+    if state.latched_tick != current_tick {
+      match self.policy {
+        LatchPolicy::LastWriteWins => {
+          if let Some(value) = state.pending.pop_back() {
+            state.latched = Some(value);
+          }
+        }
+        LatchPolicy::Queued => state.latched = state.pending.pop_front(),
+      }
+
+      state.latched_tick = current_tick;
+    }
+
+    state
+  }
+
+  fn lock_state(&self) -> MutexGuard<'_, LatchState<T>> {
+    self
+      .state
+      .lock()
+      .unwrap_or_else(|poisoned| poisoned.into_inner())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::Mutable;
+  use std::time::Duration;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  fn move_to_tick(event_sync: &mut EventSync<Mutable>, tick: u64) {
+    event_sync
+      .sync_to(Duration::from_millis(tick * TEST_TICKRATE))
+      .unwrap();
+  }
+
+  #[test]
+  fn queued_values_latch_one_per_tick() {
+    let mut event_sync = EventSync::new_paused_millis(TEST_TICKRATE);
+    let latch = InputLatch::new(event_sync.clone_immutable()).with_policy(LatchPolicy::Queued);
+
+    latch.push('a');
+    latch.push('b');
+    move_to_tick(&mut event_sync, 1);
+    latch.push('c');
+
+    assert_eq!(latch.read(), Some('a'));
+    assert_eq!(latch.read(), Some('a'));
+    assert_eq!(latch.pending(), 2);
+
+    // Skipped ticks don't use up values.
+    move_to_tick(&mut event_sync, 5);
+
+    assert_eq!(latch.take(), Some('b'));
+    assert_eq!(latch.read(), None);
+
+    move_to_tick(&mut event_sync, 6);
+
+    assert_eq!(latch.read(), Some('c'));
+    assert_eq!(latch.latched_tick(), 6);
+
+    move_to_tick(&mut event_sync, 7);
+
+    assert_eq!(latch.read(), None);
+  }
+
+  #[test]
+  fn pushes_during_a_tick_wait_for_the_next() {
+    let mut event_sync = EventSync::new_paused_millis(TEST_TICKRATE);
+    let latch = InputLatch::new(event_sync.clone_immutable());
+
+    latch.push(1);
+    move_to_tick(&mut event_sync, 1);
+    // Latches 1 before buffering 2, though nothing has been read on this tick yet.
+    latch.push(2);
+    latch.push(3);
+
+    assert_eq!(latch.read(), Some(1));
+    assert_eq!(latch.pending(), 1);
+
+    move_to_tick(&mut event_sync, 2);
+
+    assert_eq!(latch.read(), Some(3));
+  }
+}
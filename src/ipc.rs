@@ -0,0 +1,323 @@
+//! Controlling an EventSync from another process over a Unix domain socket.
+//!
+//! Enabled with the `ipc` feature. Only available on unix platforms.
+//!
+//! Windows named pipes aren't implemented, so the feature does nothing on Windows. The `net` feature's
+//! [`EventSyncTcpServer`](crate::EventSyncTcpServer) can be bound to localhost there instead.
+
+use crate::control::{ClockStatus, ControlRequest, ControlResponse};
+use crate::{EventSync, Mutable};
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::os::unix::fs::FileTypeExt;
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::Path;
+use std::thread::JoinHandle;
+
+/// The longest request that will be read from a connection, in bytes.
+const MAX_MESSAGE_SIZE: u64 = 64 * 1024;
+
+/// Serves control requests for an EventSync over a Unix domain socket.
+///
+/// Requests from [`EventSyncIpcClient`](EventSyncIpcClient) are applied to the EventSync the server was created with,
+/// and therefore every EventSync connected to it.
+///
+/// Only available on unix platforms.
+///
+/// # Example
+///
+/// ```
+/// use event_sync::*;
+///
+/// let path = std::env::temp_dir().join("event_sync_ipc_doc_example.sock");
+/// let event_sync = EventSync::new_millis(10);
+///
+/// let server = EventSyncIpcServer::bind(&path, event_sync.clone()).unwrap();
+/// server.spawn();
+///
+/// // This could be in any other process.
+/// let mut client = EventSyncIpcClient::connect(&path).unwrap();
+/// client.pause().unwrap();
+///
+/// assert!(event_sync.is_paused());
+/// ```
+pub struct EventSyncIpcServer {
+  listener: UnixListener,
+  event_sync: EventSync<Mutable>,
+}
+
+/// Sends control requests to an [`EventSyncIpcServer`](EventSyncIpcServer).
+pub struct EventSyncIpcClient {
+  reader: BufReader<UnixStream>,
+  writer: UnixStream,
+}
+
+impl EventSyncIpcServer {
+  /// Creates a socket at the given path that controls the given EventSync.
+  ///
+  /// A socket already at the path is removed, as it would be left over from a previous server.
+  ///
+  /// # Errors
+  ///
+  /// - If the socket could not be created.
+  /// - [`AlreadyExists`](io::ErrorKind::AlreadyExists) is returned if something other than a socket is at the path.
+  pub fn bind<P: AsRef<Path>>(path: P, event_sync: EventSync<Mutable>) -> io::Result<Self> {
+    let path = path.as_ref();
+
+    match std::fs::symlink_metadata(path) {
+      Ok(metadata) if metadata.file_type().is_socket() => std::fs::remove_file(path)?,
+      Ok(_) => {
+        return Err(io::Error::new(
+          io::ErrorKind::AlreadyExists,
+          "Something other than a socket is at the path.",
+        ))
+      }
+      Err(error) if error.kind() == io::ErrorKind::NotFound => (),
+      Err(error) => return Err(error),
+    }
+
+    Ok(Self {
+      listener: UnixListener::bind(path)?,
+      event_sync,
+    })
+  }
+
+  /// Accepts connections on the current thread.
+  ///
+  /// Each connection is handled on a thread of its own, so a slow or idle client can't hold up any other.
+  ///
+  /// # Errors
+  ///
+  /// - If accepting a connection fails.
+  pub fn serve(self) -> io::Result<()> {
+    loop {
+      let (stream, _) = self.listener.accept()?;
+      let mut event_sync = self.event_sync.clone();
+
+      // A client disconnecting mid-request shouldn't take down the server.
+      std::thread::spawn(move || handle_connection(stream, &mut event_sync));
+    }
+  }
+
+  /// Serves requests on a separate thread.
+  pub fn spawn(self) -> JoinHandle<io::Result<()>> {
+    std::thread::spawn(move || self.serve())
+  }
+}
+
+/// Applies every request sent over the stream until the client disconnects.
+///
+/// The connection is dropped if a request is longer than [`MAX_MESSAGE_SIZE`](MAX_MESSAGE_SIZE), as the rest of it
+/// can't be told apart from the requests after it.
+fn handle_connection(stream: UnixStream, event_sync: &mut EventSync<Mutable>) -> io::Result<()> {
+  let mut writer = stream.try_clone()?;
+  let mut reader = BufReader::new(stream);
+  let mut line = Vec::new();
+
+  loop {
+    line.clear();
+
+    // A byte past the limit is read, so a request of exactly the limit isn't mistaken for a longer one.
+    if (&mut reader)
+      .take(MAX_MESSAGE_SIZE + 1)
+      .read_until(b'\n', &mut line)?
+      == 0
+    {
+      return Ok(());
+    }
+
+    if line.len() as u64 > MAX_MESSAGE_SIZE {
+      write_message(
+        &mut writer,
+        &ControlResponse::Error("The request is too long.".to_string()),
+      )?;
+
+      return Err(io::Error::new(
+        io::ErrorKind::InvalidData,
+        "The request is too long.",
+      ));
+    }
+
+    let response = match serde_json::from_slice::<ControlRequest>(&line) {
+      Ok(request) => request.apply(event_sync),
+      Err(error) => ControlResponse::Error(error.to_string()),
+    };
+
+    write_message(&mut writer, &response)?;
+  }
+}
+
+impl EventSyncIpcClient {
+  /// Connects to the server listening at the given path.
+  ///
+  /// # Errors
+  ///
+  /// - If the connection could not be made.
+  pub fn connect<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+    let writer = UnixStream::connect(path)?;
+
+    Ok(Self {
+      reader: BufReader::new(writer.try_clone()?),
+      writer,
+    })
+  }
+
+  /// Pauses the remote EventSync.
+  pub fn pause(&mut self) -> io::Result<()> {
+    self.request(&ControlRequest::Pause)?.into_unit()
+  }
+
+  /// Unpauses the remote EventSync.
+  pub fn unpause(&mut self) -> io::Result<()> {
+    self.request(&ControlRequest::Unpause)?.into_unit()
+  }
+
+  /// Restarts the remote EventSync.
+  pub fn restart(&mut self) -> io::Result<()> {
+    self.request(&ControlRequest::Restart)?.into_unit()
+  }
+
+  /// Changes the tickrate of the remote EventSync.
+  pub fn change_tickrate_millis(&mut self, new_tickrate: u64) -> io::Result<()> {
+    self
+      .request(&ControlRequest::ChangeTickrate(new_tickrate))?
+      .into_unit()
+  }
+
+  /// [`change_tickrate_millis()`](EventSyncIpcClient::change_tickrate_millis), taking the tickrate as a `u32`.
+  #[deprecated(
+    since = "0.4.5",
+    note = "use `change_tickrate_millis()`, as tickrates are `u64` milliseconds"
+  )]
+  pub fn change_tickrate(&mut self, new_tickrate: u32) -> io::Result<()> {
+    self.change_tickrate_millis(new_tickrate.into())
+  }
+
+  /// Returns the current state of the remote EventSync.
+  pub fn status(&mut self) -> io::Result<ClockStatus> {
+    self.request(&ControlRequest::Status)?.into_status()
+  }
+
+  /// Sends a request and waits for the response.
+  fn request(&mut self, request: &ControlRequest) -> io::Result<ControlResponse> {
+    write_message(&mut self.writer, request)?;
+
+    let mut line = String::new();
+
+    if self.reader.read_line(&mut line)? == 0 {
+      return Err(io::ErrorKind::UnexpectedEof.into());
+    }
+
+    serde_json::from_str(&line).map_err(io::Error::from)
+  }
+}
+
+/// Writes the message as a single line of json.
+fn write_message<T: serde::Serialize>(writer: &mut UnixStream, message: &T) -> io::Result<()> {
+  let mut line = serde_json::to_string(message)?;
+  line.push('\n');
+
+  writer.write_all(line.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  fn test_path(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!(
+      "event_sync_ipc_{}_{}.sock",
+      name,
+      std::process::id()
+    ))
+  }
+
+  #[test]
+  fn client_controls_server_event_sync() {
+    let path = test_path("control");
+    let event_sync = EventSync::new_millis(TEST_TICKRATE);
+    EventSyncIpcServer::bind(&path, event_sync.clone())
+      .unwrap()
+      .spawn();
+
+    let mut client = EventSyncIpcClient::connect(&path).unwrap();
+
+    client.pause().unwrap();
+    assert!(event_sync.is_paused());
+
+    client.change_tickrate_millis(TEST_TICKRATE * 2).unwrap();
+    assert_eq!(event_sync.get_tickrate_millis(), TEST_TICKRATE * 2);
+
+    client.unpause().unwrap();
+    assert!(!event_sync.is_paused());
+  }
+
+  #[test]
+  fn idle_connections_dont_block_other_clients() {
+    let path = test_path("idle");
+    let event_sync = EventSync::new_millis(TEST_TICKRATE);
+    EventSyncIpcServer::bind(&path, event_sync.clone())
+      .unwrap()
+      .spawn();
+
+    let _idle = UnixStream::connect(&path).unwrap();
+    EventSyncIpcClient::connect(&path).unwrap().pause().unwrap();
+
+    assert!(event_sync.is_paused());
+  }
+
+  #[test]
+  fn overlong_requests_are_rejected() {
+    let path = test_path("overlong");
+    EventSyncIpcServer::bind(&path, EventSync::new_millis(TEST_TICKRATE))
+      .unwrap()
+      .spawn();
+
+    let mut stream = UnixStream::connect(&path).unwrap();
+    let mut response = String::new();
+
+    stream
+      .write_all(&vec![b' '; MAX_MESSAGE_SIZE as usize + 1])
+      .unwrap();
+    BufReader::new(stream).read_line(&mut response).unwrap();
+
+    assert!(matches!(
+      serde_json::from_str(&response).unwrap(),
+      ControlResponse::Error(_)
+    ));
+  }
+
+  #[test]
+  fn binding_leaves_other_files_alone() {
+    let path = test_path("not_a_socket");
+    std::fs::write(&path, b"keep me").unwrap();
+
+    let error = EventSyncIpcServer::bind(&path, EventSync::new_millis(TEST_TICKRATE))
+      .err()
+      .unwrap();
+
+    assert_eq!(error.kind(), io::ErrorKind::AlreadyExists);
+    assert_eq!(std::fs::read(&path).unwrap(), b"keep me");
+
+    std::fs::remove_file(path).unwrap();
+  }
+
+  #[test]
+  fn status_reports_state() {
+    let path = test_path("status");
+    let event_sync = EventSync::from_starting_tick_millis(TEST_TICKRATE, 5, true);
+    EventSyncIpcServer::bind(&path, event_sync.clone())
+      .unwrap()
+      .spawn();
+
+    let status = EventSyncIpcClient::connect(&path)
+      .unwrap()
+      .status()
+      .unwrap();
+
+    assert!(status.is_paused);
+    assert_eq!(status.tickrate, TEST_TICKRATE);
+    assert_eq!(status.ticks_since_started, 5);
+  }
+}
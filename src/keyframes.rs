@@ -0,0 +1,147 @@
+use crate::EventSync;
+
+/// A value that can be blended towards another value.
+pub trait Lerp {
+  /// Returns the value `t` of the way from this value to the other, where 0.0 is this value and 1.0 is the other.
+  fn lerp(&self, other: &Self, t: f64) -> Self;
+}
+
+impl Lerp for f64 {
+  fn lerp(&self, other: &Self, t: f64) -> Self {
+    self + (other - self) * t
+  }
+}
+
+impl Lerp for f32 {
+  fn lerp(&self, other: &Self, t: f64) -> Self {
+    self + (other - self) * t as f32
+  }
+}
+
+impl<T: Lerp, const N: usize> Lerp for [T; N] {
+  fn lerp(&self, other: &Self, t: f64) -> Self {
+    std::array::from_fn(|index| self[index].lerp(&other[index], t))
+  }
+}
+
+impl<A: Lerp, B: Lerp> Lerp for (A, B) {
+  fn lerp(&self, other: &Self, t: f64) -> Self {
+    (self.0.lerp(&other.0, t), self.1.lerp(&other.1, t))
+  }
+}
+
+/// Values placed at ticks, blended between for any point in time, such as the keyframes of an animation.
+///
+/// Before the first key, its value is held, and the same goes for after the last key.
+///
+/// # Example
+///
+/// ```
+/// use event_sync::*;
+///
+/// let keyframes = Keyframes::new()
+///   .with_key(0, 0.0)
+///   .with_key(10, 100.0)
+///   .with_key(20, 50.0);
+///
+/// assert_eq!(keyframes.sample(5.0), Some(50.0));
+/// assert_eq!(keyframes.sample(15.0), Some(75.0));
+/// assert_eq!(keyframes.sample(30.0), Some(50.0));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keyframes<T> {
+  keys: Vec<(u64, T)>,
+}
+
+impl<T> Default for Keyframes<T> {
+  fn default() -> Self {
+    Self { keys: Vec::new() }
+  }
+}
+
+impl<T: Lerp + Clone> Keyframes<T> {
+  /// Creates keyframes without any keys.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Places a key with the value at the tick, replacing any key already on that tick.
+  pub fn with_key(mut self, tick: u64, value: T) -> Self {
+    match self
+      .keys
+      .binary_search_by_key(&tick, |(key_tick, _)| *key_tick)
+    {
+      Ok(index) => self.keys[index].1 = value,
+      Err(index) => self.keys.insert(index, (tick, value)),
+    }
+
+    self
+  }
+
+  /// Returns every key, in tick order.
+  pub fn keys(&self) -> &[(u64, T)] {
+    &self.keys
+  }
+
+  /// Returns the value at the given fractional tick, blended between the keys on either side of it.
+  ///
+  /// None is returned if there are no keys.
+  pub fn sample(&self, tick: f64) -> Option<T> {
+    let next = self
+      .keys
+      .partition_point(|(key_tick, _)| (*key_tick as f64) <= tick);
+
+    match (
+      next.checked_sub(1).map(|index| &self.keys[index]),
+      self.keys.get(next),
+    ) {
+      (Some((start_tick, start)), Some((end_tick, end))) => {
+        let t = (tick - *start_tick as f64) / (end_tick - start_tick) as f64;
+
+        Some(start.lerp(end, t))
+      }
+      (Some((_, value)), None) | (None, Some((_, value))) => Some(value.clone()),
+      (None, None) => None,
+    }
+  }
+
+  /// Returns the value at the current fractional tick of the EventSync, including how far through the current
+  /// tick it is.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::*;
+  /// use std::time::Duration;
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let event_sync = EventSync::from_starting_time_millis(tickrate, Duration::from_millis(25), true);
+  /// let keyframes = Keyframes::new().with_key(0, [0.0, 0.0]).with_key(5, [10.0, -10.0]);
+  ///
+  /// // 2.5 ticks in.
+  /// assert_eq!(keyframes.sample_now(&event_sync), Some([5.0, -5.0]));
+  /// ```
+  pub fn sample_now<A>(&self, event_sync: &EventSync<A>) -> Option<T> {
+    let view = event_sync.view();
+
+    self.sample(view.tick as f64 + view.tick_progress())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn keys_replace_keys_on_the_same_tick() {
+    let keyframes = Keyframes::new()
+      .with_key(4, (1.0_f32, 1.0))
+      .with_key(0, (0.0, 0.0))
+      .with_key(4, (2.0, 4.0));
+
+    assert_eq!(keyframes.keys().len(), 2);
+    assert_eq!(keyframes.sample(2.0), Some((1.0, 2.0)));
+    assert_eq!(keyframes.sample(-1.0), Some((0.0, 0.0)));
+    assert_eq!(Keyframes::<f64>::new().sample(0.0), None);
+  }
+}
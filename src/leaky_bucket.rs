@@ -0,0 +1,236 @@
+use crate::{EventSync, Immutable};
+use std::collections::VecDeque;
+use std::sync::{Mutex, MutexGuard};
+
+/// What a [`LeakyBucket`](LeakyBucket) does with an item pushed while it's full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BucketOverflow {
+  /// Drops the item being pushed, so the bucket keeps the items that arrived first.
+  #[default]
+  DropNewest,
+
+  /// Drops the oldest queued item to make room, so the bucket keeps the most recent items.
+  DropOldest,
+}
+
+/// A queue that releases its items at a steady amount every tick of an EventSync, however bursty they arrive.
+///
+/// Items become due as ticks occur, and are taken with [`try_pop()`](LeakyBucket::try_pop) or
+/// [`drain_due()`](LeakyBucket::drain_due), such as once every tick before sending them out.
+/// Ticks that pass while the bucket is empty don't save up, so a burst after a quiet period is still spread out.
+/// While the EventSync is paused, nothing becomes due.
+///
+/// # Example
+///
+/// ```
+/// use event_sync::*;
+/// use std::time::Duration;
+///
+/// let tickrate = 10; // 10ms between every tick.
+/// let mut event_sync = EventSync::new_paused_millis(tickrate);
+///
+/// // Release 2 packets every tick, holding at most 5.
+/// let bucket = LeakyBucket::new(event_sync.clone_immutable(), 2, 5);
+///
+/// for packet in 0..6 {
+///   bucket.push(packet);
+/// }
+///
+/// // Nothing is due until a tick occurs.
+/// assert_eq!(bucket.try_pop(), None);
+///
+/// event_sync.sync_to(Duration::from_millis(10)).unwrap();
+///
+/// assert_eq!(bucket.drain_due(), [0, 1]);
+/// assert_eq!(bucket.len(), 3);
+/// ```
+#[derive(Debug)]
+pub struct LeakyBucket<T> {
+  event_sync: EventSync<Immutable>,
+  items_per_tick: u64,
+  capacity: usize,
+  overflow: BucketOverflow,
+  queue: Mutex<Queue<T>>,
+}
+
+#[derive(Debug)]
+struct Queue<T> {
+  items: VecDeque<T>,
+  /// The amount of items at the front of the queue that are due.
+  due: usize,
+  /// The tick items were last made due on.
+  last_leak_tick: u64,
+}
+
+impl<T> LeakyBucket<T> {
+  /// Creates an empty bucket releasing the given amount of items every tick, holding at most `capacity` items.
+  ///
+  /// Neither can go below 1. If 0 is passed in, 1 is used.
+  pub fn new(event_sync: EventSync<Immutable>, items_per_tick: u32, capacity: usize) -> Self {
+    let last_leak_tick = event_sync.ticks_since_started();
+
+    Self {
+      event_sync,
+      items_per_tick: items_per_tick.max(1).into(),
+      capacity: capacity.max(1),
+      overflow: BucketOverflow::default(),
+      queue: Mutex::new(Queue {
+        items: VecDeque::new(),
+        due: 0,
+        last_leak_tick,
+      }),
+    }
+  }
+
+  /// Sets what's done with items pushed while the bucket is full.
+  pub fn with_overflow(mut self, overflow: BucketOverflow) -> Self {
+    self.overflow = overflow;
+
+    self
+  }
+
+  /// Queues the item, returning the item that was dropped if the bucket was full.
+  ///
+  /// Items pushed aren't due until the next tick, even if the bucket could release more this tick.
+  pub fn push(&self, item: T) -> Option<T> {
+    let mut queue = self.leaked_queue();
+
+    let dropped = if queue.items.len() < self.capacity {
+      None
+    } else {
+      match self.overflow {
+        BucketOverflow::DropNewest => return Some(item),
+        BucketOverflow::DropOldest => {
+          queue.due = queue.due.saturating_sub(1);
+
+          queue.items.pop_front()
+        }
+      }
+    };
+
+    queue.items.push_back(item);
+
+    dropped
+  }
+
+  /// Takes the oldest item, if it's due.
+  pub fn try_pop(&self) -> Option<T> {
+    let mut queue = self.leaked_queue();
+
+    if queue.due == 0 {
+      return None;
+    }
+
+    queue.due -= 1;
+    queue.items.pop_front()
+  }
+
+  /// Takes every item that's due, oldest first.
+  pub fn drain_due(&self) -> Vec<T> {
+    let mut queue = self.leaked_queue();
+    let due = std::mem::take(&mut queue.due);
+
+    queue.items.drain(..due).collect()
+  }
+
+  /// Returns the amount of items that are due.
+  pub fn due(&self) -> usize {
+    self.leaked_queue().due
+  }
+
+  /// Returns the amount of queued items, including the ones that are due.
+  pub fn len(&self) -> usize {
+    self.lock_queue().items.len()
+  }
+
+  /// Returns true if there are no queued items.
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// Returns the most items the bucket holds.
+  pub fn capacity(&self) -> usize {
+    self.capacity
+  }
+
+  /// Locks the queue, first making the items for every tick since the last leak due.
+  fn leaked_queue(&self) -> MutexGuard<'_, Queue<T>> {
+    let current_tick = self.event_sync.ticks_since_started();
+    let mut queue = self.lock_queue();
+
+    // Moving back doesn't release anything, and starts counting again from where it was moved to.
+    let ticks_passed = current_tick.saturating_sub(queue.last_leak_tick);
+    let released = ticks_passed.saturating_mul(self.items_per_tick);
+
+    queue.due = usize::try_from(released)
+      .map_or(usize::MAX, |released| queue.due.saturating_add(released))
+      .min(queue.items.len());
+    queue.last_leak_tick = current_tick;
+
+    queue
+  }
+
+  fn lock_queue(&self) -> MutexGuard<'_, Queue<T>> {
+    self
+      .queue
+      .lock()
+      .unwrap_or_else(|poisoned| poisoned.into_inner())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::Mutable;
+  use std::time::Duration;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  fn move_to_tick(event_sync: &mut EventSync<Mutable>, tick: u64) {
+    event_sync
+      .sync_to(Duration::from_millis(tick * TEST_TICKRATE))
+      .unwrap();
+  }
+
+  #[test]
+  fn quiet_ticks_do_not_save_up() {
+    let mut event_sync = EventSync::new_paused_millis(TEST_TICKRATE);
+    let bucket = LeakyBucket::new(event_sync.clone_immutable(), 1, 10);
+
+    move_to_tick(&mut event_sync, 5);
+
+    for item in 0..3 {
+      bucket.push(item);
+    }
+
+    assert_eq!(bucket.due(), 0);
+
+    move_to_tick(&mut event_sync, 7);
+
+    assert_eq!(bucket.try_pop(), Some(0));
+    assert_eq!(bucket.drain_due(), [1]);
+    assert_eq!(bucket.try_pop(), None);
+  }
+
+  #[test]
+  fn overflow_drops_by_the_policy() {
+    let mut event_sync = EventSync::new_paused_millis(TEST_TICKRATE);
+    let newest = LeakyBucket::new(event_sync.clone_immutable(), 1, 2);
+    let oldest = LeakyBucket::new(event_sync.clone_immutable(), 1, 2)
+      .with_overflow(BucketOverflow::DropOldest);
+
+    for item in 0..2 {
+      newest.push(item);
+      oldest.push(item);
+    }
+
+    move_to_tick(&mut event_sync, 1);
+
+    assert_eq!(newest.push(2), Some(2));
+    assert_eq!(oldest.push(2), Some(0));
+    assert_eq!(newest.drain_due(), [0]);
+    // The due item was dropped, and what's left isn't due until the next tick.
+    assert_eq!(oldest.drain_due(), Vec::<u32>::new());
+    assert_eq!(oldest.len(), 2);
+  }
+}
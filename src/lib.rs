@@ -1,16 +1,284 @@
-#![doc = include_str!("../README.md")]
-
-use crate::errors::TimeError;
+#![cfg_attr(feature = "std", doc = include_str!("../README.md"))]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "std")]
+pub use crate::anomaly::{AnomalyDetected, AnomalyDetector, AnomalyPolicy};
+#[cfg(feature = "std")]
+pub use crate::backoff::BackoffSchedule;
+#[cfg(feature = "bevy")]
+pub use crate::bevy::{EventSyncPlugin, EventSyncTick, LastEventSyncTick};
+pub use crate::clock::ClockSource;
+#[cfg(feature = "std")]
+use crate::clock::{Instant, SystemTime};
+#[cfg(feature = "std")]
+pub use crate::clone_with::CloneWith;
+#[cfg(any(feature = "ipc", feature = "net"))]
+pub use crate::control::ClockStatus;
+#[cfg(feature = "std")]
+pub use crate::control_loop::{ControlLoop, Rate};
+#[cfg(feature = "std")]
+pub use crate::deadline::{CancellationFlag, DeadlineOutcome, TickDeadline};
+#[cfg(feature = "std")]
+pub use crate::drift::DriftReport;
+#[cfg(feature = "std")]
+pub use crate::driver::{ListenerId, TickListener, TickPhase, WakeOrder};
+#[cfg(feature = "embedded")]
+pub use crate::embedded::EmbeddedEventSync;
+pub use crate::errors::TimeError;
+pub use crate::event_sync_core::EventSyncCore;
+#[cfg(feature = "std")]
+pub use crate::executor::TickExecutor;
+#[cfg(feature = "std")]
+pub use crate::format::ElapsedStyle;
+#[cfg(feature = "governor")]
+pub use crate::governor_clock::GovernorClock;
+#[cfg(feature = "std")]
+pub use crate::input_latch::{InputLatch, LatchPolicy};
+#[cfg(all(feature = "ipc", unix))]
+pub use crate::ipc::{EventSyncIpcClient, EventSyncIpcServer};
+#[cfg(feature = "std")]
+pub use crate::keyframes::{Keyframes, Lerp};
+#[cfg(feature = "std")]
+pub use crate::leaky_bucket::{BucketOverflow, LeakyBucket};
+#[cfg(feature = "std")]
+pub use crate::lockstep::LockstepSession;
+#[cfg(feature = "mmap")]
+pub use crate::mapped::MappedEventSync;
+#[cfg(feature = "std")]
+pub use crate::media_clock::{MediaClock, ScheduledWake};
+#[cfg(feature = "midi")]
+pub use crate::midi::{MidiClock, MidiClockMessage, PULSES_PER_QUARTER_NOTE};
+#[cfg(feature = "net")]
+pub use crate::net::{OffsetEstimate, TickBeacon, TickFollower, TickLeader, TimeResponder};
+#[cfg(feature = "std")]
+pub use crate::orphan::ClockOrphaned;
+#[cfg(feature = "rayon")]
+pub use crate::parallel::BatchOverrun;
+#[cfg(feature = "std")]
+pub use crate::pipeline::{PipelineCoordinator, StageId};
+#[cfg(feature = "prometheus")]
+pub use crate::prometheus::PrometheusExporter;
+#[cfg(feature = "std")]
+pub use crate::rate_limiter::RateLimiter;
+#[cfg(feature = "std")]
+pub use crate::rate_meter::RateMeter;
+#[cfg(feature = "realtime")]
+pub use crate::realtime::{RealtimeApplied, RealtimeGuard, RealtimeOptions};
+#[cfg(feature = "net")]
+pub use crate::remote::{EventSyncTcpClient, EventSyncTcpServer};
+#[cfg(feature = "std")]
+pub use crate::retry::{RetryError, RetryPolicy};
+#[cfg(feature = "crossbeam")]
+pub use crate::select::TickOverflow;
+#[cfg(feature = "std")]
+pub use crate::select::{TickOrFuture, TickOrMessage};
+#[cfg(feature = "std")]
+pub use crate::simulation::Simulation;
+#[cfg(feature = "std")]
+pub use crate::state_machine::{StateMachine, TickState, Transition};
+#[cfg(feature = "std")]
+pub use crate::state_mut::EventSyncStateMut;
+#[cfg(feature = "std")]
+pub use crate::stats::WaitStats;
+#[cfg(feature = "std")]
+pub use crate::status::EventSyncStatus;
+#[cfg(feature = "std")]
+pub use crate::stopwatch::{Lap, Stopwatch};
+#[cfg(feature = "crossbeam")]
+pub use crate::subscription::{SubscriptionOverflow, TickSubscription};
+#[cfg(feature = "std")]
+pub use crate::suspend::{SuspendDetected, SuspendMonitor, SuspendPolicy};
+#[cfg(feature = "std")]
+pub use crate::throttle::{Debounce, Throttle};
+#[cfg(feature = "std")]
+pub use crate::tick_buffered::TickBuffered;
+#[cfg(feature = "std")]
+pub use crate::tick_cache::TickCache;
+#[cfg(feature = "std")]
+pub use crate::tick_loop::{IterationRecord, LoopControl, LoopProfile, TickContext};
+#[cfg(feature = "std")]
+pub use crate::tick_plan::TickPlan;
+#[cfg(feature = "std")]
+pub use crate::tick_rng::{TickRng, TickSeeded};
+#[cfg(all(
+  feature = "mio",
+  any(target_os = "linux", target_os = "macos", target_os = "freebsd")
+))]
+pub use crate::tick_source::TickSource;
+#[cfg(feature = "std")]
+pub use crate::time_dilation::TimeDilation;
+#[cfg(feature = "std")]
+pub use crate::timeline::{Timeline, TimelineEvent, TimelinePlayer};
+#[cfg(all(
+  feature = "timerfd",
+  any(target_os = "linux", target_os = "macos", target_os = "freebsd")
+))]
+pub use crate::timer_fd::TickTimer;
+pub use crate::view::EventSyncView;
+#[cfg(feature = "std")]
+pub use crate::wait_any::wait_any;
+#[cfg(feature = "std")]
+pub use crate::wait_each::{TargetOutcome, WaitUntilEach};
+#[cfg(feature = "std")]
+pub use crate::wait_future::{TickFuture, WaitTicks, WaitUntil};
+#[cfg(feature = "std")]
+pub use crate::waiting::WaitOutcome;
+#[cfg(feature = "wasm")]
+pub use crate::wasm::AsyncWaiting;
+#[cfg(feature = "std")]
+pub use crate::windows::TickWindows;
+#[cfg(feature = "std")]
 use inner::*;
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "std")]
 use std::marker::PhantomData;
-use std::{
-  sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard},
-  time::Duration,
-};
-
+#[cfg(feature = "std")]
+use std::time::Duration;
+#[cfg(feature = "std")]
+use sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+#[cfg(feature = "std")]
+use waiting::WaitTarget;
+
+#[cfg(feature = "std")]
+mod anomaly;
+#[cfg(feature = "std")]
+mod audio;
+#[cfg(feature = "std")]
+mod backoff;
+#[cfg(feature = "bevy")]
+mod bevy;
+#[cfg(feature = "std")]
+mod checked;
+mod clock;
+#[cfg(feature = "std")]
+mod clone_with;
+#[cfg(any(feature = "ipc", feature = "net"))]
+mod control;
+#[cfg(feature = "std")]
+mod control_loop;
+#[cfg(feature = "chrono")]
+mod datetime;
+#[cfg(feature = "std")]
+mod deadline;
+#[cfg(feature = "std")]
+mod drift;
+#[cfg(feature = "std")]
+mod driver;
+#[cfg(feature = "embedded")]
+mod embedded;
 mod errors;
+mod event_sync_core;
+#[cfg(feature = "std")]
+mod executor;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "std")]
+mod format;
+#[cfg(feature = "governor")]
+mod governor_clock;
 mod inner;
+#[cfg(feature = "std")]
+mod input_latch;
+#[cfg(all(feature = "ipc", unix))]
+mod ipc;
+#[cfg(feature = "std")]
+mod keyframes;
+#[cfg(feature = "std")]
+mod leaky_bucket;
+#[cfg(feature = "std")]
+mod lockstep;
+#[cfg(feature = "mmap")]
+mod mapped;
+#[cfg(feature = "std")]
+mod media_clock;
+#[cfg(feature = "midi")]
+mod midi;
+#[cfg(feature = "net")]
+mod net;
+#[cfg(feature = "std")]
+mod orphan;
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "std")]
+mod persist;
+#[cfg(feature = "std")]
+mod pipeline;
+#[cfg(feature = "prometheus")]
+mod prometheus;
+#[cfg(feature = "std")]
+mod rate_limiter;
+#[cfg(feature = "std")]
+mod rate_meter;
+#[cfg(feature = "realtime")]
+mod realtime;
+#[cfg(feature = "net")]
+mod remote;
+#[cfg(feature = "std")]
+mod retry;
+#[cfg(feature = "std")]
+mod select;
+#[cfg(feature = "std")]
+mod simulation;
+#[cfg(feature = "std")]
+mod snapshot;
+#[cfg(feature = "std")]
+mod state_machine;
+#[cfg(feature = "std")]
+mod state_mut;
+#[cfg(feature = "std")]
+mod stats;
+#[cfg(feature = "std")]
+mod status;
+#[cfg(feature = "std")]
+mod stopwatch;
+#[cfg(feature = "crossbeam")]
+mod subscription;
+#[cfg(feature = "std")]
+mod suspend;
+#[cfg(feature = "std")]
+pub mod sync;
+#[cfg(feature = "test-util")]
+pub mod test_util;
+#[cfg(feature = "std")]
+mod throttle;
+#[cfg(feature = "std")]
+mod tick_buffered;
+#[cfg(feature = "std")]
+mod tick_cache;
+#[cfg(feature = "std")]
+mod tick_loop;
+pub mod tick_math;
+#[cfg(feature = "std")]
+mod tick_plan;
+#[cfg(feature = "std")]
+mod tick_rng;
+#[cfg(all(
+  feature = "mio",
+  any(target_os = "linux", target_os = "macos", target_os = "freebsd")
+))]
+mod tick_source;
+#[cfg(feature = "std")]
+mod time_dilation;
+#[cfg(feature = "std")]
+mod timeline;
+#[cfg(all(
+  feature = "timerfd",
+  any(target_os = "linux", target_os = "macos", target_os = "freebsd")
+))]
+mod timer_fd;
+mod view;
+#[cfg(feature = "std")]
+mod wait_any;
+#[cfg(feature = "std")]
+mod wait_each;
+#[cfg(feature = "std")]
+mod wait_future;
+#[cfg(feature = "std")]
+mod waiting;
+#[cfg(feature = "wasm")]
+mod wasm;
+#[cfg(feature = "std")]
+mod windows;
 
 /// A way to synchronize a dynamic number of threads through sleeping.
 /// Achieved through cloning and passing around an instance of EventSync to other threads.
@@ -20,10 +288,10 @@ mod inner;
 ///
 /// # Usage
 ///
-/// In order to use EventSync, you start by creating one with [`EventSync::new()`](EventSync::new).
+/// In order to use EventSync, you start by creating one with [`EventSync::new_millis()`](EventSync::new_millis).
 /// You then pass in the desired tickrate for the EventSync to know how long 1 tick should last.
 ///
-/// The tickrate will be an integer reflected as milliseconds, and cannot go below 1.
+/// The tickrate will be an integer reflected as milliseconds, and is kept between 1 millisecond and 100 years.
 /// If you pass in 0, 1 millisecond will be set as the tickrate.
 ///
 /// ```
@@ -32,7 +300,7 @@ mod inner;
 /// let tickrate = 10; // 10ms between every tick
 ///
 /// // Create an EventSync with a 10ms tickrate.
-/// let event_sync = EventSync::new(tickrate);
+/// let event_sync = EventSync::new_millis(tickrate);
 /// ```
 ///
 /// You can then use this EventSync for both time tracking and synchronizing threads.
@@ -43,7 +311,7 @@ mod inner;
 /// use std::time::Instant;
 ///
 /// let tickrate = 10; // 10ms between every tick
-/// let event_sync = EventSync::new(tickrate as u32);
+/// let event_sync = EventSync::new_millis(tickrate);
 ///
 /// let start = Instant::now();
 ///
@@ -62,7 +330,7 @@ mod inner;
 /// use std::thread;
 ///
 /// let tickrate = 10; // 10ms between every tick
-/// let event_sync = EventSync::new(tickrate);
+/// let event_sync = EventSync::new_millis(tickrate);
 ///
 /// // All cloned EventSyncs will share their data.
 /// let passed_event_sync = event_sync.clone();
@@ -96,11 +364,11 @@ mod inner;
 /// use event_sync::*;
 ///
 /// let tickrate = 10;
-/// let event_sync = EventSync::new(tickrate);
+/// let event_sync = EventSync::new_millis(tickrate);
 ///
 /// let immutable_event_sync = event_sync.clone_immutable(); // Create an immutable EventSync.
 ///
-/// assert_eq!(immutable_event_sync.get_tickrate(), tickrate);
+/// assert_eq!(immutable_event_sync.get_tickrate_millis(), tickrate);
 /// ```
 ///
 /// The type for this Immutable EventSync would look like this:
@@ -111,9 +379,14 @@ mod inner;
 ///   event_sync: EventSync<Immutable>,
 /// }
 /// ```
-#[derive(Clone, Serialize, Deserialize)]
+#[cfg(feature = "std")]
+#[derive(Clone)]
 pub struct EventSync<Access = Mutable> {
   inner: Arc<RwLock<InnerEventSync>>,
+  stats: Arc<stats::WaitRecorder>,
+  driver: Arc<driver::Driver>,
+  /// Shared by Mutable EventSyncs only, so it's dropped once none are left. None for Immutable ones.
+  mutable_handle: Option<Arc<orphan::MutableHandle>>,
   change_access: PhantomData<Access>,
 }
 
@@ -127,12 +400,12 @@ pub struct EventSync<Access = Mutable> {
 /// use event_sync::*;
 ///
 /// let tickrate = 10; // 10ms between every tick.
-/// let master_event_sync: EventSync<Mutable> = EventSync::new(tickrate);
+/// let master_event_sync: EventSync<Mutable> = EventSync::new_millis(tickrate);
 ///
 /// let mut immutable_event_sync: EventSync<Immutable> = master_event_sync.clone_immutable();
 ///
 /// // Does not compile.
-/// immutable_event_sync.change_tickrate(20);
+/// immutable_event_sync.change_tickrate_millis(20);
 /// ```
 #[derive(Clone, Serialize, Deserialize)]
 pub struct Immutable;
@@ -149,17 +422,50 @@ pub struct Immutable;
 /// use event_sync::*;
 ///
 /// let tickrate = 10;
-/// let mut master_event_sync = EventSync::new(tickrate);
+/// let mut master_event_sync = EventSync::new_millis(tickrate);
 ///
 /// let mut mutable_event_sync = master_event_sync.clone();
 ///
-/// mutable_event_sync.change_tickrate(20);
+/// mutable_event_sync.change_tickrate_millis(20);
 ///
-/// assert_eq!(master_event_sync.get_tickrate(), 20);
+/// assert_eq!(master_event_sync.get_tickrate_millis(), 20);
 /// ```
 #[derive(Clone, Serialize, Deserialize)]
 pub struct Mutable;
 
+/// The access an EventSync has, either [`Mutable`](Mutable) or [`Immutable`](Immutable).
+///
+/// This is stored when serializing, so a deserialized EventSync can't gain more access than it was serialized with.
+pub trait AccessLevel: sealed::Sealed {
+  /// Whether the EventSync can be changed.
+  #[doc(hidden)]
+  const IS_MUTABLE: bool;
+}
+
+impl AccessLevel for Mutable {
+  const IS_MUTABLE: bool = true;
+}
+
+impl AccessLevel for Immutable {
+  const IS_MUTABLE: bool = false;
+}
+
+/// The serialized form of an [`AccessLevel`](AccessLevel).
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+enum AccessName {
+  Mutable,
+  Immutable,
+}
+
+mod sealed {
+  pub trait Sealed {}
+
+  impl Sealed for super::Mutable {}
+  impl Sealed for super::Immutable {}
+}
+
+#[cfg(feature = "std")]
 impl<T> EventSync<T> {
   /// Returns true if this instance of EventSyunc has been paused.
   ///
@@ -172,7 +478,7 @@ impl<T> EventSync<T> {
   /// use event_sync::EventSync;
   ///
   /// let tickrate = 10; // 10ms between every tick
-  /// let mut event_sync = EventSync::new(tickrate);
+  /// let mut event_sync = EventSync::new_millis(tickrate);
   ///
   /// event_sync.pause();
   ///
@@ -182,6 +488,37 @@ impl<T> EventSync<T> {
     self.read_inner().is_paused()
   }
 
+  /// Returns the reasons the EventSync is paused with, in the order they were first given.
+  ///
+  /// Only pauses from [`pause_with_reason()`](EventSync::pause_with_reason) have a reason, so this can be empty
+  /// while the EventSync is paused.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::EventSync;
+  ///
+  /// let tickrate = 10; // 10ms between every tick
+  /// let mut event_sync = EventSync::new_millis(tickrate);
+  ///
+  /// event_sync.pause_with_reason("menu open");
+  /// event_sync.pause_with_reason("loading level");
+  ///
+  /// assert_eq!(event_sync.pause_reasons(), ["menu open", "loading level"]);
+  /// ```
+  pub fn pause_reasons(&self) -> Vec<&'static str> {
+    self.read_inner().pause_reasons()
+  }
+
+  /// Returns the internal tickrate, or `u32::MAX` if it's too long to fit in a `u32`.
+  #[deprecated(
+    since = "0.4.5",
+    note = "use `get_tickrate_millis()`, as tickrates are `u64` milliseconds"
+  )]
+  pub fn get_tickrate(&self) -> u32 {
+    u32::try_from(self.get_tickrate_millis()).unwrap_or(u32::MAX)
+  }
+
   /// Returns the internal tickrate.
   ///
   /// # Examples
@@ -190,51 +527,243 @@ impl<T> EventSync<T> {
   /// use event_sync::*;
   ///
   /// let tickrate = 10; // 10ms tickrate.
-  /// let event_sync = EventSync::new(tickrate);
+  /// let event_sync = EventSync::new_millis(tickrate);
   /// let other_event_sync = event_sync.clone();
   ///
-  /// assert_eq!(event_sync.get_tickrate(), tickrate);
-  /// assert_eq!(other_event_sync.get_tickrate(), tickrate);
+  /// assert_eq!(event_sync.get_tickrate_millis(), tickrate);
+  /// assert_eq!(other_event_sync.get_tickrate_millis(), tickrate);
   /// ```
-  pub fn get_tickrate(&self) -> u32 {
+  pub fn get_tickrate_millis(&self) -> u64 {
     self.read_inner().get_tickrate()
   }
 
+  /// Returns the [`clock`](ClockSource) this EventSync measures time against.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::*;
+  ///
+  /// let tickrate = 10; // 10ms tickrate.
+  /// let event_sync = EventSync::new_millis(tickrate);
+  ///
+  /// assert_eq!(event_sync.clock_source(), ClockSource::Monotonic);
+  /// ```
+  pub fn clock_source(&self) -> ClockSource {
+    self.read_inner().clock_source()
+  }
+
   /// Waits until an absolute tick has occurred since EventSync creation.
   ///
   /// That means, if you created an instance of EventSync with a tickrate of 10ms,
   /// and you want to wait until 1 second has passed since creation.
   /// You would wait until the 100th tick, as 100 ticks would be 1 second since EventSync Creation.
   ///
+  /// The EventSync is re-checked at least once every tick while waiting, so changing the tickrate moves when the
+  /// tick occurs, and pausing holds the wait until unpaused.
+  ///
+  /// Waiting for the tick the EventSync is already on returns [`NoWait`](WaitOutcome::NoWait) without sleeping.
+  ///
   /// # Errors
   ///
-  /// - An error is returned when the given time to wait for has already occurred.
+  /// - An error is returned when the given tick occurred before the current one.
   /// - An error is returned if the EventSync is paused.
   ///
   /// # Usage
   ///
   /// ```
-  /// use event_sync::EventSync;
+  /// use event_sync::*;
   ///
   /// let tickrate = 10; // 10ms between every tick
-  /// let event_sync = EventSync::new(tickrate);
+  /// let event_sync = EventSync::new_millis(tickrate);
   ///
   /// // Wait 1 second from the creation of event_sync.
-  /// event_sync.wait_until(100).unwrap();
+  /// assert_eq!(event_sync.wait_until(100), Ok(WaitOutcome::Waited));
+  ///
+  /// // Tick 100 is the current tick now.
+  /// assert_eq!(event_sync.wait_until(100), Ok(WaitOutcome::NoWait));
+  /// ```
+  pub fn wait_until(&self, tick_to_wait_for: u64) -> Result<WaitOutcome, TimeError> {
+    {
+      let inner = self.read_inner();
+
+      if inner.is_current_tick(tick_to_wait_for)? {
+        return Ok(WaitOutcome::NoWait);
+      }
+
+      inner.time_until_tick_occurs(tick_to_wait_for)?;
+    }
+
+    self.sleep_until_tick_occurs(tick_to_wait_for, WaitTarget::Absolute);
+
+    Ok(WaitOutcome::Waited)
+  }
+
+  /// Waits until the given amount of time has passed since EventSync creation, regardless of where the ticks fall.
+  ///
+  /// This avoids rounding to ticks when thinking in time rather than ticks, such as at a tickrate of 16ms.
+  ///
+  /// Pausing while waiting holds the wait until unpaused.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned when that much time has already passed.
+  /// - An error is returned if the EventSync is paused.
+  ///
+  /// # Usage
+  ///
+  /// ```
+  /// use event_sync::EventSync;
+  /// use std::time::Duration;
+  ///
+  /// let tickrate = 16; // 16ms between every tick
+  /// let event_sync = EventSync::new_millis(tickrate);
+  ///
+  /// // Wait until 50ms have passed, partway through the 4th tick.
+  /// event_sync.wait_until_elapsed(Duration::from_millis(50)).unwrap();
+  ///
+  /// assert!(event_sync.time_since_started() >= Duration::from_millis(50));
   /// ```
-  pub fn wait_until(&self, tick_to_wait_for: u64) -> Result<(), TimeError> {
-    let wait_time = self.read_inner().time_until_tick_occurs(tick_to_wait_for)?;
+  pub fn wait_until_elapsed(&self, time_since_started: Duration) -> Result<(), TimeError> {
+    self.read_inner().time_until_elapsed(time_since_started)?;
+
+    self.record_wait(|| {
+      self.sleep_until_elapsed_in_chunks(time_since_started, || false);
 
-    std::thread::sleep(wait_time);
+      self.time_since_started().saturating_sub(time_since_started)
+    });
 
     Ok(())
   }
 
+  /// Sleeps until the end of the current tick, returning how long was slept.
+  ///
+  /// If a tick occurred within the tolerance, nothing is slept, as the work for the previous tick only just ran over
+  /// and the new tick has already started.
+  /// This is the call at the end of a do work then wait loop, where [`wait_for_tick()`](EventSync::wait_for_tick)
+  /// would skip an entire tick whenever the work runs slightly long.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if the EventSync is paused.
+  ///
+  /// # Usage
+  ///
+  /// ```
+  /// use event_sync::EventSync;
+  /// use std::time::Duration;
+  ///
+  /// let tickrate = 10; // 10ms between every tick
+  /// let event_sync = EventSync::new_millis(tickrate);
+  /// let tolerance = Duration::from_millis(1);
+  ///
+  /// // Do this tick's work.
+  /// std::thread::sleep(Duration::from_millis(4));
+  ///
+  /// event_sync.sleep_remaining(tolerance).unwrap();
+  ///
+  /// assert!(event_sync.ticks_since_started() >= 1);
+  /// ```
+  pub fn sleep_remaining(&self, tolerance: Duration) -> Result<Duration, TimeError> {
+    let wait_time = {
+      let inner = self.read_inner();
+      inner.err_if_paused()?;
+
+      if inner.time_since_last_tick() <= tolerance {
+        return Ok(Duration::ZERO);
+      }
+
+      inner.time_until_next_tick()
+    };
+
+    self.sleep_recorded(wait_time);
+
+    Ok(wait_time)
+  }
+
+  /// Returns the amount of ticks to drop when the last tick handled is more than `max_lag` ticks behind the current
+  /// tick, so a consumer that's fallen behind sheds load instead of trying to catch up on every tick.
+  ///
+  /// Dropping the returned amount leaves the consumer `max_lag` ticks behind, like the catch up limit of a
+  /// [`Simulation`](Simulation). None is returned when it's within `max_lag` ticks, where it should wait as usual.
+  ///
+  /// # Usage
+  ///
+  /// ```
+  /// use event_sync::*;
+  ///
+  /// let tickrate = 10; // 10ms between every tick
+  /// let event_sync = EventSync::from_starting_tick_millis(tickrate, 20, true);
+  /// let mut last_tick = 5;
+  ///
+  /// // 15 ticks behind, so 12 are dropped to only be 3 behind.
+  /// if let Some(dropped_ticks) = event_sync.skip_if_behind(last_tick, 3) {
+  ///   last_tick += dropped_ticks;
+  /// }
+  ///
+  /// assert_eq!(last_tick, 17);
+  /// assert_eq!(event_sync.skip_if_behind(last_tick, 3), None);
+  /// ```
+  pub fn skip_if_behind(&self, last_tick: u64, max_lag: u64) -> Option<u64> {
+    let behind = self.ticks_since_started().saturating_sub(last_tick);
+
+    (behind > max_lag).then(|| behind - max_lag)
+  }
+
+  /// Waits for at least the given amount of time, ending on the first tick after it, and returns the amount of
+  /// ticks waited.
+  ///
+  /// This keeps call sites that think in time aligned to the ticks shared with everything else.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if the EventSync is paused.
+  ///
+  /// # Usage
+  ///
+  /// ```
+  /// use event_sync::EventSync;
+  /// use std::time::Duration;
+  ///
+  /// let tickrate = 10; // 10ms between every tick
+  /// let event_sync = EventSync::from_starting_time_millis(tickrate, Duration::from_millis(5), false);
+  ///
+  /// // 20ms from 5ms is 25ms, which is covered by waiting until tick 3.
+  /// let ticks_waited = event_sync.wait_for_at_least(Duration::from_millis(20)).unwrap();
+  ///
+  /// assert_eq!(ticks_waited, 3);
+  /// ```
+  pub fn wait_for_at_least(&self, duration: Duration) -> Result<u64, TimeError> {
+    let (current_tick, target_tick) = {
+      let inner = self.read_inner();
+      inner.err_if_paused()?;
+
+      let time_since_started = inner.try_time_since_started()?;
+      let tickrate_in_nanos = inner.get_tickrate() as u128 * 1_000_000;
+      let target = time_since_started.saturating_add(duration);
+
+      (
+        (time_since_started.as_nanos() / tickrate_in_nanos) as u64,
+        target.as_nanos().div_ceil(tickrate_in_nanos) as u64,
+      )
+    };
+
+    match self.wait_until(target_tick) {
+      Ok(_) | Err(TimeError::ThatTimeHasAlreadyHappened) => (),
+      Err(error) => return Err(error),
+    }
+
+    Ok(target_tick - current_tick)
+  }
+
   /// Waits until the next tick relative to where now is between ticks.
   ///
   /// Let's say the tickrate is 10ms, and the last tick was 5ms ago.
   /// This method would sleep for 5ms to get to the next tick.
   ///
+  /// Restarting or changing the tickrate while waiting waits for the next tick after the change instead, and
+  /// pausing holds the wait until unpaused.
+  ///
   /// # Errors
   ///
   /// - An error is returned if the EventSync is paused.
@@ -244,15 +773,20 @@ impl<T> EventSync<T> {
   /// use event_sync::EventSync;
   ///
   /// let tickrate = 10; // 10ms between every tick
-  /// let event_sync = EventSync::new(tickrate);
+  /// let event_sync = EventSync::new_millis(tickrate);
   ///
   /// // wait until the next tick
   /// event_sync.wait_for_tick();
   /// ```
   pub fn wait_for_tick(&self) -> Result<(), TimeError> {
-    let wait_time = self.read_inner().time_for_tick()?;
+    let tick = {
+      let inner = self.read_inner();
+      inner.time_for_tick()?;
 
-    std::thread::sleep(wait_time);
+      inner.ticks_since_started() + 1
+    };
+
+    self.sleep_until_tick_occurs(tick, WaitTarget::Relative);
 
     Ok(())
   }
@@ -262,26 +796,45 @@ impl<T> EventSync<T> {
   /// Let's say the tickrate is 10ms, and the last tick was 5ms ago.
   /// If you wanted to wait for 3 ticks, this method would sleep for 25ms, as that would be 3 ticks from now.
   ///
+  /// Restarting or changing the tickrate while waiting counts the ticks that were left from the change instead,
+  /// and pausing holds the wait until unpaused.
+  ///
+  /// Waiting for 0 ticks returns [`NoWait`](WaitOutcome::NoWait) without sleeping.
+  ///
   /// # Errors
   ///
-  /// - An error is returned if the EventSync is paused.
+  /// - An error is returned if the EventSync is paused, even when waiting for 0 ticks.
   ///
   /// # Usage
   /// ```
-  /// use event_sync::EventSync;
+  /// use event_sync::*;
   ///
   /// let tickrate = 10; // 10ms between every tick
-  /// let event_sync = EventSync::new(tickrate);
+  /// let event_sync = EventSync::new_millis(tickrate);
   ///
   /// // wait for 3 ticks
   /// event_sync.wait_for_x_ticks(3);
+  ///
+  /// assert_eq!(event_sync.wait_for_x_ticks(0), Ok(WaitOutcome::NoWait));
   /// ```
-  pub fn wait_for_x_ticks(&self, ticks_to_wait: u32) -> Result<(), TimeError> {
-    let wait_time = self.read_inner().time_for_x_ticks(ticks_to_wait)?;
+  pub fn wait_for_x_ticks(&self, ticks_to_wait: u32) -> Result<WaitOutcome, TimeError> {
+    let tick = {
+      let inner = self.read_inner();
 
-    std::thread::sleep(wait_time);
+      if ticks_to_wait == 0 {
+        inner.err_if_paused()?;
 
-    Ok(())
+        return Ok(WaitOutcome::NoWait);
+      }
+
+      inner.time_for_x_ticks(ticks_to_wait)?;
+
+      inner.ticks_since_started() + ticks_to_wait as u64
+    };
+
+    self.sleep_until_tick_occurs(tick, WaitTarget::Relative);
+
+    Ok(WaitOutcome::Waited)
   }
 
   /// Returns the amount of time that has occurred since the creation of this instance of EventSync.
@@ -292,7 +845,7 @@ impl<T> EventSync<T> {
   /// use std::time::Duration;
   ///
   /// let tickrate = 10; // 10ms between every tick
-  /// let event_sync = EventSync::new(tickrate);
+  /// let event_sync = EventSync::new_millis(tickrate);
   ///
   /// // Wait until 5 ticks have occurred since EventSync creation.
   /// event_sync.wait_until(5);
@@ -307,19 +860,28 @@ impl<T> EventSync<T> {
 
   /// Returns the amount of ticks that have occurred since the creation of this instance of EventSync.
   ///
+  /// While a driver thread is running for [`listeners`](EventSync::add_listener) or
+  /// [`ordered waits`](WakeOrder::Fifo), the tick it last saw occur is returned without locking or reading the clock.
+  /// That tick can trail the true one for as long as the driver thread takes to catch up, such as while a listener
+  /// is running, but never comes from before a pause, restart, or tickrate change.
+  ///
   /// # Usage
   ///
   /// ```
   /// use event_sync::*;
   ///
   /// let tickrate = 10; // 10ms between every tick
-  /// let event_sync = EventSync::new(tickrate);
+  /// let event_sync = EventSync::new_millis(tickrate);
   ///
   /// event_sync.wait_until(5);
   ///
   /// assert_eq!(event_sync.ticks_since_started(), 5);
   /// ```
   pub fn ticks_since_started(&self) -> u64 {
+    if let Some(tick) = self.driver.cached_tick() {
+      return tick;
+    }
+
     self.read_inner().ticks_since_started()
   }
 
@@ -331,7 +893,7 @@ impl<T> EventSync<T> {
   /// use event_sync::EventSync;
   ///
   /// let tickrate = 10; // 10ms between every tick.
-  /// let event_sync = EventSync::new(tickrate);
+  /// let event_sync = EventSync::new_millis(tickrate);
   ///
   /// event_sync.wait_for_tick().unwrap();
   ///
@@ -349,7 +911,7 @@ impl<T> EventSync<T> {
   /// use event_sync::EventSync;
   ///
   /// let tickrate = 10; // 10ms between every tick.
-  /// let event_sync = EventSync::new(tickrate);
+  /// let event_sync = EventSync::new_millis(tickrate);
   ///
   /// event_sync.wait_for_tick().unwrap();
   ///
@@ -359,149 +921,613 @@ impl<T> EventSync<T> {
     self.read_inner().time_until_next_tick()
   }
 
-  /// Obtains a ReadGuard of the [`internal EventSync data`](InnerEventSync).
-  fn read_inner(&self) -> RwLockReadGuard<InnerEventSync> {
-    self.inner.read().unwrap()
-  }
-}
-
-impl EventSync<Mutable> {
-  /// Creates a new instance of [`EventSync`](EventSync).
+  /// Returns how long to wait until the given tick occurs, without waiting.
   ///
-  /// Takes the duration of a tick as milliseconds.
-  /// If 0 is passed in, 1 will be the assigned tickrate for this instance of EventSync.
+  /// This lets the wait be done by something other than sleeping the thread, such as the timeout of a select loop,
+  /// epoll, or a GUI timer.
+  /// As the EventSync can be paused or changed during the wait, the duration should be asked for again afterwards.
   ///
-  /// # Examples
+  /// # Errors
   ///
-  /// ```
-  /// use event_sync::*;
+  /// - An error is returned when the given tick has already occurred.
+  /// - An error is returned if the EventSync is paused.
   ///
-  /// let tickrate = 10; // 10ms between every tick
+  /// # Examples
   ///
-  /// // Create an EventSync with a 10ms tickrate.
-  /// let event_sync = EventSync::new(tickrate);
   /// ```
+  /// use event_sync::EventSync;
+  /// use std::time::Duration;
   ///
-  /// You can then use this EventSync for both time tracking and synchronizing threads.
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let event_sync = EventSync::from_starting_time_millis(tickrate, Duration::from_millis(15), false);
   ///
-  /// # Time Tracking
+  /// let timeout = event_sync.time_until_tick_occurs(3).unwrap();
+  ///
+  /// assert!(timeout <= Duration::from_millis(15));
+  /// assert!(event_sync.time_until_tick_occurs(1).is_err());
   /// ```
-  /// use event_sync::*;
-  /// use std::time::Instant;
+  pub fn time_until_tick_occurs(&self, tick: u64) -> Result<Duration, TimeError> {
+    self.read_inner().time_until_tick_occurs(tick)
+  }
+
+  /// Returns how long to wait for the given amount of ticks to occur, counted from the last tick, without waiting.
   ///
-  /// let tickrate = 10; // 10ms between every tick
-  /// let event_sync = EventSync::new(tickrate as u32);
+  /// See [`time_until_tick_occurs()`](EventSync::time_until_tick_occurs) for more details.
   ///
-  /// let start = Instant::now();
+  /// # Errors
   ///
-  /// // Wait for 5 ticks (5 * 10)ms.
-  /// event_sync.wait_for_x_ticks(5);
+  /// - An error is returned if the EventSync is paused.
   ///
-  /// let finish = start.elapsed().as_millis();
+  /// # Examples
   ///
-  /// // Check that the time it took for the operation was (waited_ticks * tickrate)ms
-  /// assert_eq!(finish, event_sync.time_since_started().as_millis());
   /// ```
+  /// use event_sync::EventSync;
+  /// use std::time::Duration;
   ///
-  /// # Thread Synchronization
-  /// ```
-  /// use event_sync::*;
-  /// use std::thread;
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let event_sync = EventSync::new_millis(tickrate);
   ///
-  /// let tickrate = 10; // 10ms between every tick
-  /// let event_sync = EventSync::new(tickrate);
+  /// let timeout = event_sync.time_for_x_ticks(2).unwrap();
   ///
-  /// // All cloned EventSyncs will share their data.
-  /// let passed_event_sync = event_sync.clone();
+  /// assert!(timeout > Duration::from_millis(10));
+  /// assert!(timeout <= Duration::from_millis(20));
+  /// ```
+  pub fn time_for_x_ticks(&self, ticks_to_wait: u32) -> Result<Duration, TimeError> {
+    self.read_inner().time_for_x_ticks(ticks_to_wait)
+  }
+
+  /// Returns the tick, time passed, tickrate, and pause state all read at the same instant.
   ///
-  /// let handle = thread::spawn(move || {
-  ///   // waiting until 5 ticks have occurred since the creation of event_sync.
-  ///   passed_event_sync.wait_until(5);
+  /// # Examples
   ///
-  ///   // do something
-  /// });
+  /// ```
+  /// use event_sync::EventSync;
   ///
-  /// // waiting until 5 ticks have occurred since the creation of event_sync.
-  /// event_sync.wait_until(5);
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let event_sync = EventSync::new_millis(tickrate);
   ///
-  /// // do something
+  /// event_sync.wait_for_x_ticks(2).unwrap();
   ///
-  /// handle.join().unwrap();
+  /// let view = event_sync.view();
+  ///
+  /// assert!(view.tick >= 2);
+  /// assert!(view.tick_progress() < 1.0);
   /// ```
-  pub fn new(tickrate_in_milliseconds: u32) -> Self {
-    Self::new_event_sync(tickrate_in_milliseconds, Duration::default(), false)
+  pub fn view(&self) -> EventSyncView {
+    EventSyncView::from_inner(&self.read_inner())
   }
 
-  /// Creates a new instance of EventSync that starts out paused.
+  /// Returns the point on the wall clock at which the given tick occurs, or occurred.
+  ///
+  /// This can be used to schedule external systems to coincide with a specific tick.
+  /// Time spent paused is accounted for, as the projection is made from the time that's passed while running.
+  ///
+  /// Returns None if the EventSync is paused, as ticks don't advance while paused.
+  /// None is also returned if the time cannot be represented by [`SystemTime`](std::time::SystemTime).
   ///
   /// # Examples
   ///
   /// ```
-  /// use event_sync::*;
+  /// use event_sync::EventSync;
+  /// use std::time::{Duration, SystemTime};
   ///
   /// let tickrate = 10; // 10ms between every tick.
-  /// let event_sync = EventSync::new_paused(tickrate); // Create an event_sync that starts out paused.
+  /// let mut event_sync = EventSync::new_millis(tickrate);
   ///
-  /// assert!(event_sync.is_paused());
-  /// assert!(event_sync.wait_for_tick().is_err());
+  /// let time_of_tick = event_sync.system_time_of_tick(100).unwrap();
+  /// let time_until_tick = time_of_tick.duration_since(SystemTime::now()).unwrap();
+  ///
+  /// assert!(time_until_tick <= Duration::from_secs(1));
+  ///
+  /// event_sync.pause();
+  ///
+  /// assert!(event_sync.system_time_of_tick(100).is_none());
   /// ```
-  pub fn new_paused(tickrate_in_milliseconds: u32) -> Self {
-    Self::new_event_sync(tickrate_in_milliseconds, Duration::default(), true)
+  pub fn system_time_of_tick(&self, tick: u64) -> Option<SystemTime> {
+    self.read_inner().system_time_of_tick(tick)
   }
 
-  /// Creates a new instance of [`EventSync`](EventSync) with the given starting time.
+  /// Returns the [`Instant`](std::time::Instant) at which the given tick occurs, or occurred.
   ///
-  /// Takes an extra arguement to determine if the EventSync should be paused upon creation or not.
+  /// Like [`system_time_of_tick()`](EventSync::system_time_of_tick), the projection is made from the time that's
+  /// passed while running, so time spent paused is accounted for.
   ///
-  /// # Example
+  /// Returns None if the EventSync is paused, or if the time cannot be represented by an Instant.
+  ///
+  /// # Examples
   ///
   /// ```
-  /// use event_sync::*;
-  /// use std::time::Duration;
+  /// use event_sync::EventSync;
+  /// use std::time::{Duration, Instant};
   ///
   /// let tickrate = 10; // 10ms between every tick.
-  /// let starting_time = Duration::from_millis(30); // Start 30ms ahead.
-  /// let event_sync = EventSync::from_starting_time(tickrate, starting_time, false);
+  /// let event_sync = EventSync::from_starting_tick_millis(tickrate, 5, false);
   ///
-  /// assert_eq!(event_sync.ticks_since_started(), 3);
+  /// let instant_of_tick = event_sync.instant_of_tick(2).unwrap();
+  ///
+  /// assert!(instant_of_tick.elapsed() >= Duration::from_millis(30));
+  /// assert_eq!(event_sync.tick_at(instant_of_tick), Some(2));
   /// ```
+  pub fn instant_of_tick(&self, tick: u64) -> Option<Instant> {
+    self.read_inner().instant_of_tick(tick)
+  }
+
+  /// Returns the tick that was, or will be, current at the given [`Instant`](std::time::Instant), such as when a
+  /// network packet or input event was timestamped, so it can be placed on the tick timeline.
   ///
-  /// # Starting Paused
+  /// The tick is projected from the time that's passed while running, so time spent paused is accounted for, as
+  /// long as the EventSync wasn't paused or moved between the instant and now.
+  ///
+  /// Returns None if the EventSync is paused, or if the instant is from before the EventSync started.
+  ///
+  /// # Examples
   ///
   /// ```
-  /// use event_sync::*;
-  /// use std::time::Duration;
+  /// use event_sync::EventSync;
+  /// use std::time::{Duration, Instant};
   ///
-  /// let tickrate = 10; // 10ms between every tick.
-  /// let starting_time = Duration::from_millis(30); // Start 30ms ahead.
-  /// let mut event_sync = EventSync::from_starting_time(tickrate, starting_time, true);
+  /// let tickrate = 100; // 100ms between every tick.
+  /// let event_sync = EventSync::from_starting_tick_millis(tickrate, 5, false);
   ///
-  /// assert!(event_sync.is_paused());
-  /// event_sync.unpause().unwrap();
+  /// let received_at = Instant::now();
+  /// std::thread::sleep(Duration::from_millis(30));
   ///
-  /// assert_eq!(event_sync.ticks_since_started(), 3);
+  /// assert_eq!(event_sync.tick_at(received_at), Some(5));
+  /// assert_eq!(event_sync.tick_at(received_at - Duration::from_secs(1)), None);
   /// ```
-  pub fn from_starting_time(
-    tickrate_in_milliseconds: u32,
-    elapsed_time: Duration,
-    start_paused: bool,
-  ) -> Self {
-    Self::new_event_sync(tickrate_in_milliseconds, elapsed_time, start_paused)
+  pub fn tick_at(&self, instant: Instant) -> Option<u64> {
+    self.read_inner().tick_at(instant)
   }
 
-  /// Creates a new instance of [`EventSync`](EventSync) with the given starting tick.
+  /// Returns the amount of tick boundaries between the two [`Instants`](std::time::Instant), in either order, so
+  /// a batch of timestamped external events can be attributed to the ticks they belong to.
   ///
-  /// Takes an extra arguement to determine if the EventSync should be paused upon creation or not.
+  /// The ticks are projected like with [`tick_at()`](EventSync::tick_at). Instants from before the EventSync
+  /// started are treated as when it started.
   ///
-  /// # Example
+  /// Returns None if the EventSync is paused.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::EventSync;
+  /// use std::time::Duration;
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let event_sync = EventSync::new_millis(tickrate);
+  ///
+  /// let first_event = event_sync.instant_of_tick(2).unwrap() + Duration::from_millis(5);
+  /// let last_event = first_event + Duration::from_millis(30);
+  ///
+  /// assert_eq!(event_sync.ticks_elapsed_between(first_event, last_event), Some(3));
+  /// assert_eq!(event_sync.ticks_elapsed_between(last_event, first_event), Some(3));
+  /// ```
+  pub fn ticks_elapsed_between(&self, start: Instant, end: Instant) -> Option<u64> {
+    self.read_inner().ticks_elapsed_between(start, end)
+  }
+
+  /// Returns the point on the wall clock at which the next tick will occur.
+  ///
+  /// Returns None if the EventSync is paused.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::EventSync;
+  /// use std::time::SystemTime;
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let event_sync = EventSync::new_millis(tickrate);
+  ///
+  /// let next_tick = event_sync.next_tick_system_time().unwrap();
+  ///
+  /// assert!(next_tick >= SystemTime::now());
+  /// ```
+  pub fn next_tick_system_time(&self) -> Option<SystemTime> {
+    self.read_inner().next_tick_system_time()
+  }
+
+  /// Wraps the closure so it runs at most once every n_ticks, with any calls in between being ignored.
+  ///
+  /// Calling the returned [`Throttle`](Throttle) returns whether the closure ran.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::EventSync;
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let event_sync = EventSync::new_millis(tickrate);
+  ///
+  /// let mut presses = 0;
+  /// let mut throttled_press = event_sync.throttle(2, || presses += 1);
+  ///
+  /// assert!(throttled_press.call());
+  /// assert!(!throttled_press.call()); // Ignored, 2 ticks haven't passed.
+  ///
+  /// event_sync.wait_for_x_ticks(2).unwrap();
+  ///
+  /// assert!(throttled_press.call());
+  /// ```
+  pub fn throttle<F: FnMut()>(&self, n_ticks: u32, closure: F) -> Throttle<F> {
+    Throttle::new(self.to_immutable(), n_ticks, closure)
+  }
+
+  /// Wraps the closure so it only runs once n_ticks have passed without it being called again.
+  ///
+  /// Calls are recorded with [`Debounce::call()`](Debounce::call), and the closure is run by
+  /// [`Debounce::poll()`](Debounce::poll) once things have settled.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::EventSync;
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let event_sync = EventSync::new_millis(tickrate);
+  ///
+  /// let mut saves = 0;
+  /// let mut debounced_save = event_sync.debounce(2, || saves += 1);
+  ///
+  /// debounced_save.call();
+  /// debounced_save.call();
+  ///
+  /// assert!(!debounced_save.poll()); // Still waiting for 2 quiet ticks.
+  ///
+  /// event_sync.wait_for_x_ticks(2).unwrap();
+  ///
+  /// assert!(debounced_save.poll());
+  /// ```
+  pub fn debounce<F: FnMut()>(&self, n_ticks: u32, closure: F) -> Debounce<F> {
+    Debounce::new(self.to_immutable(), n_ticks, closure)
+  }
+
+  /// Runs the operation until it succeeds, waiting whole ticks between attempts as set by the policy.
+  ///
+  /// # Errors
+  ///
+  /// - The error of the last attempt is returned if every attempt failed.
+  /// - An error is returned if the EventSync is paused while waiting for the next attempt.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::*;
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let event_sync = EventSync::new_millis(tickrate);
+  ///
+  /// let mut attempts = 0;
+  /// let result = event_sync.retry(RetryPolicy::fixed(1), || {
+  ///   attempts += 1;
+  ///
+  ///   if attempts < 3 {
+  ///     Err("not yet")
+  ///   } else {
+  ///     Ok(attempts)
+  ///   }
+  /// });
+  ///
+  /// assert_eq!(result, Ok(3));
+  /// ```
+  pub fn retry<R, E, F: FnMut() -> Result<R, E>>(
+    &self,
+    policy: RetryPolicy,
+    mut operation: F,
+  ) -> Result<R, RetryError<E>> {
+    let mut schedule = policy.schedule();
+    let mut attempt = 1;
+
+    loop {
+      let error = match operation() {
+        Ok(result) => return Ok(result),
+        Err(error) => error,
+      };
+
+      if attempt >= policy.max_attempts() {
+        return Err(RetryError::Operation(error));
+      }
+
+      schedule.wait_next(self).map_err(RetryError::Time)?;
+      attempt += 1;
+    }
+  }
+
+  /// Runs the closure, reporting whether it finished before n_ticks more ticks occurred.
+  ///
+  /// The deadline is the tick n_ticks after the current one, so work started partway through a tick has less than
+  /// n_ticks worth of time to finish.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::EventSync;
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let event_sync = EventSync::new_millis(tickrate);
+  ///
+  /// let outcome = event_sync.run_with_tick_deadline(1, || {
+  ///   event_sync.wait_for_x_ticks(2).unwrap(); // Takes too long.
+  /// });
+  ///
+  /// assert!(!outcome.met_deadline);
+  /// ```
+  pub fn run_with_tick_deadline<R, F: FnOnce() -> R>(
+    &self,
+    n_ticks: u32,
+    closure: F,
+  ) -> DeadlineOutcome<R> {
+    deadline::run_with_deadline(&self.to_immutable(), n_ticks, closure)
+  }
+
+  /// Runs the closure like [`run_with_tick_deadline()`](EventSync::run_with_tick_deadline), setting the given
+  /// [`CancellationFlag`](CancellationFlag) once the deadline passes so the closure can stop early.
+  ///
+  /// The deadline is watched from a separate thread, which ends as soon as the closure returns.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::EventSync;
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let event_sync = EventSync::new_millis(tickrate);
+  ///
+  /// let outcome = event_sync.run_with_cancellable_tick_deadline(2, |flag| {
+  ///   let mut steps = 0;
+  ///
+  ///   while !flag.is_cancelled() {
+  ///     steps += 1; // Keep refining until out of time.
+  ///     std::thread::yield_now();
+  ///   }
+  ///
+  ///   steps
+  /// });
+  ///
+  /// assert!(!outcome.met_deadline);
+  /// ```
+  pub fn run_with_cancellable_tick_deadline<R, F: FnOnce(&CancellationFlag) -> R>(
+    &self,
+    n_ticks: u32,
+    closure: F,
+  ) -> DeadlineOutcome<R> {
+    deadline::run_with_cancellable_deadline(&self.to_immutable(), n_ticks, closure)
+  }
+
+  /// Returns a [`TickDeadline`](TickDeadline) at the tick n_ticks after the current one.
+  pub fn deadline_in(&self, n_ticks: u32) -> TickDeadline {
+    TickDeadline::at_tick(self.ticks_since_started() + n_ticks as u64)
+  }
+
+  /// Returns a [`TickDeadline`](TickDeadline) at the first tick once the given amount of time has passed from now.
+  pub fn deadline_after(&self, duration: Duration) -> TickDeadline {
+    let inner = self.read_inner();
+
+    TickDeadline::at_time(inner.time_since_started() + duration, inner.get_tickrate())
+  }
+
+  /// Creates a [`Stopwatch`](Stopwatch) measuring time on this EventSync's clock, starting now.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::EventSync;
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let event_sync = EventSync::new_millis(tickrate);
+  ///
+  /// let mut stopwatch = event_sync.stopwatch();
+  ///
+  /// event_sync.wait_for_x_ticks(2).unwrap();
+  /// let lap = stopwatch.lap();
+  ///
+  /// assert!(lap.ticks >= 2);
+  /// ```
+  pub fn stopwatch(&self) -> Stopwatch {
+    Stopwatch::new(self.to_immutable())
+  }
+
+  /// Creates an Immutable EventSync connected to this one, regardless of this one's access.
+  fn to_immutable(&self) -> EventSync<Immutable> {
+    EventSync {
+      inner: self.inner.clone(),
+      stats: self.stats.clone(),
+      driver: self.driver.clone(),
+      mutable_handle: None,
+      change_access: PhantomData,
+    }
+  }
+
+  /// Creates an EventSync from the data, unconnected to any other, with a handle of its own if it's Mutable.
+  fn from_inner(inner: InnerEventSync, is_mutable: bool) -> Self {
+    let inner = Arc::new(RwLock::new(inner));
+    let driver = Arc::default();
+
+    Self {
+      mutable_handle: is_mutable.then(|| orphan::MutableHandle::new(&inner, &driver)),
+      inner,
+      stats: Arc::default(),
+      driver,
+      change_access: PhantomData,
+    }
+  }
+
+  /// Obtains a ReadGuard of the [`internal EventSync data`](InnerEventSync).
+  fn read_inner(&self) -> RwLockReadGuard<'_, InnerEventSync> {
+    self.inner.read().unwrap()
+  }
+}
+
+#[cfg(feature = "std")]
+impl EventSync<Mutable> {
+  /// [`new_millis()`](EventSync::new_millis), taking the tickrate as a `u32`.
+  #[deprecated(
+    since = "0.4.5",
+    note = "use `new_millis()`, as tickrates are `u64` milliseconds"
+  )]
+  pub fn new(tickrate_in_milliseconds: u32) -> Self {
+    Self::new_millis(tickrate_in_milliseconds.into())
+  }
+
+  /// Creates a new instance of [`EventSync`](EventSync).
+  ///
+  /// Takes the duration of a tick as milliseconds.
+  /// If 0 is passed in, 1 will be the assigned tickrate for this instance of EventSync, and anything longer than 100
+  /// years is set as 100 years.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::*;
+  ///
+  /// let tickrate = 10; // 10ms between every tick
+  ///
+  /// // Create an EventSync with a 10ms tickrate.
+  /// let event_sync = EventSync::new_millis(tickrate);
+  /// ```
+  ///
+  /// You can then use this EventSync for both time tracking and synchronizing threads.
+  ///
+  /// # Time Tracking
+  /// ```
+  /// use event_sync::*;
+  /// use std::time::Instant;
+  ///
+  /// let tickrate = 10; // 10ms between every tick
+  /// let event_sync = EventSync::new_millis(tickrate);
+  ///
+  /// let start = Instant::now();
+  ///
+  /// // Wait for 5 ticks (5 * 10)ms.
+  /// event_sync.wait_for_x_ticks(5);
+  ///
+  /// let finish = start.elapsed().as_millis();
+  ///
+  /// // Check that the time it took for the operation was (waited_ticks * tickrate)ms
+  /// assert_eq!(finish, event_sync.time_since_started().as_millis());
+  /// ```
+  ///
+  /// # Thread Synchronization
+  /// ```
+  /// use event_sync::*;
+  /// use std::thread;
+  ///
+  /// let tickrate = 10; // 10ms between every tick
+  /// let event_sync = EventSync::new_millis(tickrate);
+  ///
+  /// // All cloned EventSyncs will share their data.
+  /// let passed_event_sync = event_sync.clone();
+  ///
+  /// let handle = thread::spawn(move || {
+  ///   // waiting until 5 ticks have occurred since the creation of event_sync.
+  ///   passed_event_sync.wait_until(5);
+  ///
+  ///   // do something
+  /// });
+  ///
+  /// // waiting until 5 ticks have occurred since the creation of event_sync.
+  /// event_sync.wait_until(5);
+  ///
+  /// // do something
+  ///
+  /// handle.join().unwrap();
+  /// ```
+  pub fn new_millis(tickrate_in_milliseconds: u64) -> Self {
+    Self::new_event_sync(
+      tickrate_in_milliseconds,
+      Duration::default(),
+      false,
+      ClockSource::default(),
+    )
+  }
+
+  /// [`new_with_clock_millis()`](EventSync::new_with_clock_millis), taking the tickrate as a `u32`.
+  #[deprecated(
+    since = "0.4.5",
+    note = "use `new_with_clock_millis()`, as tickrates are `u64` milliseconds"
+  )]
+  pub fn new_with_clock(tickrate_in_milliseconds: u32, clock: ClockSource) -> Self {
+    Self::new_with_clock_millis(tickrate_in_milliseconds.into(), clock)
+  }
+
+  /// Creates a new instance of [`EventSync`](EventSync) that measures time against the given [`clock`](ClockSource).
+  ///
+  /// Using [`ClockSource::WallClock`](ClockSource::WallClock) keeps the EventSync aligned with civil time across
+  /// adjustments to the system's clock, which is useful for long running processes.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::*;
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let event_sync = EventSync::new_with_clock_millis(tickrate, ClockSource::WallClock);
+  ///
+  /// event_sync.wait_for_x_ticks(2).unwrap();
+  ///
+  /// assert_eq!(event_sync.ticks_since_started(), 2);
+  /// ```
+  pub fn new_with_clock_millis(tickrate_in_milliseconds: u64, clock: ClockSource) -> Self {
+    Self::new_event_sync(tickrate_in_milliseconds, Duration::default(), false, clock)
+  }
+
+  /// [`new_paused_millis()`](EventSync::new_paused_millis), taking the tickrate as a `u32`.
+  #[deprecated(
+    since = "0.4.5",
+    note = "use `new_paused_millis()`, as tickrates are `u64` milliseconds"
+  )]
+  pub fn new_paused(tickrate_in_milliseconds: u32) -> Self {
+    Self::new_paused_millis(tickrate_in_milliseconds.into())
+  }
+
+  /// Creates a new instance of EventSync that starts out paused.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::*;
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let event_sync = EventSync::new_paused_millis(tickrate); // Create an event_sync that starts out paused.
+  ///
+  /// assert!(event_sync.is_paused());
+  /// assert!(event_sync.wait_for_tick().is_err());
+  /// ```
+  pub fn new_paused_millis(tickrate_in_milliseconds: u64) -> Self {
+    Self::new_event_sync(
+      tickrate_in_milliseconds,
+      Duration::default(),
+      true,
+      ClockSource::default(),
+    )
+  }
+
+  /// [`from_starting_time_millis()`](EventSync::from_starting_time_millis), taking the tickrate as a `u32`.
+  #[deprecated(
+    since = "0.4.5",
+    note = "use `from_starting_time_millis()`, as tickrates are `u64` milliseconds"
+  )]
+  pub fn from_starting_time(
+    tickrate_in_milliseconds: u32,
+    elapsed_time: Duration,
+    start_paused: bool,
+  ) -> Self {
+    Self::from_starting_time_millis(tickrate_in_milliseconds.into(), elapsed_time, start_paused)
+  }
+
+  /// Creates a new instance of [`EventSync`](EventSync) with the given starting time.
+  ///
+  /// Takes an extra arguement to determine if the EventSync should be paused upon creation or not.
+  ///
+  /// # Example
   ///
   /// ```
   /// use event_sync::*;
   /// use std::time::Duration;
   ///
   /// let tickrate = 10; // 10ms between every tick.
-  /// let starting_tick = 3; // Start 3 ticks ahead.
-  /// let event_sync = EventSync::from_starting_tick(tickrate, starting_tick, false);
+  /// let starting_time = Duration::from_millis(30); // Start 30ms ahead.
+  /// let event_sync = EventSync::from_starting_time_millis(tickrate, starting_time, false);
   ///
   /// assert_eq!(event_sync.ticks_since_started(), 3);
   /// ```
@@ -513,33 +1539,156 @@ impl EventSync<Mutable> {
   /// use std::time::Duration;
   ///
   /// let tickrate = 10; // 10ms between every tick.
-  /// let starting_tick = 3; // Start 3 ticks ahead.
-  /// let mut event_sync = EventSync::from_starting_tick(tickrate, starting_tick, true);
+  /// let starting_time = Duration::from_millis(30); // Start 30ms ahead.
+  /// let mut event_sync = EventSync::from_starting_time_millis(tickrate, starting_time, true);
   ///
   /// assert!(event_sync.is_paused());
   /// event_sync.unpause().unwrap();
   ///
   /// assert_eq!(event_sync.ticks_since_started(), 3);
   /// ```
+  pub fn from_starting_time_millis(
+    tickrate_in_milliseconds: u64,
+    elapsed_time: Duration,
+    start_paused: bool,
+  ) -> Self {
+    Self::new_event_sync(
+      tickrate_in_milliseconds,
+      elapsed_time,
+      start_paused,
+      ClockSource::default(),
+    )
+  }
+
+  /// [`from_starting_tick_millis()`](EventSync::from_starting_tick_millis), taking the tickrate and tick as `u32`s.
+  #[deprecated(
+    since = "0.4.5",
+    note = "use `from_starting_tick_millis()`, as tickrates are `u64` milliseconds"
+  )]
   pub fn from_starting_tick(
     tickrate_in_milliseconds: u32,
     starting_tick: u32,
     start_paused: bool,
   ) -> Self {
-    let elapsed_time = Duration::from_millis((starting_tick * tickrate_in_milliseconds).into());
+    Self::from_starting_tick_millis(
+      tickrate_in_milliseconds.into(),
+      starting_tick.into(),
+      start_paused,
+    )
+  }
+
+  /// Creates a new instance of [`EventSync`](EventSync) with the given starting tick.
+  ///
+  /// Takes an extra arguement to determine if the EventSync should be paused upon creation or not.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use event_sync::*;
+  /// use std::time::Duration;
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let starting_tick = 3; // Start 3 ticks ahead.
+  /// let event_sync = EventSync::from_starting_tick_millis(tickrate, starting_tick, false);
+  ///
+  /// assert_eq!(event_sync.ticks_since_started(), 3);
+  /// ```
+  ///
+  /// # Starting Paused
+  ///
+  /// ```
+  /// use event_sync::*;
+  /// use std::time::Duration;
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let starting_tick = 3; // Start 3 ticks ahead.
+  /// let mut event_sync = EventSync::from_starting_tick_millis(tickrate, starting_tick, true);
+  ///
+  /// assert!(event_sync.is_paused());
+  /// event_sync.unpause().unwrap();
+  ///
+  /// assert_eq!(event_sync.ticks_since_started(), 3);
+  /// ```
+  pub fn from_starting_tick_millis(
+    tickrate_in_milliseconds: u64,
+    starting_tick: u64,
+    start_paused: bool,
+  ) -> Self {
+    let elapsed_time = tick_math::time_of_tick(starting_tick, tickrate_in_milliseconds);
+
+    Self::new_event_sync(
+      tickrate_in_milliseconds,
+      elapsed_time,
+      start_paused,
+      ClockSource::default(),
+    )
+  }
 
-    Self::new_event_sync(tickrate_in_milliseconds, elapsed_time, start_paused)
+  /// [`from_system_epoch_millis()`](EventSync::from_system_epoch_millis), taking the tickrate as a `u32`.
+  #[deprecated(
+    since = "0.4.5",
+    note = "use `from_system_epoch_millis()`, as tickrates are `u64` milliseconds"
+  )]
+  pub fn from_system_epoch(
+    tickrate_in_milliseconds: u32,
+    anchor: SystemTime,
+  ) -> Result<Self, TimeError> {
+    Self::from_system_epoch_millis(tickrate_in_milliseconds.into(), anchor)
   }
 
-  /// Create a new [`EventSync`](EventSync) from the given tickrate and whether or not the EventSync is started paused.
+  /// Creates a new instance of [`EventSync`](EventSync) where tick 0 occurred at the given point on the wall clock.
+  ///
+  /// Independent processes that agree on the anchor and tickrate will compute identical tick numbers, allowing them
+  /// to loosely synchronize without communicating.
+  ///
+  /// The created EventSync measures time with [`ClockSource::WallClock`](ClockSource::WallClock).
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if the anchor hasn't happened yet.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use event_sync::*;
+  /// use std::time::{Duration, SystemTime};
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let anchor = SystemTime::UNIX_EPOCH;
+  ///
+  /// // Both of these could be in separate processes.
+  /// let event_sync = EventSync::from_system_epoch_millis(tickrate, anchor).unwrap();
+  /// let other_event_sync = EventSync::from_system_epoch_millis(tickrate, anchor).unwrap();
+  ///
+  /// event_sync.wait_for_tick().unwrap();
+  ///
+  /// assert_eq!(event_sync.ticks_since_started(), other_event_sync.ticks_since_started());
+  /// ```
+  pub fn from_system_epoch_millis(
+    tickrate_in_milliseconds: u64,
+    anchor: SystemTime,
+  ) -> Result<Self, TimeError> {
+    if anchor > SystemTime::now() {
+      return Err(TimeError::StartTimeInFuture);
+    }
+
+    let inner = InnerEventSync::from_system_epoch(tickrate_in_milliseconds, anchor);
+
+    Ok(Self::from_inner(inner, true))
+  }
+
+  /// Create a new [`EventSync`](EventSync) from the given tickrate, whether or not the EventSync is started paused,
+  /// and the clock to measure time against.
   /// If paused, the stored passed time will be the passed in elapsed_time.
-  fn new_event_sync(tickrate: u32, elapsed_time: Duration, is_paused: bool) -> Self {
-    let inner = InnerEventSync::new(tickrate, elapsed_time, is_paused);
+  fn new_event_sync(
+    tickrate: u64,
+    elapsed_time: Duration,
+    is_paused: bool,
+    clock: ClockSource,
+  ) -> Self {
+    let inner = InnerEventSync::new(tickrate, elapsed_time, is_paused, clock);
 
-    Self {
-      inner: Arc::new(RwLock::new(inner)),
-      change_access: PhantomData,
-    }
+    Self::from_inner(inner, true)
   }
 
   /// This creates an Immutable instance of [`EventSync`](EventSync).
@@ -551,13 +1700,21 @@ impl EventSync<Mutable> {
   pub fn clone_immutable(&self) -> EventSync<Immutable> {
     EventSync {
       inner: self.inner.clone(),
+      stats: self.stats.clone(),
+      driver: self.driver.clone(),
+      mutable_handle: None,
       change_access: PhantomData,
     }
   }
 
   /// Obtains a WriteGuard of the [`internal EventSync data`](InnerEventSync).
-  fn write_inner(&mut self) -> RwLockWriteGuard<InnerEventSync> {
-    self.inner.write().unwrap()
+  ///
+  /// Clears the driver thread's cached tick, which is out of date once the data is changed.
+  fn write_inner(&mut self) -> RwLockWriteGuard<'_, InnerEventSync> {
+    let inner = self.inner.write().unwrap();
+    self.driver.clear_cached_tick();
+
+    inner
   }
 
   /// Restarts the starting time.
@@ -570,7 +1727,7 @@ impl EventSync<Mutable> {
   /// use event_sync::EventSync;
   ///
   /// let tickrate = 10; // 10ms between every tick
-  /// let mut event_sync = EventSync::new(tickrate);
+  /// let mut event_sync = EventSync::new_millis(tickrate);
   ///
   /// event_sync.wait_for_tick().unwrap(); // Add some time.
   ///
@@ -585,12 +1742,14 @@ impl EventSync<Mutable> {
   /// Restarts the startimg time, and changes self to paused.
   /// This will also restart and pause the time for every EventSync cloned off of this one.
   ///
+  /// Any reasons the EventSync was paused with are dropped, as restarting overrides them.
+  ///
   /// # Examples
   /// ```
   /// use event_sync::EventSync;
   ///
   /// let tickrate = 10; // 10ms between every tick
-  /// let mut event_sync = EventSync::new(tickrate);
+  /// let mut event_sync = EventSync::new_millis(tickrate);
   ///
   /// event_sync.wait_for_tick().unwrap(); // Add some time.
   ///
@@ -602,30 +1761,149 @@ impl EventSync<Mutable> {
     self.write_inner().restart_paused();
   }
 
+  /// [`restart_with_tickrate_millis()`](EventSync::restart_with_tickrate_millis), taking the tickrate as a `u32`.
+  #[deprecated(
+    since = "0.4.5",
+    note = "use `restart_with_tickrate_millis()`, as tickrates are `u64` milliseconds"
+  )]
+  pub fn restart_with_tickrate(&mut self, new_tickrate: u32) {
+    self.restart_with_tickrate_millis(new_tickrate.into());
+  }
+
+  /// Changes the tickrate and restarts the EventSync, as a single change for every connected EventSync.
+  ///
+  /// Calling [`change_tickrate_millis()`](EventSync::change_tickrate_millis) then [`restart()`](EventSync::restart) lets other
+  /// threads wait on ticks of the new tickrate counted from before the restart.
+  ///
+  /// # Examples
+  /// ```
+  /// use event_sync::EventSync;
+  ///
+  /// let tickrate = 10; // 10ms between every tick
+  /// let mut event_sync = EventSync::new_millis(tickrate);
+  ///
+  /// event_sync.wait_for_tick().unwrap(); // Add some time.
+  ///
+  /// event_sync.restart_with_tickrate_millis(20);
+  ///
+  /// assert_eq!(event_sync.get_tickrate_millis(), 20);
+  /// assert_eq!(event_sync.ticks_since_started(), 0);
+  /// ```
+  pub fn restart_with_tickrate_millis(&mut self, new_tickrate: u64) {
+    self.write_inner().restart_with_tickrate(new_tickrate);
+  }
+
+  /// [`pause_and_set_rate_millis()`](EventSync::pause_and_set_rate_millis), taking and returning tickrates as `u32`s.
+  ///
+  /// A previous tickrate too long to fit in a `u32` is returned as `u32::MAX`.
+  #[deprecated(
+    since = "0.4.5",
+    note = "use `pause_and_set_rate_millis()`, as tickrates are `u64` milliseconds"
+  )]
+  pub fn pause_and_set_rate(&mut self, new_tickrate: u32) -> (u32, u64) {
+    let (old_tickrate, current_tick) = self.pause_and_set_rate_millis(new_tickrate.into());
+
+    (
+      u32::try_from(old_tickrate).unwrap_or(u32::MAX),
+      current_tick,
+    )
+  }
+
+  /// Pauses the EventSync and changes its tickrate, as a single change for every connected EventSync.
+  ///
+  /// Calling [`pause()`](EventSync::pause) then [`change_tickrate_millis()`](EventSync::change_tickrate_millis) takes
+  /// the lock twice, so in between, other threads can see the EventSync paused with the old tickrate and act on it.
+  ///
+  /// Returns the previous tickrate and the tick the EventSync is paused on at the new tickrate, like
+  /// [`change_tickrate_millis()`](EventSync::change_tickrate_millis).
+  ///
+  /// # Examples
+  /// ```
+  /// use event_sync::EventSync;
+  /// use std::time::Duration;
+  ///
+  /// let tickrate = 10; // 10ms between every tick
+  /// let mut event_sync = EventSync::from_starting_time_millis(tickrate, Duration::from_millis(100), false);
+  ///
+  /// let (old_tickrate, current_tick) = event_sync.pause_and_set_rate_millis(50);
+  ///
+  /// assert!(event_sync.is_paused());
+  /// assert_eq!(old_tickrate, 10);
+  /// assert_eq!(current_tick, 2);
+  /// ```
+  pub fn pause_and_set_rate_millis(&mut self, new_tickrate: u64) -> (u64, u64) {
+    self.write_inner().pause_and_change_tickrate(new_tickrate)
+  }
+
+  /// Returns the amount of times the timeline has been reset, by restarting or syncing to a time.
+  ///
+  /// As a reset can repeat tick numbers, the generation tells apart ticks with the same number.
+  ///
+  /// # Examples
+  /// ```
+  /// use event_sync::EventSync;
+  ///
+  /// let tickrate = 10; // 10ms between every tick
+  /// let mut event_sync = EventSync::new_millis(tickrate);
+  ///
+  /// assert_eq!(event_sync.generation(), 0);
+  ///
+  /// event_sync.restart();
+  ///
+  /// assert_eq!(event_sync.generation(), 1);
+  /// ```
+  pub fn generation(&self) -> u64 {
+    self.read_inner().generation()
+  }
+
+  /// [`change_tickrate_millis()`](EventSync::change_tickrate_millis), taking and returning tickrates as `u32`s.
+  ///
+  /// A previous tickrate too long to fit in a `u32` is returned as `u32::MAX`.
+  #[deprecated(
+    since = "0.4.5",
+    note = "use `change_tickrate_millis()`, as tickrates are `u64` milliseconds"
+  )]
+  pub fn change_tickrate(&mut self, new_tickrate: u32) -> (u32, u64) {
+    let (old_tickrate, current_tick) = self.change_tickrate_millis(new_tickrate.into());
+
+    (
+      u32::try_from(old_tickrate).unwrap_or(u32::MAX),
+      current_tick,
+    )
+  }
+
   /// Changes how long a tick lasts internally. Retains the time that passed before method call.
   /// That means if 100ms have passed, 100ms will still have passed. The amount of ticks will be the
   /// only thing that's changed.
   ///
   /// Changes the tickrate for all connected EventSyncs.
   ///
+  /// Returns the previous tickrate and the tick the EventSync is on at the new tickrate, both read as part of the
+  /// change, so another thread can't change or move the EventSync between them.
+  ///
+  /// Like when creating an EventSync, a tickrate of 0 is set as 1, and anything longer than 100 years is set as 100
+  /// years.
+  ///
   /// # Examples
   ///
   /// ```
   /// use event_sync::*;
   ///
   /// let tickrate = 10; // 10ms tickrate.
-  /// let mut event_sync = EventSync::new(tickrate);
+  /// let mut event_sync = EventSync::new_millis(tickrate);
   ///
   /// // Wait for 100ms (10 ticks).
   /// event_sync.wait_for_x_ticks(10).unwrap();
   ///
   /// // Change the tickrate to 100ms, 10x what it was before.
-  /// event_sync.change_tickrate(tickrate * 10);
+  /// let (old_tickrate, current_tick) = event_sync.change_tickrate_millis(tickrate * 10);
   ///
+  /// assert_eq!(old_tickrate, tickrate);
   /// // Ensure that 1 tick has passed, which is now 100ms.
+  /// assert_eq!(current_tick, 1);
   /// assert_eq!(event_sync.ticks_since_started(), 1);
   /// // Ensure that the tickrate is now 100ms instead of the prior 10ms.
-  /// assert_eq!(event_sync.get_tickrate(), 100);
+  /// assert_eq!(event_sync.get_tickrate_millis(), 100);
   /// ```
   ///
   /// # EventSyncs are connected
@@ -634,17 +1912,149 @@ impl EventSync<Mutable> {
   /// use event_sync::*;
   ///
   /// let tickrate = 10; // 10ms tickrate.
-  /// let event_sync = EventSync::new(tickrate);
+  /// let event_sync = EventSync::new_millis(tickrate);
   /// let mut other_event_sync = event_sync.clone();
   ///
   /// // Change the tickrate. This will change it for both EventSyncs.
-  /// other_event_sync.change_tickrate(tickrate * 2);
+  /// other_event_sync.change_tickrate_millis(tickrate * 2);
   ///
   /// // Ensure the original EventSync's tickrate is also changed.
-  /// assert_eq!(event_sync.get_tickrate(), tickrate * 2);
+  /// assert_eq!(event_sync.get_tickrate_millis(), tickrate * 2);
+  /// ```
+  pub fn change_tickrate_millis(&mut self, new_tickrate: u64) -> (u64, u64) {
+    self.write_inner().change_tickrate(new_tickrate)
+  }
+
+  /// [`change_tickrate_millis_if()`](EventSync::change_tickrate_millis_if), taking and returning tickrates as `u32`s.
+  ///
+  /// An actual tickrate too long to fit in a `u32` is returned as `u32::MAX`.
+  #[deprecated(
+    since = "0.4.5",
+    note = "use `change_tickrate_millis_if()`, as tickrates are `u64` milliseconds"
+  )]
+  pub fn change_tickrate_if(
+    &mut self,
+    expected_tickrate: u32,
+    new_tickrate: u32,
+  ) -> Result<u64, u32> {
+    self
+      .change_tickrate_millis_if(expected_tickrate.into(), new_tickrate.into())
+      .map_err(|actual_tickrate| u32::try_from(actual_tickrate).unwrap_or(u32::MAX))
+  }
+
+  /// Changes the tickrate only if it's still the expected tickrate, like a compare-and-set.
+  ///
+  /// This keeps two parts of a program adjusting the tickrate from silently overwriting each other's changes.
+  /// When applied, the tick the EventSync is now on is returned.
+  ///
+  /// # Errors
+  ///
+  /// - The actual tickrate is returned if it isn't the expected one, leaving it unchanged.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::*;
+  ///
+  /// let tickrate = 10; // 10ms tickrate.
+  /// let mut event_sync = EventSync::new_millis(tickrate);
+  /// let mut other_event_sync = event_sync.clone();
+  ///
+  /// assert!(event_sync.change_tickrate_millis_if(tickrate, 20).is_ok());
+  ///
+  /// // The other EventSync expected the tickrate it last saw.
+  /// assert_eq!(other_event_sync.change_tickrate_millis_if(tickrate, 5), Err(20));
+  /// assert_eq!(event_sync.get_tickrate_millis(), 20);
+  /// ```
+  pub fn change_tickrate_millis_if(
+    &mut self,
+    expected_tickrate: u64,
+    new_tickrate: u64,
+  ) -> Result<u64, u64> {
+    let mut inner = self.write_inner();
+
+    if inner.get_tickrate() != expected_tickrate {
+      return Err(inner.get_tickrate());
+    }
+
+    let (_, current_tick) = inner.change_tickrate(new_tickrate);
+
+    Ok(current_tick)
+  }
+
+  /// Moves the timeline of this EventSync so the given amount of time has passed since it started.
+  /// Every EventSync connected to this one is moved as well.
+  ///
+  /// The EventSync stays paused or running.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if the new starting point can't be represented.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::EventSync;
+  /// use std::time::Duration;
+  ///
+  /// let tickrate = 10; // 10ms between every tick
+  /// let mut event_sync = EventSync::new_millis(tickrate);
+  ///
+  /// event_sync.sync_to(Duration::from_millis(50)).unwrap();
+  ///
+  /// assert_eq!(event_sync.ticks_since_started(), 5);
+  /// ```
+  pub fn sync_to(&mut self, time_since_started: Duration) -> Result<(), TimeError> {
+    self
+      .write_inner()
+      .set_time_since_started(time_since_started)
+  }
+
+  /// Gradually moves the timeline of this EventSync so it matches the given amount of time having passed,
+  /// spreading the correction over the given amount of ticks instead of jumping.
+  /// Every EventSync connected to this one is moved as well.
+  ///
+  /// While the correction is applied, ticks last slightly longer or shorter than the tickrate, so anything waiting
+  /// on ticks doesn't see a sudden jump. Time never runs backwards, backward corrections are spread over at least
+  /// their own length.
+  ///
+  /// The given time is treated as the reading of another clock, so it keeps moving forward while the correction is
+  /// applied. If the EventSync is paused or `over_ticks` is 0, this is the same as [`sync_to()`](EventSync::sync_to).
+  /// Pausing partway through stops the correction where it is, and the rest of it is dropped.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if the new starting point can't be represented.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::EventSync;
+  /// use std::time::Duration;
+  ///
+  /// let tickrate = 10; // 10ms between every tick
+  /// let mut event_sync = EventSync::new_millis(tickrate);
+  ///
+  /// // Add 20ms over the next 5 ticks.
+  /// event_sync.slew_to(Duration::from_millis(20), 5).unwrap();
+  ///
+  /// // Only a portion of the correction is applied yet.
+  /// assert!(event_sync.ticks_since_started() < 2);
+  ///
+  /// event_sync.wait_until(7).unwrap();
+  ///
+  /// // 50ms have passed, plus the 20ms correction.
+  /// assert_eq!(event_sync.ticks_since_started(), 7);
   /// ```
-  pub fn change_tickrate(&mut self, new_tickrate: u32) {
-    self.write_inner().change_tickrate(new_tickrate);
+  pub fn slew_to(
+    &mut self,
+    time_since_started: Duration,
+    over_ticks: u32,
+  ) -> Result<(), TimeError> {
+    let mut inner = self.write_inner();
+    let period = Duration::from_millis((over_ticks as u64).saturating_mul(inner.get_tickrate()));
+
+    inner.slew_time_since_started(time_since_started, period)
   }
 
   /// Unpauses this instance of EventSync if it's been paused.
@@ -654,14 +2064,17 @@ impl EventSync<Mutable> {
   ///
   /// Calling unpause when the EventSync is already running does nothing.
   ///
+  /// Any reasons the EventSync was paused with are dropped, as unpausing overrides them. To take back a single
+  /// pause instead, use [`unpause_with_reason()`](EventSync::unpause_with_reason).
+  ///
   /// # Examples
   ///
   /// ```
   /// use event_sync::EventSync;
   ///
   /// let tickrate = 10; // 10ms between every tick
-  /// let mut event_sync = EventSync::new(tickrate);
-  /// let other_event_sync = EventSync::new(tickrate); // Create a second one to desync.
+  /// let mut event_sync = EventSync::new_millis(tickrate);
+  /// let other_event_sync = EventSync::new_millis(tickrate); // Create a second one to desync.
   ///
   /// event_sync.wait_for_tick().unwrap(); // Add some time.
   /// event_sync.pause();
@@ -678,7 +2091,7 @@ impl EventSync<Mutable> {
   /// use event_sync::EventSync;
   ///
   /// let tickrate = 10; // 10ms between every tick
-  /// let mut event_sync = EventSync::new_paused(tickrate);
+  /// let mut event_sync = EventSync::new_paused_millis(tickrate);
   /// let other_event_sync = event_sync.clone();
   ///
   /// assert!(other_event_sync.is_paused());
@@ -705,8 +2118,8 @@ impl EventSync<Mutable> {
   /// use event_sync::EventSync;
   ///
   /// let tickrate = 10; // 10ms between every tick
-  /// let mut event_sync = EventSync::new(tickrate);
-  /// let other_event_sync = EventSync::new(tickrate); // Create a second one to desync.
+  /// let mut event_sync = EventSync::new_millis(tickrate);
+  /// let other_event_sync = EventSync::new_millis(tickrate); // Create a second one to desync.
   ///
   /// event_sync.wait_for_tick().unwrap(); // Add some time.
   /// event_sync.pause();
@@ -723,57 +2136,253 @@ impl EventSync<Mutable> {
   /// use event_sync::EventSync;
   ///
   /// let tickrate = 10; // 10ms between every tick
-  /// let mut event_sync = EventSync::new(tickrate);
+  /// let mut event_sync = EventSync::new_millis(tickrate);
   /// let other_event_sync = event_sync.clone();
   ///
-  /// event_sync.pause();
+  /// event_sync.pause();
+  ///
+  /// assert!(other_event_sync.is_paused());
+  ///
+  /// ```
+  pub fn pause(&mut self) {
+    self.write_inner().pause()
+  }
+
+  /// Pauses this instance of EventSync with a reason, which is held until taken back with
+  /// [`unpause_with_reason()`](EventSync::unpause_with_reason).
+  ///
+  /// Reasons are counted, so separate systems can pause with the same or different reasons without unpausing over
+  /// each other. The EventSync is unpaused once every reason is taken back, as many times as it was given, unless
+  /// it was also paused with [`pause()`](EventSync::pause), which holds it paused until
+  /// [`unpause()`](EventSync::unpause) is called.
+  ///
+  /// Like pausing, the reasons are shared with every EventSync cloned off this one. They aren't saved when
+  /// serializing.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::EventSync;
+  ///
+  /// let tickrate = 10; // 10ms between every tick
+  /// let mut event_sync = EventSync::new_millis(tickrate);
+  ///
+  /// event_sync.pause_with_reason("menu open");
+  /// event_sync.pause_with_reason("window unfocused");
+  ///
+  /// event_sync.unpause_with_reason("menu open").unwrap();
+  ///
+  /// // The window is still unfocused.
+  /// assert!(event_sync.is_paused());
+  /// assert_eq!(event_sync.pause_reasons(), ["window unfocused"]);
+  ///
+  /// event_sync.unpause_with_reason("window unfocused").unwrap();
+  ///
+  /// assert!(!event_sync.is_paused());
+  /// ```
+  pub fn pause_with_reason(&mut self, reason: &'static str) {
+    self.write_inner().pause_with_reason(reason)
+  }
+
+  /// Takes back one pause given with the reason, unpausing once no reasons are left.
+  ///
+  /// Returns false if the EventSync isn't paused with the reason, in which case nothing changes.
   ///
-  /// assert!(other_event_sync.is_paused());
+  /// # Errors
   ///
-  /// ```
-  pub fn pause(&mut self) {
-    self.write_inner().pause()
+  /// - If [`Instant::checked_sub`](https://doc.rust-lang.org/stable/std/time/struct.Instant.html#method.checked_sub) fails when unpausing.
+  pub fn unpause_with_reason(&mut self, reason: &'static str) -> Result<bool, TimeError> {
+    self.write_inner().unpause_with_reason(reason)
   }
 }
 
+#[cfg(feature = "std")]
 impl<T> PartialEq for EventSync<T> {
   fn eq(&self, other: &Self) -> bool {
     *self.read_inner() == *other.read_inner()
   }
 }
 
+#[cfg(feature = "std")]
 impl<T> Eq for EventSync<T> {}
 
+/// The layout an EventSync is serialized with.
+#[cfg(feature = "std")]
+#[derive(Serialize)]
+#[serde(rename = "EventSync")]
+struct SerializedEventSync<'a> {
+  inner: &'a InnerEventSync,
+  change_access: AccessName,
+}
+
+/// The layout an EventSync is deserialized from.
+///
+/// EventSyncs serialized before the access was stored have no access, and are treated as Mutable.
+#[cfg(feature = "std")]
+#[derive(Deserialize)]
+#[serde(rename = "EventSync")]
+struct DeserializedEventSync {
+  inner: InnerEventSync,
+  #[serde(default)]
+  change_access: Option<AccessName>,
+}
+
+#[cfg(feature = "std")]
+impl<T: AccessLevel> Serialize for EventSync<T> {
+  fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    SerializedEventSync {
+      inner: &self.read_inner(),
+      change_access: if T::IS_MUTABLE {
+        AccessName::Mutable
+      } else {
+        AccessName::Immutable
+      },
+    }
+    .serialize(serializer)
+  }
+}
+
+/// Deserializing an Immutable EventSync as Mutable is an error, as that would give it access it didn't have.
+#[cfg(feature = "std")]
+impl<'de, T: AccessLevel> Deserialize<'de> for EventSync<T> {
+  fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let deserialized = DeserializedEventSync::deserialize(deserializer)?;
+    let access = deserialized.change_access.unwrap_or(AccessName::Mutable);
+
+    if access == AccessName::Immutable && T::IS_MUTABLE {
+      return Err(serde::de::Error::custom(
+        "an Immutable EventSync can't be deserialized as Mutable",
+      ));
+    }
+
+    Ok(Self::from_inner(deserialized.inner, T::IS_MUTABLE))
+  }
+}
+
+#[cfg(feature = "std")]
 impl<T> std::fmt::Debug for EventSync<T> {
   fn fmt(
     &self,
     formatter: &mut std::fmt::Formatter<'_>,
   ) -> std::result::Result<(), std::fmt::Error> {
-    write!(formatter, "{:?}", self.time_since_started())
+    let view = self.view();
+    // Only the last segment of the access type's path, such as `Mutable`.
+    let access = std::any::type_name::<T>()
+      .rsplit("::")
+      .next()
+      .unwrap_or_default();
+
+    formatter
+      .debug_struct("EventSync")
+      .field("access", &format_args!("{access}"))
+      .field("tick", &view.tick)
+      .field("time_since_started", &view.time_since_started)
+      .field("tickrate", &view.tickrate)
+      .field("is_paused", &view.is_paused)
+      .field("generation", &view.generation)
+      .finish()
   }
 }
 
+#[cfg(feature = "std")]
+/// Formats as `tick 423 @ 10ms, running`.
+///
+/// Call [`format_elapsed()`](EventSync::format_elapsed) for other formats.
 impl<T> std::fmt::Display for EventSync<T> {
   fn fmt(
     &self,
     formatter: &mut std::fmt::Formatter<'_>,
   ) -> std::result::Result<(), std::fmt::Error> {
-    write!(formatter, "{:?}", self)
+    write!(formatter, "{}", self.view())
+  }
+}
+
+/// The tickrate [`EventSync::default()`](EventSync::default) uses until
+/// [`set_default_tickrate_millis()`](EventSync::set_default_tickrate_millis) is called.
+pub const DEFAULT_TICKRATE: u64 = 10;
+
+/// The tickrate set by [`EventSync::set_default_tickrate_millis()`](EventSync::set_default_tickrate_millis).
+#[cfg(feature = "std")]
+static CONFIGURED_DEFAULT_TICKRATE: std::sync::OnceLock<u64> = std::sync::OnceLock::new();
+
+#[cfg(feature = "std")]
+impl EventSync<Mutable> {
+  /// [`set_default_tickrate_millis()`](EventSync::set_default_tickrate_millis), taking the tickrate as a `u32`.
+  #[deprecated(
+    since = "0.4.5",
+    note = "use `set_default_tickrate_millis()`, as tickrates are `u64` milliseconds"
+  )]
+  pub fn set_default_tickrate(tickrate_in_milliseconds: u32) -> Result<(), TimeError> {
+    Self::set_default_tickrate_millis(tickrate_in_milliseconds.into())
+  }
+
+  /// Sets the tickrate [`EventSync::default()`](EventSync::default) uses for the rest of the program.
+  ///
+  /// This lets EventSyncs created through `Default`, such as in structs deriving it, use the application's tickrate.
+  /// The default can only be set once, so it can't change under EventSyncs that were already created.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if the tickrate is 0, or longer than 100 years.
+  /// - An error is returned if the default tickrate has already been set.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::*;
+  ///
+  /// EventSync::set_default_tickrate_millis(16).unwrap();
+  ///
+  /// assert_eq!(EventSync::default().get_tickrate_millis(), 16);
+  /// assert_eq!(
+  ///   EventSync::set_default_tickrate_millis(20),
+  ///   Err(TimeError::DefaultTickrateAlreadySet)
+  /// );
+  /// ```
+  pub fn set_default_tickrate_millis(tickrate_in_milliseconds: u64) -> Result<(), TimeError> {
+    inner::validate(tickrate_in_milliseconds, Duration::ZERO)?;
+
+    CONFIGURED_DEFAULT_TICKRATE
+      .set(tickrate_in_milliseconds)
+      .map_err(|_| TimeError::DefaultTickrateAlreadySet)
+  }
+
+  /// Returns the tickrate [`EventSync::default()`](EventSync::default) uses, or `u32::MAX` if it's too long to fit in a
+  /// `u32`.
+  #[deprecated(
+    since = "0.4.5",
+    note = "use `default_tickrate_millis()`, as tickrates are `u64` milliseconds"
+  )]
+  pub fn default_tickrate() -> u32 {
+    u32::try_from(Self::default_tickrate_millis()).unwrap_or(u32::MAX)
+  }
+
+  /// Returns the tickrate [`EventSync::default()`](EventSync::default) uses.
+  ///
+  /// This is [`DEFAULT_TICKRATE`](DEFAULT_TICKRATE) unless it was changed with
+  /// [`set_default_tickrate_millis()`](EventSync::set_default_tickrate_millis).
+  pub fn default_tickrate_millis() -> u64 {
+    CONFIGURED_DEFAULT_TICKRATE
+      .get()
+      .copied()
+      .unwrap_or(DEFAULT_TICKRATE)
   }
 }
 
+/// Creates an EventSync with the [`default tickrate`](EventSync::default_tickrate_millis).
+#[cfg(feature = "std")]
 impl Default for EventSync {
   fn default() -> Self {
-    Self::new(10)
+    Self::new_millis(Self::default_tickrate_millis())
   }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
   use super::*;
 
   /// Tickrate in milliseconds.
-  const TEST_TICKRATE: u32 = 10;
+  const TEST_TICKRATE: u64 = 10;
 
   #[cfg(test)]
   mod wait_until_logic {
@@ -781,7 +2390,7 @@ mod tests {
 
     #[test]
     fn wait_until_valid_time() {
-      let event_sync = EventSync::new(TEST_TICKRATE);
+      let event_sync = EventSync::new_millis(TEST_TICKRATE);
 
       event_sync.wait_until(5).unwrap();
 
@@ -792,7 +2401,7 @@ mod tests {
 
     #[test]
     fn wait_until_passed_time() {
-      let event_sync = EventSync::new(TEST_TICKRATE);
+      let event_sync = EventSync::new_millis(TEST_TICKRATE);
 
       let expected_result = Err(TimeError::ThatTimeHasAlreadyHappened);
 
@@ -802,11 +2411,83 @@ mod tests {
 
       assert_eq!(result, expected_result);
     }
+
+    #[test]
+    fn zero_tick_waits_return_without_waiting() {
+      let mut event_sync = EventSync::from_starting_tick_millis(TEST_TICKRATE, 4, true);
+
+      assert_eq!(event_sync.wait_until(4), Err(TimeError::EventSyncPaused));
+      assert_eq!(
+        event_sync.wait_for_x_ticks(0),
+        Err(TimeError::EventSyncPaused)
+      );
+
+      event_sync.unpause().unwrap();
+
+      assert_eq!(event_sync.wait_until(4), Ok(WaitOutcome::NoWait));
+      assert_eq!(event_sync.wait_for_x_ticks(0), Ok(WaitOutcome::NoWait));
+      assert_eq!(
+        event_sync.wait_until(3),
+        Err(TimeError::ThatTimeHasAlreadyHappened)
+      );
+      assert_eq!(event_sync.stats().wakes(), 0);
+      assert_eq!(event_sync.wait_until(5), Ok(WaitOutcome::Waited));
+    }
+
+    #[test]
+    fn sleep_remaining_skips_ticks_that_just_started() {
+      let mut event_sync = EventSync::from_starting_time_millis(
+        TEST_TICKRATE,
+        Duration::from_millis(TEST_TICKRATE * 2),
+        false,
+      );
+
+      assert_eq!(
+        event_sync.sleep_remaining(Duration::from_millis(TEST_TICKRATE / 2)),
+        Ok(Duration::ZERO)
+      );
+
+      event_sync.pause();
+
+      assert_eq!(
+        event_sync.sleep_remaining(Duration::ZERO),
+        Err(TimeError::EventSyncPaused)
+      );
+    }
+
+    #[test]
+    fn wait_for_at_least_ends_on_a_tick() {
+      let event_sync =
+        EventSync::from_starting_time_millis(TEST_TICKRATE, Duration::from_millis(12), false);
+
+      let ticks_waited = event_sync
+        .wait_for_at_least(Duration::from_millis(TEST_TICKRATE))
+        .unwrap();
+
+      assert_eq!(ticks_waited, 2);
+      assert!(event_sync.ticks_since_started() >= 3);
+    }
+
+    #[test]
+    fn wait_until_elapsed_ignores_ticks() {
+      let event_sync =
+        EventSync::from_starting_time_millis(TEST_TICKRATE, Duration::from_millis(12), false);
+
+      event_sync
+        .wait_until_elapsed(Duration::from_millis(15))
+        .unwrap();
+
+      assert!(event_sync.time_since_started() >= Duration::from_millis(15));
+      assert_eq!(
+        event_sync.wait_until_elapsed(Duration::from_millis(5)),
+        Err(TimeError::ThatTimeHasAlreadyHappened)
+      );
+    }
   }
 
   #[test]
   fn time_since_started_logic() {
-    let event_sync = EventSync::new(TEST_TICKRATE);
+    let event_sync = EventSync::new_millis(TEST_TICKRATE);
 
     event_sync.wait_until(2).unwrap();
 
@@ -817,7 +2498,7 @@ mod tests {
 
   #[test]
   fn ticks_since_started_logic() {
-    let event_sync = EventSync::new(TEST_TICKRATE);
+    let event_sync = EventSync::new_millis(TEST_TICKRATE);
 
     event_sync.wait_until(2).unwrap();
 
@@ -828,7 +2509,7 @@ mod tests {
 
   #[test]
   fn wait_for_tick_logic() {
-    let event_sync = EventSync::new(TEST_TICKRATE);
+    let event_sync = EventSync::new_millis(TEST_TICKRATE);
 
     event_sync.wait_for_tick().unwrap();
 
@@ -840,7 +2521,7 @@ mod tests {
   #[test]
   fn time_since_last_tick_logic() {
     let tickrate = 1;
-    let event_sync = EventSync::new(tickrate);
+    let event_sync = EventSync::new_millis(tickrate);
 
     event_sync.wait_for_tick().unwrap();
 
@@ -852,7 +2533,7 @@ mod tests {
 
   #[test]
   fn time_since_last_tick_accuracy() {
-    let event_sync = EventSync::new(TEST_TICKRATE);
+    let event_sync = EventSync::new_millis(TEST_TICKRATE);
     let extra_wait_time = 2;
 
     event_sync.wait_for_tick().unwrap();
@@ -866,12 +2547,12 @@ mod tests {
 
   #[test]
   fn time_until_next_tick_logic() {
-    let event_sync = EventSync::new(TEST_TICKRATE);
+    let event_sync = EventSync::new_millis(TEST_TICKRATE);
     let extra_wait_time = 2;
 
     event_sync.wait_for_tick().unwrap();
 
-    std::thread::sleep(Duration::from_millis(extra_wait_time as u64));
+    std::thread::sleep(Duration::from_millis(extra_wait_time));
 
     // Round to account for time passed since this was called.
     // Converting directly to milliseconds will round down, which will always be 7.
@@ -882,57 +2563,75 @@ mod tests {
     println!("{:?}", time_until_next_tick);
     assert_eq!(
       time_until_next_tick as u64,
-      (TEST_TICKRATE - extra_wait_time) as u64
+      (TEST_TICKRATE - extra_wait_time)
     );
   }
 
   mod from_start_logic {
     use super::*;
 
-    const STARTING_TICKS: u32 = 10;
+    const STARTING_TICKS: u64 = 10;
 
     #[test]
     fn from_ticks() {
-      let event_sync = EventSync::from_starting_tick(TEST_TICKRATE, STARTING_TICKS, false);
+      let event_sync = EventSync::from_starting_tick_millis(TEST_TICKRATE, STARTING_TICKS, false);
 
-      assert_eq!(event_sync.ticks_since_started(), STARTING_TICKS as u64);
+      assert_eq!(event_sync.ticks_since_started(), STARTING_TICKS);
     }
 
     #[test]
     fn from_ticks_paused() {
-      let mut event_sync = EventSync::from_starting_tick(TEST_TICKRATE, STARTING_TICKS, true);
+      let mut event_sync =
+        EventSync::from_starting_tick_millis(TEST_TICKRATE, STARTING_TICKS, true);
 
       assert!(event_sync.is_paused());
 
       event_sync.unpause().unwrap();
 
-      assert_eq!(event_sync.ticks_since_started(), STARTING_TICKS as u64);
+      assert_eq!(event_sync.ticks_since_started(), STARTING_TICKS);
     }
 
     #[test]
     fn from_time() {
-      let starting_time = Duration::from_millis((STARTING_TICKS * TEST_TICKRATE).into());
-      let event_sync = EventSync::from_starting_time(TEST_TICKRATE, starting_time, false);
+      let starting_time = Duration::from_millis(STARTING_TICKS * TEST_TICKRATE);
+      let event_sync = EventSync::from_starting_time_millis(TEST_TICKRATE, starting_time, false);
 
-      assert_eq!(event_sync.ticks_since_started(), STARTING_TICKS as u64);
+      assert_eq!(event_sync.ticks_since_started(), STARTING_TICKS);
     }
 
     #[test]
     fn from_time_paused() {
-      let starting_time = Duration::from_millis((STARTING_TICKS * TEST_TICKRATE).into());
-      let mut event_sync = EventSync::from_starting_time(TEST_TICKRATE, starting_time, true);
+      let starting_time = Duration::from_millis(STARTING_TICKS * TEST_TICKRATE);
+      let mut event_sync = EventSync::from_starting_time_millis(TEST_TICKRATE, starting_time, true);
 
       assert!(event_sync.is_paused());
 
       event_sync.unpause().unwrap();
 
-      assert_eq!(event_sync.ticks_since_started(), STARTING_TICKS as u64);
+      assert_eq!(event_sync.ticks_since_started(), STARTING_TICKS);
+    }
+
+    #[test]
+    fn from_ticks_with_long_tickrates() {
+      let event_sync = EventSync::from_starting_tick_millis(u32::MAX.into(), STARTING_TICKS, true);
+
+      assert_eq!(event_sync.ticks_since_started(), STARTING_TICKS);
     }
   }
 
+  #[test]
+  fn skip_if_behind_only_drops_past_the_lag() {
+    let event_sync = EventSync::from_starting_tick_millis(TEST_TICKRATE, 10, true);
+
+    assert_eq!(event_sync.skip_if_behind(0, 4), Some(6));
+    assert_eq!(event_sync.skip_if_behind(6, 4), None);
+    assert_eq!(event_sync.skip_if_behind(12, 0), None);
+    assert_eq!(event_sync.skip_if_behind(9, 0), Some(1));
+  }
+
   #[test]
   fn methods_error_when_paused() {
-    let mut event_sync = EventSync::new(TEST_TICKRATE);
+    let mut event_sync = EventSync::new_millis(TEST_TICKRATE);
     event_sync.wait_for_x_ticks(3).unwrap();
     event_sync.pause();
 
@@ -950,8 +2649,8 @@ mod tests {
 
     #[test]
     fn time_is_retained_when_pausing_and_unpausing() {
-      let mut event_sync = EventSync::new(TEST_TICKRATE);
-      let other_event_sync = EventSync::new(TEST_TICKRATE);
+      let mut event_sync = EventSync::new_millis(TEST_TICKRATE);
+      let other_event_sync = EventSync::new_millis(TEST_TICKRATE);
 
       event_sync.wait_for_x_ticks(3).unwrap();
       event_sync.pause();
@@ -965,8 +2664,8 @@ mod tests {
 
     #[test]
     fn time_is_still_tracked_after_unpausing() {
-      let mut event_sync = EventSync::new(TEST_TICKRATE);
-      let other_event_sync = EventSync::new(TEST_TICKRATE);
+      let mut event_sync = EventSync::new_millis(TEST_TICKRATE);
+      let other_event_sync = EventSync::new_millis(TEST_TICKRATE);
 
       event_sync.wait_for_x_ticks(3).unwrap();
       event_sync.pause();
@@ -981,7 +2680,7 @@ mod tests {
 
     #[test]
     fn restart_unpauses_eventsync() {
-      let mut event_sync = EventSync::new(TEST_TICKRATE);
+      let mut event_sync = EventSync::new_millis(TEST_TICKRATE);
       event_sync.wait_for_tick().unwrap();
       event_sync.pause();
 
@@ -993,9 +2692,76 @@ mod tests {
       assert!(!event_sync.is_paused());
     }
 
+    #[test]
+    fn combined_changes_apply_together() {
+      let mut event_sync = EventSync::from_starting_tick_millis(TEST_TICKRATE, 6, true);
+
+      event_sync.restart_with_tickrate_millis(TEST_TICKRATE * 2);
+
+      assert!(!event_sync.is_paused());
+      assert_eq!(event_sync.get_tickrate_millis(), TEST_TICKRATE * 2);
+      assert_eq!(event_sync.ticks_since_started(), 0);
+
+      event_sync.sync_to(Duration::from_millis(120)).unwrap();
+
+      assert_eq!(
+        event_sync.pause_and_set_rate_millis(TEST_TICKRATE * 4),
+        (TEST_TICKRATE * 2, 3)
+      );
+      assert!(event_sync.is_paused());
+    }
+
+    #[test]
+    fn pause_reasons_are_counted() {
+      let mut event_sync = EventSync::new_millis(TEST_TICKRATE);
+      let mut other_event_sync = event_sync.clone();
+
+      event_sync.pause_with_reason("menu");
+      other_event_sync.pause_with_reason("loading");
+      other_event_sync.pause_with_reason("menu");
+
+      assert_eq!(event_sync.unpause_with_reason("menu"), Ok(true));
+      assert_eq!(event_sync.unpause_with_reason("loading"), Ok(true));
+      assert_eq!(event_sync.pause_reasons(), ["menu"]);
+      assert!(event_sync.is_paused());
+
+      assert_eq!(other_event_sync.unpause_with_reason("menu"), Ok(true));
+      assert_eq!(other_event_sync.unpause_with_reason("menu"), Ok(false));
+      assert!(!event_sync.is_paused());
+    }
+
+    #[test]
+    fn pausing_without_a_reason_outlasts_the_reasons() {
+      let mut event_sync = EventSync::new_millis(TEST_TICKRATE);
+
+      event_sync.pause_with_reason("menu");
+      event_sync.pause();
+      event_sync.unpause_with_reason("menu").unwrap();
+
+      assert!(event_sync.is_paused());
+
+      event_sync.pause_with_reason("menu");
+      event_sync.unpause().unwrap();
+
+      assert!(!event_sync.is_paused());
+      assert!(event_sync.pause_reasons().is_empty());
+    }
+
+    #[test]
+    fn restarting_paused_drops_the_reasons() {
+      let mut event_sync = EventSync::new_millis(TEST_TICKRATE);
+
+      event_sync.pause_with_reason("menu");
+      event_sync.restart_paused();
+
+      assert!(event_sync.pause_reasons().is_empty());
+      assert_eq!(event_sync.unpause_with_reason("menu"), Ok(false));
+      assert!(event_sync.is_paused());
+    }
+
     #[test]
     fn pausing_pauses_cloned() {
-      let event_sync = EventSync::new(TEST_TICKRATE);
+      let event_sync = EventSync::new_millis(TEST_TICKRATE);
       let mut other_event_sync = event_sync.clone();
       other_event_sync.pause();
 
@@ -1013,7 +2779,7 @@ mod tests {
 
     #[test]
     fn serialize_pauses() {
-      let event_sync = EventSync::new(TEST_TICKRATE);
+      let event_sync = EventSync::new_millis(TEST_TICKRATE);
       let other_event_sync = event_sync.clone();
 
       event_sync.wait_for_tick().unwrap();
@@ -1034,7 +2800,7 @@ mod tests {
 
     #[test]
     fn serialize_doesnt_overwrite_existing_pause_value() {
-      let mut event_sync = EventSync::new(TEST_TICKRATE);
+      let mut event_sync = EventSync::new_millis(TEST_TICKRATE);
 
       event_sync.wait_for_tick().unwrap();
       event_sync.pause();
@@ -1050,28 +2816,338 @@ mod tests {
 
       assert_eq!(deserialized_event_sync.ticks_since_started(), 1);
     }
+
+    #[test]
+    fn immutable_can_not_be_deserialized_as_mutable() {
+      let event_sync = EventSync::new_paused_millis(TEST_TICKRATE);
+      let serialized_event_sync = serde_json::to_string(&event_sync.clone_immutable()).unwrap();
+
+      assert!(serde_json::from_str::<EventSync<Mutable>>(&serialized_event_sync).is_err());
+      assert_eq!(
+        serde_json::from_str::<EventSync<Immutable>>(&serialized_event_sync).unwrap(),
+        event_sync.clone_immutable()
+      );
+
+      // Giving up access is allowed.
+      let serialized_event_sync = serde_json::to_string(&event_sync).unwrap();
+
+      assert!(serde_json::from_str::<EventSync<Immutable>>(&serialized_event_sync).is_ok());
+    }
+
+    #[test]
+    fn event_syncs_without_access_are_mutable() {
+      let serialized_event_sync = r#"{"inner":{"state":{"Paused":{"secs":0,"nanos":30000000}},"tickrate":10},"change_access":null}"#;
+
+      let deserialized_event_sync =
+        serde_json::from_str::<EventSync<Mutable>>(serialized_event_sync).unwrap();
+
+      assert_eq!(deserialized_event_sync.ticks_since_started(), 3);
+    }
+
+    #[test]
+    fn invalid_states_are_rejected() {
+      let with_inner = |inner: &str| format!(r#"{{"inner":{inner},"change_access":"Mutable"}}"#);
+      let paused = r#"{"Paused":{"secs":0,"nanos":0}}"#;
+
+      let zero_tickrate = with_inner(&format!(r#"{{"state":{paused},"tickrate":0}}"#));
+      let newer_version = with_inner(&format!(
+        r#"{{"state":{paused},"tickrate":10,"version":{}}}"#,
+        u32::MAX
+      ));
+      let too_much_time = with_inner(&format!(
+        r#"{{"state":{{"Paused":{{"secs":{},"nanos":0}}}},"tickrate":10}}"#,
+        u64::MAX
+      ));
+
+      for (serialized_event_sync, reason) in [
+        (zero_tickrate, "the tickrate is 0"),
+        (newer_version, "newer version"),
+        (too_much_time, "100 years"),
+      ] {
+        let error = serde_json::from_str::<EventSync>(&serialized_event_sync)
+          .unwrap_err()
+          .to_string();
+
+        assert!(error.contains(reason), "{error}");
+      }
+    }
+
+    #[test]
+    fn serialized_event_syncs_store_their_version() {
+      let event_sync = EventSync::new_paused_millis(TEST_TICKRATE);
+
+      let serialized_event_sync = serde_json::to_string(&event_sync).unwrap();
+
+      assert!(serialized_event_sync.contains(r#""version":1"#));
+    }
+  }
+
+  #[test]
+  fn sync_to_keeps_pause_state() {
+    let mut event_sync = EventSync::new_paused_millis(TEST_TICKRATE);
+
+    event_sync.sync_to(Duration::from_millis(35)).unwrap();
+
+    assert!(event_sync.is_paused());
+    assert_eq!(event_sync.ticks_since_started(), 3);
+  }
+
+  #[cfg(test)]
+  mod slew_logic {
+    use super::*;
+
+    #[test]
+    fn slew_forward_is_gradual() {
+      let mut event_sync = EventSync::new_millis(TEST_TICKRATE);
+
+      event_sync
+        .slew_to(Duration::from_millis(TEST_TICKRATE * 4), 4)
+        .unwrap();
+
+      assert_eq!(event_sync.ticks_since_started(), 0);
+
+      std::thread::sleep(Duration::from_millis(TEST_TICKRATE * 2));
+
+      // Halfway through, half of the 4 tick correction has been applied.
+      assert_eq!(event_sync.ticks_since_started(), 4);
+
+      std::thread::sleep(Duration::from_millis(TEST_TICKRATE * 3));
+
+      assert_eq!(event_sync.ticks_since_started(), 9);
+    }
+
+    #[test]
+    fn slew_backward_never_runs_backwards() {
+      let mut event_sync = EventSync::from_starting_tick_millis(TEST_TICKRATE, 10, false);
+      let before = event_sync.time_since_started();
+
+      event_sync.slew_to(Duration::ZERO, 2).unwrap();
+
+      let mut last = event_sync.time_since_started();
+      assert!(last >= before);
+
+      for _ in 0..10 {
+        std::thread::sleep(Duration::from_millis(2));
+
+        let current = event_sync.time_since_started();
+        assert!(current >= last);
+        last = current;
+      }
+    }
+
+    #[test]
+    fn pausing_stops_the_slew_where_it_is() {
+      let mut event_sync = EventSync::from_starting_tick_millis(TEST_TICKRATE, 10, false);
+
+      event_sync.slew_to(Duration::ZERO, 100).unwrap();
+      let before = event_sync.time_since_started();
+      event_sync.pause();
+
+      assert!(event_sync.time_since_started() >= before);
+      assert_eq!(event_sync.ticks_since_started(), 10);
+    }
   }
 
   #[test]
   fn get_tickrate_logic() {
-    let event_sync = EventSync::new(TEST_TICKRATE);
+    let event_sync = EventSync::new_millis(TEST_TICKRATE);
 
-    assert_eq!(event_sync.get_tickrate(), TEST_TICKRATE);
+    assert_eq!(event_sync.get_tickrate_millis(), TEST_TICKRATE);
   }
 
   #[test]
   fn change_tickrate_logic() {
-    let mut event_sync = EventSync::new(TEST_TICKRATE);
+    let mut event_sync = EventSync::new_millis(TEST_TICKRATE);
 
     event_sync.wait_for_x_ticks(2).unwrap();
 
     // Does not compile.
-    event_sync.change_tickrate(TEST_TICKRATE * 2);
+    let (old_tickrate, current_tick) = event_sync.change_tickrate_millis(TEST_TICKRATE * 2);
 
-    assert_eq!(event_sync.get_tickrate(), TEST_TICKRATE * 2);
+    assert_eq!(old_tickrate, TEST_TICKRATE);
+    assert_eq!(current_tick, 1);
+    assert_eq!(event_sync.get_tickrate_millis(), TEST_TICKRATE * 2);
     assert_eq!(event_sync.ticks_since_started(), 1);
   }
 
+  #[test]
+  fn change_tickrate_if_logic() {
+    let mut event_sync = EventSync::from_starting_tick_millis(TEST_TICKRATE, 4, true);
+
+    assert_eq!(
+      event_sync.change_tickrate_millis_if(TEST_TICKRATE * 2, TEST_TICKRATE * 4),
+      Err(TEST_TICKRATE)
+    );
+    assert_eq!(event_sync.get_tickrate_millis(), TEST_TICKRATE);
+    assert_eq!(
+      event_sync.change_tickrate_millis_if(TEST_TICKRATE, TEST_TICKRATE * 2),
+      Ok(2)
+    );
+    assert_eq!(event_sync.get_tickrate_millis(), TEST_TICKRATE * 2);
+  }
+
+  #[test]
+  fn hour_long_tickrates() {
+    let hour = 60 * 60 * 1000;
+    let mut event_sync =
+      EventSync::from_starting_time_millis(TEST_TICKRATE, Duration::from_secs(3 * 60 * 60), true);
+
+    event_sync.change_tickrate_millis(hour * 24 * 60);
+
+    assert_eq!(event_sync.get_tickrate_millis(), hour * 24 * 60);
+    assert_eq!(event_sync.ticks_since_started(), 0);
+
+    event_sync.change_tickrate_millis(hour);
+
+    assert_eq!(event_sync.get_tickrate_millis(), hour);
+    assert_eq!(event_sync.ticks_since_started(), 3);
+
+    event_sync.change_tickrate_millis(u64::MAX);
+
+    assert_eq!(event_sync.get_tickrate_millis(), inner::MAX_TICKRATE);
+    assert_eq!(event_sync.ticks_since_started(), 0);
+  }
+
+  #[cfg(test)]
+  mod system_time_logic {
+    use super::*;
+
+    #[test]
+    fn system_time_of_tick_matches_wait() {
+      let event_sync = EventSync::new_millis(TEST_TICKRATE);
+
+      let time_of_tick = event_sync.system_time_of_tick(3).unwrap();
+
+      event_sync.wait_until(3).unwrap();
+
+      let difference = match SystemTime::now().duration_since(time_of_tick) {
+        Ok(difference) => difference,
+        Err(error) => error.duration(),
+      };
+
+      // Leaves room for the thread waking late.
+      assert!(difference < Duration::from_millis(TEST_TICKRATE));
+    }
+
+    #[test]
+    fn system_time_of_past_tick() {
+      let event_sync = EventSync::from_starting_tick_millis(TEST_TICKRATE, 5, false);
+
+      let time_of_tick = event_sync.system_time_of_tick(0).unwrap();
+      let time_since_tick = SystemTime::now().duration_since(time_of_tick).unwrap();
+      let expected = Duration::from_millis(TEST_TICKRATE * 5);
+
+      // The clocks are read at slightly different times, so the projection can be off by a little.
+      assert!(time_since_tick.abs_diff(expected) < Duration::from_millis(TEST_TICKRATE));
+    }
+
+    #[test]
+    fn system_time_is_none_when_paused() {
+      let event_sync = EventSync::new_paused_millis(TEST_TICKRATE);
+
+      assert!(event_sync.system_time_of_tick(1).is_none());
+      assert!(event_sync.next_tick_system_time().is_none());
+      assert!(event_sync.instant_of_tick(1).is_none());
+      assert!(event_sync
+        .ticks_elapsed_between(Instant::now(), Instant::now())
+        .is_none());
+    }
+
+    #[test]
+    fn instants_before_the_start_count_from_the_start() {
+      let event_sync = EventSync::from_starting_tick_millis(TEST_TICKRATE, 4, false);
+      let started_at = event_sync.instant_of_tick(0).unwrap();
+      let long_before = started_at - Duration::from_secs(1);
+
+      assert_eq!(event_sync.tick_at(long_before), None);
+      assert_eq!(
+        event_sync.ticks_elapsed_between(long_before, started_at + Duration::from_millis(25)),
+        Some(2)
+      );
+    }
+  }
+
+  #[cfg(test)]
+  mod clock_source_logic {
+    use super::*;
+
+    #[test]
+    fn wall_clock_tracks_ticks() {
+      let event_sync = EventSync::new_with_clock_millis(TEST_TICKRATE, ClockSource::WallClock);
+
+      event_sync.wait_until(3).unwrap();
+
+      assert_eq!(event_sync.ticks_since_started(), 3);
+    }
+
+    #[test]
+    fn wall_clock_pausing_retains_time() {
+      let mut event_sync = EventSync::new_with_clock_millis(TEST_TICKRATE, ClockSource::WallClock);
+
+      event_sync.wait_for_x_ticks(2).unwrap();
+      event_sync.pause();
+      std::thread::sleep(Duration::from_millis(TEST_TICKRATE * 2));
+      event_sync.unpause().unwrap();
+
+      assert_eq!(event_sync.ticks_since_started(), 2);
+      assert_eq!(event_sync.clock_source(), ClockSource::WallClock);
+    }
+
+    #[test]
+    fn system_epoch_aligns_independent_event_syncs() {
+      let anchor = SystemTime::now() - Duration::from_millis(35);
+
+      let event_sync = EventSync::from_system_epoch_millis(TEST_TICKRATE, anchor).unwrap();
+      let other_event_sync = EventSync::from_system_epoch_millis(TEST_TICKRATE, anchor).unwrap();
+
+      assert_eq!(event_sync.ticks_since_started(), 3);
+
+      event_sync.wait_for_tick().unwrap();
+
+      assert_eq!(
+        event_sync.ticks_since_started(),
+        other_event_sync.ticks_since_started()
+      );
+      assert_eq!(event_sync.clock_source(), ClockSource::WallClock);
+    }
+
+    #[test]
+    fn system_epoch_in_future_errors() {
+      let anchor = SystemTime::now() + Duration::from_secs(10);
+
+      assert_eq!(
+        EventSync::from_system_epoch_millis(TEST_TICKRATE, anchor),
+        Err(TimeError::StartTimeInFuture)
+      );
+    }
+
+    #[test]
+    fn clock_source_survives_serialization() {
+      let event_sync = EventSync::new_with_clock_millis(TEST_TICKRATE, ClockSource::WallClock);
+
+      let serialized_event_sync = serde_json::to_string(&event_sync).unwrap();
+      let deserialized_event_sync =
+        serde_json::from_str::<EventSync>(&serialized_event_sync).unwrap();
+
+      assert_eq!(
+        deserialized_event_sync.clock_source(),
+        ClockSource::WallClock
+      );
+    }
+
+    #[test]
+    #[cfg(all(feature = "boottime", target_os = "linux"))]
+    fn boottime_tracks_ticks() {
+      let mut event_sync = EventSync::new_with_clock_millis(TEST_TICKRATE, ClockSource::Boottime);
+
+      event_sync.wait_until(3).unwrap();
+      event_sync.pause();
+
+      assert_eq!(event_sync.ticks_since_started(), 3);
+      assert_eq!(event_sync.clock_source(), ClockSource::Boottime);
+    }
+  }
+
   #[test]
   fn anyhow_compatibility() {
     fn return_anyhow_error() -> anyhow::Result<()> {
@@ -1085,9 +3161,9 @@ mod tests {
 
   #[test]
   fn mutable_partial_eq_logic() {
-    let event_sync = EventSync::new(1);
+    let event_sync = EventSync::new_millis(1);
     let copied_event_sync = event_sync.clone();
-    let separate_event_sync = EventSync::new(1);
+    let separate_event_sync = EventSync::new_millis(1);
 
     assert_eq!(event_sync, copied_event_sync);
     assert_ne!(event_sync, separate_event_sync);
@@ -1096,10 +3172,10 @@ mod tests {
 
   #[test]
   fn immutable_partial_eq_logic() {
-    let event_sync = EventSync::new(1);
+    let event_sync = EventSync::new_millis(1);
     let copied_event_sync_1 = event_sync.clone_immutable();
     let copied_event_sync_2 = event_sync.clone_immutable();
-    let separate_event_sync = EventSync::new(1);
+    let separate_event_sync = EventSync::new_millis(1);
     let separate_copied_event_sync = separate_event_sync.clone_immutable();
 
     assert_ne!(event_sync, separate_event_sync);
@@ -1107,17 +3183,29 @@ mod tests {
     assert_eq!(copied_event_sync_1, copied_event_sync_2);
   }
 
+  #[test]
+  fn invalid_default_tickrates_are_not_set() {
+    assert!(matches!(
+      EventSync::set_default_tickrate_millis(0),
+      Err(TimeError::InvalidState(_))
+    ));
+    assert_eq!(EventSync::default().get_tickrate_millis(), DEFAULT_TICKRATE);
+  }
+
   #[test]
   fn debug_and_display_logic() {
-    let event_sync = EventSync::new(1);
+    let event_sync = EventSync::new_millis(1);
     let copied_event_sync = event_sync.clone_immutable();
 
-    let _mutable_debug = format!("{:?}", event_sync);
+    let mutable_debug = format!("{:?}", event_sync);
     let _mutable_display = format!("{}", event_sync);
 
-    let _immutable_debug = format!("{:?}", copied_event_sync);
+    let immutable_debug = format!("{:?}", copied_event_sync);
     let _immutable_display = format!("{}", copied_event_sync);
 
+    assert!(immutable_debug.starts_with("EventSync { access: Immutable, tick: "));
+    assert!(mutable_debug.contains("tickrate: 1, is_paused: false, generation: 0"));
+
     // Can't compare as microseconds of time would pass between each format call.
     // This is mostly to test if both mutable and immutable can event format into Debug and Display
     // in the first place.
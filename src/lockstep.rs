@@ -0,0 +1,398 @@
+use crate::errors::TimeError;
+use crate::{EventSync, Immutable};
+use std::collections::BTreeMap;
+use std::sync::{Condvar, Mutex, MutexGuard};
+use std::time::Duration;
+
+/// The tick bookkeeping for a lock-step session, where every peer's input for a tick has to arrive before that tick
+/// is confirmed.
+///
+/// Inputs are buffered per peer and keyed by the tick they're for, so they can arrive out of order or ahead of
+/// time. A tick is confirmed once every peer's input for it, and every tick before it, has arrived. Confirmed ticks
+/// are then taken in order with [`next_confirmed()`](LockstepSession::next_confirmed).
+///
+/// Ticks the EventSync has reached past the last confirmed one are the ones a rollback game has run on predicted
+/// input, counted by [`predicted_ticks()`](LockstepSession::predicted_ticks).
+///
+/// # Example
+///
+/// ```
+/// use event_sync::*;
+///
+/// let tickrate = 10; // 10ms between every tick.
+/// let event_sync = EventSync::new_paused_millis(tickrate);
+///
+/// // Runs from tick 0 to tick 100.
+/// let session = LockstepSession::new(event_sync.clone_immutable(), ["host", "guest"], 0).with_end_tick(100);
+///
+/// session.add_input(&"host", 0, 'w').unwrap();
+/// session.add_input(&"host", 1, 'a').unwrap();
+///
+/// // The guest hasn't sent anything yet.
+/// assert_eq!(session.confirmed_tick(), None);
+/// assert_eq!(session.missing_peers(0), ["guest"]);
+///
+/// session.add_input(&"guest", 0, 's').unwrap();
+///
+/// assert_eq!(session.confirmed_tick(), Some(0));
+/// assert_eq!(session.next_confirmed(), Some((0, vec![("host", 'w'), ("guest", 's')])));
+/// assert_eq!(session.next_confirmed(), None);
+/// ```
+#[derive(Debug)]
+pub struct LockstepSession<P, I> {
+  event_sync: EventSync<Immutable>,
+  start_tick: u64,
+  end_tick: Option<u64>,
+  state: Mutex<SessionState<P, I>>,
+  /// Notified whenever the confirmed tick moves forward.
+  confirmed: Condvar,
+}
+
+#[derive(Debug)]
+struct SessionState<P, I> {
+  peers: Vec<Peer<P, I>>,
+  /// The first tick whose inputs haven't been taken.
+  next_tick: u64,
+}
+
+#[derive(Debug)]
+struct Peer<P, I> {
+  id: P,
+  inputs: BTreeMap<u64, I>,
+  /// The first tick the peer hasn't sent an input for.
+  next_missing: u64,
+}
+
+impl<P, I> SessionState<P, I> {
+  /// Returns the first tick that isn't confirmed.
+  fn first_unconfirmed(&self) -> u64 {
+    self
+      .peers
+      .iter()
+      .map(|peer| peer.next_missing)
+      .min()
+      .unwrap_or(self.next_tick)
+  }
+}
+
+impl<P: Eq + Clone, I> LockstepSession<P, I> {
+  /// Creates a session between the given peers, with inputs starting from the given tick.
+  ///
+  /// Every peer has to start from the same tick, so it's best agreed on before the session is created.
+  /// Peers given more than once are only added once.
+  pub fn new(
+    event_sync: EventSync<Immutable>,
+    peers: impl IntoIterator<Item = P>,
+    start_tick: u64,
+  ) -> Self {
+    let mut peer_list: Vec<Peer<P, I>> = Vec::new();
+
+    for id in peers {
+      if !peer_list.iter().any(|peer| peer.id == id) {
+        peer_list.push(Peer {
+          id,
+          inputs: BTreeMap::new(),
+          next_missing: start_tick,
+        });
+      }
+    }
+
+    Self {
+      event_sync,
+      start_tick,
+      end_tick: None,
+      state: Mutex::new(SessionState {
+        peers: peer_list,
+        next_tick: start_tick,
+      }),
+      confirmed: Condvar::new(),
+    }
+  }
+
+  /// Ends the session on the given tick, after which no inputs are taken.
+  ///
+  /// The end tick cannot be before the start tick. If it is, the start tick is used.
+  pub fn with_end_tick(mut self, end_tick: u64) -> Self {
+    self.end_tick = Some(end_tick.max(self.start_tick));
+
+    self
+  }
+
+  /// Returns the first tick of the session.
+  pub fn start_tick(&self) -> u64 {
+    self.start_tick
+  }
+
+  /// Returns the last tick of the session, if it ends.
+  pub fn end_tick(&self) -> Option<u64> {
+    self.end_tick
+  }
+
+  /// Returns the tick the EventSync is on, held at the end tick once the session has ended.
+  pub fn current_tick(&self) -> u64 {
+    let current_tick = self.event_sync.ticks_since_started();
+
+    self
+      .end_tick
+      .map_or(current_tick, |end_tick| current_tick.min(end_tick))
+  }
+
+  /// Buffers the peer's input for the tick.
+  ///
+  /// Inputs can be added in any order, and for ticks that haven't occurred yet.
+  ///
+  /// # Errors
+  ///
+  /// - [`InputRejected`](TimeError::InputRejected) is returned if the peer isn't in the session.
+  /// - [`InputRejected`](TimeError::InputRejected) is returned if the tick is outside of the session.
+  /// - [`InputRejected`](TimeError::InputRejected) is returned if the peer already sent an input for the tick, in
+  ///   which case the first input is kept.
+  pub fn add_input(&self, peer: &P, tick: u64, input: I) -> Result<(), TimeError> {
+    if tick < self.start_tick {
+      return Err(TimeError::InputRejected(
+        "the tick is before the session started",
+      ));
+    }
+
+    if self.end_tick.is_some_and(|end_tick| tick > end_tick) {
+      return Err(TimeError::InputRejected(
+        "the tick is after the session ends",
+      ));
+    }
+
+    let mut state = self.lock_state();
+    let first_unconfirmed = state.first_unconfirmed();
+    let Some(peer) = state.peers.iter_mut().find(|entry| entry.id == *peer) else {
+      return Err(TimeError::InputRejected("the peer isn't in the session"));
+    };
+
+    if tick < peer.next_missing || peer.inputs.contains_key(&tick) {
+      return Err(TimeError::InputRejected(
+        "the peer already sent an input for the tick",
+      ));
+    }
+
+    peer.inputs.insert(tick, input);
+
+    while peer.inputs.contains_key(&peer.next_missing) {
+      peer.next_missing += 1;
+    }
+
+    if state.first_unconfirmed() != first_unconfirmed {
+      self.confirmed.notify_all();
+    }
+
+    Ok(())
+  }
+
+  /// Returns the last tick every peer has sent their input for, along with every tick before it.
+  ///
+  /// None is returned until the start tick is confirmed.
+  pub fn confirmed_tick(&self) -> Option<u64> {
+    self.confirmed_tick_of(&self.lock_state())
+  }
+
+  /// Returns true if every peer has sent their input for the tick, along with every tick before it.
+  pub fn is_confirmed(&self, tick: u64) -> bool {
+    self
+      .confirmed_tick()
+      .is_some_and(|confirmed| tick <= confirmed)
+  }
+
+  /// Returns true once every input through the end tick has been confirmed.
+  ///
+  /// Sessions without an end tick never finish.
+  pub fn is_finished(&self) -> bool {
+    self
+      .end_tick
+      .is_some_and(|end_tick| self.is_confirmed(end_tick))
+  }
+
+  /// Returns the peers that haven't sent their input for the tick, in the order they were added.
+  ///
+  /// Ticks outside of the session, or whose inputs were already taken, have none missing.
+  pub fn missing_peers(&self, tick: u64) -> Vec<P> {
+    let state = self.lock_state();
+
+    if tick < state.next_tick || self.end_tick.is_some_and(|end_tick| tick > end_tick) {
+      return Vec::new();
+    }
+
+    state
+      .peers
+      .iter()
+      .filter(|peer| tick >= peer.next_missing && !peer.inputs.contains_key(&tick))
+      .map(|peer| peer.id.clone())
+      .collect()
+  }
+
+  /// Returns the amount of ticks that have occurred on the EventSync past the last confirmed tick.
+  ///
+  /// These are the ticks a rollback game has run on predicted input, and re-runs once they're confirmed.
+  pub fn predicted_ticks(&self) -> u64 {
+    let first_unconfirmed = self.lock_state().first_unconfirmed();
+
+    (self.current_tick() + 1).saturating_sub(first_unconfirmed)
+  }
+
+  /// Takes every peer's input for the earliest confirmed tick that hasn't been taken, in the order the peers were
+  /// added.
+  ///
+  /// None is returned if the next tick isn't confirmed yet.
+  pub fn next_confirmed(&self) -> Option<(u64, Vec<(P, I)>)> {
+    let mut state = self.lock_state();
+    let tick = state.next_tick;
+
+    if tick >= state.first_unconfirmed() {
+      return None;
+    }
+
+    let inputs = state
+      .peers
+      .iter_mut()
+      .filter_map(|peer| Some((peer.id.clone(), peer.inputs.remove(&tick)?)))
+      .collect();
+    state.next_tick += 1;
+
+    Some((tick, inputs))
+  }
+
+  /// Blocks until every peer has confirmed through the tick, returning false if that didn't happen within
+  /// `timeout_ticks` ticks of the EventSync.
+  ///
+  /// While the EventSync is paused, no ticks pass, so the wait only ends once the tick is confirmed.
+  /// False is returned right away for ticks after the end tick, which are never confirmed.
+  pub fn wait_for_confirmation(&self, tick: u64, timeout_ticks: u32) -> bool {
+    if self.end_tick.is_some_and(|end_tick| tick > end_tick) {
+      return false;
+    }
+
+    let deadline = self.event_sync.ticks_since_started() + timeout_ticks as u64;
+    let mut state = self.lock_state();
+
+    loop {
+      if self
+        .confirmed_tick_of(&state)
+        .is_some_and(|confirmed| tick <= confirmed)
+      {
+        return true;
+      }
+
+      if self.event_sync.ticks_since_started() >= deadline {
+        return false;
+      }
+
+      // Re-checked every tick, so the timeout is noticed without anything being confirmed.
+      let tickrate = Duration::from_millis(self.event_sync.get_tickrate_millis());
+
+      state = self
+        .confirmed
+        .wait_timeout(state, tickrate)
+        .map_or_else(|poisoned| poisoned.into_inner().0, |(state, _)| state);
+    }
+  }
+
+  fn confirmed_tick_of(&self, state: &SessionState<P, I>) -> Option<u64> {
+    state
+      .first_unconfirmed()
+      .checked_sub(1)
+      .filter(|confirmed| *confirmed >= self.start_tick)
+  }
+
+  fn lock_state(&self) -> MutexGuard<'_, SessionState<P, I>> {
+    self
+      .state
+      .lock()
+      .unwrap_or_else(|poisoned| poisoned.into_inner())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::Mutable;
+  use std::sync::Arc;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  fn move_to_tick(event_sync: &mut EventSync<Mutable>, tick: u64) {
+    event_sync
+      .sync_to(Duration::from_millis(tick * TEST_TICKRATE))
+      .unwrap();
+  }
+
+  #[test]
+  fn out_of_order_inputs_confirm_once_filled_in() {
+    let mut event_sync = EventSync::new_paused_millis(TEST_TICKRATE);
+    let session = LockstepSession::new(event_sync.clone_immutable(), [1, 2], 5).with_end_tick(7);
+
+    session.add_input(&1, 6, "b").unwrap();
+    session.add_input(&2, 5, "c").unwrap();
+    session.add_input(&2, 6, "d").unwrap();
+
+    move_to_tick(&mut event_sync, 6);
+
+    assert_eq!(session.confirmed_tick(), None);
+    assert_eq!(session.predicted_ticks(), 2);
+    assert_eq!(session.missing_peers(5), [1]);
+
+    session.add_input(&1, 5, "a").unwrap();
+
+    assert_eq!(session.confirmed_tick(), Some(6));
+    assert_eq!(session.predicted_ticks(), 0);
+    assert_eq!(
+      session.next_confirmed(),
+      Some((5, vec![(1, "a"), (2, "c")]))
+    );
+    assert_eq!(
+      session.next_confirmed(),
+      Some((6, vec![(1, "b"), (2, "d")]))
+    );
+    assert_eq!(session.next_confirmed(), None);
+    assert!(!session.is_finished());
+
+    move_to_tick(&mut event_sync, 20);
+
+    assert_eq!(session.current_tick(), 7);
+  }
+
+  #[test]
+  fn invalid_inputs_are_rejected() {
+    let event_sync = EventSync::new_paused_millis(TEST_TICKRATE);
+    let session = LockstepSession::new(event_sync.clone_immutable(), ['a'], 2).with_end_tick(4);
+
+    session.add_input(&'a', 2, ()).unwrap();
+
+    for (peer, tick) in [('b', 3), ('a', 1), ('a', 5), ('a', 2)] {
+      assert!(matches!(
+        session.add_input(&peer, tick, ()),
+        Err(TimeError::InputRejected(_))
+      ));
+    }
+
+    session.add_input(&'a', 4, ()).unwrap();
+    session.add_input(&'a', 3, ()).unwrap();
+
+    assert!(session.is_finished());
+  }
+
+  #[test]
+  fn barriers_wait_for_every_peer() {
+    let event_sync = EventSync::new_millis(TEST_TICKRATE);
+    let session = Arc::new(LockstepSession::new(
+      event_sync.clone_immutable(),
+      ["host", "guest"],
+      0,
+    ));
+
+    session.add_input(&"host", 0, ()).unwrap();
+
+    assert!(!session.wait_for_confirmation(0, 2));
+
+    let guest_session = session.clone();
+    let guest = std::thread::spawn(move || guest_session.add_input(&"guest", 0, ()));
+
+    assert!(session.wait_for_confirmation(0, 1_000));
+    assert!(guest.join().unwrap().is_ok());
+  }
+}
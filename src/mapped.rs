@@ -0,0 +1,539 @@
+//! A clock shared between processes through a memory-mapped file.
+//!
+//! Enabled with the `mmap` feature.
+
+use crate::clock::{ClockSource, SystemTime};
+use crate::errors::TimeError;
+use crate::inner::{clamp_tickrate, InnerEventSync};
+use crate::waiting::WaitTarget;
+use memmap2::MmapMut;
+use std::fs::OpenOptions;
+use std::io;
+use std::path::Path;
+use std::ptr::NonNull;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+/// Identifies a file as one created by [`MappedEventSync`](MappedEventSync).
+const MAGIC: u64 = u64::from_le_bytes(*b"EVSYNC01");
+
+/// The amount of u64 fields stored in the file.
+const FIELD_COUNT: usize = 5;
+
+/// The size of the file in bytes.
+const FILE_SIZE: usize = FIELD_COUNT * std::mem::size_of::<u64>();
+
+/// The position of each field in the file.
+const MAGIC_FIELD: usize = 0;
+const SEQUENCE_FIELD: usize = 1;
+const TICKRATE_FIELD: usize = 2;
+const PAUSED_FIELD: usize = 3;
+/// Stores the anchor as nanoseconds since the unix epoch when running, and the elapsed nanoseconds when paused.
+const TIME_FIELD: usize = 4;
+
+/// An EventSync whose state lives in a memory-mapped file, letting several processes on one machine share a single
+/// tick timeline.
+///
+/// Pausing, unpausing, restarting, or changing the tickrate in one process is seen by every process that has the
+/// same file open.
+///
+/// Time is measured against the wall clock, as [`Instant`](std::time::Instant) can't be shared between processes.
+///
+/// # Crashes
+///
+/// Writes to the file are guarded by a sequence lock. If a process exits in the middle of a write, the lock is never
+/// released, and every other process blocks on its next access to the file.
+/// Calling [`create_millis()`](MappedEventSync::create_millis) on the same path resets the file in place and releases
+/// the lock, leaving it mapped in every other process.
+///
+/// # Example
+///
+/// ```
+/// use event_sync::MappedEventSync;
+///
+/// let path = std::env::temp_dir().join("event_sync_mapped_doc_example");
+///
+/// let tickrate = 10; // 10ms between every tick.
+/// let mut event_sync = MappedEventSync::create_millis(&path, tickrate).unwrap();
+///
+/// // This could be opened from any other process.
+/// let other_event_sync = MappedEventSync::open(&path).unwrap();
+///
+/// event_sync.pause();
+///
+/// assert!(other_event_sync.is_paused());
+/// # std::fs::remove_file(path).unwrap();
+/// ```
+pub struct MappedEventSync {
+  /// Kept alive for as long as `fields` points into it.
+  _map: MmapMut,
+  fields: NonNull<AtomicU64>,
+}
+
+// Safety: `fields` points into the map owned by this struct, and is only accessed through atomics.
+unsafe impl Send for MappedEventSync {}
+unsafe impl Sync for MappedEventSync {}
+
+/// A consistent copy of the fields stored in the file.
+struct MappedState {
+  tickrate: u64,
+  is_paused: bool,
+  time: Duration,
+}
+
+impl MappedEventSync {
+  /// Creates the file at the given path, overwriting any existing one, and starts a running clock in it.
+  ///
+  /// A file left by an earlier MappedEventSync is reset in place, so processes that still have it open carry on
+  /// with the new clock.
+  ///
+  /// # Errors
+  ///
+  /// - If the file could not be created or mapped.
+  pub fn create_millis<P: AsRef<Path>>(path: P, tickrate_in_milliseconds: u64) -> io::Result<Self> {
+    let file = OpenOptions::new()
+      .read(true)
+      .write(true)
+      .create(true)
+      .truncate(false)
+      .open(path)?;
+
+    // A file of the right size could be mapped by other processes, and resizing it would pull the pages out from
+    // under them.
+    if file.metadata()?.len() != FILE_SIZE as u64 {
+      file.set_len(FILE_SIZE as u64)?;
+    }
+
+    // Safety: The file has the expected size. Other processes only access it through atomics.
+    let map = unsafe { MmapMut::map_mut(&file)? };
+    let mut mapped_event_sync = Self::from_map(map);
+
+    mapped_event_sync.reset(clamp_tickrate(tickrate_in_milliseconds));
+
+    Ok(mapped_event_sync)
+  }
+
+  /// [`create_millis()`](MappedEventSync::create_millis), taking the tickrate as a `u32`.
+  #[deprecated(
+    since = "0.4.5",
+    note = "use `create_millis()`, as tickrates are `u64` milliseconds"
+  )]
+  pub fn create<P: AsRef<Path>>(path: P, tickrate_in_milliseconds: u32) -> io::Result<Self> {
+    Self::create_millis(path, tickrate_in_milliseconds.into())
+  }
+
+  /// Opens a file previously made with [`create_millis()`](MappedEventSync::create_millis).
+  ///
+  /// # Errors
+  ///
+  /// - If the file could not be opened or mapped.
+  /// - If the file was not created by MappedEventSync.
+  pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+    let file = OpenOptions::new().read(true).write(true).open(path)?;
+
+    if file.metadata()?.len() != FILE_SIZE as u64 {
+      return Err(io::Error::new(
+        io::ErrorKind::InvalidData,
+        "The file is not a MappedEventSync.",
+      ));
+    }
+
+    // Safety: The file has the expected size. Other processes only access it through atomics.
+    let map = unsafe { MmapMut::map_mut(&file)? };
+    let mapped_event_sync = Self::from_map(map);
+
+    if mapped_event_sync.field(MAGIC_FIELD).load(Ordering::SeqCst) != MAGIC {
+      return Err(io::Error::new(
+        io::ErrorKind::InvalidData,
+        "The file is not a MappedEventSync.",
+      ));
+    }
+
+    Ok(mapped_event_sync)
+  }
+
+  /// Wraps a map of FILE_SIZE bytes.
+  fn from_map(mut map: MmapMut) -> Self {
+    debug_assert_eq!(map.len(), FILE_SIZE);
+
+    // The pointer is taken from the mutable map, as other processes write to it through the same fields.
+    let fields =
+      NonNull::new(map.as_mut_ptr() as *mut AtomicU64).expect("Mapped memory is never null.");
+
+    Self { _map: map, fields }
+  }
+
+  /// Returns true if the shared clock is paused.
+  pub fn is_paused(&self) -> bool {
+    self.read_state().is_paused
+  }
+
+  /// Returns the shared tickrate, or `u32::MAX` if it's too long to fit in a `u32`.
+  #[deprecated(
+    since = "0.4.5",
+    note = "use `get_tickrate_millis()`, as tickrates are `u64` milliseconds"
+  )]
+  pub fn get_tickrate(&self) -> u32 {
+    u32::try_from(self.get_tickrate_millis()).unwrap_or(u32::MAX)
+  }
+
+  /// Returns the shared tickrate.
+  pub fn get_tickrate_millis(&self) -> u64 {
+    self.read_state().tickrate
+  }
+
+  /// Returns the amount of time that has passed on the shared clock.
+  pub fn time_since_started(&self) -> Duration {
+    self.snapshot().time_since_started()
+  }
+
+  /// Returns the amount of ticks that have passed on the shared clock.
+  pub fn ticks_since_started(&self) -> u64 {
+    self.snapshot().ticks_since_started()
+  }
+
+  /// Waits until an absolute tick has occurred on the shared clock.
+  ///
+  /// The file is re-checked at least once every tick, so pausing or changing the tickrate from another process is
+  /// followed. While paused, the wait carries on until the shared clock is unpaused.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned when the given tick has already occurred.
+  /// - An error is returned if the shared clock is paused.
+  pub fn wait_until(&self, tick_to_wait_for: u64) -> Result<(), TimeError> {
+    self.snapshot().time_until_tick_occurs(tick_to_wait_for)?;
+    self.sleep_until_tick_in_chunks(tick_to_wait_for, WaitTarget::Absolute);
+
+    Ok(())
+  }
+
+  /// Waits until the next tick on the shared clock.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if the shared clock is paused.
+  pub fn wait_for_tick(&self) -> Result<(), TimeError> {
+    self.wait_for_x_ticks(1)
+  }
+
+  /// Waits for the passed in amount of ticks on the shared clock.
+  ///
+  /// The file is re-checked at least once every tick, so pausing, restarting, or changing the tickrate from another
+  /// process is followed, counting the ticks that were left from wherever the timeline is moved to.
+  /// While paused, the wait carries on until the shared clock is unpaused.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if the shared clock is paused.
+  pub fn wait_for_x_ticks(&self, ticks_to_wait: u32) -> Result<(), TimeError> {
+    let snapshot = self.snapshot();
+    snapshot.time_for_x_ticks(ticks_to_wait)?;
+
+    self.sleep_until_tick_in_chunks(
+      snapshot.ticks_since_started() + ticks_to_wait as u64,
+      WaitTarget::Relative,
+    );
+
+    Ok(())
+  }
+
+  /// Pauses the shared clock for every process. Does nothing if already paused.
+  pub fn pause(&mut self) {
+    self.write_state(|state| {
+      if !state.is_paused {
+        state.time = SystemTime::now()
+          .duration_since(SystemTime::UNIX_EPOCH + state.time)
+          .unwrap_or_default();
+        state.is_paused = true;
+      }
+    });
+  }
+
+  /// Unpauses the shared clock for every process, retaining the time that passed before pausing.
+  /// Does nothing if already running.
+  pub fn unpause(&mut self) {
+    self.write_state(|state| {
+      if state.is_paused {
+        let anchor = SystemTime::now() - state.time;
+
+        state.time = Duration::from_nanos(nanos_since_epoch(anchor));
+        state.is_paused = false;
+      }
+    });
+  }
+
+  /// Restarts the shared clock for every process, unpausing it if paused.
+  pub fn restart(&mut self) {
+    self.write_state(|state| {
+      state.time = Duration::from_nanos(nanos_since_epoch(SystemTime::now()));
+      state.is_paused = false;
+    });
+  }
+
+  /// Changes the tickrate for every process, retaining the time that has passed.
+  ///
+  /// The tickrate is kept between 1 and 100 years.
+  pub fn change_tickrate_millis(&mut self, new_tickrate: u64) {
+    self.write_state(|state| state.tickrate = clamp_tickrate(new_tickrate));
+  }
+
+  /// [`change_tickrate_millis()`](MappedEventSync::change_tickrate_millis), taking the tickrate as a `u32`.
+  #[deprecated(
+    since = "0.4.5",
+    note = "use `change_tickrate_millis()`, as tickrates are `u64` milliseconds"
+  )]
+  pub fn change_tickrate(&mut self, new_tickrate: u32) {
+    self.change_tickrate_millis(new_tickrate.into())
+  }
+
+  /// Sleeps until the given tick has occurred, re-checking the file at least once every tick.
+  ///
+  /// The file has no restart count like an EventSync does, so the timeline is only known to have been moved when
+  /// the tickrate changes or the tick goes backwards.
+  fn sleep_until_tick_in_chunks(&self, mut tick: u64, target: WaitTarget) {
+    let mut last_seen = None;
+
+    loop {
+      let snapshot = self.snapshot();
+      let current_tick = snapshot.ticks_since_started();
+      let tickrate = snapshot.get_tickrate();
+
+      if let Some((last_tick, last_tickrate)) = last_seen {
+        let was_moved = tickrate != last_tickrate || current_tick < last_tick;
+
+        if target == WaitTarget::Relative && was_moved {
+          let ticks_left = tick.saturating_sub(last_tick).max(1);
+
+          tick = current_tick + ticks_left;
+        }
+      }
+
+      if current_tick >= tick {
+        return;
+      }
+
+      last_seen = Some((current_tick, tickrate));
+      let tickrate = Duration::from_millis(tickrate);
+
+      let wait_time = if snapshot.is_paused() {
+        tickrate
+      } else {
+        snapshot
+          .time_until_tick_occurs(tick)
+          .map_or(Duration::ZERO, |wait_time| wait_time.min(tickrate))
+      };
+
+      std::thread::sleep(wait_time);
+    }
+  }
+
+  /// Converts the current state of the file into the internal EventSync data.
+  fn snapshot(&self) -> InnerEventSync {
+    let state = self.read_state();
+
+    if state.is_paused {
+      InnerEventSync::new(state.tickrate, state.time, true, ClockSource::WallClock)
+    } else {
+      InnerEventSync::from_system_epoch(state.tickrate, SystemTime::UNIX_EPOCH + state.time)
+    }
+  }
+
+  /// Reads every field from the file, retrying if another process was writing at the same time.
+  ///
+  /// Blocks forever if a process exited in the middle of a write. See the [`Crashes`](MappedEventSync#crashes) section.
+  fn read_state(&self) -> MappedState {
+    loop {
+      let sequence = self.field(SEQUENCE_FIELD).load(Ordering::Acquire);
+
+      if !sequence.is_multiple_of(2) {
+        std::hint::spin_loop();
+        continue;
+      }
+
+      let state = MappedState {
+        tickrate: self.field(TICKRATE_FIELD).load(Ordering::Acquire),
+        is_paused: self.field(PAUSED_FIELD).load(Ordering::Acquire) == 1,
+        time: Duration::from_nanos(self.field(TIME_FIELD).load(Ordering::Acquire)),
+      };
+
+      if self.field(SEQUENCE_FIELD).load(Ordering::Acquire) == sequence {
+        return state;
+      }
+    }
+  }
+
+  /// Applies the given change to the fields in the file, blocking any other process from writing at the same time.
+  ///
+  /// Blocks forever if a process exited in the middle of a write. See the [`Crashes`](MappedEventSync#crashes) section.
+  fn write_state<F: FnOnce(&mut MappedState)>(&mut self, change: F) {
+    let sequence_field = self.field(SEQUENCE_FIELD);
+
+    let sequence = loop {
+      let sequence = sequence_field.load(Ordering::Acquire);
+
+      if sequence.is_multiple_of(2)
+        && sequence_field
+          .compare_exchange(sequence, sequence + 1, Ordering::AcqRel, Ordering::Acquire)
+          .is_ok()
+      {
+        break sequence;
+      }
+
+      std::hint::spin_loop();
+    };
+
+    let mut state = MappedState {
+      tickrate: self.field(TICKRATE_FIELD).load(Ordering::Acquire),
+      is_paused: self.field(PAUSED_FIELD).load(Ordering::Acquire) == 1,
+      time: Duration::from_nanos(self.field(TIME_FIELD).load(Ordering::Acquire)),
+    };
+
+    change(&mut state);
+
+    self
+      .field(TICKRATE_FIELD)
+      .store(state.tickrate, Ordering::Release);
+    self
+      .field(PAUSED_FIELD)
+      .store(state.is_paused as u64, Ordering::Release);
+    self
+      .field(TIME_FIELD)
+      .store(state.time.as_nanos() as u64, Ordering::Release);
+
+    sequence_field.store(sequence + 2, Ordering::Release);
+  }
+
+  /// Starts a running clock in the file, taking the lock even if it's held, as the process holding it may have exited
+  /// in the middle of a write.
+  fn reset(&mut self, tickrate: u64) {
+    let sequence_field = self.field(SEQUENCE_FIELD);
+    let sequence = sequence_field.fetch_or(1, Ordering::AcqRel) | 1;
+
+    self
+      .field(TICKRATE_FIELD)
+      .store(tickrate, Ordering::Release);
+    self.field(PAUSED_FIELD).store(0, Ordering::Release);
+    self
+      .field(TIME_FIELD)
+      .store(nanos_since_epoch(SystemTime::now()), Ordering::Release);
+    self.field(MAGIC_FIELD).store(MAGIC, Ordering::Release);
+
+    sequence_field.store(sequence.wrapping_add(1), Ordering::Release);
+  }
+
+  /// Returns the field at the given position in the file.
+  fn field(&self, index: usize) -> &AtomicU64 {
+    debug_assert!(index < FIELD_COUNT);
+
+    // Safety: The map is page aligned and FILE_SIZE long, so every field is in bounds and aligned for a u64.
+    // Every access to the file goes through atomics, including from other processes.
+    unsafe { self.fields.add(index).as_ref() }
+  }
+}
+
+/// Returns the nanoseconds between the unix epoch and the given time.
+fn nanos_since_epoch(time: SystemTime) -> u64 {
+  time
+    .duration_since(SystemTime::UNIX_EPOCH)
+    .unwrap_or_default()
+    .as_nanos() as u64
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  fn test_path(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("event_sync_mapped_{}_{}", name, std::process::id()))
+  }
+
+  #[test]
+  fn state_is_shared_between_mappings() {
+    let path = test_path("shared");
+    let mut event_sync = MappedEventSync::create_millis(&path, TEST_TICKRATE).unwrap();
+    let other_event_sync = MappedEventSync::open(&path).unwrap();
+
+    event_sync.wait_until(2).unwrap();
+    event_sync.pause();
+    event_sync.change_tickrate_millis(TEST_TICKRATE * 2);
+
+    assert!(other_event_sync.is_paused());
+    assert_eq!(other_event_sync.get_tickrate_millis(), TEST_TICKRATE * 2);
+    assert_eq!(
+      other_event_sync.wait_for_tick(),
+      Err(TimeError::EventSyncPaused)
+    );
+
+    std::fs::remove_file(path).unwrap();
+  }
+
+  #[test]
+  fn time_is_retained_when_pausing_and_unpausing() {
+    let path = test_path("pausing");
+    let mut event_sync = MappedEventSync::create_millis(&path, TEST_TICKRATE).unwrap();
+
+    event_sync.wait_until(3).unwrap();
+    event_sync.pause();
+    std::thread::sleep(Duration::from_millis(TEST_TICKRATE * 2));
+    event_sync.unpause();
+
+    assert_eq!(event_sync.ticks_since_started(), 3);
+
+    std::fs::remove_file(path).unwrap();
+  }
+
+  #[test]
+  fn waits_follow_changes_from_other_mappings() {
+    let path = test_path("waiting");
+    let event_sync = MappedEventSync::create_millis(&path, TEST_TICKRATE).unwrap();
+    let mut other_event_sync = MappedEventSync::open(&path).unwrap();
+
+    let started_at = std::time::Instant::now();
+
+    std::thread::scope(|scope| {
+      let waiting = scope.spawn(|| {
+        event_sync.wait_until(50).unwrap();
+
+        started_at.elapsed()
+      });
+
+      std::thread::sleep(Duration::from_millis(TEST_TICKRATE * 2));
+      // Tick 50 is now at 100ms rather than 500ms.
+      other_event_sync.change_tickrate_millis(2);
+
+      assert!(waiting.join().unwrap() < Duration::from_millis(TEST_TICKRATE * 20));
+    });
+
+    std::fs::remove_file(path).unwrap();
+  }
+
+  #[test]
+  fn creating_releases_a_crashed_writers_lock() {
+    let path = test_path("crashed");
+    let event_sync = MappedEventSync::create_millis(&path, TEST_TICKRATE).unwrap();
+
+    // Left locked, as if a process exited in the middle of a write.
+    event_sync
+      .field(SEQUENCE_FIELD)
+      .fetch_add(1, Ordering::SeqCst);
+    MappedEventSync::create_millis(&path, TEST_TICKRATE * 2).unwrap();
+
+    assert_eq!(event_sync.get_tickrate_millis(), TEST_TICKRATE * 2);
+    assert!(!event_sync.is_paused());
+
+    std::fs::remove_file(path).unwrap();
+  }
+
+  #[test]
+  fn open_rejects_other_files() {
+    let path = test_path("invalid");
+    std::fs::write(&path, b"not an event sync").unwrap();
+
+    assert!(MappedEventSync::open(&path).is_err());
+
+    std::fs::remove_file(path).unwrap();
+  }
+}
@@ -0,0 +1,173 @@
+//! Using an EventSync as the master clock of a media pipeline.
+
+use crate::errors::TimeError;
+use crate::EventSync;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+/// The clock interface media pipelines drive their elements from, such as a GStreamer-like framework's master clock.
+///
+/// Media time is the time since the clock started, and stands still while the clock is paused.
+/// Implemented by [`EventSync`](EventSync), so a pipeline clock can forward to it and share its pausable timeline with
+/// everything else keyed to it.
+///
+/// # Example
+///
+/// ```
+/// use event_sync::*;
+/// use std::time::Duration;
+///
+/// fn present_frames<C: MediaClock>(clock: &C) {
+///   let frame_time = Duration::from_millis(40);
+///
+///   for frame in 1..=3 {
+///     clock.wait_for_media_time(frame_time * frame).unwrap();
+///     // Present the frame.
+///   }
+/// }
+///
+/// let tickrate = 10; // 10ms between every tick.
+/// let event_sync = EventSync::new_millis(tickrate);
+///
+/// present_frames(&event_sync);
+///
+/// assert!(event_sync.media_time() >= Duration::from_millis(120));
+/// ```
+pub trait MediaClock {
+  /// Returns the current media time.
+  fn media_time(&self) -> Duration;
+
+  /// Returns true if media time is moving, and false while it's paused.
+  fn is_running(&self) -> bool;
+
+  /// Blocks until the media time has been reached, returning how late past it the wait ended.
+  ///
+  /// A time that's already been reached returns straight away.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if the clock is paused.
+  fn wait_for_media_time(&self, media_time: Duration) -> Result<Duration, TimeError>;
+
+  /// Calls the wake on a separate thread once the media time has been reached, or straight away if it already has.
+  ///
+  /// Waits through pauses until the clock is running again, unless the wake is
+  /// [`unscheduled`](ScheduledWake::unschedule).
+  fn schedule_wake(&self, media_time: Duration, wake: Box<dyn FnOnce() + Send>) -> ScheduledWake;
+}
+
+/// A wake scheduled with [`MediaClock::schedule_wake()`](MediaClock::schedule_wake).
+///
+/// Dropping the handle doesn't unschedule the wake.
+#[derive(Debug)]
+pub struct ScheduledWake {
+  unscheduled: Arc<AtomicBool>,
+  thread: JoinHandle<()>,
+}
+
+impl ScheduledWake {
+  /// Stops the wake from being called, if it hasn't been already.
+  ///
+  /// The wake is checked at least once every tick, so it could still be called within a tick of unscheduling.
+  pub fn unschedule(&self) {
+    self.unscheduled.store(true, Ordering::Release);
+  }
+
+  /// Returns true once the wake has been called, or given up on after being unscheduled.
+  pub fn is_finished(&self) -> bool {
+    self.thread.is_finished()
+  }
+
+  /// Blocks until the wake has been called, or given up on after being unscheduled.
+  pub fn join(self) {
+    let _ = self.thread.join();
+  }
+}
+
+impl<T> MediaClock for EventSync<T> {
+  fn media_time(&self) -> Duration {
+    self.time_since_started()
+  }
+
+  fn is_running(&self) -> bool {
+    !self.is_paused()
+  }
+
+  fn wait_for_media_time(&self, media_time: Duration) -> Result<Duration, TimeError> {
+    match self.wait_until_elapsed(media_time) {
+      Ok(()) | Err(TimeError::ThatTimeHasAlreadyHappened) => {
+        Ok(self.time_since_started().saturating_sub(media_time))
+      }
+      Err(error) => Err(error),
+    }
+  }
+
+  fn schedule_wake(&self, media_time: Duration, wake: Box<dyn FnOnce() + Send>) -> ScheduledWake {
+    let unscheduled = Arc::new(AtomicBool::new(false));
+    let event_sync = self.to_immutable();
+    let is_unscheduled = unscheduled.clone();
+
+    let thread = std::thread::spawn(move || {
+      if event_sync
+        .sleep_until_elapsed_in_chunks(media_time, || is_unscheduled.load(Ordering::Acquire))
+      {
+        wake();
+      }
+    });
+
+    ScheduledWake {
+      unscheduled,
+      thread,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::sync::mpsc;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  #[test]
+  fn unscheduled_wakes_are_never_called() {
+    let event_sync = EventSync::new_paused_millis(TEST_TICKRATE);
+    let (sender, receiver) = mpsc::channel();
+
+    let wake = event_sync.schedule_wake(
+      Duration::ZERO,
+      Box::new(move || {
+        let _ = sender.send(());
+      }),
+    );
+
+    std::thread::sleep(Duration::from_millis(TEST_TICKRATE * 2));
+    assert!(!wake.is_finished());
+
+    wake.unschedule();
+    wake.join();
+
+    assert!(receiver.try_recv().is_err());
+  }
+
+  #[test]
+  fn wakes_are_called_at_their_media_time() {
+    let event_sync = EventSync::new_millis(TEST_TICKRATE);
+    let (sender, receiver) = mpsc::channel();
+    let media_time = Duration::from_millis(25);
+
+    let wake_clock = event_sync.clone_immutable();
+    let wake = event_sync.schedule_wake(
+      media_time,
+      Box::new(move || {
+        let _ = sender.send(wake_clock.media_time());
+      }),
+    );
+    wake.join();
+
+    assert!(receiver.recv().unwrap() >= media_time);
+    assert!(event_sync.wait_for_media_time(Duration::ZERO).is_ok());
+  }
+}
@@ -0,0 +1,311 @@
+//! Sending MIDI clock from an EventSync where every tick is a quarter note.
+//!
+//! Enabled with the `midi` feature.
+
+use crate::errors::TimeError;
+use crate::tick_loop::LoopControl;
+use crate::{EventSync, Immutable, Mutable};
+use std::sync::mpsc::Sender;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+/// The amount of MIDI clock pulses in every quarter note, and so every tick of a [`from_bpm()`](EventSync::from_bpm)
+/// EventSync.
+pub const PULSES_PER_QUARTER_NOTE: u64 = 24;
+
+/// A MIDI real-time message sent by a [`MidiClock`](MidiClock).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MidiClockMessage {
+  /// A pulse, 24 of which make up a quarter note.
+  Clock,
+
+  /// Playback started from the beginning.
+  Start,
+
+  /// Playback carried on from where it stopped.
+  Continue,
+
+  /// Playback stopped.
+  Stop,
+}
+
+impl MidiClockMessage {
+  /// Returns the status byte the message is sent as.
+  pub fn status_byte(self) -> u8 {
+    match self {
+      Self::Clock => 0xF8,
+      Self::Start => 0xFA,
+      Self::Continue => 0xFB,
+      Self::Stop => 0xFC,
+    }
+  }
+}
+
+impl EventSync<Mutable> {
+  /// Creates a new instance of [`EventSync`](EventSync) where every tick is a quarter note at the given tempo.
+  ///
+  /// The tickrate is rounded to the nearest millisecond, so [`bpm()`](EventSync::bpm) may be slightly off from
+  /// the tempo given.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if the tempo isn't a positive number, or rounds to a tickrate of 0 or longer than
+  ///   100 years.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::*;
+  ///
+  /// let event_sync = EventSync::from_bpm(120.0).unwrap();
+  ///
+  /// assert_eq!(event_sync.get_tickrate_millis(), 500);
+  /// assert_eq!(event_sync.bpm(), 120.0);
+  /// ```
+  pub fn from_bpm(bpm: f64) -> Result<Self, TimeError> {
+    if !(bpm.is_finite() && bpm > 0.0) {
+      return Err(TimeError::InvalidState("the tempo isn't a positive number"));
+    }
+
+    let tickrate = (60_000.0 / bpm).round().min(u64::MAX as f64) as u64;
+
+    Self::try_from(tickrate)
+  }
+}
+
+impl<T> EventSync<T> {
+  /// Returns the tempo in beats per minute, treating every tick as a quarter note.
+  pub fn bpm(&self) -> f64 {
+    60_000.0 / self.get_tickrate_millis() as f64
+  }
+}
+
+/// What a [`MidiClock`](MidiClock) saw on the EventSync the last time it checked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Observed {
+  is_paused: bool,
+  generation: u64,
+  tickrate: u64,
+}
+
+/// Sends MIDI clock for an EventSync, [`PULSES_PER_QUARTER_NOTE`](PULSES_PER_QUARTER_NOTE) pulses every tick.
+///
+/// Pausing the EventSync sends [`Stop`](MidiClockMessage::Stop), and unpausing it sends
+/// [`Continue`](MidiClockMessage::Continue), or [`Start`](MidiClockMessage::Start) if it was restarted or moved
+/// in the meantime.
+///
+/// # Example
+///
+/// ```
+/// use event_sync::*;
+/// use std::sync::mpsc;
+///
+/// let event_sync = EventSync::from_bpm(600.0).unwrap();
+/// let (sender, receiver) = mpsc::channel();
+///
+/// MidiClock::new(event_sync.clone_immutable()).spawn(sender);
+///
+/// assert_eq!(receiver.recv(), Ok(MidiClockMessage::Start));
+/// assert_eq!(receiver.recv(), Ok(MidiClockMessage::Clock));
+/// ```
+pub struct MidiClock {
+  event_sync: EventSync<Immutable>,
+  last_seen: Option<Observed>,
+  next_pulse: u64,
+}
+
+impl MidiClock {
+  /// Creates a clock for the EventSync. Nothing is sent until it's [`run`](MidiClock::run).
+  pub fn new(event_sync: EventSync<Immutable>) -> Self {
+    Self {
+      event_sync,
+      last_seen: None,
+      next_pulse: 0,
+    }
+  }
+
+  /// Sends every message to the closure as it's due, until the closure returns
+  /// [`LoopControl::Break`](LoopControl::Break).
+  pub fn run<F: FnMut(MidiClockMessage) -> LoopControl>(&mut self, mut send: F) {
+    loop {
+      if self.poll(&mut send) == LoopControl::Break {
+        return;
+      }
+
+      std::thread::sleep(self.time_until_next_pulse());
+    }
+  }
+
+  /// Runs the clock on a separate thread, sending every message over the channel.
+  ///
+  /// The clock stops once the receiver is dropped.
+  pub fn spawn(mut self, sender: Sender<MidiClockMessage>) -> JoinHandle<()> {
+    std::thread::spawn(move || {
+      self.run(|message| match sender.send(message) {
+        Ok(()) => LoopControl::Continue,
+        Err(_) => LoopControl::Break,
+      })
+    })
+  }
+
+  /// Checks the EventSync once, sending every message that's due.
+  fn poll<F: FnMut(MidiClockMessage) -> LoopControl>(&mut self, send: &mut F) -> LoopControl {
+    let (mut observed, current_pulse) = {
+      let inner = self.event_sync.read_inner();
+      let observed = Observed {
+        is_paused: inner.is_paused(),
+        generation: inner.generation(),
+        tickrate: inner.get_tickrate(),
+      };
+
+      (
+        observed,
+        pulse_at(inner.time_since_started(), observed.tickrate),
+      )
+    };
+
+    // Moves made while stopped are kept track of until playback carries on, to know whether it starts over.
+    if let Some(last_seen) = self.last_seen.filter(|_| observed.is_paused) {
+      observed.generation = last_seen.generation;
+    }
+
+    let last_seen = self.last_seen.replace(observed);
+
+    let was_running = last_seen.is_some_and(|last_seen| !last_seen.is_paused);
+    let was_moved = last_seen.is_some_and(|last_seen| last_seen.generation != observed.generation);
+
+    if observed.is_paused {
+      return if was_running {
+        send(MidiClockMessage::Stop)
+      } else {
+        LoopControl::Continue
+      };
+    }
+
+    if !was_running || was_moved {
+      self.next_pulse = current_pulse + 1;
+
+      return if was_moved || current_pulse == 0 {
+        send(MidiClockMessage::Start)
+      } else {
+        send(MidiClockMessage::Continue)
+      };
+    }
+
+    // Pulses are counted again at the new tickrate, rather than sent in a burst to catch up.
+    if last_seen.is_some_and(|last_seen| last_seen.tickrate != observed.tickrate) {
+      self.next_pulse = current_pulse + 1;
+    }
+
+    while self.next_pulse <= current_pulse {
+      self.next_pulse += 1;
+
+      if send(MidiClockMessage::Clock) == LoopControl::Break {
+        return LoopControl::Break;
+      }
+    }
+
+    LoopControl::Continue
+  }
+
+  /// Returns how long to sleep before the next pulse is due, at most a pulse, so pausing and unpausing are noticed
+  /// quickly.
+  fn time_until_next_pulse(&self) -> Duration {
+    let inner = self.event_sync.read_inner();
+    let tickrate = inner.get_tickrate();
+    let pulse_length = time_of_pulse(1, tickrate);
+
+    if inner.is_paused() {
+      return pulse_length;
+    }
+
+    inner
+      .time_until_elapsed(time_of_pulse(self.next_pulse, tickrate))
+      .map_or(Duration::ZERO, |wait_time| wait_time.min(pulse_length))
+  }
+}
+
+/// Returns the pulse that was last sent at the given time since the EventSync started.
+fn pulse_at(time_since_started: Duration, tickrate: u64) -> u64 {
+  (time_since_started.as_nanos() * PULSES_PER_QUARTER_NOTE as u128 / (tickrate as u128 * 1_000_000))
+    as u64
+}
+
+/// Returns the time since the EventSync started that the pulse is sent at.
+fn time_of_pulse(pulse: u64, tickrate: u64) -> Duration {
+  let nanos = pulse as u128 * tickrate as u128 * 1_000_000 / PULSES_PER_QUARTER_NOTE as u128;
+
+  Duration::from_nanos(nanos as u64)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn poll(clock: &mut MidiClock) -> Vec<MidiClockMessage> {
+    let mut messages = Vec::new();
+
+    clock.poll(&mut |message| {
+      messages.push(message);
+
+      LoopControl::Continue
+    });
+
+    messages
+  }
+
+  #[test]
+  fn pulses_land_on_ticks() {
+    assert_eq!(pulse_at(Duration::from_millis(500), 500), 24);
+    assert_eq!(pulse_at(Duration::from_millis(499), 500), 23);
+    assert_eq!(time_of_pulse(48, 500), Duration::from_secs(1));
+  }
+
+  #[test]
+  fn transport_follows_pausing() {
+    let mut event_sync = EventSync::from_bpm(120.0).unwrap();
+    event_sync.pause();
+    let mut clock = MidiClock::new(event_sync.clone_immutable());
+
+    assert!(poll(&mut clock).is_empty());
+
+    event_sync.unpause().unwrap();
+    assert_eq!(poll(&mut clock), [MidiClockMessage::Start]);
+
+    event_sync.pause();
+    event_sync.sync_to(Duration::from_millis(750)).unwrap();
+    assert_eq!(poll(&mut clock), [MidiClockMessage::Stop]);
+
+    event_sync.unpause().unwrap();
+    assert_eq!(poll(&mut clock), [MidiClockMessage::Start]);
+    assert_eq!(clock.next_pulse, 37);
+
+    event_sync.pause();
+    assert_eq!(poll(&mut clock), [MidiClockMessage::Stop]);
+
+    event_sync.unpause().unwrap();
+    assert_eq!(poll(&mut clock), [MidiClockMessage::Continue]);
+  }
+
+  #[test]
+  fn every_pulse_is_sent() {
+    let event_sync = EventSync::from_bpm(600.0).unwrap();
+    let mut clock = MidiClock::new(event_sync.clone_immutable());
+
+    assert_eq!(poll(&mut clock), [MidiClockMessage::Start]);
+
+    std::thread::sleep(Duration::from_millis(100));
+
+    let pulses = poll(&mut clock);
+    let current_pulse = pulse_at(
+      event_sync.time_since_started(),
+      event_sync.get_tickrate_millis(),
+    );
+
+    assert!(pulses
+      .iter()
+      .all(|message| *message == MidiClockMessage::Clock));
+    assert!(pulses.len() >= PULSES_PER_QUARTER_NOTE as usize);
+    assert!(clock.next_pulse <= current_pulse + 1);
+  }
+}
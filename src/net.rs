@@ -0,0 +1,660 @@
+//! Synchronizing EventSyncs on separate machines over UDP.
+//!
+//! Enabled with the `net` feature.
+
+use crate::inner::validate;
+use crate::{EventSync, Immutable, Mutable};
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::net::{SocketAddr, ToSocketAddrs, UdpSocket};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::thread::JoinHandle;
+use std::time::{Duration, SystemTime};
+
+/// The largest beacon that will be read from a socket.
+const MAX_BEACON_SIZE: usize = 512;
+
+/// How long to wait for a response when measuring the offset to a peer.
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// The state of a leader's EventSync, broadcast to every follower.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TickBeacon {
+  /// Counts up with every beacon a leader sends, so followers can tell when beacons arrive out of order.
+  pub sequence: u64,
+  /// The tickrate of the leader in milliseconds.
+  pub tickrate: u64,
+  /// The time that has passed on the leader.
+  pub time_since_started: Duration,
+  /// Whether the leader is paused.
+  pub is_paused: bool,
+}
+
+/// Sends beacons describing an EventSync to followers on other machines.
+///
+/// The target address can be a broadcast address to reach every follower on a network at once.
+///
+/// # Example
+///
+/// ```
+/// use event_sync::*;
+/// use std::time::Duration;
+///
+/// let tickrate = 10; // 10ms between every tick.
+/// let event_sync = EventSync::from_starting_tick_millis(tickrate, 5, false);
+/// let follower_event_sync = EventSync::new_millis(tickrate);
+///
+/// let follower = TickFollower::bind("127.0.0.1:0", follower_event_sync.clone()).unwrap();
+/// let leader = TickLeader::bind(
+///   "127.0.0.1:0",
+///   follower.local_addr().unwrap(),
+///   event_sync.clone_immutable(),
+/// )
+/// .unwrap();
+///
+/// leader.send_beacon().unwrap();
+/// follower.receive_beacon().unwrap();
+///
+/// assert_eq!(follower_event_sync.ticks_since_started(), 5);
+/// ```
+pub struct TickLeader {
+  socket: UdpSocket,
+  event_sync: EventSync<Immutable>,
+  next_sequence: AtomicU64,
+}
+
+/// Aligns a local EventSync to the beacons sent by a [`TickLeader`](TickLeader).
+///
+/// When a beacon arrives, the tickrate and pause state of the leader are applied.
+/// The elapsed time is only moved if it differs from the leader's by more than the
+/// [`tolerance`](TickFollower::with_tolerance), to avoid constantly nudging the timeline over network jitter.
+///
+/// The leader's pauses are held with the [`LEADER_PAUSE_REASON`](TickFollower::LEADER_PAUSE_REASON), so pauses
+/// made locally are kept when the leader unpauses.
+///
+/// Beacons older than the last one applied are rejected, so a reordered or replayed beacon can't move the timeline
+/// back. Beacons can also be limited to a single sender with [`with_leader()`](TickFollower::with_leader).
+pub struct TickFollower {
+  socket: UdpSocket,
+  event_sync: EventSync<Mutable>,
+  tolerance: Duration,
+  latency: Duration,
+  slew_ticks: u32,
+  leader_address: Option<SocketAddr>,
+  next_sequence: AtomicU64,
+  rejected_beacons: AtomicU64,
+}
+
+/// Answers the time requests made by [`OffsetEstimate::measure()`](OffsetEstimate::measure) with the time that has
+/// passed on an EventSync.
+pub struct TimeResponder {
+  socket: UdpSocket,
+  event_sync: EventSync<Immutable>,
+}
+
+/// The estimated difference between a local EventSync and one on a peer, measured over a few round trips.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct OffsetEstimate {
+  /// How far ahead the peer's EventSync is in nanoseconds. Negative if the peer is behind.
+  pub offset_nanos: i128,
+  /// The time it took for a request to reach the peer and come back.
+  pub round_trip: Duration,
+}
+
+/// A request for the peer's time, containing the local time it was sent at.
+#[derive(Serialize, Deserialize)]
+struct TimeRequest {
+  sent_at: Duration,
+}
+
+/// The peer's answer to a [`TimeRequest`](TimeRequest).
+#[derive(Serialize, Deserialize)]
+struct TimeResponse {
+  sent_at: Duration,
+  peer_time: Duration,
+}
+
+impl TickLeader {
+  /// Creates a leader bound to the given local address, sending beacons to the target address.
+  ///
+  /// # Errors
+  ///
+  /// - If the socket could not be bound or connected to the target.
+  pub fn bind<A: ToSocketAddrs, B: ToSocketAddrs>(
+    local_address: A,
+    target_address: B,
+    event_sync: EventSync<Immutable>,
+  ) -> io::Result<Self> {
+    let socket = UdpSocket::bind(local_address)?;
+    socket.set_broadcast(true)?;
+    socket.connect(target_address)?;
+
+    // Starting from the wall clock lets the beacons of a restarted leader follow on from the ones it sent before.
+    let first_sequence = SystemTime::now()
+      .duration_since(SystemTime::UNIX_EPOCH)
+      .unwrap_or_default()
+      .as_nanos() as u64;
+
+    Ok(Self {
+      socket,
+      event_sync,
+      next_sequence: AtomicU64::new(first_sequence),
+    })
+  }
+
+  /// Returns a beacon describing the current state of the leader's EventSync, numbered after the last one.
+  pub fn beacon(&self) -> TickBeacon {
+    let sequence = self.next_sequence.fetch_add(1, Ordering::Relaxed);
+    let inner = self.event_sync.read_inner();
+
+    TickBeacon {
+      sequence,
+      tickrate: inner.get_tickrate(),
+      time_since_started: inner.time_since_started(),
+      is_paused: inner.is_paused(),
+    }
+  }
+
+  /// Sends a single beacon to the target.
+  pub fn send_beacon(&self) -> io::Result<()> {
+    let message = serde_json::to_vec(&self.beacon())?;
+
+    self.socket.send(&message).map(|_| ())
+  }
+
+  /// Sends a beacon every interval on a separate thread, until sending fails.
+  pub fn spawn(self, interval: Duration) -> JoinHandle<io::Result<()>> {
+    std::thread::spawn(move || loop {
+      self.send_beacon()?;
+
+      std::thread::sleep(interval);
+    })
+  }
+}
+
+impl TickFollower {
+  /// The default amount the follower's time can differ from the leader's before being corrected.
+  pub const DEFAULT_TOLERANCE: Duration = Duration::from_millis(1);
+
+  /// The reason the local EventSync is paused with while the leader is paused.
+  pub const LEADER_PAUSE_REASON: &'static str = "tick leader paused";
+
+  /// Creates a follower listening for beacons on the given local address.
+  ///
+  /// # Errors
+  ///
+  /// - If the socket could not be bound.
+  pub fn bind<A: ToSocketAddrs>(
+    local_address: A,
+    event_sync: EventSync<Mutable>,
+  ) -> io::Result<Self> {
+    Ok(Self {
+      socket: UdpSocket::bind(local_address)?,
+      event_sync,
+      tolerance: Self::DEFAULT_TOLERANCE,
+      latency: Duration::ZERO,
+      slew_ticks: 0,
+      leader_address: None,
+      next_sequence: AtomicU64::new(0),
+      rejected_beacons: AtomicU64::new(0),
+    })
+  }
+
+  /// Only accepts beacons sent from the given address, rejecting any others.
+  pub fn with_leader(mut self, leader_address: SocketAddr) -> Self {
+    self.leader_address = Some(leader_address);
+
+    self
+  }
+
+  /// Spreads corrections over the given amount of ticks instead of jumping to the leader's time.
+  ///
+  /// See [`EventSync::slew_to()`](EventSync::slew_to) for more details.
+  pub fn with_slew(mut self, over_ticks: u32) -> Self {
+    self.slew_ticks = over_ticks;
+
+    self
+  }
+
+  /// Sets the time it takes for a beacon to arrive, which is added to the leader's time before aligning to it.
+  ///
+  /// Half of an [`OffsetEstimate`'s round trip](OffsetEstimate::one_way_latency) is a good estimate.
+  pub fn with_latency(mut self, latency: Duration) -> Self {
+    self.latency = latency;
+
+    self
+  }
+
+  /// Sets how far the follower's time can differ from the leader's before being corrected.
+  pub fn with_tolerance(mut self, tolerance: Duration) -> Self {
+    self.tolerance = tolerance;
+
+    self
+  }
+
+  /// Returns the address the follower is listening on.
+  pub fn local_addr(&self) -> io::Result<SocketAddr> {
+    self.socket.local_addr()
+  }
+
+  /// Returns the amount of messages that were received and rejected.
+  pub fn rejected_beacons(&self) -> u64 {
+    self.rejected_beacons.load(Ordering::Relaxed)
+  }
+
+  /// Waits for the next beacon and aligns the local EventSync to it.
+  ///
+  /// Returns the received beacon.
+  ///
+  /// # Errors
+  ///
+  /// - If receiving fails.
+  /// - [`InvalidData`](io::ErrorKind::InvalidData) is returned if the message was rejected, in which case nothing is
+  ///   changed. That's when it wasn't a beacon, came from somewhere other than the
+  ///   [`leader`](TickFollower::with_leader), is older than the last beacon applied, or its tickrate or time
+  ///   couldn't belong to an EventSync.
+  pub fn receive_beacon(&self) -> io::Result<TickBeacon> {
+    let mut buffer = [0; MAX_BEACON_SIZE];
+    let (length, sender) = self.socket.recv_from(&mut buffer)?;
+
+    if self
+      .leader_address
+      .is_some_and(|leader_address| leader_address != sender)
+    {
+      return Err(self.reject("The beacon wasn't sent by the leader."));
+    }
+
+    let beacon: TickBeacon =
+      serde_json::from_slice(&buffer[..length]).map_err(|error| self.reject(error))?;
+
+    if beacon.sequence < self.next_sequence.load(Ordering::Relaxed) {
+      return Err(self.reject("The beacon is older than one already applied."));
+    }
+
+    self.apply(&beacon).map_err(|error| self.reject(error))?;
+    self
+      .next_sequence
+      .store(beacon.sequence.saturating_add(1), Ordering::Relaxed);
+
+    Ok(beacon)
+  }
+
+  /// Aligns to every beacon received on a separate thread, until receiving fails.
+  ///
+  /// Rejected messages are skipped, and counted in [`rejected_beacons()`](TickFollower::rejected_beacons).
+  pub fn spawn(self) -> JoinHandle<io::Result<()>> {
+    std::thread::spawn(move || loop {
+      match self.receive_beacon() {
+        Err(error) if error.kind() != io::ErrorKind::InvalidData => return Err(error),
+        _ => (),
+      }
+    })
+  }
+
+  /// Counts the rejected message, returning the error to report it with.
+  fn reject<E: Into<Box<dyn std::error::Error + Send + Sync>>>(&self, error: E) -> io::Error {
+    self.rejected_beacons.fetch_add(1, Ordering::Relaxed);
+
+    io::Error::new(io::ErrorKind::InvalidData, error)
+  }
+
+  /// Applies the state of the beacon to the local EventSync under a single lock.
+  ///
+  /// Beacons come from the network, so they're checked the same way as deserialized EventSyncs before anything is
+  /// changed, leaving the EventSync as it was if they're rejected.
+  fn apply(&self, beacon: &TickBeacon) -> Result<(), crate::TimeError> {
+    let leader_time = if beacon.is_paused {
+      beacon.time_since_started
+    } else {
+      beacon.time_since_started.saturating_add(self.latency)
+    };
+
+    validate(beacon.tickrate, leader_time)?;
+
+    let mut inner = self.event_sync.inner.write().unwrap();
+    self.event_sync.driver.clear_cached_tick();
+
+    let held_for_leader = inner.pause_reasons().contains(&Self::LEADER_PAUSE_REASON);
+
+    // Unpausing is the only change that can fail, so it's made first.
+    if beacon.is_paused && !held_for_leader {
+      inner.pause_with_reason(Self::LEADER_PAUSE_REASON);
+    } else if !beacon.is_paused && held_for_leader {
+      inner.unpause_with_reason(Self::LEADER_PAUSE_REASON)?;
+    }
+
+    inner.change_tickrate(beacon.tickrate);
+
+    if inner.time_since_started().abs_diff(leader_time) > self.tolerance {
+      let period =
+        Duration::from_millis((self.slew_ticks as u64).saturating_mul(inner.get_tickrate()));
+
+      inner.slew_time_since_started(leader_time, period)?;
+    }
+
+    Ok(())
+  }
+}
+
+impl TimeResponder {
+  /// Creates a responder listening on the given local address.
+  ///
+  /// # Errors
+  ///
+  /// - If the socket could not be bound.
+  pub fn bind<A: ToSocketAddrs>(
+    local_address: A,
+    event_sync: EventSync<Immutable>,
+  ) -> io::Result<Self> {
+    Ok(Self {
+      socket: UdpSocket::bind(local_address)?,
+      event_sync,
+    })
+  }
+
+  /// Returns the address the responder is listening on.
+  pub fn local_addr(&self) -> io::Result<std::net::SocketAddr> {
+    self.socket.local_addr()
+  }
+
+  /// Waits for a single request and answers it.
+  ///
+  /// # Errors
+  ///
+  /// - If receiving or sending fails.
+  /// - [`InvalidData`](io::ErrorKind::InvalidData) is returned if the message wasn't a time request, in which case
+  ///   nothing is sent.
+  pub fn respond(&self) -> io::Result<()> {
+    let mut buffer = [0; MAX_BEACON_SIZE];
+    let (length, sender) = self.socket.recv_from(&mut buffer)?;
+    let request: TimeRequest = serde_json::from_slice(&buffer[..length])
+      .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+
+    let response = TimeResponse {
+      sent_at: request.sent_at,
+      peer_time: self.event_sync.time_since_started(),
+    };
+
+    self
+      .socket
+      .send_to(&serde_json::to_vec(&response)?, sender)
+      .map(|_| ())
+  }
+
+  /// Answers every request on a separate thread, until receiving or sending fails.
+  ///
+  /// Messages that aren't time requests are skipped.
+  pub fn spawn(self) -> JoinHandle<io::Result<()>> {
+    std::thread::spawn(move || loop {
+      match self.respond() {
+        Err(error) if error.kind() != io::ErrorKind::InvalidData => return Err(error),
+        _ => (),
+      }
+    })
+  }
+}
+
+impl OffsetEstimate {
+  /// Exchanges the given amount of round trips with the [`TimeResponder`](TimeResponder) at the peer address,
+  /// estimating the offset between the local EventSync and the peer's.
+  ///
+  /// The sample with the shortest round trip is used, as it had the least room for delays to skew it.
+  /// Samples that don't get a response within a second are skipped.
+  ///
+  /// # Errors
+  ///
+  /// - If a socket could not be created.
+  /// - If no sample received a response.
+  ///
+  /// # Example
+  ///
+  /// ```
+  /// use event_sync::*;
+  /// use std::time::Duration;
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let peer_event_sync = EventSync::from_starting_tick_millis(tickrate, 100, false);
+  /// let event_sync = EventSync::new_millis(tickrate);
+  ///
+  /// let responder = TimeResponder::bind("127.0.0.1:0", peer_event_sync.clone_immutable()).unwrap();
+  /// let peer_address = responder.local_addr().unwrap();
+  /// responder.spawn();
+  ///
+  /// let estimate = OffsetEstimate::measure(peer_address, &event_sync, 5).unwrap();
+  ///
+  /// // The peer started 100 ticks (1 second) ahead.
+  /// assert!((estimate.offset_nanos - 1_000_000_000).abs() < 5_000_000);
+  /// ```
+  pub fn measure<A: ToSocketAddrs, T>(
+    peer_address: A,
+    event_sync: &EventSync<T>,
+    samples: u32,
+  ) -> io::Result<Self> {
+    let socket = UdpSocket::bind(("0.0.0.0", 0))?;
+    socket.connect(peer_address)?;
+    socket.set_read_timeout(Some(RESPONSE_TIMEOUT))?;
+
+    let mut best_estimate: Option<Self> = None;
+    let mut buffer = [0; MAX_BEACON_SIZE];
+
+    for _ in 0..samples {
+      let request = TimeRequest {
+        sent_at: event_sync.time_since_started(),
+      };
+      socket.send(&serde_json::to_vec(&request)?)?;
+
+      let response: TimeResponse = match socket.recv(&mut buffer) {
+        Ok(length) => serde_json::from_slice(&buffer[..length])?,
+        Err(error)
+          if matches!(
+            error.kind(),
+            io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut
+          ) =>
+        {
+          continue
+        }
+        Err(error) => return Err(error),
+      };
+      let received_at = event_sync.time_since_started();
+
+      let round_trip = received_at.saturating_sub(response.sent_at);
+      let midpoint = response.sent_at + round_trip / 2;
+      let estimate = Self {
+        offset_nanos: response.peer_time.as_nanos() as i128 - midpoint.as_nanos() as i128,
+        round_trip,
+      };
+
+      if best_estimate.is_none_or(|best| estimate.round_trip < best.round_trip) {
+        best_estimate = Some(estimate);
+      }
+    }
+
+    best_estimate
+      .ok_or_else(|| io::Error::new(io::ErrorKind::TimedOut, "The peer never responded."))
+  }
+
+  /// Returns the estimated time it takes for a message to reach the peer.
+  pub fn one_way_latency(&self) -> Duration {
+    self.round_trip / 2
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  fn connect(
+    leader_event_sync: &EventSync,
+    follower_event_sync: &EventSync,
+  ) -> (TickLeader, TickFollower) {
+    let follower = TickFollower::bind("127.0.0.1:0", follower_event_sync.clone()).unwrap();
+    let leader = TickLeader::bind(
+      "127.0.0.1:0",
+      follower.local_addr().unwrap(),
+      leader_event_sync.clone_immutable(),
+    )
+    .unwrap();
+
+    (leader, follower)
+  }
+
+  #[test]
+  fn follower_aligns_to_leader() {
+    let leader_event_sync = EventSync::from_starting_tick_millis(TEST_TICKRATE, 10, false);
+    let follower_event_sync = EventSync::new_millis(TEST_TICKRATE * 2);
+    let (leader, follower) = connect(&leader_event_sync, &follower_event_sync);
+
+    leader.send_beacon().unwrap();
+    follower.receive_beacon().unwrap();
+
+    assert_eq!(follower_event_sync.get_tickrate_millis(), TEST_TICKRATE);
+    assert_eq!(
+      follower_event_sync.ticks_since_started(),
+      leader_event_sync.ticks_since_started()
+    );
+  }
+
+  #[test]
+  fn follower_copies_pause_state() {
+    let leader_event_sync = EventSync::from_starting_tick_millis(TEST_TICKRATE, 3, true);
+    let follower_event_sync = EventSync::new_millis(TEST_TICKRATE);
+    let (leader, follower) = connect(&leader_event_sync, &follower_event_sync);
+
+    leader.send_beacon().unwrap();
+    follower.receive_beacon().unwrap();
+
+    assert!(follower_event_sync.is_paused());
+    assert_eq!(follower_event_sync.ticks_since_started(), 3);
+  }
+
+  #[test]
+  fn invalid_beacons_change_nothing() {
+    let follower_event_sync = EventSync::new_paused_millis(TEST_TICKRATE);
+    let follower = TickFollower::bind("127.0.0.1:0", follower_event_sync.clone())
+      .unwrap()
+      .with_latency(Duration::from_millis(1));
+    let sender = UdpSocket::bind("127.0.0.1:0").unwrap();
+
+    sender
+      .send_to(
+        br#"{"sequence":0,"tickrate":1,"time_since_started":{"secs":18446744073709551615,"nanos":0},"is_paused":false}"#,
+        follower.local_addr().unwrap(),
+      )
+      .unwrap();
+
+    assert!(follower.receive_beacon().is_err());
+
+    let beacon = TickBeacon {
+      sequence: 0,
+      tickrate: u64::MAX,
+      time_since_started: Duration::ZERO,
+      is_paused: false,
+    };
+
+    assert!(follower.apply(&beacon).is_err());
+    assert!(follower_event_sync.is_paused());
+    assert_eq!(follower_event_sync.get_tickrate_millis(), TEST_TICKRATE);
+  }
+
+  #[test]
+  fn bad_messages_are_rejected() {
+    let leader_event_sync = EventSync::from_starting_tick_millis(TEST_TICKRATE, 10, true);
+    let follower_event_sync = EventSync::new_millis(TEST_TICKRATE);
+    let (leader, follower) = connect(&leader_event_sync, &follower_event_sync);
+    let stale_beacon = serde_json::to_vec(&leader.beacon()).unwrap();
+    let follower_address = follower.local_addr().unwrap();
+    let follower = follower.with_leader(leader.socket.local_addr().unwrap());
+    let sender = UdpSocket::bind("127.0.0.1:0").unwrap();
+
+    sender
+      .send_to(
+        &serde_json::to_vec(&leader.beacon()).unwrap(),
+        follower_address,
+      )
+      .unwrap();
+    leader.socket.send(b"not a beacon").unwrap();
+    leader.send_beacon().unwrap();
+    leader.socket.send(&stale_beacon).unwrap();
+
+    assert_eq!(
+      follower.receive_beacon().unwrap_err().kind(),
+      io::ErrorKind::InvalidData
+    );
+    assert_eq!(
+      follower.receive_beacon().unwrap_err().kind(),
+      io::ErrorKind::InvalidData
+    );
+    assert!(follower.receive_beacon().is_ok());
+    assert!(follower.receive_beacon().is_err());
+    assert_eq!(follower.rejected_beacons(), 3);
+    assert_eq!(follower_event_sync.ticks_since_started(), 10);
+  }
+
+  #[test]
+  fn local_pauses_outlast_the_leader() {
+    let mut leader_event_sync = EventSync::from_starting_tick_millis(TEST_TICKRATE, 3, true);
+    let mut follower_event_sync = EventSync::new_millis(TEST_TICKRATE);
+    let (leader, follower) = connect(&leader_event_sync, &follower_event_sync);
+
+    leader.send_beacon().unwrap();
+    follower.receive_beacon().unwrap();
+    follower_event_sync.pause_with_reason("menu");
+    leader_event_sync.unpause().unwrap();
+    leader.send_beacon().unwrap();
+    follower.receive_beacon().unwrap();
+
+    assert_eq!(follower_event_sync.pause_reasons(), ["menu"]);
+    assert!(follower_event_sync.is_paused());
+  }
+
+  #[test]
+  fn offset_estimate_finds_peer_offset() {
+    let peer_event_sync = EventSync::from_starting_tick_millis(TEST_TICKRATE, 50, false);
+    let event_sync = EventSync::from_starting_tick_millis(TEST_TICKRATE, 20, false);
+
+    let responder = TimeResponder::bind("127.0.0.1:0", peer_event_sync.clone_immutable()).unwrap();
+    let peer_address = responder.local_addr().unwrap();
+    responder.spawn();
+
+    // A bad request shouldn't stop the responder.
+    UdpSocket::bind("127.0.0.1:0")
+      .unwrap()
+      .send_to(b"not a request", peer_address)
+      .unwrap();
+
+    let estimate = OffsetEstimate::measure(peer_address, &event_sync, 3).unwrap();
+    let expected_offset = Duration::from_millis(300).as_nanos() as i128;
+
+    assert!((estimate.offset_nanos - expected_offset).abs() < 2_000_000);
+    assert!(estimate.round_trip < Duration::from_millis(10));
+  }
+
+  #[test]
+  fn latency_is_added_to_leader_time() {
+    let leader_event_sync = EventSync::from_starting_tick_millis(TEST_TICKRATE, 2, false);
+    let follower_event_sync = EventSync::new_millis(TEST_TICKRATE);
+    let (leader, follower) = connect(&leader_event_sync, &follower_event_sync);
+    let follower = follower.with_latency(Duration::from_millis(TEST_TICKRATE * 3));
+
+    leader.send_beacon().unwrap();
+    follower.receive_beacon().unwrap();
+
+    assert_eq!(follower_event_sync.ticks_since_started(), 5);
+  }
+
+  #[test]
+  fn differences_within_tolerance_are_ignored() {
+    let leader_event_sync =
+      EventSync::from_starting_time_millis(TEST_TICKRATE, Duration::from_millis(4), true);
+    let follower_event_sync = EventSync::new_paused_millis(TEST_TICKRATE);
+    let (leader, follower) = connect(&leader_event_sync, &follower_event_sync);
+    let follower = follower.with_tolerance(Duration::from_millis(5));
+
+    leader.send_beacon().unwrap();
+    follower.receive_beacon().unwrap();
+
+    assert_eq!(follower_event_sync.time_since_started(), Duration::ZERO);
+  }
+}
@@ -0,0 +1,241 @@
+//! Pausing an EventSync once nothing is left that can control it.
+
+use crate::driver::Driver;
+use crate::inner::InnerEventSync;
+use crate::sync::atomic::{AtomicBool, Ordering};
+use crate::sync::{Arc, Mutex, MutexGuard, RwLock};
+use crate::{EventSync, Mutable};
+use std::time::Duration;
+
+/// Sent to the observers of an EventSync once every Mutable EventSync connected to it has been dropped, and it was
+/// paused because of it.
+///
+/// Only sent for EventSyncs created with [`pause_when_orphaned()`](EventSync::pause_when_orphaned).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ClockOrphaned {
+  /// The amount of ticks that had occurred when it was paused.
+  pub tick: u64,
+  /// The time that had passed on the EventSync when it was paused.
+  pub time_since_started: Duration,
+}
+
+type OrphanedCallback = Box<dyn FnOnce(ClockOrphaned) + Send>;
+
+/// Whether an EventSync and its clones pause once orphaned, and who to tell when they are.
+#[derive(Default)]
+pub(crate) struct Orphaning {
+  pause_when_orphaned: AtomicBool,
+  state: Mutex<OrphanState>,
+}
+
+#[derive(Default)]
+struct OrphanState {
+  orphaned: Option<ClockOrphaned>,
+  callbacks: Vec<OrphanedCallback>,
+}
+
+impl Orphaning {
+  fn lock_state(&self) -> MutexGuard<'_, OrphanState> {
+    self
+      .state
+      .lock()
+      .unwrap_or_else(|poisoned| poisoned.into_inner())
+  }
+}
+
+/// Shared by every Mutable EventSync connected to the same data, and dropped along with the last of them.
+pub(crate) struct MutableHandle {
+  inner: Arc<RwLock<InnerEventSync>>,
+  driver: Arc<Driver>,
+}
+
+impl MutableHandle {
+  pub(crate) fn new(inner: &Arc<RwLock<InnerEventSync>>, driver: &Arc<Driver>) -> Arc<Self> {
+    Arc::new(Self {
+      inner: inner.clone(),
+      driver: driver.clone(),
+    })
+  }
+}
+
+impl Drop for MutableHandle {
+  fn drop(&mut self) {
+    let orphaning = &self.driver.orphaning;
+
+    if !orphaning.pause_when_orphaned.load(Ordering::Acquire) {
+      return;
+    }
+
+    let orphaned = {
+      let mut inner = self
+        .inner
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+      inner.pause();
+      self.driver.clear_cached_tick();
+
+      ClockOrphaned {
+        tick: inner.ticks_since_started(),
+        time_since_started: inner.time_since_started(),
+      }
+    };
+
+    let callbacks = {
+      let mut state = orphaning.lock_state();
+      state.orphaned = Some(orphaned);
+
+      std::mem::take(&mut state.callbacks)
+    };
+
+    for callback in callbacks {
+      callback(orphaned);
+    }
+  }
+}
+
+impl<T> EventSync<T> {
+  /// Returns the [`ClockOrphaned`](ClockOrphaned) event if the EventSync has been orphaned.
+  ///
+  /// Once orphaned, nothing can unpause the EventSync again, so this never goes back to None.
+  pub fn orphaned(&self) -> Option<ClockOrphaned> {
+    self.driver.orphaning.lock_state().orphaned
+  }
+
+  /// Calls the callback once the EventSync is orphaned, on the thread that dropped the last Mutable EventSync.
+  ///
+  /// If the EventSync has already been orphaned, the callback is called right away.
+  /// Callbacks are never called for EventSyncs not created with
+  /// [`pause_when_orphaned()`](EventSync::pause_when_orphaned).
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::*;
+  /// use std::sync::mpsc;
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let event_sync = EventSync::new_millis(tickrate).pause_when_orphaned();
+  /// let observer = event_sync.clone_immutable();
+  /// let (sender, receiver) = mpsc::channel();
+  ///
+  /// observer.on_orphaned(Box::new(move |orphaned| {
+  ///   let _ = sender.send(orphaned);
+  /// }));
+  ///
+  /// drop(event_sync);
+  ///
+  /// let orphaned = receiver.recv().unwrap();
+  ///
+  /// assert!(observer.is_paused());
+  /// assert_eq!(observer.ticks_since_started(), orphaned.tick);
+  /// ```
+  pub fn on_orphaned(&self, callback: Box<dyn FnOnce(ClockOrphaned) + Send>) {
+    let mut state = self.driver.orphaning.lock_state();
+
+    match state.orphaned {
+      Some(orphaned) => {
+        drop(state);
+
+        callback(orphaned);
+      }
+      None => state.callbacks.push(callback),
+    }
+  }
+
+  /// Returns true if the EventSync pauses once every Mutable EventSync connected to it has been dropped.
+  pub fn pauses_when_orphaned(&self) -> bool {
+    self
+      .driver
+      .orphaning
+      .pause_when_orphaned
+      .load(Ordering::Acquire)
+  }
+}
+
+impl EventSync<Mutable> {
+  /// Makes the EventSync pause once every Mutable EventSync connected to it has been dropped, leaving only
+  /// Immutable ones that can't control it.
+  ///
+  /// Without this, an orphaned EventSync keeps running with nothing able to pause it.
+  /// Observers can check for it with [`orphaned()`](EventSync::orphaned), or be told with
+  /// [`on_orphaned()`](EventSync::on_orphaned).
+  ///
+  /// Applies to this EventSync and every EventSync connected to it.
+  /// Waits in progress when it's orphaned carry on as they do through any pause, so won't return.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::*;
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let event_sync = EventSync::new_millis(tickrate).pause_when_orphaned();
+  /// let observer = event_sync.clone_immutable();
+  ///
+  /// // Clones of the Mutable EventSync keep it from being orphaned.
+  /// let controller = event_sync.clone();
+  /// drop(event_sync);
+  ///
+  /// assert_eq!(observer.orphaned(), None);
+  ///
+  /// drop(controller);
+  ///
+  /// assert!(observer.orphaned().is_some());
+  /// assert_eq!(observer.wait_for_tick(), Err(TimeError::EventSyncPaused));
+  /// ```
+  pub fn pause_when_orphaned(self) -> Self {
+    self
+      .driver
+      .orphaning
+      .pause_when_orphaned
+      .store(true, Ordering::Release);
+
+    self
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::sync::mpsc;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  #[test]
+  fn only_the_last_mutable_drop_orphans() {
+    let event_sync =
+      EventSync::from_starting_tick_millis(TEST_TICKRATE, 4, false).pause_when_orphaned();
+    let observer = event_sync.clone_immutable();
+    let controller = event_sync.clone_with().build().unwrap();
+    let (sender, receiver) = mpsc::channel();
+
+    observer.on_orphaned(Box::new(move |orphaned| {
+      let _ = sender.send(orphaned);
+    }));
+    drop(event_sync);
+
+    assert!(!observer.is_paused());
+    assert!(receiver.try_recv().is_err());
+
+    drop(controller);
+
+    let orphaned = receiver.try_recv().unwrap();
+
+    assert!(observer.is_paused());
+    assert!(orphaned.tick >= 4);
+    assert_eq!(observer.orphaned(), Some(orphaned));
+    assert_eq!(observer.time_since_started(), orphaned.time_since_started);
+  }
+
+  #[test]
+  fn clocks_keep_running_by_default() {
+    let event_sync = EventSync::new_millis(TEST_TICKRATE);
+    let observer = event_sync.clone_immutable();
+
+    drop(event_sync);
+
+    assert!(!observer.pauses_when_orphaned());
+    assert!(!observer.is_paused());
+    assert_eq!(observer.orphaned(), None);
+  }
+}
@@ -0,0 +1,122 @@
+//! Processing batches of work in parallel, one batch per tick.
+//!
+//! Enabled with the `rayon` feature.
+
+use crate::errors::TimeError;
+use crate::EventSync;
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+/// A batch that was still running when the next batch should have started.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatchOverrun {
+  /// The position of the batch, counting from 0.
+  pub batch: usize,
+  /// The amount of ticks the batch took, which is more than it was given.
+  pub ticks_taken: u64,
+}
+
+impl<T> EventSync<T> {
+  /// Processes each batch's items in parallel with rayon, starting every batch on a tick boundary and giving it
+  /// n_ticks to finish.
+  ///
+  /// A batch that runs over its budget delays the next one to the first tick after it finishes, and is returned
+  /// in the list of overruns.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if the EventSync is paused.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::*;
+  /// use std::sync::atomic::{AtomicU32, Ordering};
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let event_sync = EventSync::new_millis(tickrate);
+  ///
+  /// let total = AtomicU32::new(0);
+  /// let batches = vec![vec![1, 2, 3], vec![4, 5, 6]];
+  ///
+  /// let overruns = event_sync
+  ///   .for_each_tick_parallel(1, batches, |item| {
+  ///     total.fetch_add(item, Ordering::Relaxed);
+  ///   })
+  ///   .unwrap();
+  ///
+  /// assert_eq!(total.into_inner(), 21);
+  /// assert!(overruns.is_empty());
+  /// ```
+  pub fn for_each_tick_parallel<I, F>(
+    &self,
+    n_ticks: u32,
+    batches: I,
+    closure: F,
+  ) -> Result<Vec<BatchOverrun>, TimeError>
+  where
+    I: IntoIterator,
+    I::Item: IntoParallelIterator,
+    F: Fn(<I::Item as IntoParallelIterator>::Item) + Sync + Send,
+  {
+    let n_ticks = n_ticks.max(1) as u64;
+    let mut overruns = Vec::new();
+    let mut start_tick = self.ticks_since_started() + 1;
+
+    for (batch_index, batch) in batches.into_iter().enumerate() {
+      match self.wait_until(start_tick) {
+        Ok(_) | Err(TimeError::ThatTimeHasAlreadyHappened) => (),
+        Err(error) => return Err(error),
+      }
+
+      batch.into_par_iter().for_each(&closure);
+
+      let ticks_taken = self.ticks_since_started().saturating_sub(start_tick);
+
+      if ticks_taken >= n_ticks {
+        overruns.push(BatchOverrun {
+          batch: batch_index,
+          ticks_taken: ticks_taken + 1,
+        });
+
+        start_tick += ticks_taken + 1;
+      } else {
+        start_tick += n_ticks;
+      }
+    }
+
+    Ok(overruns)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  #[test]
+  fn slow_batches_are_reported() {
+    let event_sync = EventSync::new_millis(TEST_TICKRATE);
+    let batches = vec![vec![0], vec![3], vec![0]];
+
+    let overruns = event_sync
+      .for_each_tick_parallel(1, batches, |ticks| {
+        std::thread::sleep(std::time::Duration::from_millis(ticks * TEST_TICKRATE));
+      })
+      .unwrap();
+
+    assert_eq!(overruns.len(), 1);
+    assert_eq!(overruns[0].batch, 1);
+    assert!(overruns[0].ticks_taken >= 3);
+  }
+
+  #[test]
+  fn paused_event_syncs_are_an_error() {
+    let mut event_sync = EventSync::new_millis(TEST_TICKRATE);
+    event_sync.pause();
+
+    let result = event_sync.for_each_tick_parallel(1, vec![vec![1]], |_: i32| ());
+
+    assert_eq!(result, Err(TimeError::EventSyncPaused));
+  }
+}
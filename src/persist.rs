@@ -0,0 +1,321 @@
+//! Saving EventSyncs to files, so a process can carry on its timeline after restarting or crashing.
+
+use crate::driver::{ListenerId, TickPhase};
+use crate::{snapshot, AccessLevel, EventSync};
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+
+/// Tells apart the temporary files of saves happening at the same time.
+static NEXT_TEMPORARY_FILE: AtomicU64 = AtomicU64::new(0);
+
+impl<T: AccessLevel> EventSync<T> {
+  /// Saves a [`snapshot`](EventSync::to_snapshot) of the EventSync to the file, replacing it.
+  ///
+  /// The snapshot is written to a temporary file next to it, flushed to disk, then renamed over the file, so a crash
+  /// part way through leaves the previous save intact rather than a half written one.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if the EventSync can't be saved, such as when it uses a
+  ///   [`custom clock`](crate::ClockSource::Custom).
+  /// - An error is returned if the file couldn't be written.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::*;
+  ///
+  /// let path = std::env::temp_dir().join("event_sync_save_doc_example");
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let event_sync = EventSync::from_starting_tick_millis(tickrate, 30, true);
+  ///
+  /// event_sync.save_to(&path).unwrap();
+  /// let loaded: EventSync = EventSync::load_from(&path).unwrap();
+  ///
+  /// assert!(loaded.is_paused());
+  /// assert_eq!(loaded.ticks_since_started(), 30);
+  /// # std::fs::remove_file(path).unwrap();
+  /// ```
+  pub fn save_to<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+    let snapshot = self
+      .to_snapshot()
+      .map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error))?;
+
+    write_atomically(path.as_ref(), &snapshot)
+  }
+
+  /// Loads an EventSync, paused, from a file saved with [`save_to()`](EventSync::save_to).
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if the file couldn't be read.
+  /// - An error of kind [`InvalidData`](io::ErrorKind::InvalidData) is returned if the file isn't a valid snapshot,
+  ///   wrapping the [`TimeError`](crate::TimeError) for why.
+  pub fn load_from<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+    let snapshot = fs::read(path)?;
+
+    Self::from_snapshot(&snapshot)
+      .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+  }
+
+  /// Saves the EventSync to the file now, then again every n_ticks ticks from the driver thread, returning the id of
+  /// the listener doing it, which stops the saves when removed.
+  ///
+  /// Saves are made the same way as [`save_to()`](EventSync::save_to), so after a crash, the file holds the last
+  /// checkpoint to resume from with [`load_from()`](EventSync::load_from).
+  /// The driver thread only takes the snapshot, which is written to the file by a thread of its own. If the file
+  /// is still being written when the next snapshot is taken, only the latest one waits to be written after it.
+  /// A save that fails to be written is skipped, leaving the previous one in place until the next succeeds.
+  /// The listener stops along with the driver thread once every EventSync connected to this one is dropped.
+  ///
+  /// # Errors
+  ///
+  /// - The same as [`save_to()`](EventSync::save_to), for the first save. Nothing is scheduled if it fails.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::*;
+  ///
+  /// let path = std::env::temp_dir().join("event_sync_auto_persist_doc_example");
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let event_sync = EventSync::new_millis(tickrate);
+  ///
+  /// let listener = event_sync.auto_persist_every(5, &path).unwrap();
+  /// event_sync.wait_until(15).unwrap();
+  /// event_sync.remove_listener(listener);
+  ///
+  /// // The latest checkpoint, at least at tick 5.
+  /// let resumed: EventSync = EventSync::load_from(&path).unwrap();
+  ///
+  /// assert!(resumed.ticks_since_started() >= 5);
+  /// # std::fs::remove_file(path).unwrap();
+  /// ```
+  pub fn auto_persist_every<P: Into<PathBuf>>(
+    &self,
+    n_ticks: u64,
+    path: P,
+  ) -> io::Result<ListenerId> {
+    let path = path.into();
+    let n_ticks = n_ticks.max(1);
+
+    self.save_to(&path)?;
+
+    let inner = self.inner.clone();
+    let writer = SaveWriter::spawn(path);
+
+    Ok(self.add_listener_in_phase(
+      TickPhase::OUTPUT,
+      Box::new(move |tick| {
+        if tick % n_ticks != 0 {
+          return;
+        }
+
+        let snapshot = snapshot::encode(&inner.read().unwrap(), T::IS_MUTABLE);
+
+        if let Ok(snapshot) = snapshot {
+          writer.replace_pending(snapshot);
+        }
+      }),
+    ))
+  }
+}
+
+/// The driver thread's end of an auto persist, handing snapshots to a thread writing them to the file, so a slow
+/// disk doesn't hold up the other listeners.
+///
+/// Only the latest snapshot waits to be written, replacing any older one that hasn't been yet.
+/// The writing thread writes what's left, then ends, once this is dropped along with the listener.
+struct SaveWriter(Arc<PendingSave>);
+
+struct PendingSave {
+  state: Mutex<PendingState>,
+  /// Notified when a snapshot is handed over, or the listener is dropped.
+  changed: Condvar,
+}
+
+struct PendingState {
+  snapshot: Option<Vec<u8>>,
+  closed: bool,
+}
+
+impl SaveWriter {
+  fn spawn(path: PathBuf) -> Self {
+    let pending = Arc::new(PendingSave {
+      state: Mutex::new(PendingState {
+        snapshot: None,
+        closed: false,
+      }),
+      changed: Condvar::new(),
+    });
+
+    {
+      let pending = Arc::clone(&pending);
+
+      std::thread::spawn(move || write_pending_saves(&path, &pending));
+    }
+
+    Self(pending)
+  }
+
+  fn replace_pending(&self, snapshot: Vec<u8>) {
+    self.0.state.lock().unwrap().snapshot = Some(snapshot);
+    self.0.changed.notify_one();
+  }
+}
+
+impl Drop for SaveWriter {
+  fn drop(&mut self) {
+    self.0.state.lock().unwrap().closed = true;
+    self.0.changed.notify_one();
+  }
+}
+
+/// Writes every snapshot handed over until the writer is dropped.
+///
+/// A write that fails is skipped, leaving the previous save in place until the next succeeds.
+fn write_pending_saves(path: &Path, pending: &PendingSave) {
+  loop {
+    let snapshot = {
+      let mut state = pending.state.lock().unwrap();
+
+      loop {
+        if let Some(snapshot) = state.snapshot.take() {
+          break snapshot;
+        }
+
+        if state.closed {
+          return;
+        }
+
+        state = pending.changed.wait(state).unwrap();
+      }
+    };
+
+    let _ = write_atomically(path, &snapshot);
+  }
+}
+
+/// Replaces the file with the contents, through a temporary file renamed over it once it's on disk.
+fn write_atomically(path: &Path, contents: &[u8]) -> io::Result<()> {
+  let mut temporary_path = path.as_os_str().to_owned();
+  temporary_path.push(format!(
+    ".{}.{}.tmp",
+    std::process::id(),
+    NEXT_TEMPORARY_FILE.fetch_add(1, Ordering::Relaxed)
+  ));
+  let temporary_path = PathBuf::from(temporary_path);
+
+  let written = File::create(&temporary_path).and_then(|mut file| {
+    file.write_all(contents)?;
+    file.sync_all()
+  });
+
+  if let Err(error) = written.and_then(|()| fs::rename(&temporary_path, path)) {
+    let _ = fs::remove_file(&temporary_path);
+
+    return Err(error);
+  }
+
+  // The rename itself only survives a crash once the directory holding the file is on disk.
+  #[cfg(unix)]
+  {
+    let directory = match path.parent() {
+      Some(parent) if !parent.as_os_str().is_empty() => parent,
+      _ => Path::new("."),
+    };
+
+    File::open(directory)?.sync_all()?;
+  }
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{ClockSource, Mutable, TimeError};
+  use std::time::Duration;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  fn test_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!(
+      "event_sync_persist_{}_{}",
+      name,
+      std::process::id()
+    ))
+  }
+
+  #[test]
+  fn saves_replace_the_previous_one() {
+    let path = test_path("replace");
+    let mut event_sync = EventSync::from_starting_tick_millis(TEST_TICKRATE, 3, true);
+
+    event_sync.save_to(&path).unwrap();
+    event_sync.sync_to(Duration::from_millis(80)).unwrap();
+    event_sync.save_to(&path).unwrap();
+
+    let loaded = EventSync::<Mutable>::load_from(&path).unwrap();
+
+    assert_eq!(loaded.ticks_since_started(), 8);
+    fs::remove_file(path).unwrap();
+  }
+
+  #[test]
+  fn corrupt_files_are_invalid_data() {
+    let path = test_path("corrupt");
+    fs::write(&path, [0; 10]).unwrap();
+
+    let error = EventSync::<Mutable>::load_from(&path).unwrap_err();
+
+    assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    assert!(matches!(
+      error
+        .into_inner()
+        .unwrap()
+        .downcast::<TimeError>()
+        .as_deref(),
+      Ok(TimeError::CorruptSnapshot(_))
+    ));
+    fs::remove_file(path).unwrap();
+  }
+
+  #[test]
+  fn dropped_writers_write_the_latest_snapshot() {
+    let path = test_path("latest");
+    let writer = SaveWriter::spawn(path.clone());
+
+    writer.replace_pending(vec![1]);
+    writer.replace_pending(vec![2]);
+    drop(writer);
+
+    let mut tries = 0;
+
+    while fs::read(&path).ok() != Some(vec![2]) {
+      assert!(tries < 100, "the latest snapshot was never written");
+
+      tries += 1;
+      std::thread::sleep(Duration::from_millis(TEST_TICKRATE));
+    }
+
+    fs::remove_file(path).unwrap();
+  }
+
+  #[test]
+  fn unsavable_event_syncs_are_not_scheduled() {
+    fn zero() -> Duration {
+      Duration::ZERO
+    }
+
+    let path = test_path("unsavable");
+    let event_sync = EventSync::new_with_clock_millis(TEST_TICKRATE, ClockSource::Custom(zero));
+
+    assert!(event_sync.auto_persist_every(1, &path).is_err());
+    assert_eq!(event_sync.listener_count(), 0);
+    assert!(!path.exists());
+  }
+}
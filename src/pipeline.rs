@@ -0,0 +1,330 @@
+use crate::errors::TimeError;
+use crate::{EventSync, Immutable};
+use std::collections::BTreeMap;
+use std::sync::{Condvar, Mutex, MutexGuard};
+use std::time::Duration;
+
+/// Identifies a stage added to a [`PipelineCoordinator`](PipelineCoordinator).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StageId(usize);
+
+/// Coordinates the stages of a pipeline running at different divisors of one EventSync's ticks, such as capturing
+/// every tick, processing every 2nd, and uploading every 10th.
+///
+/// A stage runs on the ticks its divisor divides, called its boundaries, which are waited for with
+/// [`wait_for_boundary()`](PipelineCoordinator::wait_for_boundary). Each stage
+/// [`publishes`](PipelineCoordinator::publish) what it made on a boundary, and a stage fed by it only reads what was
+/// published on its own boundaries, so a slower stage never consumes data from between the ticks it aligns with.
+///
+/// Stages are added before the coordinator is shared, after which every method can be called from any thread.
+///
+/// # Example
+///
+/// ```
+/// use event_sync::*;
+/// use std::time::Duration;
+///
+/// let tickrate = 10; // 10ms between every tick.
+/// let mut event_sync = EventSync::new_paused_millis(tickrate);
+///
+/// let mut pipeline = PipelineCoordinator::new(event_sync.clone_immutable());
+/// let capture = pipeline.add_stage(1);
+/// let process = pipeline.add_stage_fed_by(capture, 2);
+///
+/// for tick in 1..=4 {
+///   event_sync.sync_to(Duration::from_millis(tick * 10)).unwrap();
+///   assert_eq!(pipeline.wait_for_boundary(capture), Ok(tick));
+///   pipeline.publish(capture, tick, format!("frame {tick}"));
+/// }
+///
+/// // Processing only sees the frames captured on its boundaries.
+/// assert_eq!(pipeline.wait_for_boundary(process), Ok(4));
+/// assert_eq!(pipeline.input_for(process, 4), Some("frame 4".to_string()));
+/// assert_eq!(pipeline.input_for(process, 3), None);
+/// ```
+#[derive(Debug)]
+pub struct PipelineCoordinator<T> {
+  event_sync: EventSync<Immutable>,
+  stages: Vec<Stage>,
+  states: Mutex<Vec<StageState<T>>>,
+  /// Notified whenever a stage publishes.
+  published: Condvar,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Stage {
+  divisor: u64,
+  input: Option<StageId>,
+}
+
+#[derive(Debug)]
+struct StageState<T> {
+  /// The boundary the stage last ran on.
+  last_boundary: Option<u64>,
+  /// What the stage published on each boundary that a stage fed by it may still read.
+  outputs: BTreeMap<u64, T>,
+  latest_published: Option<u64>,
+}
+
+impl<T> PipelineCoordinator<T> {
+  /// Creates a coordinator with no stages.
+  pub fn new(event_sync: EventSync<Immutable>) -> Self {
+    Self {
+      event_sync,
+      stages: Vec::new(),
+      states: Mutex::new(Vec::new()),
+      published: Condvar::new(),
+    }
+  }
+
+  /// Adds a stage running every `divisor` ticks, taking no input from other stages.
+  ///
+  /// The divisor cannot go below 1. If 0 is passed in, 1 is used.
+  pub fn add_stage(&mut self, divisor: u32) -> StageId {
+    self.push_stage(divisor, None)
+  }
+
+  /// Adds a stage running every `divisor` ticks, reading what the input stage publishes on its boundaries.
+  ///
+  /// The input only publishes on its own boundaries, so a divisor that's a multiple of the input's has input on
+  /// every boundary. Others only have input on the boundaries the two share.
+  ///
+  /// The divisor cannot go below 1. If 0 is passed in, 1 is used.
+  pub fn add_stage_fed_by(&mut self, input: StageId, divisor: u32) -> StageId {
+    self.push_stage(divisor, Some(input))
+  }
+
+  fn push_stage(&mut self, divisor: u32, input: Option<StageId>) -> StageId {
+    let id = StageId(self.stages.len());
+
+    self.stages.push(Stage {
+      divisor: divisor.max(1).into(),
+      input,
+    });
+    self.lock_states().push(StageState {
+      last_boundary: None,
+      outputs: BTreeMap::new(),
+      latest_published: None,
+    });
+
+    id
+  }
+
+  /// Returns the amount of ticks between the stage's boundaries, or None if it isn't from this coordinator.
+  pub fn divisor(&self, stage: StageId) -> Option<u64> {
+    self.stages.get(stage.0).map(|stage| stage.divisor)
+  }
+
+  /// Returns true if the stage runs on the tick.
+  pub fn is_boundary(&self, stage: StageId, tick: u64) -> bool {
+    self
+      .divisor(stage)
+      .is_some_and(|divisor| tick.is_multiple_of(divisor))
+  }
+
+  /// Waits for the stage's next boundary, returning the tick it's on.
+  ///
+  /// Every boundary is only returned once. If the stage fell behind, the boundaries it missed are skipped, and the
+  /// latest one that occurred is returned without waiting.
+  ///
+  /// # Errors
+  ///
+  /// - [`EventSyncPaused`](TimeError::EventSyncPaused) is returned if the boundary has to be waited for while the
+  ///   EventSync is paused.
+  /// - [`InvalidState`](TimeError::InvalidState) is returned if the stage isn't from this coordinator.
+  pub fn wait_for_boundary(&self, stage: StageId) -> Result<u64, TimeError> {
+    let divisor = self.divisor(stage).ok_or(TimeError::InvalidState(
+      "the stage isn't from this coordinator",
+    ))?;
+    let last_boundary = self.lock_states()[stage.0].last_boundary;
+    let current_tick = self.event_sync.ticks_since_started();
+    let latest_boundary = current_tick - current_tick % divisor;
+
+    let boundary = match last_boundary {
+      Some(last_boundary) if last_boundary < latest_boundary => latest_boundary,
+      Some(last_boundary) => last_boundary + divisor,
+      None if latest_boundary == current_tick => latest_boundary,
+      None => latest_boundary + divisor,
+    };
+
+    if boundary > current_tick {
+      match self.event_sync.wait_until(boundary) {
+        // The boundary occurred right before waiting.
+        Ok(_) | Err(TimeError::ThatTimeHasAlreadyHappened) => (),
+        Err(error) => return Err(error),
+      }
+    }
+
+    self.lock_states()[stage.0].last_boundary = Some(boundary);
+
+    Ok(boundary)
+  }
+
+  /// Publishes what the stage made on the boundary, for the stages fed by it to read.
+  ///
+  /// Returns false if the tick isn't one of the stage's boundaries, in which case nothing is published.
+  pub fn publish(&self, stage: StageId, tick: u64, value: T) -> bool {
+    if !self.is_boundary(stage, tick) {
+      return false;
+    }
+
+    let mut states = self.lock_states();
+    let consumers: Vec<(u64, Option<u64>)> = self
+      .stages
+      .iter()
+      .zip(states.iter())
+      .filter(|(consumer, _)| consumer.input == Some(stage))
+      .map(|(consumer, state)| (consumer.divisor, state.last_boundary))
+      .collect();
+    let state = &mut states[stage.0];
+
+    state.outputs.insert(tick, value);
+    state.latest_published = state.latest_published.max(Some(tick));
+
+    // Only keeps what a consumer could still read on one of its boundaries.
+    state.outputs.retain(|output_tick, _| {
+      *output_tick == tick
+        || consumers.iter().any(|(divisor, last_boundary)| {
+          output_tick.is_multiple_of(*divisor)
+            && last_boundary.is_none_or(|last_boundary| *output_tick >= last_boundary)
+        })
+    });
+
+    drop(states);
+    self.published.notify_all();
+
+    true
+  }
+
+  /// Returns what the stage's input published on the tick, if the tick is one of the stage's boundaries.
+  pub fn input_for(&self, stage: StageId, tick: u64) -> Option<T>
+  where
+    T: Clone,
+  {
+    let input = self.aligned_input(stage, tick)?;
+
+    self.lock_states()[input.0].outputs.get(&tick).cloned()
+  }
+
+  /// [`input_for()`](PipelineCoordinator::input_for), waiting for the input to publish on the tick.
+  ///
+  /// Gives up once the input publishes on a later tick, or the stage's next boundary occurs, returning None.
+  pub fn wait_for_input(&self, stage: StageId, tick: u64) -> Option<T>
+  where
+    T: Clone,
+  {
+    let input = self.aligned_input(stage, tick)?;
+    let give_up_tick = tick + self.divisor(stage)?;
+    let mut states = self.lock_states();
+
+    loop {
+      let state = &states[input.0];
+
+      if state.latest_published >= Some(tick) {
+        return state.outputs.get(&tick).cloned();
+      }
+
+      if self.event_sync.ticks_since_started() >= give_up_tick {
+        return None;
+      }
+
+      // Re-checked every tick, so giving up is noticed without anything being published.
+      let tickrate = Duration::from_millis(self.event_sync.get_tickrate_millis());
+
+      states = self
+        .published
+        .wait_timeout(states, tickrate)
+        .map_or_else(|poisoned| poisoned.into_inner().0, |(states, _)| states);
+    }
+  }
+
+  /// Returns the stage's input, if the tick is one of the stage's boundaries.
+  fn aligned_input(&self, stage: StageId, tick: u64) -> Option<StageId> {
+    if !self.is_boundary(stage, tick) {
+      return None;
+    }
+
+    self.stages[stage.0].input
+  }
+
+  fn lock_states(&self) -> MutexGuard<'_, Vec<StageState<T>>> {
+    self
+      .states
+      .lock()
+      .unwrap_or_else(|poisoned| poisoned.into_inner())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::Mutable;
+  use std::sync::Arc;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  fn move_to_tick(event_sync: &mut EventSync<Mutable>, tick: u64) {
+    event_sync
+      .sync_to(Duration::from_millis(tick * TEST_TICKRATE))
+      .unwrap();
+  }
+
+  #[test]
+  fn slow_stages_only_read_their_boundaries() {
+    let mut event_sync = EventSync::new_paused_millis(TEST_TICKRATE);
+    let mut pipeline = PipelineCoordinator::new(event_sync.clone_immutable());
+    let capture = pipeline.add_stage(1);
+    let process = pipeline.add_stage_fed_by(capture, 2);
+    let upload = pipeline.add_stage_fed_by(process, 10);
+
+    assert_eq!(pipeline.wait_for_boundary(upload), Ok(0));
+
+    for tick in 1..=10 {
+      move_to_tick(&mut event_sync, tick);
+      pipeline.publish(capture, tick, tick);
+
+      if pipeline.is_boundary(process, tick) {
+        assert_eq!(pipeline.wait_for_boundary(process), Ok(tick));
+
+        let captured = pipeline.input_for(process, tick).unwrap();
+        assert!(pipeline.publish(process, tick, captured * 100));
+      }
+    }
+
+    assert!(!pipeline.publish(process, 3, 0));
+    assert_eq!(pipeline.input_for(process, 5), None);
+
+    // The boundaries between 0 and 10 were missed, so the stage skips straight to 10.
+    assert_eq!(pipeline.wait_for_boundary(upload), Ok(10));
+    assert_eq!(pipeline.input_for(upload, 10), Some(1_000));
+    assert_eq!(
+      pipeline.wait_for_boundary(upload),
+      Err(TimeError::EventSyncPaused)
+    );
+  }
+
+  #[test]
+  fn waiting_for_input_sees_other_threads_publish() {
+    let event_sync = EventSync::new_millis(TEST_TICKRATE);
+    let mut pipeline = PipelineCoordinator::new(event_sync.clone_immutable());
+    let capture = pipeline.add_stage(1);
+    let process = pipeline.add_stage_fed_by(capture, 3);
+    let pipeline = Arc::new(pipeline);
+
+    let capturing = {
+      let pipeline = pipeline.clone();
+
+      std::thread::spawn(move || loop {
+        let boundary = pipeline.wait_for_boundary(capture).unwrap();
+        pipeline.publish(capture, boundary, boundary);
+
+        if boundary >= 3 {
+          return;
+        }
+      })
+    };
+
+    assert_eq!(pipeline.wait_for_input(process, 3), Some(3));
+    capturing.join().unwrap();
+  }
+}
@@ -0,0 +1,232 @@
+//! Exposing the health of an EventSync's clock as Prometheus metrics.
+//!
+//! Enabled with the `prometheus` feature.
+//! Wake lateness quantiles are only included when the `hdrhistogram` feature is also enabled.
+
+use crate::{EventSync, Immutable};
+use std::fmt::Write as _;
+use std::io::{self, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::thread::JoinHandle;
+
+/// The largest request that will be read from a connection before responding.
+const MAX_REQUEST_SIZE: usize = 8 * 1024;
+
+/// The quantiles of wake lateness that are exported.
+#[cfg(feature = "hdrhistogram")]
+const LATENESS_QUANTILES: [f64; 4] = [0.5, 0.9, 0.99, 0.999];
+
+impl<T> EventSync<T> {
+  /// Returns the current state of the clock in the Prometheus text format.
+  ///
+  /// This can be returned from any HTTP handler, or served directly with a
+  /// [`PrometheusExporter`](PrometheusExporter).
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::*;
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let event_sync = EventSync::from_starting_tick_millis(tickrate, 423, true);
+  ///
+  /// let metrics = event_sync.prometheus_metrics();
+  ///
+  /// assert!(metrics.contains("event_sync_tick 423\n"));
+  /// assert!(metrics.contains("event_sync_paused 1\n"));
+  /// ```
+  pub fn prometheus_metrics(&self) -> String {
+    let view = self.view();
+    let stats = self.stats();
+    let mut metrics = String::new();
+
+    let gauges = [
+      (
+        "event_sync_tick",
+        "The amount of ticks that have occurred.",
+        view.tick.to_string(),
+      ),
+      (
+        "event_sync_tickrate_seconds",
+        "The time between every tick.",
+        (view.tickrate as f64 / 1000.0).to_string(),
+      ),
+      (
+        "event_sync_paused",
+        "1 if the clock is paused, 0 if running.",
+        (view.is_paused as u8).to_string(),
+      ),
+      (
+        "event_sync_generation",
+        "The amount of times the timeline has been reset.",
+        view.generation.to_string(),
+      ),
+      (
+        "event_sync_waiters",
+        "The amount of waits currently sleeping.",
+        stats.waiting().to_string(),
+      ),
+      (
+        "event_sync_wake_lateness_max_seconds",
+        "The latest any wait has woken up.",
+        stats.max_lateness().as_secs_f64().to_string(),
+      ),
+    ];
+
+    for (name, help, value) in gauges {
+      let _ = write!(
+        metrics,
+        "# HELP {name} {help}\n# TYPE {name} gauge\n{name} {value}\n"
+      );
+    }
+
+    let _ = write!(
+      metrics,
+      "# HELP event_sync_missed_ticks_total Ticks that passed while waits were late to wake up.\n\
+       # TYPE event_sync_missed_ticks_total counter\n\
+       event_sync_missed_ticks_total {}\n",
+      stats.missed_ticks()
+    );
+
+    let _ = write!(
+      metrics,
+      "# HELP event_sync_wake_lateness_seconds How late waits woke up.\n\
+       # TYPE event_sync_wake_lateness_seconds summary\n"
+    );
+
+    #[cfg(feature = "hdrhistogram")]
+    for quantile in LATENESS_QUANTILES {
+      let lateness = stats.latency_histogram().value_at_quantile(quantile) as f64 / 1e9;
+
+      let _ = writeln!(
+        metrics,
+        "event_sync_wake_lateness_seconds{{quantile=\"{quantile}\"}} {lateness}"
+      );
+    }
+
+    let _ = write!(
+      metrics,
+      "event_sync_wake_lateness_seconds_sum {}\nevent_sync_wake_lateness_seconds_count {}\n",
+      stats.total_lateness().as_secs_f64(),
+      stats.wakes()
+    );
+
+    metrics
+  }
+}
+
+/// A tiny HTTP server answering every request with the [`prometheus_metrics()`](EventSync::prometheus_metrics) of
+/// an EventSync.
+///
+/// # Example
+///
+/// ```
+/// use event_sync::*;
+/// use std::io::{Read, Write};
+/// use std::net::TcpStream;
+///
+/// let event_sync = EventSync::new_millis(10);
+/// let exporter = PrometheusExporter::bind("127.0.0.1:0", event_sync.clone_immutable()).unwrap();
+/// let address = exporter.local_addr().unwrap();
+/// exporter.spawn();
+///
+/// // Normally this is done by Prometheus scraping the address.
+/// let mut stream = TcpStream::connect(address).unwrap();
+/// stream.write_all(b"GET /metrics HTTP/1.1\r\n\r\n").unwrap();
+///
+/// let mut response = String::new();
+/// stream.read_to_string(&mut response).unwrap();
+///
+/// assert!(response.starts_with("HTTP/1.1 200 OK"));
+/// assert!(response.contains("event_sync_tickrate_seconds 0.01"));
+/// ```
+pub struct PrometheusExporter {
+  listener: TcpListener,
+  event_sync: EventSync<Immutable>,
+}
+
+impl PrometheusExporter {
+  /// Creates an exporter listening on the given address.
+  ///
+  /// # Errors
+  ///
+  /// - If the address could not be bound.
+  pub fn bind<A: ToSocketAddrs>(address: A, event_sync: EventSync<Immutable>) -> io::Result<Self> {
+    Ok(Self {
+      listener: TcpListener::bind(address)?,
+      event_sync,
+    })
+  }
+
+  /// Returns the address the exporter is listening on.
+  pub fn local_addr(&self) -> io::Result<SocketAddr> {
+    self.listener.local_addr()
+  }
+
+  /// Serves metrics on the current thread.
+  ///
+  /// # Errors
+  ///
+  /// - If accepting a connection fails.
+  pub fn serve(self) -> io::Result<()> {
+    loop {
+      let (stream, _) = self.listener.accept()?;
+
+      // A misbehaving scraper shouldn't take down the exporter.
+      let _ = self.handle_connection(stream);
+    }
+  }
+
+  /// Serves metrics on a separate thread.
+  pub fn spawn(self) -> JoinHandle<io::Result<()>> {
+    std::thread::spawn(move || self.serve())
+  }
+
+  /// Reads the request headers, then responds with the metrics and closes the connection.
+  fn handle_connection(&self, mut stream: TcpStream) -> io::Result<()> {
+    let mut request = Vec::new();
+    let mut buffer = [0; 1024];
+
+    while !request.ends_with(b"\r\n\r\n") && request.len() < MAX_REQUEST_SIZE {
+      let read = stream.read(&mut buffer)?;
+
+      if read == 0 {
+        break;
+      }
+
+      request.extend_from_slice(&buffer[..read]);
+    }
+
+    let body = self.event_sync.prometheus_metrics();
+
+    write!(
+      stream,
+      "HTTP/1.1 200 OK\r\n\
+       Content-Type: text/plain; version=0.0.4\r\n\
+       Content-Length: {}\r\n\
+       Connection: close\r\n\r\n{body}",
+      body.len()
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn every_metric_has_a_type() {
+    let event_sync = EventSync::new_millis(10);
+    event_sync.wait_for_tick().unwrap();
+
+    let metrics = event_sync.prometheus_metrics();
+    let types = metrics
+      .lines()
+      .filter(|line| line.starts_with("# TYPE"))
+      .count();
+
+    assert_eq!(types, 8);
+    assert!(metrics.contains("event_sync_wake_lateness_seconds_count 1\n"));
+    assert!(metrics.contains("event_sync_waiters 0\n"));
+  }
+}
@@ -0,0 +1,180 @@
+use crate::errors::TimeError;
+use crate::{EventSync, Immutable};
+use std::sync::{Mutex, MutexGuard};
+
+/// A token bucket that refills a set amount of permits on every tick of an EventSync.
+///
+/// As permits only refill while the EventSync is running, pausing the EventSync also pauses the limiter.
+///
+/// # Example
+///
+/// ```
+/// use event_sync::*;
+///
+/// let tickrate = 10; // 10ms between every tick.
+/// let event_sync = EventSync::new_millis(tickrate);
+///
+/// // Allow 2 requests every tick.
+/// let limiter = RateLimiter::new(event_sync.clone_immutable(), 2);
+///
+/// limiter.try_acquire().unwrap();
+/// limiter.try_acquire().unwrap();
+/// assert_eq!(limiter.try_acquire(), Err(TimeError::RateLimited));
+///
+/// // Waits for the next tick to refill the permits.
+/// limiter.acquire().unwrap();
+/// ```
+pub struct RateLimiter {
+  event_sync: EventSync<Immutable>,
+  permits_per_tick: u32,
+  capacity: u32,
+  bucket: Mutex<Bucket>,
+}
+
+/// The permits currently available, and the tick they were last refilled on.
+struct Bucket {
+  permits: u32,
+  last_refill_tick: u64,
+}
+
+impl RateLimiter {
+  /// Creates a full limiter that refills the given amount of permits every tick.
+  ///
+  /// Permits don't build up past the amount refilled in one tick, call
+  /// [`with_capacity()`](RateLimiter::with_capacity) to allow bursts.
+  pub fn new(event_sync: EventSync<Immutable>, permits_per_tick: u32) -> Self {
+    let last_refill_tick = event_sync.ticks_since_started();
+
+    Self {
+      event_sync,
+      permits_per_tick,
+      capacity: permits_per_tick,
+      bucket: Mutex::new(Bucket {
+        permits: permits_per_tick,
+        last_refill_tick,
+      }),
+    }
+  }
+
+  /// Sets the most permits that can build up while unused, and fills the limiter to it.
+  pub fn with_capacity(mut self, capacity: u32) -> Self {
+    self.capacity = capacity;
+    self.bucket.get_mut().unwrap().permits = capacity;
+
+    self
+  }
+
+  /// Returns the amount of permits that can currently be taken.
+  pub fn available_permits(&self) -> u32 {
+    self.refilled_bucket().permits
+  }
+
+  /// Takes a permit if one is available.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if every permit has been taken until the next tick.
+  pub fn try_acquire(&self) -> Result<(), TimeError> {
+    let mut bucket = self.refilled_bucket();
+
+    if bucket.permits == 0 {
+      return Err(TimeError::RateLimited);
+    }
+
+    bucket.permits -= 1;
+
+    Ok(())
+  }
+
+  /// Takes a permit, waiting for the next tick if none are available.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if the EventSync is paused while no permits are available.
+  pub fn acquire(&self) -> Result<(), TimeError> {
+    loop {
+      let next_tick = {
+        let mut bucket = self.refilled_bucket();
+
+        if bucket.permits > 0 {
+          bucket.permits -= 1;
+
+          return Ok(());
+        }
+
+        bucket.last_refill_tick + 1
+      };
+
+      match self.event_sync.wait_until(next_tick) {
+        Ok(_) | Err(TimeError::ThatTimeHasAlreadyHappened) => (),
+        Err(error) => return Err(error),
+      }
+    }
+  }
+
+  /// Adds the permits for every tick since the last refill, and returns the bucket.
+  ///
+  /// If the EventSync was restarted, the bucket continues from the new tick without refilling.
+  fn refilled_bucket(&self) -> MutexGuard<'_, Bucket> {
+    let ticks_since_started = self.event_sync.ticks_since_started();
+    let mut bucket = self.bucket.lock().unwrap();
+
+    if ticks_since_started > bucket.last_refill_tick {
+      let refill = (ticks_since_started - bucket.last_refill_tick)
+        .saturating_mul(self.permits_per_tick as u64)
+        .min(self.capacity as u64) as u32;
+
+      bucket.permits = bucket.permits.saturating_add(refill).min(self.capacity);
+    }
+
+    bucket.last_refill_tick = ticks_since_started;
+
+    bucket
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  #[test]
+  fn permits_refill_every_tick() {
+    let event_sync = EventSync::new_millis(TEST_TICKRATE);
+    let limiter = RateLimiter::new(event_sync.clone_immutable(), 2);
+
+    limiter.try_acquire().unwrap();
+    limiter.try_acquire().unwrap();
+    assert_eq!(limiter.try_acquire(), Err(TimeError::RateLimited));
+
+    event_sync.wait_for_tick().unwrap();
+
+    assert_eq!(limiter.available_permits(), 2);
+  }
+
+  #[test]
+  fn permits_build_up_to_capacity() {
+    let event_sync = EventSync::new_millis(TEST_TICKRATE);
+    let limiter = RateLimiter::new(event_sync.clone_immutable(), 1).with_capacity(3);
+
+    for _ in 0..3 {
+      limiter.try_acquire().unwrap();
+    }
+
+    event_sync.wait_for_x_ticks(5).unwrap();
+
+    assert_eq!(limiter.available_permits(), 3);
+  }
+
+  #[test]
+  fn acquire_errors_while_paused() {
+    let mut event_sync = EventSync::new_millis(TEST_TICKRATE);
+    let limiter = RateLimiter::new(event_sync.clone_immutable(), 1);
+
+    limiter.acquire().unwrap();
+    event_sync.pause();
+
+    assert_eq!(limiter.acquire(), Err(TimeError::EventSyncPaused));
+  }
+}
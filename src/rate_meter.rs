@@ -0,0 +1,161 @@
+use crate::{EventSync, Immutable};
+use std::collections::VecDeque;
+use std::sync::{Mutex, MutexGuard};
+
+/// Measures how often events happen over a sliding window of the last few ticks of an EventSync.
+///
+/// Events are counted against the tick they were recorded on, so throughput is measured in the EventSync's time.
+/// While the EventSync is paused, the window stops sliding, so the rate holds where it was.
+/// Moving the timeline back, such as by restarting, forgets every event recorded after the point it was moved to.
+///
+/// # Example
+///
+/// ```
+/// use event_sync::*;
+/// use std::time::Duration;
+///
+/// let tickrate = 10; // 10ms between every tick.
+/// let mut event_sync = EventSync::new_paused_millis(tickrate);
+///
+/// // Measured over the last 4 ticks.
+/// let meter = RateMeter::new(event_sync.clone_immutable(), 4);
+///
+/// meter.record_many(6);
+/// event_sync.sync_to(Duration::from_millis(20)).unwrap();
+/// meter.record_many(2);
+///
+/// assert_eq!(meter.count(), 8);
+/// assert_eq!(meter.per_tick(), 2.0);
+/// assert_eq!(meter.events_per(10), 20.0);
+///
+/// // The first 6 events slide out of the window.
+/// event_sync.sync_to(Duration::from_millis(40)).unwrap();
+///
+/// assert_eq!(meter.count(), 2);
+/// ```
+#[derive(Debug)]
+pub struct RateMeter {
+  event_sync: EventSync<Immutable>,
+  window_ticks: u64,
+  /// The events recorded on each tick still in the window, oldest first.
+  buckets: Mutex<VecDeque<(u64, u64)>>,
+}
+
+impl RateMeter {
+  /// Creates a meter with no events, measuring over the given amount of ticks.
+  ///
+  /// The window cannot go below 1 tick. If 0 is passed in, 1 is used.
+  pub fn new(event_sync: EventSync<Immutable>, window_ticks: u64) -> Self {
+    Self {
+      event_sync,
+      window_ticks: window_ticks.max(1),
+      buckets: Mutex::new(VecDeque::new()),
+    }
+  }
+
+  /// Returns the amount of ticks the rate is measured over.
+  pub fn window_ticks(&self) -> u64 {
+    self.window_ticks
+  }
+
+  /// Records an event on the current tick.
+  pub fn record(&self) {
+    self.record_many(1);
+  }
+
+  /// Records the given amount of events on the current tick.
+  pub fn record_many(&self, events: u64) {
+    let current_tick = self.event_sync.ticks_since_started();
+    let mut buckets = self.slid_buckets(current_tick);
+
+    match buckets.back_mut() {
+      Some((tick, count)) if *tick == current_tick => *count = count.saturating_add(events),
+      _ => buckets.push_back((current_tick, events)),
+    }
+  }
+
+  /// Returns the amount of events recorded in the window, which is the current tick and the ones before it.
+  pub fn count(&self) -> u64 {
+    let current_tick = self.event_sync.ticks_since_started();
+
+    self
+      .slid_buckets(current_tick)
+      .iter()
+      .fold(0, |total, (_, count)| total.saturating_add(*count))
+  }
+
+  /// Returns the average amount of events per tick over the window.
+  pub fn per_tick(&self) -> f64 {
+    self.count() as f64 / self.window_ticks as f64
+  }
+
+  /// Returns the average amount of events every n_ticks ticks over the window.
+  pub fn events_per(&self, n_ticks: u64) -> f64 {
+    self.per_tick() * n_ticks as f64
+  }
+
+  /// Forgets every event recorded.
+  pub fn reset(&self) {
+    self.lock_buckets().clear();
+  }
+
+  /// Locks the buckets, first dropping any that have slid out of the window or come after the current tick.
+  fn slid_buckets(&self, current_tick: u64) -> MutexGuard<'_, VecDeque<(u64, u64)>> {
+    let mut buckets = self.lock_buckets();
+    let oldest_tick = current_tick.saturating_sub(self.window_ticks - 1);
+
+    while buckets.back().is_some_and(|(tick, _)| *tick > current_tick) {
+      buckets.pop_back();
+    }
+
+    while buckets.front().is_some_and(|(tick, _)| *tick < oldest_tick) {
+      buckets.pop_front();
+    }
+
+    buckets
+  }
+
+  fn lock_buckets(&self) -> MutexGuard<'_, VecDeque<(u64, u64)>> {
+    self
+      .buckets
+      .lock()
+      .unwrap_or_else(|poisoned| poisoned.into_inner())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::Mutable;
+  use std::time::Duration;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  fn move_to_tick(event_sync: &mut EventSync<Mutable>, tick: u64) {
+    event_sync
+      .sync_to(Duration::from_millis(tick * TEST_TICKRATE))
+      .unwrap();
+  }
+
+  #[test]
+  fn events_slide_out_of_the_window() {
+    let mut event_sync = EventSync::from_starting_tick_millis(TEST_TICKRATE, 10, true);
+    let meter = RateMeter::new(event_sync.clone_immutable(), 3);
+
+    for tick in 10..15 {
+      move_to_tick(&mut event_sync, tick);
+      meter.record_many(tick);
+    }
+
+    // Ticks 12 to 14.
+    assert_eq!(meter.count(), 12 + 13 + 14);
+
+    move_to_tick(&mut event_sync, 16);
+
+    assert_eq!(meter.count(), 14);
+
+    move_to_tick(&mut event_sync, 13);
+
+    assert_eq!(meter.count(), 0);
+  }
+}
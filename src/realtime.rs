@@ -0,0 +1,391 @@
+//! Running tick-critical loops on a pinned thread with a raised scheduling priority, to cut down the jitter in
+//! when each tick is woken up for.
+//!
+//! Enabled with the `realtime` feature. Pinning and raising the priority are supported on Linux and Windows, and do
+//! nothing elsewhere.
+
+use crate::tick_loop::{LoopControl, TickContext};
+use crate::EventSync;
+use std::marker::PhantomData;
+
+/// What to change about the calling thread while a [`RealtimeGuard`](RealtimeGuard) is held.
+///
+/// Nothing is changed by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct RealtimeOptions {
+  core: Option<usize>,
+  priority: Option<u8>,
+}
+
+impl RealtimeOptions {
+  /// Creates options that change nothing.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Pins the thread to the CPU core with the given index, so it isn't moved between cores.
+  pub fn pinned_to(mut self, core: usize) -> Self {
+    self.core = Some(core);
+
+    self
+  }
+
+  /// Raises the thread's scheduling priority.
+  ///
+  /// On Linux, the thread is scheduled with `SCHED_FIFO` at the given priority, clamped to the range the system
+  /// allows, usually 1 to 99. On Windows, the thread joins the "Pro Audio" MMCSS task, at critical priority from 50
+  /// and up, and high priority below that.
+  pub fn with_priority(mut self, priority: u8) -> Self {
+    self.priority = Some(priority);
+
+    self
+  }
+}
+
+/// Which of the [`RealtimeOptions`](RealtimeOptions) were applied to the thread.
+///
+/// Options that need privileges the process doesn't have, or aren't supported by the platform, aren't applied,
+/// and the thread carries on as it was.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct RealtimeApplied {
+  /// The thread was pinned to the core.
+  pub pinned: bool,
+  /// The thread's scheduling priority was raised.
+  pub prioritized: bool,
+}
+
+/// Applies [`RealtimeOptions`](RealtimeOptions) to the calling thread until it's dropped, when the thread's previous
+/// affinity and priority are restored.
+///
+/// Applying the options never fails. Whatever couldn't be applied is left as it was, and reported by
+/// [`applied()`](RealtimeGuard::applied).
+///
+/// The guard changes the thread it was created on, so it can't be sent to another thread.
+///
+/// # Example
+///
+/// ```
+/// use event_sync::*;
+///
+/// let tickrate = 1; // 1ms between every tick.
+/// let event_sync = EventSync::new_millis(tickrate);
+///
+/// {
+///   let guard = RealtimeGuard::enter(RealtimeOptions::new().pinned_to(0).with_priority(80));
+///
+///   // Without privileges, the priority is left as it was.
+///   println!("Running with {:?}", guard.applied());
+///
+///   for _ in 0..10 {
+///     event_sync.wait_for_tick().unwrap();
+///   }
+/// }
+/// ```
+pub struct RealtimeGuard {
+  applied: RealtimeApplied,
+  restore: platform::Restore,
+  /// Keeps the guard on the thread it changed.
+  not_send: PhantomData<*const ()>,
+}
+
+impl RealtimeGuard {
+  /// Applies the options to the calling thread.
+  pub fn enter(options: RealtimeOptions) -> Self {
+    let mut restore = platform::Restore::default();
+    let applied = RealtimeApplied {
+      pinned: options
+        .core
+        .is_some_and(|core| platform::pin(core, &mut restore)),
+      prioritized: options
+        .priority
+        .is_some_and(|priority| platform::raise_priority(priority, &mut restore)),
+    };
+
+    Self {
+      applied,
+      restore,
+      not_send: PhantomData,
+    }
+  }
+
+  /// Returns which of the options were applied.
+  pub fn applied(&self) -> RealtimeApplied {
+    self.applied
+  }
+}
+
+impl Drop for RealtimeGuard {
+  fn drop(&mut self) {
+    platform::restore(&mut self.restore);
+  }
+}
+
+impl std::fmt::Debug for RealtimeGuard {
+  fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    formatter
+      .debug_struct("RealtimeGuard")
+      .field("applied", &self.applied)
+      .finish_non_exhaustive()
+  }
+}
+
+impl<T> EventSync<T> {
+  /// [`run_every_tick()`](EventSync::run_every_tick) with the options applied to the calling thread for as long as
+  /// the loop runs, returning which of them were applied.
+  ///
+  /// Useful at tickrates of 1 or 2 milliseconds, where being woken late by the scheduler is most of a tick's jitter.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::*;
+  ///
+  /// let tickrate = 1; // 1ms between every tick.
+  /// let event_sync = EventSync::new_millis(tickrate);
+  /// let options = RealtimeOptions::new().pinned_to(0).with_priority(80);
+  ///
+  /// let applied = event_sync.run_every_tick_realtime(options, |context| {
+  ///   // Poll the hardware.
+  ///
+  ///   if context.iteration == 9 {
+  ///     LoopControl::Break
+  ///   } else {
+  ///     LoopControl::Continue
+  ///   }
+  /// });
+  ///
+  /// if !applied.prioritized {
+  ///   eprintln!("Running without a raised priority.");
+  /// }
+  /// ```
+  pub fn run_every_tick_realtime<F: FnMut(&TickContext) -> LoopControl>(
+    &self,
+    options: RealtimeOptions,
+    closure: F,
+  ) -> RealtimeApplied {
+    let guard = RealtimeGuard::enter(options);
+
+    self.run_every_tick(closure);
+
+    guard.applied()
+  }
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+  use std::mem;
+
+  /// The thread's settings from before they were changed.
+  #[derive(Default)]
+  pub(super) struct Restore {
+    affinity: Option<libc::cpu_set_t>,
+    schedule: Option<(libc::c_int, libc::sched_param)>,
+  }
+
+  pub(super) fn pin(core: usize, restore: &mut Restore) -> bool {
+    if core >= libc::CPU_SETSIZE as usize {
+      return false;
+    }
+
+    // Safety: Both sets are valid cpu_set_ts of the size passed in, and 0 is the calling thread.
+    unsafe {
+      let mut previous: libc::cpu_set_t = mem::zeroed();
+
+      if libc::sched_getaffinity(0, mem::size_of::<libc::cpu_set_t>(), &mut previous) != 0 {
+        return false;
+      }
+
+      let mut pinned: libc::cpu_set_t = mem::zeroed();
+      libc::CPU_SET(core, &mut pinned);
+
+      if libc::sched_setaffinity(0, mem::size_of::<libc::cpu_set_t>(), &pinned) != 0 {
+        return false;
+      }
+
+      restore.affinity = Some(previous);
+    }
+
+    true
+  }
+
+  pub(super) fn raise_priority(priority: u8, restore: &mut Restore) -> bool {
+    // Safety: The policy and params are valid to write to, and the thread is the calling thread.
+    unsafe {
+      let thread = libc::pthread_self();
+      let mut previous_policy = 0;
+      let mut previous_param: libc::sched_param = mem::zeroed();
+
+      if libc::pthread_getschedparam(thread, &mut previous_policy, &mut previous_param) != 0 {
+        return false;
+      }
+
+      let min = libc::sched_get_priority_min(libc::SCHED_FIFO);
+      let max = libc::sched_get_priority_max(libc::SCHED_FIFO);
+      let mut param: libc::sched_param = mem::zeroed();
+      param.sched_priority = libc::c_int::from(priority).clamp(min, max);
+
+      // Fails without CAP_SYS_NICE or a real-time rlimit, leaving the thread as it was.
+      if libc::pthread_setschedparam(thread, libc::SCHED_FIFO, &param) != 0 {
+        return false;
+      }
+
+      restore.schedule = Some((previous_policy, previous_param));
+    }
+
+    true
+  }
+
+  pub(super) fn restore(restore: &mut Restore) {
+    // Safety: The settings were read from the calling thread, which the guard never leaves.
+    unsafe {
+      if let Some((policy, param)) = restore.schedule.take() {
+        libc::pthread_setschedparam(libc::pthread_self(), policy, &param);
+      }
+
+      if let Some(affinity) = restore.affinity.take() {
+        libc::sched_setaffinity(0, mem::size_of::<libc::cpu_set_t>(), &affinity);
+      }
+    }
+  }
+}
+
+#[cfg(windows)]
+mod platform {
+  use std::ffi::c_void;
+
+  const AVRT_PRIORITY_HIGH: i32 = 1;
+  const AVRT_PRIORITY_CRITICAL: i32 = 2;
+
+  #[link(name = "kernel32")]
+  extern "system" {
+    fn GetCurrentThread() -> *mut c_void;
+    fn SetThreadAffinityMask(thread: *mut c_void, mask: usize) -> usize;
+  }
+
+  #[link(name = "avrt")]
+  extern "system" {
+    fn AvSetMmThreadCharacteristicsW(task_name: *const u16, task_index: *mut u32) -> *mut c_void;
+    fn AvSetMmThreadPriority(handle: *mut c_void, priority: i32) -> i32;
+    fn AvRevertMmThreadCharacteristics(handle: *mut c_void) -> i32;
+  }
+
+  /// The thread's settings from before they were changed.
+  #[derive(Default)]
+  pub(super) struct Restore {
+    affinity: Option<usize>,
+    mmcss_task: Option<*mut c_void>,
+  }
+
+  pub(super) fn pin(core: usize, restore: &mut Restore) -> bool {
+    if core >= usize::BITS as usize {
+      return false;
+    }
+
+    // Safety: The pseudo handle of the calling thread is always valid.
+    let previous = unsafe { SetThreadAffinityMask(GetCurrentThread(), 1 << core) };
+
+    if previous == 0 {
+      return false;
+    }
+
+    restore.affinity = Some(previous);
+
+    true
+  }
+
+  pub(super) fn raise_priority(priority: u8, restore: &mut Restore) -> bool {
+    let task_name: Vec<u16> = "Pro Audio\0".encode_utf16().collect();
+    let mut task_index = 0;
+
+    // Safety: The task name is null terminated, and the index is valid to write to.
+    let task = unsafe { AvSetMmThreadCharacteristicsW(task_name.as_ptr(), &mut task_index) };
+
+    if task.is_null() {
+      return false;
+    }
+
+    let priority = if priority >= 50 {
+      AVRT_PRIORITY_CRITICAL
+    } else {
+      AVRT_PRIORITY_HIGH
+    };
+
+    // Safety: The task handle was just returned for the calling thread.
+    unsafe { AvSetMmThreadPriority(task, priority) };
+    restore.mmcss_task = Some(task);
+
+    true
+  }
+
+  pub(super) fn restore(restore: &mut Restore) {
+    // Safety: The task handle and affinity were taken from the calling thread, which the guard never leaves.
+    unsafe {
+      if let Some(task) = restore.mmcss_task.take() {
+        AvRevertMmThreadCharacteristics(task);
+      }
+
+      if let Some(affinity) = restore.affinity.take() {
+        SetThreadAffinityMask(GetCurrentThread(), affinity);
+      }
+    }
+  }
+}
+
+#[cfg(not(any(target_os = "linux", windows)))]
+mod platform {
+  /// Nothing is changed on this platform, so there's nothing to restore.
+  #[derive(Default)]
+  pub(super) struct Restore;
+
+  pub(super) fn pin(_core: usize, _restore: &mut Restore) -> bool {
+    false
+  }
+
+  pub(super) fn raise_priority(_priority: u8, _restore: &mut Restore) -> bool {
+    false
+  }
+
+  pub(super) fn restore(_restore: &mut Restore) {}
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+  use super::*;
+  use std::mem;
+
+  /// Returns the cores the calling thread can run on.
+  fn current_cores() -> Vec<usize> {
+    // Safety: The set is a valid cpu_set_t of the size passed in.
+    unsafe {
+      let mut affinity: libc::cpu_set_t = mem::zeroed();
+      libc::sched_getaffinity(0, mem::size_of::<libc::cpu_set_t>(), &mut affinity);
+
+      (0..libc::CPU_SETSIZE as usize)
+        .filter(|core| libc::CPU_ISSET(*core, &affinity))
+        .collect()
+    }
+  }
+
+  #[test]
+  fn the_thread_is_restored_after_the_loop() {
+    let event_sync = EventSync::new_millis(1);
+    let cores = current_cores();
+    let options = RealtimeOptions::new().pinned_to(0).with_priority(10);
+
+    let applied = event_sync.run_every_tick_realtime(options, |context| {
+      if context.iteration == 2 {
+        LoopControl::Break
+      } else {
+        LoopControl::Continue
+      }
+    });
+
+    assert!(applied.pinned);
+    assert_eq!(current_cores(), cores);
+    assert!(
+      !RealtimeGuard::enter(RealtimeOptions::new().pinned_to(usize::MAX))
+        .applied()
+        .pinned
+    );
+  }
+}
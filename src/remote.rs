@@ -0,0 +1,347 @@
+//! Administering an EventSync from another machine over TCP.
+//!
+//! Enabled with the `net` feature.
+//!
+//! Every message is a big-endian u32 length followed by that many bytes of json.
+//! The first message a client sends must be the token the server was created with, any other connection is dropped.
+//! Clients have five seconds to send it, and only a few clients can be waiting to send it at once.
+//!
+//! The token is sent in plain text, so it isn't authentication over an untrusted network. See the security section
+//! on [`EventSyncTcpServer`](EventSyncTcpServer).
+
+use crate::control::{ClockStatus, ControlRequest, ControlResponse};
+use crate::{EventSync, Mutable};
+use serde::{de::DeserializeOwned, Serialize};
+use std::io::{self, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+/// The largest message that will be read from a connection.
+const MAX_MESSAGE_SIZE: u32 = 64 * 1024;
+
+/// How long a client has to send its token before it's disconnected.
+const AUTH_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The most connections that can be waiting to send their token at once. Any more are dropped as soon as they're
+/// accepted.
+const MAX_UNAUTHENTICATED_CONNECTIONS: usize = 16;
+
+/// Serves authenticated control requests for an EventSync over TCP.
+///
+/// # Security
+///
+/// The token is sent as is, without encryption or a challenge, so anyone who can see the traffic can reuse it.
+/// It keeps out clients that don't know the token, but isn't authentication over an untrusted network.
+/// Only serve on a trusted network, or behind a tunnel such as SSH or a VPN.
+///
+/// # Example
+///
+/// ```
+/// use event_sync::*;
+///
+/// let event_sync = EventSync::new_millis(10);
+/// let server = EventSyncTcpServer::bind("127.0.0.1:0", event_sync.clone(), "secret").unwrap();
+/// let address = server.local_addr().unwrap();
+/// server.spawn();
+///
+/// // This could be on any other machine.
+/// let mut client = EventSyncTcpClient::connect(address, "secret").unwrap();
+/// client.change_tickrate_millis(20).unwrap();
+///
+/// assert_eq!(event_sync.get_tickrate_millis(), 20);
+/// ```
+pub struct EventSyncTcpServer {
+  listener: TcpListener,
+  event_sync: EventSync<Mutable>,
+  token: Arc<str>,
+  unauthenticated_connections: Arc<AtomicUsize>,
+}
+
+/// Counts a connection as waiting to send its token until dropped.
+struct UnauthenticatedConnection(Arc<AtomicUsize>);
+
+/// Sends authenticated control requests to an [`EventSyncTcpServer`](EventSyncTcpServer).
+pub struct EventSyncTcpClient {
+  stream: TcpStream,
+}
+
+impl EventSyncTcpServer {
+  /// Creates a server listening on the given address, only accepting clients that know the token.
+  ///
+  /// # Errors
+  ///
+  /// - If the address could not be bound.
+  pub fn bind<A: ToSocketAddrs>(
+    address: A,
+    event_sync: EventSync<Mutable>,
+    token: &str,
+  ) -> io::Result<Self> {
+    Ok(Self {
+      listener: TcpListener::bind(address)?,
+      event_sync,
+      token: token.into(),
+      unauthenticated_connections: Arc::default(),
+    })
+  }
+
+  /// Returns the address the server is listening on.
+  pub fn local_addr(&self) -> io::Result<SocketAddr> {
+    self.listener.local_addr()
+  }
+
+  /// Accepts connections on the current thread.
+  ///
+  /// Each connection is handled on a thread of its own, so a slow or idle client can't hold up any other.
+  /// Connections are dropped straight away while too many others are yet to send their token.
+  ///
+  /// # Errors
+  ///
+  /// - If accepting a connection fails.
+  pub fn serve(self) -> io::Result<()> {
+    loop {
+      let (stream, _) = self.listener.accept()?;
+
+      // Connections are only counted on this thread, so the count can't go over the limit between checking it and
+      // adding to it.
+      if self.unauthenticated_connections.load(Ordering::Acquire) >= MAX_UNAUTHENTICATED_CONNECTIONS
+      {
+        continue;
+      }
+
+      self
+        .unauthenticated_connections
+        .fetch_add(1, Ordering::AcqRel);
+      let unauthenticated = UnauthenticatedConnection(self.unauthenticated_connections.clone());
+      let event_sync = self.event_sync.clone();
+      let token = self.token.clone();
+
+      // A misbehaving client shouldn't take down the server.
+      std::thread::spawn(move || handle_connection(stream, event_sync, &token, unauthenticated));
+    }
+  }
+
+  /// Serves requests on a separate thread.
+  pub fn spawn(self) -> JoinHandle<io::Result<()>> {
+    std::thread::spawn(move || self.serve())
+  }
+}
+
+impl Drop for UnauthenticatedConnection {
+  fn drop(&mut self) {
+    self.0.fetch_sub(1, Ordering::AcqRel);
+  }
+}
+
+/// Authenticates the client, then applies every request sent until it disconnects.
+fn handle_connection(
+  mut stream: TcpStream,
+  mut event_sync: EventSync<Mutable>,
+  expected_token: &str,
+  unauthenticated: UnauthenticatedConnection,
+) -> io::Result<()> {
+  stream.set_read_timeout(Some(AUTH_TIMEOUT))?;
+  let token: String = read_message(&mut stream)?;
+
+  if !tokens_match(token.as_bytes(), expected_token.as_bytes()) {
+    return write_message(
+      &mut stream,
+      &ControlResponse::Error("Invalid token.".to_string()),
+    );
+  }
+
+  // Authenticated clients can take as long as they like between requests.
+  drop(unauthenticated);
+  stream.set_read_timeout(None)?;
+  write_message(&mut stream, &ControlResponse::Ok)?;
+
+  loop {
+    let request: ControlRequest = match read_message(&mut stream) {
+      Ok(request) => request,
+      Err(error) if error.kind() == io::ErrorKind::UnexpectedEof => return Ok(()),
+      Err(error) => return Err(error),
+    };
+
+    write_message(&mut stream, &request.apply(&mut event_sync))?;
+  }
+}
+
+impl EventSyncTcpClient {
+  /// Connects and authenticates with the server at the given address.
+  ///
+  /// # Errors
+  ///
+  /// - If the connection could not be made.
+  /// - If the server rejected the token.
+  pub fn connect<A: ToSocketAddrs>(address: A, token: &str) -> io::Result<Self> {
+    let mut stream = TcpStream::connect(address)?;
+
+    write_message(&mut stream, &token)?;
+
+    match read_message::<ControlResponse>(&mut stream)? {
+      ControlResponse::Ok => Ok(Self { stream }),
+      ControlResponse::Error(message) => {
+        Err(io::Error::new(io::ErrorKind::PermissionDenied, message))
+      }
+      ControlResponse::Status(_) => Err(io::ErrorKind::InvalidData.into()),
+    }
+  }
+
+  /// Pauses the remote EventSync.
+  pub fn pause(&mut self) -> io::Result<()> {
+    self.request(&ControlRequest::Pause)?.into_unit()
+  }
+
+  /// Unpauses the remote EventSync.
+  pub fn unpause(&mut self) -> io::Result<()> {
+    self.request(&ControlRequest::Unpause)?.into_unit()
+  }
+
+  /// Restarts the remote EventSync.
+  pub fn restart(&mut self) -> io::Result<()> {
+    self.request(&ControlRequest::Restart)?.into_unit()
+  }
+
+  /// Changes the tickrate of the remote EventSync.
+  pub fn change_tickrate_millis(&mut self, new_tickrate: u64) -> io::Result<()> {
+    self
+      .request(&ControlRequest::ChangeTickrate(new_tickrate))?
+      .into_unit()
+  }
+
+  /// [`change_tickrate_millis()`](EventSyncTcpClient::change_tickrate_millis), taking the tickrate as a `u32`.
+  #[deprecated(
+    since = "0.4.5",
+    note = "use `change_tickrate_millis()`, as tickrates are `u64` milliseconds"
+  )]
+  pub fn change_tickrate(&mut self, new_tickrate: u32) -> io::Result<()> {
+    self.change_tickrate_millis(new_tickrate.into())
+  }
+
+  /// Returns the current state of the remote EventSync.
+  pub fn status(&mut self) -> io::Result<ClockStatus> {
+    self.request(&ControlRequest::Status)?.into_status()
+  }
+
+  /// Sends a request and waits for the response.
+  fn request(&mut self, request: &ControlRequest) -> io::Result<ControlResponse> {
+    write_message(&mut self.stream, request)?;
+
+    read_message(&mut self.stream)
+  }
+}
+
+/// Compares the tokens without returning early on the first difference.
+fn tokens_match(given: &[u8], expected: &[u8]) -> bool {
+  given.len() == expected.len()
+    && given
+      .iter()
+      .zip(expected)
+      .fold(0, |difference, (a, b)| difference | (a ^ b))
+      == 0
+}
+
+/// Writes the length of the message followed by the message as json.
+fn write_message<T: Serialize>(stream: &mut TcpStream, message: &T) -> io::Result<()> {
+  let message = serde_json::to_vec(message)?;
+
+  stream.write_all(&(message.len() as u32).to_be_bytes())?;
+  stream.write_all(&message)
+}
+
+/// Reads a message written by [`write_message()`](write_message).
+fn read_message<T: DeserializeOwned>(stream: &mut TcpStream) -> io::Result<T> {
+  let mut length = [0; 4];
+  stream.read_exact(&mut length)?;
+  let length = u32::from_be_bytes(length);
+
+  if length > MAX_MESSAGE_SIZE {
+    return Err(io::Error::new(
+      io::ErrorKind::InvalidData,
+      "Received a message that was too large.",
+    ));
+  }
+
+  let mut message = vec![0; length as usize];
+  stream.read_exact(&mut message)?;
+
+  serde_json::from_slice(&message).map_err(io::Error::from)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TEST_TICKRATE: u64 = 10;
+  const TEST_TOKEN: &str = "test token";
+
+  fn start_server(event_sync: &EventSync) -> SocketAddr {
+    let server = EventSyncTcpServer::bind("127.0.0.1:0", event_sync.clone(), TEST_TOKEN).unwrap();
+    let address = server.local_addr().unwrap();
+    server.spawn();
+
+    address
+  }
+
+  #[test]
+  fn client_controls_server_event_sync() {
+    let event_sync = EventSync::new_millis(TEST_TICKRATE);
+    let mut client = EventSyncTcpClient::connect(start_server(&event_sync), TEST_TOKEN).unwrap();
+
+    client.pause().unwrap();
+    client.change_tickrate_millis(TEST_TICKRATE * 2).unwrap();
+
+    let status = client.status().unwrap();
+
+    assert!(status.is_paused);
+    assert_eq!(status.tickrate, TEST_TICKRATE * 2);
+    assert!(event_sync.is_paused());
+  }
+
+  #[test]
+  fn idle_connections_dont_block_other_clients() {
+    let event_sync = EventSync::new_millis(TEST_TICKRATE);
+    let address = start_server(&event_sync);
+    let _idle = TcpStream::connect(address).unwrap();
+
+    let mut client = EventSyncTcpClient::connect(address, TEST_TOKEN).unwrap();
+    client.pause().unwrap();
+
+    assert!(event_sync.is_paused());
+  }
+
+  #[test]
+  fn unauthenticated_connections_are_limited() {
+    let event_sync = EventSync::new_millis(TEST_TICKRATE);
+    let address = start_server(&event_sync);
+    let idle: Vec<_> = (0..MAX_UNAUTHENTICATED_CONNECTIONS)
+      .map(|_| TcpStream::connect(address).unwrap())
+      .collect();
+
+    assert!(EventSyncTcpClient::connect(address, TEST_TOKEN).is_err());
+
+    drop(idle);
+
+    // The server notices the idle connections closing on their own threads.
+    let connected = (0..100).any(|_| {
+      std::thread::sleep(Duration::from_millis(TEST_TICKRATE));
+
+      EventSyncTcpClient::connect(address, TEST_TOKEN).is_ok()
+    });
+
+    assert!(connected);
+  }
+
+  #[test]
+  fn invalid_token_is_rejected() {
+    let event_sync = EventSync::new_millis(TEST_TICKRATE);
+    let result = EventSyncTcpClient::connect(start_server(&event_sync), "wrong token");
+
+    assert_eq!(
+      result.err().map(|error| error.kind()),
+      Some(io::ErrorKind::PermissionDenied)
+    );
+  }
+}
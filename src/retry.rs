@@ -0,0 +1,151 @@
+use crate::backoff::BackoffSchedule;
+use crate::errors::TimeError;
+
+/// How long to wait between the attempts of [`EventSync::retry()`](crate::EventSync::retry), in ticks.
+///
+/// # Example
+///
+/// ```
+/// use event_sync::RetryPolicy;
+///
+/// // Wait 1, 2, 4, then 5 ticks between attempts, giving up after 5 attempts.
+/// let policy = RetryPolicy::exponential(1, 2).with_cap(5).with_max_attempts(5);
+///
+/// assert_eq!(policy.delay_after_attempt(1), 1);
+/// assert_eq!(policy.delay_after_attempt(3), 4);
+/// assert_eq!(policy.delay_after_attempt(4), 5);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+  schedule: BackoffSchedule,
+  max_attempts: u32,
+}
+
+/// The reason [`EventSync::retry()`](crate::EventSync::retry) gave up.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RetryError<E> {
+  /// Every attempt failed, this is the error of the last one.
+  Operation(E),
+
+  /// Waiting for the next attempt failed, such as when the EventSync was paused.
+  Time(TimeError),
+}
+
+/// The amount of attempts made when a policy doesn't set it.
+const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+
+impl RetryPolicy {
+  /// Waits the same amount of ticks between every attempt.
+  pub fn fixed(ticks: u32) -> Self {
+    Self::from_schedule(BackoffSchedule::fixed(ticks))
+  }
+
+  /// Waits initial ticks after the first attempt, then step more ticks after every attempt after it.
+  pub fn linear(initial: u32, step: u32) -> Self {
+    Self::from_schedule(BackoffSchedule::linear(initial, step))
+  }
+
+  /// Waits initial ticks after the first attempt, multiplying the wait by factor after every attempt after it.
+  pub fn exponential(initial: u32, factor: u32) -> Self {
+    Self::from_schedule(BackoffSchedule::exponential(initial, factor))
+  }
+
+  /// Waits between attempts according to the given schedule.
+  pub fn from_schedule(schedule: BackoffSchedule) -> Self {
+    Self {
+      schedule,
+      max_attempts: DEFAULT_MAX_ATTEMPTS,
+    }
+  }
+
+  /// Sets the most ticks that will be waited between two attempts.
+  pub fn with_cap(mut self, cap: u32) -> Self {
+    self.schedule = self.schedule.with_cap(cap);
+
+    self
+  }
+
+  /// Randomly shortens every wait by up to half, see [`BackoffSchedule::with_jitter()`](BackoffSchedule::with_jitter).
+  pub fn with_jitter(mut self) -> Self {
+    self.schedule = self.schedule.with_jitter();
+
+    self
+  }
+
+  /// Sets the amount of attempts made before giving up, including the first.
+  ///
+  /// At least one attempt is always made.
+  pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+    self.max_attempts = max_attempts.max(1);
+
+    self
+  }
+
+  /// Returns the amount of attempts made before giving up.
+  pub fn max_attempts(&self) -> u32 {
+    self.max_attempts
+  }
+
+  /// Returns the delays waited between attempts, starting with the one after the first attempt.
+  pub fn schedule(&self) -> BackoffSchedule {
+    self.schedule.clone()
+  }
+
+  /// Returns the amount of ticks to wait after the given attempt failed, counting from 1, before any jitter.
+  pub fn delay_after_attempt(&self, attempt: u32) -> u32 {
+    self.schedule.delay_at(attempt.saturating_sub(1))
+  }
+}
+
+impl<E: std::fmt::Display> std::fmt::Display for RetryError<E> {
+  fn fmt(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      RetryError::Operation(error) => {
+        write!(formatter, "Every attempt failed, the last with: {}", error)
+      }
+      RetryError::Time(error) => {
+        write!(formatter, "Failed to wait for the next attempt: {}", error)
+      }
+    }
+  }
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for RetryError<E> {
+  fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    match self {
+      RetryError::Operation(error) => Some(error),
+      RetryError::Time(error) => Some(error),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::EventSync;
+
+  #[test]
+  fn retry_gives_up_after_max_attempts() {
+    let event_sync = EventSync::new_millis(10);
+    let mut attempts = 0;
+
+    let result: Result<(), _> =
+      event_sync.retry(RetryPolicy::fixed(1).with_max_attempts(2), || {
+        attempts += 1;
+
+        Err(attempts)
+      });
+
+    assert_eq!(result, Err(RetryError::Operation(2)));
+  }
+
+  #[test]
+  fn retry_stops_while_paused() {
+    let mut event_sync = EventSync::new_millis(10);
+    event_sync.pause();
+
+    let result: Result<(), _> = event_sync.retry(RetryPolicy::fixed(1), || Err(()));
+
+    assert_eq!(result, Err(RetryError::Time(TimeError::EventSyncPaused)));
+  }
+}
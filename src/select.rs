@@ -0,0 +1,310 @@
+//! Waiting for either the next tick or a message, whichever comes first, and receiving ticks over a channel.
+//!
+//! The blocking versions are enabled with the `crossbeam` feature.
+//! The async version is runtime-agnostic, built on [`TickFuture`](crate::TickFuture), with another for the browser
+//! under the `wasm` feature.
+
+#[cfg(feature = "crossbeam")]
+use crate::waiting::WaitTarget;
+#[cfg(feature = "crossbeam")]
+use crate::Immutable;
+use crate::{errors::TimeError, wait_future::TickFuture, EventSync};
+#[cfg(feature = "crossbeam")]
+use crossbeam_channel::{bounded, Receiver, RecvTimeoutError, Sender, TrySendError};
+use std::future::{Future, IntoFuture};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+#[cfg(feature = "crossbeam")]
+use std::time::Duration;
+
+/// What ended a wait for either the next tick or a message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TickOrMessage<M> {
+  /// The next tick occurred before any message arrived.
+  Tick,
+
+  /// A message arrived before the next tick.
+  Message(M),
+
+  /// The channel was closed, so no more messages can arrive.
+  Disconnected,
+}
+
+/// A future resolving on either the next tick or a message, created with [`EventSync::tick_or()`](EventSync::tick_or).
+///
+/// The tick is waited for with a [`TickFuture`](TickFuture), so it doesn't depend on any async runtime.
+/// Like the blocking waits, pausing holds the wait for the tick until unpaused, while messages still end it.
+#[must_use = "futures do nothing unless awaited"]
+pub struct TickOrFuture<F> {
+  tick: TickFuture,
+  message: Pin<Box<F>>,
+}
+
+impl<M, F: Future<Output = Option<M>>> Future for TickOrFuture<F> {
+  type Output = Result<TickOrMessage<M>, TimeError>;
+
+  fn poll(mut self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Self::Output> {
+    if let Poll::Ready(result) = Pin::new(&mut self.tick).poll(context) {
+      return Poll::Ready(result.map(|_| TickOrMessage::Tick));
+    }
+
+    self.message.as_mut().poll(context).map(|message| {
+      Ok(match message {
+        Some(message) => TickOrMessage::Message(message),
+        None => TickOrMessage::Disconnected,
+      })
+    })
+  }
+}
+
+impl<T> EventSync<T> {
+  /// Returns a wait for either the next tick or the message future, whichever comes first, to be awaited.
+  ///
+  /// The message future resolving to None is taken as its source being closed, such as a stream or channel
+  /// receiver ending.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if the EventSync is paused, when awaited.
+  ///
+  /// # Examples
+  ///
+  /// ```no_run
+  /// use event_sync::*;
+  /// use std::future::Future;
+  ///
+  /// async fn worker<F: Future<Output = Option<u32>>>(event_sync: EventSync, mut next_command: impl FnMut() -> F) {
+  ///   loop {
+  ///     match event_sync.tick_or(next_command()).await {
+  ///       Ok(TickOrMessage::Tick) => (), // Do this tick's work.
+  ///       Ok(TickOrMessage::Message(command)) => (), // Handle the command.
+  ///       Ok(TickOrMessage::Disconnected) | Err(_) => break,
+  ///     }
+  ///   }
+  /// }
+  /// ```
+  pub fn tick_or<M, F: Future<Output = Option<M>>>(&self, message: F) -> TickOrFuture<F> {
+    TickOrFuture {
+      tick: self.for_ticks(1).into_future(),
+      message: Box::pin(message),
+    }
+  }
+}
+
+/// What a [`tick_sender()`](EventSync::tick_sender) does with a tick when its channel is full.
+#[cfg(feature = "crossbeam")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TickOverflow {
+  /// Waits for room in the channel, sending every tick late rather than losing any.
+  #[default]
+  Block,
+
+  /// Drops the tick, so the channel only holds ticks that were sent on time.
+  DropNewest,
+}
+
+#[cfg(feature = "crossbeam")]
+impl<T> EventSync<T> {
+  /// Waits for either the next tick or a message on the receiver, returning whichever came first.
+  ///
+  /// The EventSync is re-checked at least once every tick, so pausing or changing the tickrate while waiting is
+  /// followed, and a pause holds the wait for the tick until unpaused.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if the EventSync is paused.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::*;
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let event_sync = EventSync::new_millis(tickrate);
+  /// let (sender, receiver) = crossbeam_channel::unbounded();
+  ///
+  /// sender.send("stop").unwrap();
+  ///
+  /// loop {
+  ///   match event_sync.wait_for_tick_or_recv(&receiver).unwrap() {
+  ///     TickOrMessage::Tick => (), // Do this tick's work.
+  ///     TickOrMessage::Message(_) | TickOrMessage::Disconnected => break,
+  ///   }
+  /// }
+  /// ```
+  pub fn wait_for_tick_or_recv<M>(
+    &self,
+    receiver: &Receiver<M>,
+  ) -> Result<TickOrMessage<M>, TimeError> {
+    let tick = {
+      let inner = self.read_inner();
+      inner.time_for_x_ticks(1)?;
+
+      inner.ticks_since_started() + 1
+    };
+    let mut message = None;
+
+    self.wait_until_tick_in_chunks(tick, WaitTarget::Relative, |wait_time| {
+      message = match receiver.recv_timeout(wait_time) {
+        Ok(received) => Some(TickOrMessage::Message(received)),
+        Err(RecvTimeoutError::Disconnected) => Some(TickOrMessage::Disconnected),
+        Err(RecvTimeoutError::Timeout) => None,
+      };
+
+      message.is_some()
+    });
+
+    Ok(message.unwrap_or(TickOrMessage::Tick))
+  }
+
+  /// Spawns a thread sending every tick's number on a bounded channel as it occurs, returning the receiving end.
+  ///
+  /// Nothing is sent while the EventSync is paused.
+  /// The thread ends on the first tick after every receiver has been dropped.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::*;
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let event_sync = EventSync::new_millis(tickrate);
+  ///
+  /// let ticks = event_sync.tick_sender(4, TickOverflow::DropNewest);
+  ///
+  /// assert_eq!(ticks.recv(), Ok(1));
+  /// assert_eq!(ticks.recv(), Ok(2));
+  /// ```
+  pub fn tick_sender(&self, capacity: usize, overflow: TickOverflow) -> Receiver<u64> {
+    let (sender, receiver) = bounded(capacity);
+    let event_sync = self.to_immutable();
+
+    std::thread::spawn(move || send_ticks(event_sync, sender, overflow));
+
+    receiver
+  }
+}
+
+/// Sends every tick that occurs until the channel is disconnected.
+///
+/// The EventSync is re-checked at least once every tick, so pausing, restarting, or changing the tickrate is
+/// followed.
+#[cfg(feature = "crossbeam")]
+fn send_ticks(event_sync: EventSync<Immutable>, sender: Sender<u64>, overflow: TickOverflow) {
+  let mut next_tick = event_sync.ticks_since_started() + 1;
+
+  loop {
+    let (current_tick, tickrate, is_paused) = {
+      let inner = event_sync.read_inner();
+
+      (
+        inner.ticks_since_started(),
+        Duration::from_millis(inner.get_tickrate()),
+        inner.is_paused(),
+      )
+    };
+
+    // Restarted, or moved back.
+    if current_tick + 1 < next_tick {
+      next_tick = current_tick + 1;
+    }
+
+    while next_tick <= current_tick {
+      let disconnected = match overflow {
+        TickOverflow::Block => sender.send(next_tick).is_err(),
+        TickOverflow::DropNewest => {
+          matches!(
+            sender.try_send(next_tick),
+            Err(TrySendError::Disconnected(_))
+          )
+        }
+      };
+
+      if disconnected {
+        return;
+      }
+
+      next_tick += 1;
+    }
+
+    let wait_time = if is_paused {
+      tickrate
+    } else {
+      event_sync
+        .read_inner()
+        .time_until_tick_occurs(next_tick)
+        .map_or(Duration::ZERO, |wait_time| wait_time.min(tickrate))
+    };
+
+    std::thread::sleep(wait_time);
+  }
+}
+
+#[cfg(all(test, feature = "crossbeam"))]
+mod tests {
+  use super::*;
+  use crossbeam_channel::unbounded;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  #[test]
+  fn tick_arrives_without_messages() {
+    let event_sync = EventSync::new_millis(TEST_TICKRATE);
+    let (_sender, receiver) = unbounded::<()>();
+
+    assert_eq!(
+      event_sync.wait_for_tick_or_recv(&receiver),
+      Ok(TickOrMessage::Tick)
+    );
+    assert!(event_sync.ticks_since_started() >= 1);
+  }
+
+  #[test]
+  fn tick_sender_skips_ticks_when_full() {
+    let event_sync = EventSync::new_millis(TEST_TICKRATE);
+    let ticks = event_sync.tick_sender(1, TickOverflow::DropNewest);
+
+    event_sync.wait_until(4).unwrap();
+
+    assert_eq!(ticks.recv(), Ok(1));
+    assert!(ticks.recv().unwrap() >= 4);
+  }
+
+  #[test]
+  fn waits_carry_on_through_pauses() {
+    let mut event_sync = EventSync::new_millis(TEST_TICKRATE);
+    let (_sender, receiver) = unbounded::<()>();
+
+    std::thread::scope(|scope| {
+      let waiter = event_sync.clone_immutable();
+      let waiting = scope.spawn(move || waiter.wait_for_tick_or_recv(&receiver));
+
+      std::thread::sleep(Duration::from_millis(TEST_TICKRATE / 2));
+      event_sync.pause();
+      std::thread::sleep(Duration::from_millis(TEST_TICKRATE * 5));
+      assert!(!waiting.is_finished());
+
+      event_sync.unpause().unwrap();
+
+      assert_eq!(waiting.join().unwrap(), Ok(TickOrMessage::Tick));
+    });
+  }
+
+  #[test]
+  fn messages_and_disconnects_end_the_wait() {
+    let event_sync = EventSync::new_millis(TEST_TICKRATE * 100);
+    let (sender, receiver) = unbounded();
+
+    sender.send(5).unwrap();
+    drop(sender);
+
+    assert_eq!(
+      event_sync.wait_for_tick_or_recv(&receiver),
+      Ok(TickOrMessage::Message(5))
+    );
+    assert_eq!(
+      event_sync.wait_for_tick_or_recv(&receiver),
+      Ok(TickOrMessage::Disconnected)
+    );
+  }
+}
@@ -0,0 +1,228 @@
+use crate::errors::TimeError;
+use crate::{EventSync, Immutable};
+
+type System<W> = Box<dyn FnMut(&mut W, u64)>;
+
+/// Runs a set of systems on a shared world once for every tick of an EventSync.
+///
+/// Systems are registered once with [`with_system()`](Simulation::with_system), then run in the order they
+/// were added on every tick.
+/// If the simulation falls behind, the missed ticks are run back to back to catch up, optionally limited with
+/// [`with_max_catch_up()`](Simulation::with_max_catch_up).
+///
+/// # Example
+///
+/// ```
+/// use event_sync::*;
+///
+/// let tickrate = 10; // 10ms between every tick.
+/// let event_sync = EventSync::new_millis(tickrate);
+///
+/// let mut simulation = Simulation::new(event_sync.clone_immutable(), (0, 0))
+///   .with_system(|(position, velocity): &mut (i32, i32), _tick| *position += *velocity)
+///   .with_system(|(_, velocity), _tick| *velocity += 1);
+///
+/// simulation.run_for(3).unwrap();
+///
+/// assert_eq!(simulation.world(), &(3, 3));
+/// ```
+pub struct Simulation<W> {
+  event_sync: EventSync<Immutable>,
+  world: W,
+  systems: Vec<System<W>>,
+  /// The last tick the systems were run on.
+  last_tick: u64,
+  max_catch_up: Option<u64>,
+  skipped_ticks: u64,
+}
+
+impl<W> Simulation<W> {
+  /// Creates a simulation with no systems, starting from the EventSync's current tick.
+  pub fn new(event_sync: EventSync<Immutable>, world: W) -> Self {
+    let last_tick = event_sync.ticks_since_started();
+
+    Self {
+      event_sync,
+      world,
+      systems: Vec::new(),
+      last_tick,
+      max_catch_up: None,
+      skipped_ticks: 0,
+    }
+  }
+
+  /// Adds a system, run after every system added before it.
+  ///
+  /// The system is given the world and the tick being run.
+  pub fn with_system<F: FnMut(&mut W, u64) + 'static>(mut self, system: F) -> Self {
+    self.systems.push(Box::new(system));
+
+    self
+  }
+
+  /// Sets the most ticks run back to back when the simulation falls behind.
+  ///
+  /// Ticks past the limit are skipped, and counted in [`skipped_ticks()`](Simulation::skipped_ticks).
+  pub fn with_max_catch_up(mut self, max_ticks: u64) -> Self {
+    self.max_catch_up = Some(max_ticks.max(1));
+
+    self
+  }
+
+  /// Returns the world the systems run on.
+  pub fn world(&self) -> &W {
+    &self.world
+  }
+
+  /// Returns the world the systems run on, to change it between ticks.
+  pub fn world_mut(&mut self) -> &mut W {
+    &mut self.world
+  }
+
+  /// Ends the simulation, returning its world.
+  pub fn into_world(self) -> W {
+    self.world
+  }
+
+  /// Returns the last tick the systems were run on.
+  pub fn last_tick(&self) -> u64 {
+    self.last_tick
+  }
+
+  /// Returns the amount of ticks skipped from falling further behind than the catch up limit.
+  pub fn skipped_ticks(&self) -> u64 {
+    self.skipped_ticks
+  }
+
+  /// Runs the systems for the next n ticks, waiting for each tick to occur.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if the EventSync is paused while waiting for a tick.
+  pub fn run_for(&mut self, n_ticks: u64) -> Result<(), TimeError> {
+    self.run_until(self.last_tick + n_ticks)
+  }
+
+  /// Runs the systems for every tick up to and including the given one, waiting for each tick to occur.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if the EventSync is paused while waiting for a tick.
+  pub fn run_until(&mut self, tick: u64) -> Result<(), TimeError> {
+    while self.last_tick < tick {
+      let next_tick = self.last_tick + 1;
+
+      if self.event_sync.ticks_since_started() < next_tick {
+        match self.event_sync.wait_until(next_tick) {
+          Ok(_) | Err(TimeError::ThatTimeHasAlreadyHappened) => (),
+          Err(error) => return Err(error),
+        }
+      }
+
+      self.skip_past_catch_up_limit(tick);
+      self.run_tick(self.last_tick + 1);
+    }
+
+    Ok(())
+  }
+
+  /// Runs the systems for every tick that has occurred since they were last run, without waiting.
+  pub fn run_pending(&mut self) {
+    let current_tick = self.event_sync.ticks_since_started();
+
+    self.skip_past_catch_up_limit(current_tick);
+
+    while self.last_tick < current_tick {
+      self.run_tick(self.last_tick + 1);
+    }
+  }
+
+  /// Moves past any ticks further behind the EventSync than the catch up limit allows, never going past the
+  /// given tick.
+  fn skip_past_catch_up_limit(&mut self, target_tick: u64) {
+    let Some(max_catch_up) = self.max_catch_up else {
+      return;
+    };
+
+    let current_tick = self.event_sync.ticks_since_started().min(target_tick);
+    let behind = current_tick.saturating_sub(self.last_tick);
+
+    if behind > max_catch_up {
+      let skipped = behind - max_catch_up;
+
+      self.last_tick += skipped;
+      self.skipped_ticks += skipped;
+    }
+  }
+
+  fn run_tick(&mut self, tick: u64) {
+    for system in &mut self.systems {
+      system(&mut self.world, tick);
+    }
+
+    self.last_tick = tick;
+  }
+}
+
+impl<W: std::fmt::Debug> std::fmt::Debug for Simulation<W> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("Simulation")
+      .field("world", &self.world)
+      .field("systems", &self.systems.len())
+      .field("last_tick", &self.last_tick)
+      .field("max_catch_up", &self.max_catch_up)
+      .field("skipped_ticks", &self.skipped_ticks)
+      .finish()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::time::Duration;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  fn move_to_tick(event_sync: &mut EventSync, tick: u64) {
+    event_sync
+      .sync_to(Duration::from_millis(tick * TEST_TICKRATE))
+      .unwrap();
+  }
+
+  fn recording_simulation(event_sync: &EventSync) -> Simulation<Vec<(u64, char)>> {
+    Simulation::new(event_sync.clone_immutable(), Vec::new())
+      .with_system(|log: &mut Vec<(u64, char)>, tick| log.push((tick, 'a')))
+      .with_system(|log, tick| log.push((tick, 'b')))
+  }
+
+  #[test]
+  fn missed_ticks_are_caught_up_in_order() {
+    let mut event_sync = EventSync::new_paused_millis(TEST_TICKRATE);
+    let mut simulation = recording_simulation(&event_sync);
+
+    move_to_tick(&mut event_sync, 2);
+    simulation.run_until(2).unwrap();
+
+    assert_eq!(
+      simulation.world(),
+      &[(1, 'a'), (1, 'b'), (2, 'a'), (2, 'b')]
+    );
+    assert_eq!(simulation.run_for(1), Err(TimeError::EventSyncPaused));
+  }
+
+  #[test]
+  fn ticks_past_the_catch_up_limit_are_skipped() {
+    let mut event_sync = EventSync::new_paused_millis(TEST_TICKRATE);
+    let mut simulation = recording_simulation(&event_sync).with_max_catch_up(2);
+
+    move_to_tick(&mut event_sync, 5);
+    simulation.run_pending();
+
+    assert_eq!(
+      simulation.world(),
+      &[(4, 'a'), (4, 'b'), (5, 'a'), (5, 'b')]
+    );
+    assert_eq!(simulation.skipped_ticks(), 3);
+    assert_eq!(simulation.last_tick(), 5);
+  }
+}
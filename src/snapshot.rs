@@ -0,0 +1,239 @@
+//! A compact binary format for saving EventSyncs, checked for corruption when loaded.
+//!
+//! Every snapshot is [`SNAPSHOT_LEN`](SNAPSHOT_LEN) bytes, all little-endian:
+//!
+//! | Bytes  | Contents                                                          |
+//! |--------|-------------------------------------------------------------------|
+//! | 0..4   | The magic bytes `ESNP`.                                           |
+//! | 4..6   | The format version.                                               |
+//! | 6      | The clock: 0 for monotonic, 1 for the wall clock, 2 for boottime. |
+//! | 7      | Flags, bit 0 set for an Immutable EventSync.                      |
+//! | 8..16  | The tickrate in milliseconds.                                     |
+//! | 16..24 | The whole seconds that had passed.                                |
+//! | 24..28 | The nanoseconds that had passed on top of the seconds.            |
+//! | 28..36 | The generation.                                                   |
+//! | 36..40 | The sample rate, or 0 if there isn't one.                         |
+//! | 40..44 | The CRC-32 of every byte before it.                               |
+
+use crate::clock::ClockSource;
+use crate::errors::TimeError;
+use crate::inner::InnerEventSync;
+use crate::{AccessLevel, EventSync};
+use std::time::Duration;
+
+/// The bytes every snapshot starts with.
+const MAGIC: [u8; 4] = *b"ESNP";
+
+/// The version of the format written, increased whenever the layout changes.
+const SNAPSHOT_VERSION: u16 = 1;
+
+/// The length of every snapshot in bytes.
+pub(crate) const SNAPSHOT_LEN: usize = 44;
+
+/// The flag set for snapshots of Immutable EventSyncs.
+const IMMUTABLE_FLAG: u8 = 1;
+
+impl<T: AccessLevel> EventSync<T> {
+  /// Saves the EventSync as a compact snapshot, which can be loaded again with
+  /// [`from_snapshot()`](EventSync::from_snapshot).
+  ///
+  /// Like serializing, the snapshot is loaded paused at the time that had passed when it was taken.
+  /// Snapshots carry a checksum, so one that was truncated or corrupted is rejected instead of loaded.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if the EventSync uses a [`custom clock`](ClockSource::Custom), which can't be saved.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::*;
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let event_sync = EventSync::from_starting_tick_millis(tickrate, 9, true);
+  ///
+  /// let mut snapshot = event_sync.to_snapshot().unwrap();
+  /// let loaded: EventSync = EventSync::from_snapshot(&snapshot).unwrap();
+  ///
+  /// assert_eq!(loaded.ticks_since_started(), 9);
+  ///
+  /// // A single flipped bit is noticed.
+  /// snapshot[20] ^= 1;
+  ///
+  /// assert!(matches!(
+  ///   EventSync::<Mutable>::from_snapshot(&snapshot),
+  ///   Err(TimeError::CorruptSnapshot(_))
+  /// ));
+  /// ```
+  pub fn to_snapshot(&self) -> Result<Vec<u8>, TimeError> {
+    encode(&self.read_inner(), T::IS_MUTABLE)
+  }
+
+  /// Loads an EventSync, paused, from a snapshot taken with [`to_snapshot()`](EventSync::to_snapshot).
+  ///
+  /// # Errors
+  ///
+  /// - [`CorruptSnapshot`](TimeError::CorruptSnapshot) is returned if the bytes aren't a snapshot, or were truncated
+  ///   or corrupted.
+  /// - An error is returned if the snapshot was taken by a newer version of event_sync, or of an Immutable EventSync
+  ///   being loaded as Mutable.
+  /// - An error is returned if the state in the snapshot is invalid, the same as when deserializing.
+  pub fn from_snapshot(snapshot: &[u8]) -> Result<Self, TimeError> {
+    if snapshot.len() < 6 || snapshot[..4] != MAGIC {
+      return Err(TimeError::CorruptSnapshot("it isn't an EventSync snapshot"));
+    }
+
+    if snapshot.len() != SNAPSHOT_LEN {
+      return Err(TimeError::CorruptSnapshot("it's the wrong length"));
+    }
+
+    let (contents, checksum) = snapshot.split_at(SNAPSHOT_LEN - 4);
+
+    if crc32(contents).to_le_bytes() != checksum {
+      return Err(TimeError::CorruptSnapshot("its checksum doesn't match"));
+    }
+
+    if u16::from_le_bytes([contents[4], contents[5]]) > SNAPSHOT_VERSION {
+      return Err(TimeError::InvalidState(
+        "it was saved by a newer version of event_sync",
+      ));
+    }
+
+    let clock = match contents[6] {
+      0 => ClockSource::Monotonic,
+      1 => ClockSource::WallClock,
+      #[cfg(all(feature = "boottime", target_os = "linux"))]
+      2 => ClockSource::Boottime,
+      _ => {
+        return Err(TimeError::InvalidState(
+          "its clock isn't available on this platform",
+        ))
+      }
+    };
+
+    if contents[7] & IMMUTABLE_FLAG != 0 && T::IS_MUTABLE {
+      return Err(TimeError::InvalidState(
+        "an Immutable EventSync can't be loaded as Mutable",
+      ));
+    }
+
+    let nanos = read_u32(contents, 24);
+
+    if nanos >= 1_000_000_000 {
+      return Err(TimeError::CorruptSnapshot("its time is malformed"));
+    }
+
+    let sample_rate = Some(read_u32(contents, 36)).filter(|sample_rate| *sample_rate != 0);
+    let inner = InnerEventSync::from_saved(
+      read_u64(contents, 8),
+      Duration::new(read_u64(contents, 16), nanos),
+      clock,
+      read_u64(contents, 28),
+      sample_rate,
+    )?;
+
+    Ok(Self::from_inner(inner, T::IS_MUTABLE))
+  }
+}
+
+/// Takes a snapshot of the state, for an EventSync with the given access.
+pub(crate) fn encode(inner: &InnerEventSync, is_mutable: bool) -> Result<Vec<u8>, TimeError> {
+  let time_since_started = inner.time_since_started();
+  let clock = match inner.clock_source() {
+    ClockSource::Monotonic => 0,
+    ClockSource::WallClock => 1,
+    #[cfg(all(feature = "boottime", target_os = "linux"))]
+    ClockSource::Boottime => 2,
+    ClockSource::Custom(_) => {
+      return Err(TimeError::InvalidState(
+        "it uses a custom clock, which can't be saved",
+      ))
+    }
+  };
+
+  let mut snapshot = Vec::with_capacity(SNAPSHOT_LEN);
+  snapshot.extend_from_slice(&MAGIC);
+  snapshot.extend_from_slice(&SNAPSHOT_VERSION.to_le_bytes());
+  snapshot.push(clock);
+  snapshot.push(if is_mutable { 0 } else { IMMUTABLE_FLAG });
+  snapshot.extend_from_slice(&inner.get_tickrate().to_le_bytes());
+  snapshot.extend_from_slice(&time_since_started.as_secs().to_le_bytes());
+  snapshot.extend_from_slice(&time_since_started.subsec_nanos().to_le_bytes());
+  snapshot.extend_from_slice(&inner.generation().to_le_bytes());
+  snapshot.extend_from_slice(&inner.sample_rate().unwrap_or(0).to_le_bytes());
+  snapshot.extend_from_slice(&crc32(&snapshot).to_le_bytes());
+
+  Ok(snapshot)
+}
+
+fn read_u32(bytes: &[u8], at: usize) -> u32 {
+  u32::from_le_bytes(bytes[at..at + 4].try_into().unwrap())
+}
+
+fn read_u64(bytes: &[u8], at: usize) -> u64 {
+  u64::from_le_bytes(bytes[at..at + 8].try_into().unwrap())
+}
+
+/// Returns the CRC-32 (IEEE) of the bytes.
+fn crc32(bytes: &[u8]) -> u32 {
+  let mut crc = !0_u32;
+
+  for byte in bytes {
+    crc ^= *byte as u32;
+
+    for _ in 0..8 {
+      crc = (crc >> 1) ^ (0xEDB8_8320 & (crc & 1).wrapping_neg());
+    }
+  }
+
+  !crc
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{Immutable, Mutable};
+
+  const TEST_TICKRATE: u64 = 10;
+
+  #[test]
+  fn crc32_matches_the_standard() {
+    assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+  }
+
+  #[test]
+  fn snapshots_round_trip() {
+    let event_sync = EventSync::from_starting_tick_millis(TEST_TICKRATE, 42, true);
+    let snapshot = event_sync.to_snapshot().unwrap();
+    let loaded = EventSync::<Mutable>::from_snapshot(&snapshot).unwrap();
+
+    assert_eq!(snapshot.len(), SNAPSHOT_LEN);
+    assert_eq!(loaded, event_sync);
+
+    let immutable_snapshot = event_sync.clone_immutable().to_snapshot().unwrap();
+
+    assert!(EventSync::<Immutable>::from_snapshot(&immutable_snapshot).is_ok());
+    assert!(EventSync::<Mutable>::from_snapshot(&immutable_snapshot).is_err());
+  }
+
+  #[test]
+  fn damaged_snapshots_are_rejected() {
+    let snapshot = EventSync::new_paused_millis(TEST_TICKRATE)
+      .to_snapshot()
+      .unwrap();
+    let load = |bytes: &[u8]| EventSync::<Mutable>::from_snapshot(bytes);
+
+    assert!(matches!(
+      load(&snapshot[..SNAPSHOT_LEN - 1]),
+      Err(TimeError::CorruptSnapshot(_))
+    ));
+    assert!(matches!(load(&[]), Err(TimeError::CorruptSnapshot(_))));
+
+    for byte in 0..SNAPSHOT_LEN {
+      let mut damaged = snapshot.clone();
+      damaged[byte] ^= 0x80;
+
+      assert!(matches!(load(&damaged), Err(TimeError::CorruptSnapshot(_))));
+    }
+  }
+}
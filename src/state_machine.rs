@@ -0,0 +1,276 @@
+use crate::{EventSync, Immutable};
+use std::time::Duration;
+
+/// A state of a [`StateMachine`](StateMachine), advanced once every tick.
+///
+/// Every state shares the same context `C`, which holds whatever the states work on.
+pub trait TickState<C> {
+  /// Called when the machine switches to this state, before its first tick.
+  fn on_enter(&mut self, _context: &mut C, _tick: u64) {}
+
+  /// Called on every tick while this is the current state, returning what the machine should do next.
+  fn on_tick(&mut self, context: &mut C, tick: u64) -> Transition<C>;
+
+  /// Called when the machine switches away from this state, or finishes.
+  fn on_exit(&mut self, _context: &mut C, _tick: u64) {}
+}
+
+/// What a [`StateMachine`](StateMachine) does after a state's tick.
+pub enum Transition<C> {
+  /// Keeps the current state for the next tick.
+  Stay,
+
+  /// Switches to the given state, which starts on the next tick.
+  Switch(Box<dyn TickState<C>>),
+
+  /// Stops the machine.
+  Finish,
+}
+
+/// Runs a [`TickState`](TickState) machine, advancing it once for every tick of an EventSync.
+///
+/// As the machine only advances when ticks occur, pausing the EventSync also pauses the machine.
+///
+/// # Example
+///
+/// ```
+/// use event_sync::*;
+///
+/// struct Countdown;
+///
+/// impl TickState<u32> for Countdown {
+///   fn on_tick(&mut self, remaining: &mut u32, _tick: u64) -> Transition<u32> {
+///     *remaining -= 1;
+///
+///     if *remaining == 0 {
+///       Transition::Finish
+///     } else {
+///       Transition::Stay
+///     }
+///   }
+/// }
+///
+/// let tickrate = 10; // 10ms between every tick.
+/// let event_sync = EventSync::new_millis(tickrate);
+///
+/// let machine = StateMachine::new(event_sync.clone_immutable(), Countdown, 3);
+///
+/// // Runs until the countdown finishes, 3 ticks later.
+/// assert_eq!(machine.run(), 0);
+/// ```
+pub struct StateMachine<C> {
+  event_sync: EventSync<Immutable>,
+  state: Option<Box<dyn TickState<C>>>,
+  context: C,
+  /// The last tick the machine was advanced on, or None if the first state hasn't been entered.
+  last_tick: Option<u64>,
+}
+
+impl<C> StateMachine<C> {
+  /// Creates a machine starting in the given state.
+  ///
+  /// The state is entered on the first call to [`update()`](StateMachine::update) or
+  /// [`run()`](StateMachine::run), and gets its first tick on the tick after that.
+  pub fn new<S: TickState<C> + 'static>(
+    event_sync: EventSync<Immutable>,
+    state: S,
+    context: C,
+  ) -> Self {
+    Self {
+      event_sync,
+      state: Some(Box::new(state)),
+      context,
+      last_tick: None,
+    }
+  }
+
+  /// Returns the context shared by the states.
+  pub fn context(&self) -> &C {
+    &self.context
+  }
+
+  /// Returns the context shared by the states, to change it between ticks.
+  pub fn context_mut(&mut self) -> &mut C {
+    &mut self.context
+  }
+
+  /// Returns true once a state has returned [`Transition::Finish`](Transition::Finish).
+  pub fn is_finished(&self) -> bool {
+    self.state.is_none()
+  }
+
+  /// Advances the machine once for every tick that occurred since the last update, without waiting.
+  ///
+  /// Returns false once the machine has finished.
+  pub fn update(&mut self) -> bool {
+    let current_tick = self.event_sync.ticks_since_started();
+
+    let Some(mut last_tick) = self.last_tick else {
+      if let Some(state) = &mut self.state {
+        state.on_enter(&mut self.context, current_tick);
+      }
+
+      self.last_tick = Some(current_tick);
+
+      return !self.is_finished();
+    };
+
+    // Restarted, or moved back.
+    if current_tick < last_tick {
+      last_tick = current_tick;
+    }
+
+    while last_tick < current_tick {
+      let Some(state) = &mut self.state else {
+        break;
+      };
+
+      last_tick += 1;
+
+      match state.on_tick(&mut self.context, last_tick) {
+        Transition::Stay => (),
+        Transition::Switch(mut next_state) => {
+          state.on_exit(&mut self.context, last_tick);
+          next_state.on_enter(&mut self.context, last_tick);
+
+          self.state = Some(next_state);
+        }
+        Transition::Finish => {
+          state.on_exit(&mut self.context, last_tick);
+
+          self.state = None;
+        }
+      }
+    }
+
+    self.last_tick = Some(last_tick);
+
+    !self.is_finished()
+  }
+
+  /// Advances the machine on every tick until it finishes, returning the context.
+  ///
+  /// While the EventSync is paused, the machine waits for it to be unpaused.
+  pub fn run(mut self) -> C {
+    while self.update() {
+      let wait_time = {
+        let inner = self.event_sync.read_inner();
+        let tickrate = Duration::from_millis(inner.get_tickrate());
+
+        if inner.is_paused() {
+          tickrate
+        } else {
+          inner
+            .time_until_tick_occurs(inner.ticks_since_started() + 1)
+            .map_or(Duration::ZERO, |wait_time| wait_time.min(tickrate))
+        }
+      };
+
+      std::thread::sleep(wait_time);
+    }
+
+    self.context
+  }
+}
+
+impl<C: std::fmt::Debug> std::fmt::Debug for StateMachine<C> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("StateMachine")
+      .field("context", &self.context)
+      .field("is_finished", &self.is_finished())
+      .field("last_tick", &self.last_tick)
+      .finish()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  fn move_to_tick(event_sync: &mut EventSync, tick: u64) {
+    event_sync
+      .sync_to(Duration::from_millis(tick * TEST_TICKRATE))
+      .unwrap();
+  }
+
+  /// Records every call, switching to `Done` after 2 ticks.
+  struct Walking {
+    ticks: u32,
+  }
+
+  struct Done;
+
+  impl TickState<Vec<String>> for Walking {
+    fn on_enter(&mut self, log: &mut Vec<String>, tick: u64) {
+      log.push(format!("enter walking {tick}"));
+    }
+
+    fn on_tick(&mut self, log: &mut Vec<String>, tick: u64) -> Transition<Vec<String>> {
+      log.push(format!("walking {tick}"));
+      self.ticks += 1;
+
+      if self.ticks == 2 {
+        Transition::Switch(Box::new(Done))
+      } else {
+        Transition::Stay
+      }
+    }
+
+    fn on_exit(&mut self, log: &mut Vec<String>, tick: u64) {
+      log.push(format!("exit walking {tick}"));
+    }
+  }
+
+  impl TickState<Vec<String>> for Done {
+    fn on_tick(&mut self, log: &mut Vec<String>, tick: u64) -> Transition<Vec<String>> {
+      log.push(format!("done {tick}"));
+
+      Transition::Finish
+    }
+  }
+
+  #[test]
+  fn states_are_advanced_once_per_tick() {
+    let mut event_sync = EventSync::new_paused_millis(TEST_TICKRATE);
+    let mut machine = StateMachine::new(
+      event_sync.clone_immutable(),
+      Walking { ticks: 0 },
+      Vec::new(),
+    );
+
+    assert!(machine.update());
+    // No ticks while paused.
+    assert!(machine.update());
+
+    move_to_tick(&mut event_sync, 4);
+
+    assert!(!machine.update());
+    assert_eq!(
+      machine.context(),
+      &[
+        "enter walking 0",
+        "walking 1",
+        "walking 2",
+        "exit walking 2",
+        "done 3",
+      ]
+    );
+  }
+
+  #[test]
+  fn run_returns_the_context_when_finished() {
+    let event_sync = EventSync::new_millis(TEST_TICKRATE);
+    let machine = StateMachine::new(
+      event_sync.clone_immutable(),
+      Walking { ticks: 0 },
+      Vec::new(),
+    );
+
+    let log = machine.run();
+
+    assert_eq!(log.last().map(|entry| &entry[..4]), Some("done"));
+    assert!(event_sync.ticks_since_started() >= 3);
+  }
+}
@@ -0,0 +1,150 @@
+use crate::errors::TimeError;
+use crate::inner::InnerEventSync;
+use crate::{EventSync, Mutable};
+use std::time::Duration;
+
+/// The state of an EventSync while it's being changed with [`EventSync::with_state_mut()`](EventSync::with_state_mut).
+///
+/// Every change is made while holding the EventSync's lock, so other threads see either none or all of them.
+pub struct EventSyncStateMut<'a> {
+  inner: &'a mut InnerEventSync,
+}
+
+impl EventSyncStateMut<'_> {
+  /// Returns the tickrate in milliseconds.
+  pub fn tickrate(&self) -> u64 {
+    self.inner.get_tickrate()
+  }
+
+  /// Changes the tickrate, retaining the time that has passed.
+  ///
+  /// The tickrate cannot go below 1. If 0 is passed in, 1 is set as the tickrate.
+  pub fn set_tickrate(&mut self, tickrate_in_milliseconds: u64) {
+    self.inner.change_tickrate(tickrate_in_milliseconds);
+  }
+
+  /// Returns true if paused.
+  pub fn is_paused(&self) -> bool {
+    self.inner.is_paused()
+  }
+
+  /// Pauses time, retaining the time that had passed. Does nothing if already paused.
+  pub fn pause(&mut self) {
+    self.inner.pause();
+  }
+
+  /// Unpauses time, continuing from the time that had passed before pausing. Does nothing if already running.
+  ///
+  /// # Errors
+  ///
+  /// - If the time that had passed is earlier than the clock can represent.
+  pub fn unpause(&mut self) -> Result<(), TimeError> {
+    self.inner.unpause()
+  }
+
+  /// Restarts time from 0, unpausing if paused.
+  pub fn restart(&mut self) {
+    self.inner.restart();
+  }
+
+  /// Returns the amount of time that has passed.
+  pub fn time_since_started(&self) -> Duration {
+    self.inner.time_since_started()
+  }
+
+  /// Moves the timeline so the given amount of time has passed, keeping the running or paused state.
+  ///
+  /// # Errors
+  ///
+  /// - If the given time is earlier than the clock can represent.
+  pub fn set_time_since_started(&mut self, time_since_started: Duration) -> Result<(), TimeError> {
+    self.inner.set_time_since_started(time_since_started)
+  }
+
+  /// Returns the amount of ticks that have passed.
+  pub fn ticks_since_started(&self) -> u64 {
+    self.inner.ticks_since_started()
+  }
+
+  /// Moves the timeline to the start of the given tick at the current tickrate, keeping the running or paused state.
+  ///
+  /// # Errors
+  ///
+  /// - If the tick is further in than the clock can represent.
+  pub fn set_ticks_since_started(&mut self, tick: u64) -> Result<(), TimeError> {
+    let time_of_tick = tick
+      .checked_mul(self.tickrate())
+      .map(Duration::from_millis)
+      .ok_or(TimeError::InvalidState(
+        "more than 100 years have passed on it",
+      ))?;
+
+    self.set_time_since_started(time_of_tick)
+  }
+}
+
+impl EventSync<Mutable> {
+  /// Changes the tickrate, time passed, and pause state together, without any other thread seeing them half changed.
+  ///
+  /// The EventSync stays locked for the whole closure, so it should only change the state, not wait on it.
+  /// Changes made before an error in the closure are kept.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::*;
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let mut event_sync = EventSync::new_millis(tickrate);
+  ///
+  /// // Restart at tick 50 with a new tickrate, paused.
+  /// event_sync
+  ///   .with_state_mut(|state| {
+  ///     state.pause();
+  ///     state.set_tickrate(20);
+  ///     state.set_ticks_since_started(50)
+  ///   })
+  ///   .unwrap();
+  ///
+  /// assert!(event_sync.is_paused());
+  /// assert_eq!(event_sync.get_tickrate_millis(), 20);
+  /// assert_eq!(event_sync.ticks_since_started(), 50);
+  /// ```
+  pub fn with_state_mut<R, F: FnOnce(&mut EventSyncStateMut<'_>) -> R>(&mut self, change: F) -> R {
+    let mut inner = self.write_inner();
+
+    change(&mut EventSyncStateMut { inner: &mut inner })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  #[test]
+  fn changes_are_applied_together() {
+    let mut event_sync = EventSync::new_millis(TEST_TICKRATE);
+    let other_event_sync = event_sync.clone_immutable();
+
+    let ticks = event_sync.with_state_mut(|state| {
+      state.restart();
+      state.pause();
+      state.set_tickrate(TEST_TICKRATE * 2);
+      state.set_ticks_since_started(3).unwrap();
+
+      state.ticks_since_started()
+    });
+
+    assert_eq!(ticks, 3);
+    assert!(other_event_sync.is_paused());
+    assert_eq!(
+      other_event_sync.time_since_started(),
+      Duration::from_millis(60)
+    );
+    assert!(event_sync
+      .with_state_mut(|state| state.set_ticks_since_started(u64::MAX))
+      .is_err());
+  }
+}
@@ -0,0 +1,237 @@
+use crate::clock::Instant;
+use crate::sync::atomic::{AtomicU64, Ordering};
+#[cfg(feature = "hdrhistogram")]
+use crate::sync::Mutex;
+use crate::EventSync;
+#[cfg(feature = "hdrhistogram")]
+use hdrhistogram::Histogram;
+use std::time::Duration;
+
+/// How late waits on an EventSync woke up compared to when they were meant to.
+///
+/// Every wait on an EventSync and its clones is recorded, such as [`wait_for_tick()`](EventSync::wait_for_tick) and
+/// [`wait_until()`](EventSync::wait_until).
+///
+/// Created with [`EventSync::stats()`](EventSync::stats).
+#[derive(Debug, Clone)]
+pub struct WaitStats {
+  waiting: u64,
+  wakes: u64,
+  missed_ticks: u64,
+  total_lateness: Duration,
+  max_lateness: Duration,
+  #[cfg(feature = "hdrhistogram")]
+  latency_histogram: Histogram<u64>,
+}
+
+impl WaitStats {
+  /// Returns the amount of waits that were still sleeping when the stats were taken.
+  pub fn waiting(&self) -> u64 {
+    self.waiting
+  }
+
+  /// Returns the amount of waits that have woken up.
+  pub fn wakes(&self) -> u64 {
+    self.wakes
+  }
+
+  /// Returns the total amount of ticks that passed while waits were late to wake up.
+  pub fn missed_ticks(&self) -> u64 {
+    self.missed_ticks
+  }
+
+  /// Returns how late waits have woken up altogether.
+  pub fn total_lateness(&self) -> Duration {
+    self.total_lateness
+  }
+
+  /// Returns the latest any wait has woken up.
+  pub fn max_lateness(&self) -> Duration {
+    self.max_lateness
+  }
+
+  /// Returns how late waits have woken up on average.
+  pub fn average_lateness(&self) -> Duration {
+    let wakes = u32::try_from(self.wakes).unwrap_or(u32::MAX);
+
+    self.total_lateness.checked_div(wakes).unwrap_or_default()
+  }
+
+  /// Returns every wake lateness recorded, in nanoseconds.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::*;
+  /// use std::time::Duration;
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let event_sync = EventSync::new_millis(tickrate);
+  ///
+  /// event_sync.wait_for_x_ticks(3).unwrap();
+  ///
+  /// let stats = event_sync.stats();
+  /// let p99_9 = Duration::from_nanos(stats.latency_histogram().value_at_quantile(0.999));
+  ///
+  /// assert_eq!(stats.latency_histogram().len(), 1);
+  /// assert!(p99_9 <= stats.max_lateness());
+  /// ```
+  #[cfg(feature = "hdrhistogram")]
+  pub fn latency_histogram(&self) -> &Histogram<u64> {
+    &self.latency_histogram
+  }
+}
+
+impl Default for WaitStats {
+  fn default() -> Self {
+    Self {
+      waiting: 0,
+      wakes: 0,
+      missed_ticks: 0,
+      total_lateness: Duration::ZERO,
+      max_lateness: Duration::ZERO,
+      // 3 significant figures auto resizes and is always valid.
+      #[cfg(feature = "hdrhistogram")]
+      latency_histogram: Histogram::new(3).unwrap(),
+    }
+  }
+}
+
+/// The counters [`WaitStats`](WaitStats) are taken from, shared by an EventSync and its clones.
+///
+/// The counters are atomic, so recording a wait only takes a lock for the histogram.
+pub(crate) struct WaitRecorder {
+  waiting: AtomicU64,
+  wakes: AtomicU64,
+  missed_ticks: AtomicU64,
+  total_lateness_nanos: AtomicU64,
+  max_lateness_nanos: AtomicU64,
+  #[cfg(feature = "hdrhistogram")]
+  latency_histogram: Mutex<Histogram<u64>>,
+}
+
+impl WaitRecorder {
+  /// Records a wait that woke up late by the given amount.
+  fn record(&self, lateness: Duration, tickrate: Duration) {
+    let lateness_nanos = u64::try_from(lateness.as_nanos()).unwrap_or(u64::MAX);
+    let missed_ticks = (lateness.as_nanos() / tickrate.as_nanos().max(1)) as u64;
+
+    self.missed_ticks.fetch_add(missed_ticks, Ordering::Relaxed);
+    let _ = self
+      .total_lateness_nanos
+      .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |total| {
+        Some(total.saturating_add(lateness_nanos))
+      });
+    self
+      .max_lateness_nanos
+      .fetch_max(lateness_nanos, Ordering::Relaxed);
+
+    #[cfg(feature = "hdrhistogram")]
+    self
+      .latency_histogram
+      .lock()
+      .unwrap()
+      .saturating_record(lateness_nanos);
+
+    self.wakes.fetch_add(1, Ordering::Relaxed);
+  }
+
+  /// Returns a copy of the counters.
+  fn stats(&self) -> WaitStats {
+    WaitStats {
+      waiting: self.waiting.load(Ordering::Relaxed),
+      wakes: self.wakes.load(Ordering::Relaxed),
+      missed_ticks: self.missed_ticks.load(Ordering::Relaxed),
+      total_lateness: Duration::from_nanos(self.total_lateness_nanos.load(Ordering::Relaxed)),
+      max_lateness: Duration::from_nanos(self.max_lateness_nanos.load(Ordering::Relaxed)),
+      #[cfg(feature = "hdrhistogram")]
+      latency_histogram: self.latency_histogram.lock().unwrap().clone(),
+    }
+  }
+}
+
+impl Default for WaitRecorder {
+  fn default() -> Self {
+    Self {
+      waiting: AtomicU64::new(0),
+      wakes: AtomicU64::new(0),
+      missed_ticks: AtomicU64::new(0),
+      total_lateness_nanos: AtomicU64::new(0),
+      max_lateness_nanos: AtomicU64::new(0),
+      // 3 significant figures auto resizes and is always valid.
+      #[cfg(feature = "hdrhistogram")]
+      latency_histogram: Mutex::new(Histogram::new(3).unwrap()),
+    }
+  }
+}
+
+impl<T> EventSync<T> {
+  /// Returns how late waits on this EventSync and all of its clones have woken up.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::*;
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let event_sync = EventSync::new_millis(tickrate);
+  ///
+  /// event_sync.wait_for_tick().unwrap();
+  /// event_sync.clone_immutable().wait_for_tick().unwrap();
+  ///
+  /// assert_eq!(event_sync.stats().wakes(), 2);
+  /// ```
+  pub fn stats(&self) -> WaitStats {
+    self.stats.stats()
+  }
+
+  /// Sleeps for the given time, recording how late the sleep woke up.
+  pub(crate) fn sleep_recorded(&self, wait_time: Duration) {
+    self.record_wait(|| {
+      let started_at = Instant::now();
+
+      std::thread::sleep(wait_time);
+
+      started_at.elapsed().saturating_sub(wait_time)
+    })
+  }
+
+  /// Counts the thread as waiting while the wait runs, then records the lateness the wait returns.
+  pub(crate) fn record_wait<F: FnOnce() -> Duration>(&self, wait: F) {
+    self.stats.waiting.fetch_add(1, Ordering::Relaxed);
+
+    let lateness = wait();
+    let tickrate = Duration::from_millis(self.get_tickrate_millis());
+
+    self.stats.record(lateness, tickrate);
+    self.stats.waiting.fetch_sub(1, Ordering::Relaxed);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn lateness_is_summarized() {
+    let recorder = WaitRecorder::default();
+
+    recorder.record(Duration::from_millis(1), Duration::from_millis(10));
+    recorder.record(Duration::from_millis(23), Duration::from_millis(10));
+
+    let stats = recorder.stats();
+
+    assert_eq!(stats.wakes(), 2);
+    assert_eq!(stats.missed_ticks(), 2);
+    assert_eq!(stats.max_lateness(), Duration::from_millis(23));
+    assert_eq!(stats.average_lateness(), Duration::from_millis(12));
+  }
+
+  #[test]
+  fn failed_waits_are_not_recorded() {
+    let event_sync = EventSync::new_paused_millis(10);
+
+    assert!(event_sync.wait_for_tick().is_err());
+    assert_eq!(event_sync.stats().wakes(), 0);
+  }
+}
@@ -0,0 +1,117 @@
+use crate::clock::SystemTime;
+use crate::view::EventSyncView;
+use crate::EventSync;
+use std::time::Duration;
+
+/// Whether an EventSync is running or paused, along with what that means for it, all read at the same instant.
+///
+/// Created with [`EventSync::status()`](EventSync::status).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EventSyncStatus {
+  /// Time is passing on the EventSync.
+  Running {
+    /// The point on the wall clock the EventSync would have started at, had it never been paused or moved.
+    since: SystemTime,
+    /// The amount of ticks that had occurred.
+    current_tick: u64,
+  },
+
+  /// Time is standing still on the EventSync.
+  Paused {
+    /// The time that had passed on the EventSync before it was paused.
+    elapsed: Duration,
+    /// The amount of ticks that had occurred before it was paused.
+    current_tick: u64,
+  },
+}
+
+impl EventSyncStatus {
+  /// Returns true if the EventSync was paused.
+  pub fn is_paused(&self) -> bool {
+    matches!(self, Self::Paused { .. })
+  }
+
+  /// Returns the amount of ticks that had occurred.
+  pub fn current_tick(&self) -> u64 {
+    match self {
+      Self::Running { current_tick, .. } | Self::Paused { current_tick, .. } => *current_tick,
+    }
+  }
+}
+
+impl<T> EventSync<T> {
+  /// Returns whether the EventSync is running or paused, along with its tick and time, all read at the same instant.
+  ///
+  /// Calling [`is_paused()`](EventSync::is_paused), [`ticks_since_started()`](EventSync::ticks_since_started), and
+  /// [`time_since_started()`](EventSync::time_since_started) separately can give values from either side of a pause
+  /// made in between them, which the status never does.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::*;
+  /// use std::time::Duration;
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let starting_time = Duration::from_millis(35);
+  /// let mut event_sync = EventSync::from_starting_time_millis(tickrate, starting_time, true);
+  ///
+  /// assert_eq!(
+  ///   event_sync.status(),
+  ///   EventSyncStatus::Paused {
+  ///     elapsed: starting_time,
+  ///     current_tick: 3,
+  ///   }
+  /// );
+  ///
+  /// event_sync.unpause().unwrap();
+  ///
+  /// match event_sync.status() {
+  ///   EventSyncStatus::Running { since, current_tick } => {
+  ///     assert!(since.elapsed().unwrap() >= starting_time);
+  ///     assert!(current_tick >= 3);
+  ///   }
+  ///   EventSyncStatus::Paused { .. } => unreachable!(),
+  /// }
+  /// ```
+  pub fn status(&self) -> EventSyncStatus {
+    let (view, now) = {
+      let inner = self.read_inner();
+
+      (EventSyncView::from_inner(&inner), SystemTime::now())
+    };
+
+    if view.is_paused {
+      EventSyncStatus::Paused {
+        elapsed: view.time_since_started,
+        current_tick: view.tick,
+      }
+    } else {
+      EventSyncStatus::Running {
+        since: now
+          .checked_sub(view.time_since_started)
+          .unwrap_or(SystemTime::UNIX_EPOCH),
+        current_tick: view.tick,
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn status_matches_the_pause_state() {
+    let mut event_sync = EventSync::from_starting_tick_millis(10, 4, true);
+
+    assert!(event_sync.status().is_paused());
+    assert_eq!(event_sync.status().current_tick(), 4);
+
+    event_sync.unpause().unwrap();
+    let status = event_sync.status();
+
+    assert!(!status.is_paused());
+    assert!(status.current_tick() >= 4);
+  }
+}
@@ -0,0 +1,151 @@
+use crate::{EventSync, Immutable};
+use std::time::Duration;
+
+/// Measures time on the clock of an EventSync, split into laps.
+///
+/// As time is read from the EventSync, the stopwatch stops whenever the EventSync is paused.
+///
+/// Created with [`EventSync::stopwatch()`](EventSync::stopwatch).
+#[derive(Debug, Clone)]
+pub struct Stopwatch {
+  event_sync: EventSync<Immutable>,
+  started_at: Mark,
+  last_lap_at: Mark,
+  laps: Vec<Lap>,
+}
+
+/// The time measured for a single lap of a [`Stopwatch`](Stopwatch).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lap {
+  /// The time that passed on the EventSync during the lap.
+  pub duration: Duration,
+  /// The amount of ticks that occurred during the lap.
+  pub ticks: u64,
+}
+
+/// A reading of the EventSync's clock.
+#[derive(Debug, Clone, Copy)]
+struct Mark {
+  time: Duration,
+  tick: u64,
+}
+
+impl Stopwatch {
+  pub(crate) fn new(event_sync: EventSync<Immutable>) -> Self {
+    let now = Mark::now(&event_sync);
+
+    Self {
+      event_sync,
+      started_at: now,
+      last_lap_at: now,
+      laps: Vec::new(),
+    }
+  }
+
+  /// Returns the time and ticks since the stopwatch was started or reset.
+  pub fn elapsed(&self) -> Lap {
+    Mark::now(&self.event_sync).since(self.started_at)
+  }
+
+  /// Returns the time and ticks since the last lap was recorded.
+  pub fn current_lap(&self) -> Lap {
+    Mark::now(&self.event_sync).since(self.last_lap_at)
+  }
+
+  /// Ends the current lap, recording and returning it.
+  pub fn lap(&mut self) -> Lap {
+    let now = Mark::now(&self.event_sync);
+    let lap = now.since(self.last_lap_at);
+
+    self.last_lap_at = now;
+    self.laps.push(lap);
+
+    lap
+  }
+
+  /// Returns every lap recorded since the stopwatch was started or reset.
+  pub fn laps(&self) -> &[Lap] {
+    &self.laps
+  }
+
+  /// Starts measuring from the current time, clearing every recorded lap.
+  pub fn reset(&mut self) {
+    let now = Mark::now(&self.event_sync);
+
+    self.started_at = now;
+    self.last_lap_at = now;
+    self.laps.clear();
+  }
+}
+
+impl Mark {
+  fn now(event_sync: &EventSync<Immutable>) -> Self {
+    let inner = event_sync.read_inner();
+
+    Self {
+      time: inner.time_since_started(),
+      tick: inner.ticks_since_started(),
+    }
+  }
+
+  /// Returns the lap between an earlier mark and this one.
+  ///
+  /// If the EventSync was restarted in between, the lap is counted as 0.
+  fn since(&self, earlier: Mark) -> Lap {
+    Lap {
+      duration: self.time.saturating_sub(earlier.time),
+      ticks: self.tick.saturating_sub(earlier.tick),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  fn move_to_tick(event_sync: &mut EventSync, tick: u64) {
+    event_sync
+      .sync_to(Duration::from_millis(tick * TEST_TICKRATE))
+      .unwrap();
+  }
+
+  #[test]
+  fn laps_are_measured_in_ticks_and_time() {
+    let mut event_sync = EventSync::new_paused_millis(TEST_TICKRATE);
+    let mut stopwatch = event_sync.stopwatch();
+
+    move_to_tick(&mut event_sync, 2);
+    stopwatch.lap();
+    move_to_tick(&mut event_sync, 5);
+    stopwatch.lap();
+
+    assert_eq!(
+      stopwatch.laps(),
+      [
+        Lap {
+          duration: Duration::from_millis(20),
+          ticks: 2
+        },
+        Lap {
+          duration: Duration::from_millis(30),
+          ticks: 3
+        }
+      ]
+    );
+    assert_eq!(stopwatch.elapsed().ticks, 5);
+  }
+
+  #[test]
+  fn stopwatch_stops_while_paused() {
+    let mut event_sync = EventSync::new_millis(TEST_TICKRATE);
+    let stopwatch = event_sync.stopwatch();
+
+    event_sync.pause();
+    let elapsed = stopwatch.elapsed();
+    std::thread::sleep(Duration::from_millis(TEST_TICKRATE * 2));
+
+    assert_eq!(stopwatch.elapsed(), elapsed);
+  }
+}
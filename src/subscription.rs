@@ -0,0 +1,158 @@
+//! Receiving ticks from an EventSync's driver thread over a channel.
+//!
+//! Enabled with the `crossbeam` feature.
+
+use crate::driver::ListenerId;
+use crate::{EventSync, Immutable};
+use crossbeam_channel::{bounded, Receiver, Sender, TrySendError};
+use std::ops::Deref;
+
+/// What a [`subscribe_ticks()`](EventSync::subscribe_ticks) channel does with a tick when it's full.
+///
+/// The driver thread never waits for room, as that would delay every other listener.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SubscriptionOverflow {
+  /// Drops the oldest queued tick to make room, so the channel holds the most recent ticks.
+  #[default]
+  DropOldest,
+
+  /// Drops the tick, so the channel only holds ticks that were sent on time.
+  DropNewest,
+
+  /// Drops every queued tick and sends only the newest one, so the receiver can skip straight to it.
+  Coalesce,
+}
+
+/// A channel receiving every tick from an EventSync's driver thread.
+///
+/// Dereferences to the crossbeam [`Receiver`](crossbeam_channel::Receiver), so it can be used anywhere a receiver
+/// can, including `select!`. Dropping it stops the ticks from being sent.
+///
+/// Created with [`EventSync::subscribe_ticks()`](EventSync::subscribe_ticks).
+pub struct TickSubscription {
+  receiver: Receiver<u64>,
+  event_sync: EventSync<Immutable>,
+  listener: ListenerId,
+}
+
+impl TickSubscription {
+  /// Returns the receiving end of the channel.
+  pub fn receiver(&self) -> &Receiver<u64> {
+    &self.receiver
+  }
+}
+
+impl Deref for TickSubscription {
+  type Target = Receiver<u64>;
+
+  fn deref(&self) -> &Self::Target {
+    &self.receiver
+  }
+}
+
+impl Drop for TickSubscription {
+  fn drop(&mut self) {
+    self.event_sync.remove_listener(self.listener);
+  }
+}
+
+impl<T> EventSync<T> {
+  /// Sends every tick's number on a bounded channel from the driver thread, shared with every
+  /// [`listener`](EventSync::add_listener), rather than a thread of its own.
+  ///
+  /// When the channel is full, the overflow decides which ticks are kept.
+  /// Nothing is sent while the EventSync is paused.
+  ///
+  /// A capacity of 0 is raised to 1, as the driver can't wait for the tick to be received.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::*;
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let event_sync = EventSync::new_millis(tickrate);
+  ///
+  /// let ticks = event_sync.subscribe_ticks(4, SubscriptionOverflow::DropOldest);
+  ///
+  /// assert_eq!(ticks.recv(), Ok(1));
+  /// assert_eq!(ticks.recv(), Ok(2));
+  /// ```
+  pub fn subscribe_ticks(
+    &self,
+    capacity: usize,
+    overflow: SubscriptionOverflow,
+  ) -> TickSubscription {
+    let (sender, receiver) = bounded(capacity.max(1));
+    // The listener keeps a receiver to make room in a full channel, so the channel never disconnects and the
+    // subscription removes the listener itself when dropped.
+    let overflow_receiver = receiver.clone();
+
+    let listener = self.add_listener(Box::new(move |tick| {
+      send_tick(&sender, &overflow_receiver, tick, overflow);
+    }));
+
+    TickSubscription {
+      receiver,
+      event_sync: self.to_immutable(),
+      listener,
+    }
+  }
+}
+
+/// Sends the tick, making room by the overflow's rules if the channel is full.
+fn send_tick(
+  sender: &Sender<u64>,
+  receiver: &Receiver<u64>,
+  tick: u64,
+  overflow: SubscriptionOverflow,
+) {
+  let Err(TrySendError::Full(tick)) = sender.try_send(tick) else {
+    return;
+  };
+
+  match overflow {
+    SubscriptionOverflow::DropOldest => {
+      let _ = receiver.try_recv();
+    }
+    SubscriptionOverflow::DropNewest => return,
+    SubscriptionOverflow::Coalesce => while receiver.try_recv().is_ok() {},
+  }
+
+  // Only this listener sends, so there's room after making it.
+  let _ = sender.try_send(tick);
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn full_channel(overflow: SubscriptionOverflow) -> Vec<u64> {
+    let (sender, receiver) = bounded(3);
+
+    for tick in 1..=5 {
+      send_tick(&sender, &receiver, tick, overflow);
+    }
+
+    receiver.try_iter().collect()
+  }
+
+  #[test]
+  fn overflow_keeps_the_right_ticks() {
+    assert_eq!(full_channel(SubscriptionOverflow::DropOldest), [3, 4, 5]);
+    assert_eq!(full_channel(SubscriptionOverflow::DropNewest), [1, 2, 3]);
+    assert_eq!(full_channel(SubscriptionOverflow::Coalesce), [4, 5]);
+  }
+
+  #[test]
+  fn dropping_a_subscription_removes_its_listener() {
+    let event_sync = EventSync::new_millis(10);
+    let subscription = event_sync.subscribe_ticks(1, SubscriptionOverflow::Coalesce);
+
+    assert_eq!(event_sync.listener_count(), 1);
+
+    drop(subscription);
+
+    assert_eq!(event_sync.listener_count(), 0);
+  }
+}
@@ -0,0 +1,272 @@
+//! Noticing when the system was suspended, such as by closing a laptop's lid, and deciding what an EventSync does
+//! about it.
+
+use crate::clock::{Instant, SystemTime};
+use crate::errors::TimeError;
+use crate::tick_math;
+use crate::{EventSync, Mutable};
+use std::sync::mpsc::Sender;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+/// What a [`SuspendMonitor`](SuspendMonitor) does to its EventSync after noticing a suspend.
+///
+/// Whether an EventSync's time carries on through a suspend depends on the platform's monotonic clock, so without a
+/// policy its ticks could either jump ahead or stall across one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SuspendPolicy {
+  /// Moves the EventSync back to where it was when last checked before the suspend, so the suspend never happened
+  /// as far as its ticks are concerned, on every platform.
+  #[default]
+  Absorb,
+
+  /// Absorbs the suspend, then pauses the EventSync so it can be unpaused once whatever's using it is ready.
+  Pause,
+
+  /// Leaves the EventSync as it is, only reporting the suspend.
+  Report,
+}
+
+/// A suspend noticed by a [`SuspendMonitor`](SuspendMonitor).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SuspendDetected {
+  /// The time that passed on the wall clock between the checks before and after the suspend.
+  pub wall_clock_gap: Duration,
+  /// The time that passed on the EventSync between the same checks, before the policy was applied.
+  ///
+  /// Close to the wall clock gap if the EventSync's clock carried on through the suspend, or close to nothing if it
+  /// stalled.
+  pub event_sync_gap: Duration,
+  /// The tick the EventSync was on when it was last checked before the suspend.
+  pub tick_before: u64,
+}
+
+/// Watches an EventSync for suspends of the system, applying a [`SuspendPolicy`](SuspendPolicy) whenever one is
+/// noticed.
+///
+/// A suspend is noticed when the wall clock moved more than the threshold further than the monotonic clock between two
+/// checks, as the monotonic clock stands still while the system is suspended. Checks can happen as rarely as needed,
+/// either by calling [`check()`](SuspendMonitor::check) regularly or with [`spawn()`](SuspendMonitor::spawn), since
+/// a thread that was only blocked for a while sees both clocks move together.
+///
+/// On platforms where the monotonic clock carries on through a suspend, suspends can't be told apart from a blocked
+/// thread, so none are noticed. The wall clock being moved forward by more than the threshold is treated as a suspend.
+///
+/// # Example
+///
+/// ```
+/// use event_sync::*;
+/// use std::time::Duration;
+///
+/// let tickrate = 10; // 10ms between every tick.
+/// let event_sync = EventSync::new_millis(tickrate);
+/// let mut monitor = SuspendMonitor::new(event_sync.clone(), SuspendPolicy::Pause)
+///   .with_threshold(Duration::from_secs(5));
+///
+/// // Somewhere in the main loop.
+/// if let Some(suspend) = monitor.check() {
+///   println!("Slept for {:?}", suspend.wall_clock_gap);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct SuspendMonitor {
+  event_sync: EventSync<Mutable>,
+  policy: SuspendPolicy,
+  threshold: Duration,
+  last_check: Option<LastCheck>,
+}
+
+/// When a [`SuspendMonitor`](SuspendMonitor) last checked, on each clock it compares.
+#[derive(Debug, Clone, Copy)]
+struct LastCheck {
+  wall_clock: SystemTime,
+  monotonic: Instant,
+  time_since_started: Duration,
+}
+
+impl SuspendMonitor {
+  /// The default amount of time the wall clock can move past the monotonic clock between checks before it's treated
+  /// as a suspend.
+  pub const DEFAULT_THRESHOLD: Duration = Duration::from_secs(2);
+
+  /// Creates a monitor applying the policy to the EventSync.
+  pub fn new(event_sync: EventSync<Mutable>, policy: SuspendPolicy) -> Self {
+    Self {
+      event_sync,
+      policy,
+      threshold: Self::DEFAULT_THRESHOLD,
+      last_check: None,
+    }
+  }
+
+  /// Sets the amount of time the wall clock can move past the monotonic clock between checks before it's treated as a
+  /// suspend.
+  pub fn with_threshold(mut self, threshold: Duration) -> Self {
+    self.threshold = threshold;
+
+    self
+  }
+
+  /// Returns the policy applied to suspends.
+  pub fn policy(&self) -> SuspendPolicy {
+    self.policy
+  }
+
+  /// Checks whether the system was suspended since the last check, applying the policy if it was.
+  ///
+  /// Nothing is checked while the EventSync is paused, and the time spent paused is never treated as a suspend.
+  /// Neither is the first check, or one after the wall clock was moved back.
+  pub fn check(&mut self) -> Option<SuspendDetected> {
+    let now = LastCheck {
+      wall_clock: SystemTime::now(),
+      monotonic: Instant::now(),
+      time_since_started: self.event_sync.time_since_started(),
+    };
+
+    if self.event_sync.is_paused() {
+      self.last_check = None;
+
+      return None;
+    }
+
+    let last_check = self.last_check.replace(now)?;
+    let wall_clock_gap = now.wall_clock.duration_since(last_check.wall_clock).ok()?;
+    let monotonic_gap = now
+      .monotonic
+      .saturating_duration_since(last_check.monotonic);
+
+    // A thread that was only blocked sees both clocks move together.
+    if wall_clock_gap.saturating_sub(monotonic_gap) <= self.threshold {
+      return None;
+    }
+
+    let suspend = SuspendDetected {
+      wall_clock_gap,
+      event_sync_gap: now
+        .time_since_started
+        .saturating_sub(last_check.time_since_started),
+      tick_before: tick_math::ticks_in(
+        last_check.time_since_started,
+        self.event_sync.get_tickrate_millis(),
+      ),
+    };
+
+    if self.apply_policy(last_check.time_since_started).is_err() {
+      self.last_check = None;
+    }
+
+    Some(suspend)
+  }
+
+  /// Checks every interval on a separate thread, sending every suspend over the channel.
+  ///
+  /// Checking stops once a suspend can't be sent, as the receiver
+  /// was dropped.
+  pub fn spawn(mut self, interval: Duration, sender: Sender<SuspendDetected>) -> JoinHandle<()> {
+    std::thread::spawn(move || loop {
+      if let Some(suspend) = self.check() {
+        if sender.send(suspend).is_err() {
+          return;
+        }
+      }
+
+      std::thread::sleep(interval);
+    })
+  }
+
+  fn apply_policy(&mut self, time_before_suspend: Duration) -> Result<(), TimeError> {
+    if self.policy == SuspendPolicy::Report {
+      return Ok(());
+    }
+
+    if self.policy == SuspendPolicy::Pause {
+      self.event_sync.pause();
+    }
+
+    // Only a clock that carried on through the suspend needs moving back.
+    if self.event_sync.time_since_started() > time_before_suspend {
+      self.event_sync.sync_to(time_before_suspend)?;
+    }
+
+    if let Some(last_check) = &mut self.last_check {
+      last_check.time_since_started = self.event_sync.time_since_started();
+    }
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  /// Creates a monitor that was last checked the given amount of wall clock time ago, with the EventSync at 1 second.
+  ///
+  /// The monotonic clock only moved for the time the thread was blocked, standing still for the rest.
+  fn suspended_monitor(
+    policy: SuspendPolicy,
+    suspended_for: Duration,
+    blocked_for: Duration,
+  ) -> SuspendMonitor {
+    let event_sync =
+      EventSync::from_starting_time_millis(TEST_TICKRATE, Duration::from_secs(1), false);
+    let mut monitor = SuspendMonitor::new(event_sync, policy);
+
+    monitor.last_check = Some(LastCheck {
+      wall_clock: SystemTime::now() - suspended_for - blocked_for,
+      monotonic: Instant::now() - blocked_for,
+      time_since_started: Duration::from_secs(1),
+    });
+
+    monitor
+  }
+
+  #[test]
+  fn short_gaps_are_not_suspends() {
+    let mut monitor = suspended_monitor(
+      SuspendPolicy::Absorb,
+      Duration::from_millis(100),
+      Duration::ZERO,
+    );
+
+    assert_eq!(monitor.check(), None);
+  }
+
+  #[test]
+  fn blocked_loops_are_not_suspends() {
+    let mut monitor = suspended_monitor(
+      SuspendPolicy::Absorb,
+      Duration::ZERO,
+      Duration::from_secs(60),
+    );
+
+    assert_eq!(monitor.check(), None);
+    assert!(monitor.event_sync.time_since_started() >= Duration::from_secs(1));
+  }
+
+  #[test]
+  fn policies_are_applied_to_suspends() {
+    let mut monitor = suspended_monitor(
+      SuspendPolicy::Pause,
+      Duration::from_secs(60),
+      Duration::ZERO,
+    );
+    let suspend = monitor.check().unwrap();
+
+    assert_eq!(suspend.tick_before, 100);
+    assert!(suspend.wall_clock_gap >= Duration::from_secs(60));
+    assert!(monitor.event_sync.is_paused());
+    assert_eq!(monitor.event_sync.ticks_since_started(), 100);
+
+    let mut monitor = suspended_monitor(
+      SuspendPolicy::Report,
+      Duration::from_secs(60),
+      Duration::ZERO,
+    );
+
+    assert!(monitor.check().is_some());
+    assert!(!monitor.event_sync.is_paused());
+  }
+}
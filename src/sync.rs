@@ -0,0 +1,118 @@
+//! The synchronization primitives EventSync is built on.
+//!
+//! These are the ones from `std`, unless the crate is built with `--cfg loom`, in which case they're swapped for
+//! [loom](https://docs.rs/loom)'s, so the interleavings of threads sharing an EventSync can be model checked.
+//! Wrappers built on EventSync can use them too, so they're checked along with it.
+//!
+//! Under loom, every EventSync has to be created and used within `loom::model`, so the checks are run on their own:
+//!
+//! ```text
+//! RUSTFLAGS="--cfg loom" cargo test --release --lib sync::tests
+//! ```
+//!
+//! The driver thread isn't modeled, so the checks don't add listeners or wait in [`Fifo`](crate::WakeOrder::Fifo)
+//! order.
+
+#[cfg(loom)]
+pub use loom::sync::{
+  atomic, Arc, Condvar, Mutex, MutexGuard, RwLock, RwLockReadGuard, RwLockWriteGuard,
+};
+#[cfg(loom)]
+pub use loom::thread;
+
+#[cfg(not(loom))]
+pub use std::sync::{
+  atomic, Arc, Condvar, Mutex, MutexGuard, RwLock, RwLockReadGuard, RwLockWriteGuard,
+};
+#[cfg(not(loom))]
+pub use std::thread;
+
+#[cfg(all(test, loom))]
+mod tests {
+  use super::atomic::{AtomicU64, Ordering};
+  use crate::{ClockSource, EventSync, TimeError, WaitOutcome};
+  use std::time::Duration;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  loom::lazy_static! {
+    /// The time on the advancing clock, reset for every interleaving.
+    static ref ADVANCING_CLOCK_MILLIS: AtomicU64 = AtomicU64::new(55);
+  }
+
+  /// A clock moving forward 1ms every time it's read, so time passes between each step of an interleaving.
+  fn advancing_clock() -> Duration {
+    Duration::from_millis(ADVANCING_CLOCK_MILLIS.fetch_add(1, Ordering::Relaxed))
+  }
+
+  /// A clock standing still at 55ms, so every interleaving sees the same time.
+  fn frozen_clock() -> Duration {
+    Duration::from_millis(55)
+  }
+
+  fn frozen_event_sync() -> EventSync {
+    EventSync::new_with_clock_millis(TEST_TICKRATE, ClockSource::Custom(frozen_clock))
+  }
+
+  #[test]
+  fn pausing_and_unpausing_never_lose_time() {
+    loom::model(|| {
+      let mut event_sync =
+        EventSync::new_with_clock_millis(TEST_TICKRATE, ClockSource::Custom(advancing_clock));
+      let mut other_event_sync = event_sync.clone();
+
+      let pausing = super::thread::spawn(move || {
+        other_event_sync.pause();
+        other_event_sync.unpause().unwrap();
+      });
+
+      event_sync.pause();
+      let paused_at = event_sync.time_since_started();
+      event_sync.unpause().unwrap();
+
+      pausing.join().unwrap();
+
+      assert!(paused_at > Duration::ZERO);
+      assert!(event_sync.time_since_started() >= paused_at);
+      assert!(!event_sync.is_paused());
+    });
+  }
+
+  #[test]
+  fn tickrate_changes_are_never_torn() {
+    loom::model(|| {
+      let mut event_sync = EventSync::from_starting_tick_millis(TEST_TICKRATE, 12, true);
+      let mut other_event_sync = event_sync.clone();
+
+      let changing =
+        super::thread::spawn(move || other_event_sync.change_tickrate_millis(TEST_TICKRATE * 2));
+      let view = event_sync.view();
+
+      assert!(matches!((view.tickrate, view.tick), (10, 12) | (20, 6)));
+
+      event_sync.change_tickrate_millis(TEST_TICKRATE * 3);
+      let (old_tickrate, _) = changing.join().unwrap();
+
+      assert!(matches!(old_tickrate, 10 | 30));
+    });
+  }
+
+  #[test]
+  fn waits_see_either_side_of_a_pause() {
+    loom::model(|| {
+      let event_sync = frozen_event_sync();
+      let mut other_event_sync = event_sync.clone();
+
+      let pausing = super::thread::spawn(move || other_event_sync.pause());
+      let waited = event_sync.wait_until(0);
+
+      pausing.join().unwrap();
+
+      // Tick 0 is the current tick until paused.
+      assert!(matches!(
+        waited,
+        Ok(WaitOutcome::NoWait) | Err(TimeError::EventSyncPaused)
+      ));
+    });
+  }
+}
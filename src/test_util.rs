@@ -0,0 +1,199 @@
+//! [proptest](https://docs.rs/proptest) strategies and checks for testing code built on EventSync.
+//!
+//! [`arb_event_sync()`](arb_event_sync) generates EventSyncs in any valid state, and
+//! [`arb_operations()`](arb_operations) generates sequences of changes to make to them, so logic depending on ticks
+//! can be tested against pausing, restarting, and tickrate changes landing at any point.
+//! [`assert_invariants()`](assert_invariants) checks an EventSync is still behaving as one should.
+//!
+//! # Example
+//!
+//! ```
+//! use event_sync::test_util::*;
+//! use proptest::prelude::*;
+//!
+//! proptest!(|(mut event_sync in arb_event_sync(), operations in arb_operations(16))| {
+//!   for operation in operations {
+//!     let _ = operation.apply(&mut event_sync);
+//!
+//!     assert_invariants(&event_sync);
+//!   }
+//! });
+//! ```
+
+use crate::errors::TimeError;
+use crate::tick_math;
+use crate::{AccessLevel, ClockSource, EventSync, Mutable};
+use proptest::prelude::*;
+use std::time::Duration;
+
+/// The longest tickrate generated, in milliseconds.
+const MAX_GENERATED_TICKRATE: u64 = 60_000;
+
+/// The most time generated as having passed, in milliseconds.
+///
+/// Kept to an hour so running EventSyncs can be created on machines that haven't been up long.
+const MAX_GENERATED_TIME: u64 = 3_600_000;
+
+/// A change to make to an EventSync, generated by [`arb_operation()`](arb_operation).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Operation {
+  /// Calls [`pause()`](EventSync::pause).
+  Pause,
+  /// Calls [`unpause()`](EventSync::unpause).
+  Unpause,
+  /// Calls [`restart()`](EventSync::restart).
+  Restart,
+  /// Calls [`restart_paused()`](EventSync::restart_paused).
+  RestartPaused,
+  /// Calls [`change_tickrate_millis()`](EventSync::change_tickrate_millis) with the tickrate.
+  ChangeTickrate(u64),
+  /// Calls [`sync_to()`](EventSync::sync_to) with the time.
+  SyncTo(Duration),
+}
+
+impl Operation {
+  /// Makes the change to the EventSync.
+  ///
+  /// # Errors
+  ///
+  /// - The same as the method called, such as when syncing to a time earlier than the clock can represent.
+  pub fn apply(self, event_sync: &mut EventSync<Mutable>) -> Result<(), TimeError> {
+    match self {
+      Self::Pause => event_sync.pause(),
+      Self::Unpause => event_sync.unpause()?,
+      Self::Restart => event_sync.restart(),
+      Self::RestartPaused => event_sync.restart_paused(),
+      Self::ChangeTickrate(tickrate) => {
+        event_sync.change_tickrate_millis(tickrate);
+      }
+      Self::SyncTo(time_since_started) => event_sync.sync_to(time_since_started)?,
+    }
+
+    Ok(())
+  }
+}
+
+/// Generates EventSyncs measured against the monotonic clock, with any tickrate up to a minute, up to an hour
+/// passed, and either paused or running.
+///
+/// Every EventSync generated is its own, not connected to any other.
+pub fn arb_event_sync() -> impl Strategy<Value = EventSync<Mutable>> {
+  (
+    1..=MAX_GENERATED_TICKRATE,
+    0..=MAX_GENERATED_TIME,
+    any::<bool>(),
+  )
+    .prop_filter_map(
+      "the time passed is earlier than the clock can represent",
+      |(tickrate, time_since_started, is_paused)| {
+        let mut event_sync = EventSync::new_event_sync(
+          tickrate,
+          Duration::from_millis(time_since_started),
+          true,
+          ClockSource::Monotonic,
+        );
+
+        if !is_paused {
+          event_sync.unpause().ok()?;
+        }
+
+        Some(event_sync)
+      },
+    )
+}
+
+/// Generates any [`Operation`](Operation), with tickrates and times in the same ranges as
+/// [`arb_event_sync()`](arb_event_sync).
+pub fn arb_operation() -> impl Strategy<Value = Operation> {
+  prop_oneof![
+    Just(Operation::Pause),
+    Just(Operation::Unpause),
+    Just(Operation::Restart),
+    Just(Operation::RestartPaused),
+    (1..=MAX_GENERATED_TICKRATE).prop_map(Operation::ChangeTickrate),
+    (0..=MAX_GENERATED_TIME).prop_map(|time| Operation::SyncTo(Duration::from_millis(time))),
+  ]
+}
+
+/// Generates sequences of up to max_length [`Operation`](Operation)s.
+pub fn arb_operations(max_length: usize) -> impl Strategy<Value = Vec<Operation>> {
+  prop::collection::vec(arb_operation(), 0..=max_length)
+}
+
+/// Panics if the EventSync isn't behaving as one should:
+///
+/// - While running, ticks and time never go backwards.
+/// - While paused, the time that has passed stays where it is.
+/// - The tickrate is never 0.
+/// - The tick is the one [`tick_math`](crate::tick_math) counts from the time that has passed.
+///
+/// The EventSync is looked at twice in a row, so nothing else should change it while it's being checked.
+///
+/// # Panics
+///
+/// - If any of the above don't hold.
+pub fn assert_invariants<T: AccessLevel>(event_sync: &EventSync<T>) {
+  let before = event_sync.view();
+  let after = event_sync.view();
+
+  assert_ne!(before.tickrate, 0, "the tickrate is 0");
+  assert_eq!(
+    before.tick,
+    tick_math::ticks_in(before.time_since_started, before.tickrate),
+    "the tick doesn't match the time that has passed"
+  );
+  assert_eq!(
+    before.is_paused, after.is_paused,
+    "the EventSync was paused or unpaused while being checked"
+  );
+
+  if before.is_paused {
+    assert_eq!(
+      before.time_since_started, after.time_since_started,
+      "time passed while paused"
+    );
+  } else {
+    assert!(
+      after.time_since_started >= before.time_since_started,
+      "time went backwards while running, from {:?} to {:?}",
+      before.time_since_started,
+      after.time_since_started
+    );
+    assert!(
+      after.tick >= before.tick,
+      "ticks went backwards while running, from {} to {}",
+      before.tick,
+      after.tick
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn operations_keep_the_invariants(
+      mut event_sync in arb_event_sync(),
+      operations in arb_operations(16),
+    ) {
+      assert_invariants(&event_sync);
+
+      for operation in operations {
+        let is_ok = operation.apply(&mut event_sync).is_ok();
+
+        match operation {
+          Operation::Pause | Operation::RestartPaused => prop_assert!(event_sync.is_paused()),
+          Operation::Unpause | Operation::Restart if is_ok => prop_assert!(!event_sync.is_paused()),
+          Operation::ChangeTickrate(tickrate) => prop_assert_eq!(event_sync.get_tickrate_millis(), tickrate),
+          _ => (),
+        }
+
+        assert_invariants(&event_sync);
+      }
+    }
+  }
+}
@@ -0,0 +1,148 @@
+use crate::{EventSync, Immutable};
+
+/// A closure that runs at most once every set amount of ticks.
+///
+/// Created with [`EventSync::throttle()`](EventSync::throttle).
+pub struct Throttle<F: FnMut()> {
+  event_sync: EventSync<Immutable>,
+  n_ticks: u32,
+  closure: F,
+  last_run_tick: Option<u64>,
+}
+
+/// A closure that runs once a set amount of ticks have passed since it was last called.
+///
+/// Created with [`EventSync::debounce()`](EventSync::debounce).
+pub struct Debounce<F: FnMut()> {
+  event_sync: EventSync<Immutable>,
+  n_ticks: u32,
+  closure: F,
+  last_call_tick: Option<u64>,
+}
+
+impl<F: FnMut()> Throttle<F> {
+  pub(crate) fn new(event_sync: EventSync<Immutable>, n_ticks: u32, closure: F) -> Self {
+    Self {
+      event_sync,
+      n_ticks,
+      closure,
+      last_run_tick: None,
+    }
+  }
+
+  /// Runs the closure if n_ticks have passed since it last ran, returning whether it ran.
+  ///
+  /// The first call always runs the closure, as does the first call after the EventSync was restarted.
+  pub fn call(&mut self) -> bool {
+    let current_tick = self.event_sync.ticks_since_started();
+
+    let should_run = self.last_run_tick.is_none_or(|last_run_tick| {
+      current_tick < last_run_tick || current_tick - last_run_tick >= self.n_ticks as u64
+    });
+
+    if should_run {
+      self.last_run_tick = Some(current_tick);
+      (self.closure)();
+    }
+
+    should_run
+  }
+}
+
+impl<F: FnMut()> Debounce<F> {
+  pub(crate) fn new(event_sync: EventSync<Immutable>, n_ticks: u32, closure: F) -> Self {
+    Self {
+      event_sync,
+      n_ticks,
+      closure,
+      last_call_tick: None,
+    }
+  }
+
+  /// Records a call, pushing back when the closure will run.
+  pub fn call(&mut self) {
+    self.last_call_tick = Some(self.event_sync.ticks_since_started());
+  }
+
+  /// Returns true if a call is waiting to run the closure.
+  pub fn is_pending(&self) -> bool {
+    self.last_call_tick.is_some()
+  }
+
+  /// Runs the closure if it was called and n_ticks have passed since the last call, returning whether it ran.
+  pub fn poll(&mut self) -> bool {
+    let Some(last_call_tick) = self.last_call_tick else {
+      return false;
+    };
+    let current_tick = self.event_sync.ticks_since_started();
+
+    // A restart moves the current tick back, which is treated as the EventSync having settled.
+    if current_tick >= last_call_tick && current_tick - last_call_tick < self.n_ticks as u64 {
+      return false;
+    }
+
+    self.last_call_tick = None;
+    (self.closure)();
+
+    true
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::time::Duration;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  // The EventSyncs are kept paused and moved by hand, so the tick each call lands on is exact.
+  fn move_to_tick(event_sync: &mut EventSync, tick: u64) {
+    event_sync
+      .sync_to(Duration::from_millis(tick * TEST_TICKRATE))
+      .unwrap();
+  }
+
+  #[test]
+  fn throttle_ignores_calls_between_runs() {
+    let mut event_sync = EventSync::new_paused_millis(TEST_TICKRATE);
+    let mut runs = 0;
+    let mut throttle = event_sync.throttle(2, || runs += 1);
+
+    assert!(throttle.call());
+    assert!(!throttle.call());
+
+    move_to_tick(&mut event_sync, 1);
+    assert!(!throttle.call());
+
+    move_to_tick(&mut event_sync, 2);
+    assert!(throttle.call());
+
+    drop(throttle);
+    assert_eq!(runs, 2);
+  }
+
+  #[test]
+  fn debounce_runs_once_calls_settle() {
+    let mut event_sync = EventSync::new_paused_millis(TEST_TICKRATE);
+    let mut runs = 0;
+    let mut debounce = event_sync.debounce(2, || runs += 1);
+
+    assert!(!debounce.poll());
+
+    debounce.call();
+    move_to_tick(&mut event_sync, 1);
+    debounce.call();
+    move_to_tick(&mut event_sync, 2);
+
+    assert!(!debounce.poll());
+
+    move_to_tick(&mut event_sync, 3);
+
+    assert!(debounce.poll());
+    assert!(!debounce.is_pending());
+    assert!(!debounce.poll());
+
+    drop(debounce);
+    assert_eq!(runs, 1);
+  }
+}
@@ -0,0 +1,152 @@
+use crate::{EventSync, Immutable};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// A double buffer where writes go to a back buffer, and reads see a snapshot of it taken on the last tick.
+///
+/// Writers can change the value as often as they like during a tick, while readers such as a render thread
+/// always see a consistent state from the last tick boundary.
+///
+/// The swap happens on the first read or write after a tick, so the snapshot holds exactly the writes made
+/// before that tick, with no thread needed to drive it.
+///
+/// # Example
+///
+/// ```
+/// use event_sync::*;
+///
+/// let tickrate = 10; // 10ms between every tick.
+/// let event_sync = EventSync::new_millis(tickrate);
+///
+/// let positions = TickBuffered::new(event_sync.clone_immutable(), vec![0, 0]);
+///
+/// positions.write(|positions| positions[0] = 5);
+///
+/// // The write isn't seen until the next tick.
+/// assert_eq!(*positions.read(), [0, 0]);
+///
+/// event_sync.wait_for_tick().unwrap();
+///
+/// assert_eq!(*positions.read(), [5, 0]);
+/// ```
+pub struct TickBuffered<T> {
+  event_sync: EventSync<Immutable>,
+  buffers: Mutex<Buffers<T>>,
+}
+
+struct Buffers<T> {
+  /// The snapshot readers see.
+  front: Arc<T>,
+  /// The value writers change.
+  back: T,
+  /// The tick the front buffer was taken on.
+  published_tick: u64,
+}
+
+impl<T: Clone> TickBuffered<T> {
+  /// Creates a buffer where both readers and writers start with the given value.
+  pub fn new(event_sync: EventSync<Immutable>, value: T) -> Self {
+    let published_tick = event_sync.ticks_since_started();
+
+    Self {
+      event_sync,
+      buffers: Mutex::new(Buffers {
+        front: Arc::new(value.clone()),
+        back: value,
+        published_tick,
+      }),
+    }
+  }
+
+  /// Returns the snapshot taken on the last tick.
+  ///
+  /// The snapshot is kept as is, even as later ticks take new ones.
+  pub fn read(&self) -> Arc<T> {
+    Arc::clone(&self.lock_published().front)
+  }
+
+  /// Changes the back buffer, which readers will see after the next tick.
+  pub fn write<R, F: FnOnce(&mut T) -> R>(&self, closure: F) -> R {
+    closure(&mut self.lock_published().back)
+  }
+
+  /// Returns the tick the current snapshot was taken on.
+  pub fn published_tick(&self) -> u64 {
+    self.lock_published().published_tick
+  }
+
+  /// Locks the buffers, first taking a snapshot if a tick has occurred since the last one.
+  fn lock_published(&self) -> MutexGuard<'_, Buffers<T>> {
+    let current_tick = self.event_sync.ticks_since_started();
+    let mut buffers = self
+      .buffers
+      .lock()
+      .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    // Restarting moves the tick back, which also counts as a new tick.
+    if current_tick != buffers.published_tick {
+      buffers.front = Arc::new(buffers.back.clone());
+      buffers.published_tick = current_tick;
+    }
+
+    buffers
+  }
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for TickBuffered<T> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let buffers = self
+      .buffers
+      .lock()
+      .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    f.debug_struct("TickBuffered")
+      .field("front", &buffers.front)
+      .field("back", &buffers.back)
+      .field("published_tick", &buffers.published_tick)
+      .finish()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::time::Duration;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  fn move_to_tick(event_sync: &mut EventSync, tick: u64) {
+    event_sync
+      .sync_to(Duration::from_millis(tick * TEST_TICKRATE))
+      .unwrap();
+  }
+
+  #[test]
+  fn writes_after_a_tick_are_not_in_its_snapshot() {
+    let mut event_sync = EventSync::new_paused_millis(TEST_TICKRATE);
+    let buffered = TickBuffered::new(event_sync.clone_immutable(), 0);
+
+    buffered.write(|value| *value = 1);
+    move_to_tick(&mut event_sync, 1);
+    buffered.write(|value| *value = 2);
+
+    assert_eq!(*buffered.read(), 1);
+    assert_eq!(buffered.published_tick(), 1);
+
+    move_to_tick(&mut event_sync, 2);
+
+    assert_eq!(*buffered.read(), 2);
+  }
+
+  #[test]
+  fn old_snapshots_are_kept() {
+    let mut event_sync = EventSync::new_paused_millis(TEST_TICKRATE);
+    let buffered = TickBuffered::new(event_sync.clone_immutable(), 0);
+
+    let first_snapshot = buffered.read();
+    buffered.write(|value| *value = 1);
+    move_to_tick(&mut event_sync, 1);
+
+    assert_eq!(*buffered.read(), 1);
+    assert_eq!(*first_snapshot, 0);
+  }
+}
@@ -0,0 +1,230 @@
+use crate::{EventSync, Immutable};
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A map whose entries expire after a set amount of ticks of an EventSync, rather than of the wall clock.
+///
+/// Entries are checked for expiry as they're looked at, so no thread is needed to drive it.
+/// While the EventSync is paused, no ticks pass, so nothing expires.
+/// Moving the timeline back, such as by restarting, expires every entry inserted after the point it was moved to.
+///
+/// Expired entries are never returned, but are only dropped once overwritten, removed, or
+/// [`purged`](TickCache::purge_expired).
+///
+/// # Example
+///
+/// ```
+/// use event_sync::*;
+///
+/// let tickrate = 10; // 10ms between every tick.
+/// let mut event_sync = EventSync::new_paused_millis(tickrate);
+///
+/// // Entries last for 3 ticks.
+/// let mut cache = TickCache::new(event_sync.clone_immutable(), 3);
+/// cache.insert("path", 12);
+///
+/// // Paused, so the entry doesn't expire however long it's waited for.
+/// std::thread::sleep(std::time::Duration::from_millis(50));
+/// assert_eq!(cache.get("path"), Some(&12));
+///
+/// event_sync.unpause().unwrap();
+/// event_sync.wait_until(3).unwrap();
+///
+/// assert_eq!(cache.get("path"), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TickCache<K, V> {
+  event_sync: EventSync<Immutable>,
+  ttl_ticks: u64,
+  entries: HashMap<K, Entry<V>>,
+}
+
+#[derive(Debug, Clone)]
+struct Entry<V> {
+  value: V,
+  /// The tick the entry was inserted on.
+  inserted_tick: u64,
+}
+
+impl<K: Eq + Hash, V> TickCache<K, V> {
+  /// Creates an empty cache whose entries expire the given amount of ticks after they're inserted.
+  ///
+  /// The ttl cannot go below 1. If 0 is passed in, 1 is used.
+  pub fn new(event_sync: EventSync<Immutable>, ttl_ticks: u64) -> Self {
+    Self {
+      event_sync,
+      ttl_ticks: ttl_ticks.max(1),
+      entries: HashMap::new(),
+    }
+  }
+
+  /// Returns the amount of ticks entries last for.
+  pub fn ttl_ticks(&self) -> u64 {
+    self.ttl_ticks
+  }
+
+  /// Inserts the value, expiring the ttl from now, and returns the value it replaced if that hadn't expired.
+  pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+    let current_tick = self.event_sync.ticks_since_started();
+    let entry = Entry {
+      value,
+      inserted_tick: current_tick,
+    };
+
+    self
+      .entries
+      .insert(key, entry)
+      .filter(|replaced| !self.is_expired(replaced, current_tick))
+      .map(|replaced| replaced.value)
+  }
+
+  /// Returns the value for the key, if there is one that hasn't expired.
+  pub fn get<Q>(&self, key: &Q) -> Option<&V>
+  where
+    K: Borrow<Q>,
+    Q: Eq + Hash + ?Sized,
+  {
+    let current_tick = self.event_sync.ticks_since_started();
+
+    self
+      .entries
+      .get(key)
+      .filter(|entry| !self.is_expired(entry, current_tick))
+      .map(|entry| &entry.value)
+  }
+
+  /// Returns true if there's a value for the key that hasn't expired.
+  pub fn contains_key<Q>(&self, key: &Q) -> bool
+  where
+    K: Borrow<Q>,
+    Q: Eq + Hash + ?Sized,
+  {
+    self.get(key).is_some()
+  }
+
+  /// Returns the amount of ticks until the value for the key expires, if there is one that hasn't expired.
+  pub fn ticks_left<Q>(&self, key: &Q) -> Option<u64>
+  where
+    K: Borrow<Q>,
+    Q: Eq + Hash + ?Sized,
+  {
+    let current_tick = self.event_sync.ticks_since_started();
+    let entry = self
+      .entries
+      .get(key)
+      .filter(|entry| !self.is_expired(entry, current_tick))?;
+
+    Some(self.ttl_ticks - (current_tick - entry.inserted_tick))
+  }
+
+  /// Removes the value for the key, returning it if it hadn't expired.
+  pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+  where
+    K: Borrow<Q>,
+    Q: Eq + Hash + ?Sized,
+  {
+    let current_tick = self.event_sync.ticks_since_started();
+
+    self
+      .entries
+      .remove(key)
+      .filter(|entry| !self.is_expired(entry, current_tick))
+      .map(|entry| entry.value)
+  }
+
+  /// Drops every expired entry, returning how many there were.
+  pub fn purge_expired(&mut self) -> usize {
+    let current_tick = self.event_sync.ticks_since_started();
+    let ttl_ticks = self.ttl_ticks;
+    let len_before = self.entries.len();
+
+    self
+      .entries
+      .retain(|_, entry| !expired(entry, current_tick, ttl_ticks));
+
+    len_before - self.entries.len()
+  }
+
+  /// Returns the amount of entries that haven't expired.
+  pub fn len(&self) -> usize {
+    let current_tick = self.event_sync.ticks_since_started();
+
+    self
+      .entries
+      .values()
+      .filter(|entry| !self.is_expired(entry, current_tick))
+      .count()
+  }
+
+  /// Returns true if every entry has expired.
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// Drops every entry.
+  pub fn clear(&mut self) {
+    self.entries.clear();
+  }
+
+  fn is_expired(&self, entry: &Entry<V>, current_tick: u64) -> bool {
+    expired(entry, current_tick, self.ttl_ticks)
+  }
+}
+
+/// Returns true once the ttl has passed since the entry was inserted, or the timeline was moved back before it.
+fn expired<V>(entry: &Entry<V>, current_tick: u64, ttl_ticks: u64) -> bool {
+  current_tick < entry.inserted_tick || current_tick - entry.inserted_tick >= ttl_ticks
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::Mutable;
+  use std::time::Duration;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  fn move_to_tick(event_sync: &mut EventSync<Mutable>, tick: u64) {
+    event_sync
+      .sync_to(Duration::from_millis(tick * TEST_TICKRATE))
+      .unwrap();
+  }
+
+  #[test]
+  fn entries_expire_after_the_ttl() {
+    let mut event_sync = EventSync::from_starting_tick_millis(TEST_TICKRATE, 10, true);
+    let mut cache = TickCache::new(event_sync.clone_immutable(), 4);
+
+    cache.insert("first", 1);
+    move_to_tick(&mut event_sync, 12);
+    cache.insert("second", 2);
+
+    assert_eq!(cache.ticks_left("first"), Some(2));
+    assert_eq!(cache.len(), 2);
+
+    move_to_tick(&mut event_sync, 14);
+
+    assert_eq!(cache.get("first"), None);
+    assert_eq!(cache.insert("first", 3), None);
+    assert_eq!(cache.get("second"), Some(&2));
+    assert_eq!(cache.purge_expired(), 0);
+
+    move_to_tick(&mut event_sync, 16);
+
+    assert_eq!(cache.purge_expired(), 1);
+    assert_eq!(cache.remove("first"), Some(3));
+    assert!(cache.is_empty());
+  }
+
+  #[test]
+  fn moving_back_expires_later_entries() {
+    let mut event_sync = EventSync::from_starting_tick_millis(TEST_TICKRATE, 5, true);
+    let mut cache = TickCache::new(event_sync.clone_immutable(), 10);
+
+    cache.insert("key", ());
+    event_sync.restart_paused();
+
+    assert!(!cache.contains_key("key"));
+  }
+}
@@ -0,0 +1,335 @@
+use crate::clock::Instant;
+use crate::waiting::WaitTarget;
+use crate::EventSync;
+use std::sync::mpsc::Sender;
+use std::time::Duration;
+
+/// Whether [`run_every_tick()`](EventSync::run_every_tick) keeps going after the current tick.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LoopControl {
+  /// Runs the closure again on the next tick.
+  Continue,
+
+  /// Stops the loop.
+  Break,
+}
+
+/// Information about the tick a [`run_every_tick()`](EventSync::run_every_tick) closure is running for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TickContext {
+  /// The tick the closure is running on.
+  pub tick: u64,
+  /// The amount of ticks skipped since the last run, as the closure or something else took longer than a tick.
+  pub missed_ticks: u64,
+  /// The amount of times the closure has run before this one.
+  pub iteration: u64,
+}
+
+/// How long a single call of a [`run_every_tick_profiled()`](EventSync::run_every_tick_profiled) closure took.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IterationRecord {
+  /// The tick the closure ran on.
+  pub tick: u64,
+  /// How long the closure took.
+  pub work_duration: Duration,
+  /// How long the loop slept after the closure, waiting for the next tick.
+  pub sleep_duration: Duration,
+  /// Whether the closure was still running when the next tick occurred.
+  pub overrun: bool,
+}
+
+/// Collects an [`IterationRecord`](IterationRecord) for every call of a
+/// [`run_every_tick_profiled()`](EventSync::run_every_tick_profiled) closure.
+///
+/// Records are either kept to be read after the loop, or sent over a channel as they're made.
+#[derive(Debug, Default)]
+pub struct LoopProfile {
+  records: Vec<IterationRecord>,
+  sender: Option<Sender<IterationRecord>>,
+}
+
+impl LoopProfile {
+  /// Creates a profile that keeps every record.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Creates a profile that sends every record over the channel instead of keeping it.
+  ///
+  /// Records stop being sent once the receiver is dropped.
+  pub fn streaming(sender: Sender<IterationRecord>) -> Self {
+    Self {
+      records: Vec::new(),
+      sender: Some(sender),
+    }
+  }
+
+  /// Returns every record kept so far, which is empty when streaming.
+  pub fn records(&self) -> &[IterationRecord] {
+    &self.records
+  }
+
+  /// Returns the amount of kept records where the closure ran past the next tick.
+  pub fn overruns(&self) -> usize {
+    self.records.iter().filter(|record| record.overrun).count()
+  }
+
+  /// Returns the longest time a closure took out of the kept records.
+  pub fn max_work_duration(&self) -> Duration {
+    self
+      .records
+      .iter()
+      .map(|record| record.work_duration)
+      .max()
+      .unwrap_or_default()
+  }
+
+  /// Returns the average time a closure took out of the kept records.
+  pub fn average_work_duration(&self) -> Duration {
+    let total: Duration = self.records.iter().map(|record| record.work_duration).sum();
+
+    total
+      .checked_div(self.records.len() as u32)
+      .unwrap_or_default()
+  }
+
+  fn record(&mut self, record: IterationRecord) {
+    match &self.sender {
+      Some(sender) => {
+        let _ = sender.send(record);
+      }
+      None => self.records.push(record),
+    }
+  }
+}
+
+impl<T> EventSync<T> {
+  /// Calls the closure once every tick until it returns [`LoopControl::Break`](LoopControl::Break).
+  ///
+  /// The first call happens on the next tick.
+  /// The time the closure takes is part of the tick, so calls stay on the tick boundaries instead of drifting later.
+  /// If a call takes longer than a tick, the ticks that were missed are skipped rather than run back to back, and
+  /// counted in [`TickContext::missed_ticks`](TickContext::missed_ticks).
+  ///
+  /// While the EventSync is paused, the loop waits for it to be unpaused.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::*;
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let event_sync = EventSync::new_millis(tickrate);
+  ///
+  /// event_sync.run_every_tick(|context| {
+  ///   // Do this tick's work.
+  ///
+  ///   if context.iteration == 4 {
+  ///     LoopControl::Break
+  ///   } else {
+  ///     LoopControl::Continue
+  ///   }
+  /// });
+  ///
+  /// assert!(event_sync.ticks_since_started() >= 5);
+  /// ```
+  pub fn run_every_tick<F: FnMut(&TickContext) -> LoopControl>(&self, mut closure: F) {
+    let mut next_tick = self.ticks_since_started() + 1;
+    let mut iteration = 0;
+
+    loop {
+      let (target, tick) = self.sleep_until_tick(next_tick);
+
+      let context = TickContext {
+        tick,
+        // Counted from the target the wait ended on, as restarting or moving the EventSync back moves it.
+        missed_ticks: tick.saturating_sub(target),
+        iteration,
+      };
+
+      if closure(&context) == LoopControl::Break {
+        return;
+      }
+
+      iteration += 1;
+      next_tick = tick + 1;
+    }
+  }
+
+  /// [`run_every_tick()`](EventSync::run_every_tick), recording how long each call took and how long the loop
+  /// slept after it into the profile.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::*;
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let event_sync = EventSync::new_millis(tickrate);
+  /// let mut profile = LoopProfile::new();
+  ///
+  /// event_sync.run_every_tick_profiled(&mut profile, |context| {
+  ///   if context.iteration == 2 {
+  ///     LoopControl::Break
+  ///   } else {
+  ///     LoopControl::Continue
+  ///   }
+  /// });
+  ///
+  /// assert_eq!(profile.records().len(), 3);
+  /// assert_eq!(profile.overruns(), 0);
+  /// ```
+  pub fn run_every_tick_profiled<F: FnMut(&TickContext) -> LoopControl>(
+    &self,
+    profile: &mut LoopProfile,
+    mut closure: F,
+  ) {
+    // The sleep after a call is only known once the next call starts.
+    let mut previous: Option<(IterationRecord, Instant)> = None;
+
+    self.run_every_tick(|context| {
+      let started_at = Instant::now();
+
+      if let Some((mut record, finished_at)) = previous.take() {
+        record.sleep_duration = started_at.saturating_duration_since(finished_at);
+        profile.record(record);
+      }
+
+      let control = closure(context);
+      let finished_at = Instant::now();
+
+      let record = IterationRecord {
+        tick: context.tick,
+        work_duration: finished_at.saturating_duration_since(started_at),
+        sleep_duration: Duration::ZERO,
+        overrun: self.ticks_since_started() > context.tick,
+      };
+
+      match control {
+        LoopControl::Continue => previous = Some((record, finished_at)),
+        LoopControl::Break => profile.record(record),
+      }
+
+      control
+    });
+  }
+
+  /// Sleeps until the given tick has occurred, returning the tick that was waited for and the tick it's on.
+  ///
+  /// If the EventSync is moved back or has its tickrate changed, the next tick after that is waited for instead.
+  fn sleep_until_tick(&self, tick: u64) -> (u64, u64) {
+    self
+      .sleep_until_tick_in_chunks(tick, WaitTarget::Relative, || false)
+      .unwrap_or((tick, tick))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  #[test]
+  fn slow_ticks_are_skipped() {
+    let event_sync = EventSync::new_millis(TEST_TICKRATE);
+    let mut contexts = Vec::new();
+
+    event_sync.run_every_tick(|context| {
+      contexts.push(*context);
+
+      if context.iteration == 0 {
+        std::thread::sleep(Duration::from_millis(TEST_TICKRATE * 3));
+      }
+
+      if context.iteration == 2 {
+        LoopControl::Break
+      } else {
+        LoopControl::Continue
+      }
+    });
+
+    assert_eq!(contexts.len(), 3);
+    assert!(contexts[1].missed_ticks >= 2);
+    assert_eq!(
+      contexts[1].tick,
+      contexts[0].tick + contexts[1].missed_ticks + 1
+    );
+    assert!(contexts[2].tick > contexts[1].tick);
+  }
+
+  #[test]
+  fn restarting_during_a_wait_misses_no_ticks() {
+    let tickrate = TEST_TICKRATE * 10;
+    let event_sync = EventSync::from_starting_tick_millis(tickrate, 100, false);
+    let mut restarting_event_sync = event_sync.clone();
+    let mut contexts = Vec::new();
+
+    let restarting = std::thread::spawn(move || {
+      std::thread::sleep(Duration::from_millis(tickrate / 5));
+      restarting_event_sync.restart();
+    });
+
+    event_sync.run_every_tick(|context| {
+      contexts.push(*context);
+
+      LoopControl::Break
+    });
+    restarting.join().unwrap();
+
+    assert_eq!(contexts[0].tick, 1);
+    assert_eq!(contexts[0].missed_ticks, 0);
+  }
+
+  #[test]
+  fn profiles_record_overruns() {
+    let event_sync = EventSync::new_millis(TEST_TICKRATE);
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let mut profile = LoopProfile::streaming(sender);
+
+    event_sync.run_every_tick_profiled(&mut profile, |context| {
+      if context.iteration == 0 {
+        std::thread::sleep(Duration::from_millis(TEST_TICKRATE * 2));
+
+        LoopControl::Continue
+      } else {
+        LoopControl::Break
+      }
+    });
+    drop(profile);
+
+    let records: Vec<IterationRecord> = receiver.iter().collect();
+
+    assert_eq!(records.len(), 2);
+    assert!(records[0].overrun);
+    assert!(records[0].work_duration >= Duration::from_millis(TEST_TICKRATE * 2));
+    assert_eq!(records[1].sleep_duration, Duration::ZERO);
+  }
+
+  #[test]
+  fn paused_loops_wait_for_unpausing() {
+    let mut event_sync = EventSync::new_paused_millis(TEST_TICKRATE);
+    let mut runs = 0;
+
+    std::thread::scope(|scope| {
+      let immutable_event_sync = event_sync.clone_immutable();
+
+      let runner = scope.spawn(move || {
+        immutable_event_sync.run_every_tick(|_| {
+          runs += 1;
+
+          LoopControl::Break
+        });
+
+        runs
+      });
+
+      std::thread::sleep(Duration::from_millis(TEST_TICKRATE * 2));
+      assert!(!runner.is_finished());
+
+      event_sync.unpause().unwrap();
+
+      assert_eq!(runner.join().unwrap(), 1);
+    });
+  }
+}
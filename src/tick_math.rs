@@ -0,0 +1,92 @@
+//! The tick arithmetic EventSync uses, as free functions over plain values.
+//!
+//! Every function takes the time that has passed since the EventSync started and the tickrate in milliseconds, and
+//! rounds the same way an EventSync does, so schedules computed outside of one can be checked against it exactly.
+//!
+//! - A tick occurs once the whole of its time has passed, so the tick an EventSync is on is rounded down.
+//! - Time within a tick is kept to the nanosecond.
+//! - A tickrate of 0 is treated as 1, as an EventSync never has a tickrate below 1.
+//!
+//! # Example
+//!
+//! ```
+//! use event_sync::tick_math;
+//! use std::time::Duration;
+//!
+//! let tickrate = 10; // 10ms between every tick.
+//! let elapsed = Duration::from_micros(25_500);
+//!
+//! assert_eq!(tick_math::ticks_in(elapsed, tickrate), 2);
+//! assert_eq!(tick_math::time_until_next_tick(elapsed, tickrate), Duration::from_micros(4_500));
+//! assert_eq!(tick_math::time_until_tick(elapsed, 5, tickrate), Some(Duration::from_micros(24_500)));
+//! assert_eq!(tick_math::time_until_tick(elapsed, 2, tickrate), None);
+//! ```
+
+use core::time::Duration;
+
+/// Returns the amount of whole ticks that fit in the elapsed time, which is the tick an EventSync is on.
+pub fn ticks_in(elapsed: Duration, tickrate: u64) -> u64 {
+  (elapsed.as_millis() / tickrate.max(1) as u128) as u64
+}
+
+/// Returns the time since starting that the tick occurs at, saturating at the longest Duration that whole
+/// milliseconds can make.
+pub fn time_of_tick(tick: u64, tickrate: u64) -> Duration {
+  Duration::from_millis(tick.saturating_mul(tickrate.max(1)))
+}
+
+/// Returns the time since starting that the tick occurs at, or None if it's too far out to be represented.
+pub fn checked_time_of_tick(tick: u64, tickrate: u64) -> Option<Duration> {
+  tick.checked_mul(tickrate.max(1)).map(Duration::from_millis)
+}
+
+/// Returns the time that has passed since the last tick.
+pub fn time_since_last_tick(elapsed: Duration, tickrate: u64) -> Duration {
+  Duration::from_nanos((elapsed.as_nanos() % (tickrate.max(1) as u128 * 1_000_000)) as u64)
+}
+
+/// Returns the time left until the next tick.
+pub fn time_until_next_tick(elapsed: Duration, tickrate: u64) -> Duration {
+  Duration::from_millis(tickrate.max(1)).saturating_sub(time_since_last_tick(elapsed, tickrate))
+}
+
+/// Returns how far through the current tick the elapsed time is, from 0.0 up to but not including 1.0.
+pub fn tick_progress(elapsed: Duration, tickrate: u64) -> f64 {
+  time_since_last_tick(elapsed, tickrate).as_secs_f64()
+    / Duration::from_millis(tickrate.max(1)).as_secs_f64()
+}
+
+/// Returns the time left until the tick occurs.
+///
+/// None is returned if the tick is the current one or has already passed, as waiting for it wouldn't sleep.
+pub fn time_until_tick(elapsed: Duration, tick: u64, tickrate: u64) -> Option<Duration> {
+  if ticks_in(elapsed, tickrate) >= tick {
+    return None;
+  }
+
+  Some(time_of_tick(tick, tickrate).saturating_sub(elapsed))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn ticks_round_down_to_whole_milliseconds() {
+    let elapsed = Duration::from_nanos(29_999_999);
+
+    assert_eq!(ticks_in(elapsed, 10), 2);
+    assert_eq!(
+      time_since_last_tick(elapsed, 10),
+      Duration::from_nanos(9_999_999)
+    );
+    assert_eq!(time_until_next_tick(elapsed, 10), Duration::from_nanos(1));
+    assert_eq!(
+      time_until_tick(elapsed, 3, 10),
+      Some(Duration::from_nanos(1))
+    );
+    assert_eq!(ticks_in(elapsed, 0), 29);
+    assert_eq!(time_of_tick(u64::MAX, 2), Duration::from_millis(u64::MAX));
+    assert_eq!(checked_time_of_tick(u64::MAX, 2), None);
+  }
+}
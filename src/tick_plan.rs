@@ -0,0 +1,183 @@
+use crate::errors::TimeError;
+use crate::EventSync;
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+
+/// A precomputed schedule of ticks to act on, such as the steps of a cutscene or a test scenario.
+///
+/// Plans are built from explicit ticks, ranges, and every-n patterns, then every planned tick can be waited on in
+/// order with [`wait_next()`](TickPlan::wait_next), or read with [`next()`](Iterator::next).
+/// Ticks are absolute, counted from when the EventSync started.
+///
+/// Plans serialize as their ticks and how far through them they are, so a scripted sequence can be stored as data.
+///
+/// # Example
+///
+/// ```
+/// use event_sync::TickPlan;
+///
+/// let plan = TickPlan::new()
+///   .with_ticks([2, 30])
+///   .with_range(5..8)
+///   .with_every(10, 0..30);
+///
+/// assert_eq!(plan.collect::<Vec<_>>(), [0, 2, 5, 6, 7, 10, 20, 30]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "SerializedTickPlan")]
+pub struct TickPlan {
+  ticks: Vec<u64>,
+  next: usize,
+}
+
+/// A plan as it's serialized, which may be out of order.
+#[derive(Deserialize)]
+struct SerializedTickPlan {
+  ticks: Vec<u64>,
+  #[serde(default)]
+  next: usize,
+}
+
+impl From<SerializedTickPlan> for TickPlan {
+  fn from(serialized: SerializedTickPlan) -> Self {
+    let mut plan = Self::new().with_ticks(serialized.ticks);
+    plan.next = serialized.next.min(plan.ticks.len());
+
+    plan
+  }
+}
+
+impl TickPlan {
+  /// Creates a plan with no ticks.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Adds every given tick to the plan.
+  pub fn with_ticks<I: IntoIterator<Item = u64>>(mut self, ticks: I) -> Self {
+    self.ticks.extend(ticks);
+    self.ticks.sort_unstable();
+    self.ticks.dedup();
+
+    self
+  }
+
+  /// Adds every tick in the range to the plan.
+  pub fn with_range(self, range: Range<u64>) -> Self {
+    self.with_ticks(range)
+  }
+
+  /// Adds every nth tick in the range to the plan, starting from the start of the range.
+  ///
+  /// A step of 0 is treated as 1.
+  pub fn with_every(self, step: u64, range: Range<u64>) -> Self {
+    self.with_ticks(range.step_by(step.max(1) as usize))
+  }
+
+  /// Returns every planned tick, in order.
+  pub fn ticks(&self) -> &[u64] {
+    &self.ticks
+  }
+
+  /// Returns the next planned tick, without moving past it.
+  pub fn peek(&self) -> Option<u64> {
+    self.ticks.get(self.next).copied()
+  }
+
+  /// Returns the amount of planned ticks that haven't been moved past.
+  pub fn remaining(&self) -> usize {
+    self.ticks.len() - self.next
+  }
+
+  /// Returns true once every planned tick has been moved past.
+  pub fn is_finished(&self) -> bool {
+    self.remaining() == 0
+  }
+
+  /// Starts the plan over from its first tick.
+  pub fn reset(&mut self) {
+    self.next = 0;
+  }
+
+  /// Waits on the EventSync for the next planned tick, returning it, or None if the plan is finished.
+  ///
+  /// If the tick has already occurred, nothing is waited for, so acts that ran late still happen in order.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if the EventSync is paused, without moving past the tick.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::*;
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let event_sync = EventSync::new_millis(tickrate);
+  /// let mut plan = TickPlan::new().with_every(2, 1..6);
+  ///
+  /// while let Some(tick) = plan.wait_next(&event_sync).unwrap() {
+  ///   // Act on the tick.
+  ///   assert!(event_sync.ticks_since_started() >= tick);
+  /// }
+  ///
+  /// assert!(event_sync.ticks_since_started() >= 5);
+  /// ```
+  pub fn wait_next<T>(&mut self, event_sync: &EventSync<T>) -> Result<Option<u64>, TimeError> {
+    let Some(tick) = self.peek() else {
+      return Ok(None);
+    };
+
+    match event_sync.wait_until(tick) {
+      Ok(_) | Err(TimeError::ThatTimeHasAlreadyHappened) => (),
+      Err(error) => return Err(error),
+    }
+
+    self.next += 1;
+
+    Ok(Some(tick))
+  }
+}
+
+impl Iterator for TickPlan {
+  type Item = u64;
+
+  fn next(&mut self) -> Option<u64> {
+    let tick = self.peek()?;
+    self.next += 1;
+
+    Some(tick)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn paused_waits_keep_their_place() {
+    let mut event_sync = EventSync::new_paused_millis(10);
+    let mut plan = TickPlan::new().with_ticks([0, 1]);
+
+    assert_eq!(plan.wait_next(&event_sync), Err(TimeError::EventSyncPaused));
+    assert_eq!(plan.remaining(), 2);
+
+    event_sync.unpause().unwrap();
+
+    assert_eq!(plan.wait_next(&event_sync), Ok(Some(0)));
+    assert_eq!(plan.wait_next(&event_sync), Ok(Some(1)));
+    assert_eq!(plan.wait_next(&event_sync), Ok(None));
+  }
+
+  #[test]
+  fn plans_are_sorted_when_deserialized() {
+    let plan: TickPlan = serde_json::from_str(r#"{"ticks":[9,3,3,5],"next":1}"#).unwrap();
+
+    assert_eq!(plan.ticks(), [3, 5, 9]);
+    assert_eq!(plan.peek(), Some(5));
+    assert_eq!(
+      serde_json::to_string(&plan).unwrap(),
+      r#"{"ticks":[3,5,9],"next":1}"#
+    );
+  }
+}
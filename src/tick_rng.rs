@@ -0,0 +1,195 @@
+use crate::EventSync;
+
+/// An RNG that can be created from the seed of a tick, for [`tick_rng_with()`](EventSync::tick_rng_with).
+///
+/// Implement this to use a different RNG algorithm for per-tick randomness, such as one from the `rand` crate.
+///
+/// # Example
+///
+/// ```
+/// use event_sync::*;
+///
+/// /// A xorshift generator, standing in for any RNG that can be seeded from a u64.
+/// struct Xorshift(u64);
+///
+/// impl TickSeeded for Xorshift {
+///   fn from_tick_seed(seed: u64) -> Self {
+///     // Xorshift can't start from 0.
+///     Self(seed.max(1))
+///   }
+/// }
+///
+/// let tickrate = 10; // 10ms between every tick.
+/// let event_sync = EventSync::new_paused_millis(tickrate);
+///
+/// let rng: Xorshift = event_sync.tick_rng_with(42);
+/// let other_rng: Xorshift = event_sync.tick_rng_with(42);
+///
+/// assert_eq!(rng.0, other_rng.0);
+/// ```
+pub trait TickSeeded {
+  /// Creates the RNG from the seed of a tick.
+  ///
+  /// The same seed has to give the same sequence on every machine, or peers will disagree.
+  fn from_tick_seed(seed: u64) -> Self;
+}
+
+/// A small deterministic RNG, created for a tick with [`EventSync::tick_rng()`](EventSync::tick_rng).
+///
+/// Gives the same sequence for the same seed on every platform, so every thread and peer on the same tick draws
+/// the same numbers. It's a SplitMix64 generator, which is fast and well distributed but not cryptographically
+/// secure.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TickRng {
+  state: u64,
+}
+
+const GOLDEN_GAMMA: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// Scrambles the bits of the value, so nearby values give unrelated results.
+fn mix(mut value: u64) -> u64 {
+  value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+  value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+  value ^ (value >> 31)
+}
+
+impl TickRng {
+  /// Returns the seed for the given tick and generation, which every RNG for that tick is created from.
+  ///
+  /// Useful for recreating the randomness of a tick that has already passed, such as when re-running it.
+  pub fn seed_for(seed: u64, tick: u64, generation: u64) -> u64 {
+    [tick, generation]
+      .into_iter()
+      .fold(mix(seed), |seed, value| {
+        mix(seed ^ mix(value.wrapping_add(GOLDEN_GAMMA)))
+      })
+  }
+
+  /// Returns the next random u64.
+  pub fn next_u64(&mut self) -> u64 {
+    self.state = self.state.wrapping_add(GOLDEN_GAMMA);
+
+    mix(self.state)
+  }
+
+  /// Returns the next random u32.
+  pub fn next_u32(&mut self) -> u32 {
+    (self.next_u64() >> 32) as u32
+  }
+
+  /// Returns a random f64 from 0.0 up to, but not including, 1.0.
+  pub fn next_f64(&mut self) -> f64 {
+    (self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64
+  }
+
+  /// Returns a random number from 0 up to, but not including, the bound, with every number equally likely.
+  ///
+  /// The bound cannot go below 1. If 0 is passed in, 1 is used.
+  pub fn next_below(&mut self, bound: u64) -> u64 {
+    let bound = bound.max(1);
+    // Drops the values past the last whole multiple of the bound, which would favor the smaller numbers.
+    let rejected = bound.wrapping_neg() % bound;
+
+    loop {
+      let value = self.next_u64();
+
+      if value <= u64::MAX - rejected {
+        return value % bound;
+      }
+    }
+  }
+}
+
+impl TickSeeded for TickRng {
+  fn from_tick_seed(seed: u64) -> Self {
+    Self { state: seed }
+  }
+}
+
+impl<T> EventSync<T> {
+  /// Returns an RNG for the current tick, derived from the seed, the tick, and the
+  /// [`generation`](EventSync::generation).
+  ///
+  /// Every thread sharing the EventSync, and every peer on the same tick with the same seed, gets the same RNG, so
+  /// they agree on the randomness of each tick in lock-step simulations. Restarting gives the repeated tick numbers
+  /// different randomness.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::*;
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let event_sync = EventSync::from_starting_tick_millis(tickrate, 12, true);
+  /// let seed = 0xC0FFEE;
+  ///
+  /// let mut rng = event_sync.tick_rng(seed);
+  /// let mut other_rng = event_sync.clone_immutable().tick_rng(seed);
+  ///
+  /// // A critical hit roll both sides agree on.
+  /// assert_eq!(rng.next_below(100), other_rng.next_below(100));
+  /// ```
+  pub fn tick_rng(&self, seed: u64) -> TickRng {
+    self.tick_rng_with(seed)
+  }
+
+  /// [`tick_rng()`](EventSync::tick_rng), creating an RNG of your choice through [`TickSeeded`](TickSeeded).
+  pub fn tick_rng_with<R: TickSeeded>(&self, seed: u64) -> R {
+    let (tick, generation) = {
+      let inner = self.read_inner();
+
+      (inner.ticks_since_started(), inner.generation())
+    };
+
+    R::from_tick_seed(TickRng::seed_for(seed, tick, generation))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::time::Duration;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  fn draw(event_sync: &EventSync, seed: u64) -> [u64; 4] {
+    let mut rng = event_sync.tick_rng(seed);
+
+    std::array::from_fn(|_| rng.next_u64())
+  }
+
+  #[test]
+  fn rngs_change_with_the_seed_tick_and_generation() {
+    let mut event_sync = EventSync::from_starting_tick_millis(TEST_TICKRATE, 3, true);
+    let first_draw = draw(&event_sync, 1);
+
+    assert_eq!(draw(&event_sync.clone(), 1), first_draw);
+    assert_ne!(draw(&event_sync, 2), first_draw);
+
+    event_sync
+      .sync_to(Duration::from_millis(4 * TEST_TICKRATE))
+      .unwrap();
+
+    assert_ne!(draw(&event_sync, 1), first_draw);
+
+    event_sync.restart_paused();
+    event_sync
+      .sync_to(Duration::from_millis(3 * TEST_TICKRATE))
+      .unwrap();
+
+    assert_eq!(event_sync.ticks_since_started(), 3);
+    assert_ne!(draw(&event_sync, 1), first_draw);
+  }
+
+  #[test]
+  fn bounded_draws_stay_in_bounds() {
+    let mut rng = TickRng::from_tick_seed(TickRng::seed_for(7, 0, 0));
+
+    for bound in [0, 1, 3, 100, u64::MAX] {
+      assert!(rng.next_below(bound) < bound.max(1));
+    }
+
+    assert!((0.0..1.0).contains(&rng.next_f64()));
+  }
+}
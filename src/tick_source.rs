@@ -0,0 +1,167 @@
+//! Registering ticks with a mio reactor, as an event source like any socket.
+//!
+//! Enabled with the `mio` feature.
+
+use crate::timer_fd::TickTimer;
+use crate::EventSync;
+use mio::event::Source;
+use mio::unix::SourceFd;
+use mio::{Interest, Registry, Token};
+use std::io;
+use std::os::fd::AsRawFd;
+
+/// A mio [`Source`](Source) that's readable once the next tick of an EventSync occurs.
+///
+/// Register it with a readable interest, and when an event comes in for its token, call
+/// [`acknowledge()`](TickSource::acknowledge) to get the tick that occurred. Acknowledging arms it for the next tick,
+/// so it keeps firing once per tick for as long as every event is acknowledged. mio's events are edge-triggered, so an
+/// event that isn't acknowledged is never followed by another.
+///
+/// Pausing and changes to the tickrate are picked up on each acknowledgement. While paused, events still come in once
+/// every tick's worth of time, but acknowledging them returns None until a tick occurs.
+///
+/// Created with [`EventSync::tick_source()`](EventSync::tick_source).
+///
+/// # Example
+///
+/// ```
+/// use event_sync::*;
+/// use mio::{Events, Interest, Poll, Token};
+///
+/// const TICK: Token = Token(0);
+///
+/// let tickrate = 10; // 10ms between every tick.
+/// let event_sync = EventSync::new_millis(tickrate);
+///
+/// let mut poll = Poll::new().unwrap();
+/// let mut events = Events::with_capacity(16);
+/// let mut ticks = event_sync.tick_source().unwrap();
+///
+/// poll
+///   .registry()
+///   .register(&mut ticks, TICK, Interest::READABLE)
+///   .unwrap();
+///
+/// let mut ticks_seen = 0;
+///
+/// while ticks_seen < 3 {
+///   poll.poll(&mut events, None).unwrap();
+///
+///   for event in &events {
+///     if event.token() == TICK && ticks.acknowledge().unwrap().is_some() {
+///       ticks_seen += 1;
+///     }
+///   }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct TickSource {
+  timer: TickTimer,
+}
+
+impl TickSource {
+  /// Clears the source's readiness and arms it for the next tick, returning the tick the EventSync is on if it has
+  /// changed since the last time it was acknowledged.
+  ///
+  /// # Errors
+  ///
+  /// - If the OS fails to read or arm the timer.
+  pub fn acknowledge(&mut self) -> io::Result<Option<u64>> {
+    self.timer.acknowledge()
+  }
+
+  /// Arms the source for the next tick, replacing when it was set to fire.
+  ///
+  /// Only needed after changing the EventSync, such as unpausing it, to be woken for the new next tick sooner than
+  /// the source was set for.
+  ///
+  /// # Errors
+  ///
+  /// - If the OS fails to arm the timer.
+  pub fn rearm(&self) -> io::Result<()> {
+    self.timer.rearm()
+  }
+}
+
+impl Source for TickSource {
+  fn register(&mut self, registry: &Registry, token: Token, interests: Interest) -> io::Result<()> {
+    self.timer.rearm()?;
+
+    SourceFd(&self.timer.as_raw_fd()).register(registry, token, interests)
+  }
+
+  fn reregister(
+    &mut self,
+    registry: &Registry,
+    token: Token,
+    interests: Interest,
+  ) -> io::Result<()> {
+    self.timer.rearm()?;
+
+    SourceFd(&self.timer.as_raw_fd()).reregister(registry, token, interests)
+  }
+
+  fn deregister(&mut self, registry: &Registry) -> io::Result<()> {
+    SourceFd(&self.timer.as_raw_fd()).deregister(registry)
+  }
+}
+
+impl From<TickTimer> for TickSource {
+  fn from(timer: TickTimer) -> Self {
+    Self { timer }
+  }
+}
+
+impl<T> EventSync<T> {
+  /// Creates a [`TickSource`](TickSource), which can be registered with a mio reactor to be woken for each tick.
+  ///
+  /// # Errors
+  ///
+  /// - If the OS fails to create or arm the timer, such as when the process is out of file descriptors.
+  pub fn tick_source(&self) -> io::Result<TickSource> {
+    self.tick_timer().map(TickSource::from)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use mio::{Events, Poll};
+  use std::time::Duration;
+
+  const TEST_TICKRATE: u64 = 10;
+  const TICK: Token = Token(1);
+
+  #[test]
+  fn ticks_arrive_after_a_tickrate_change() {
+    let mut event_sync = EventSync::new_millis(TEST_TICKRATE);
+    let mut poll = Poll::new().unwrap();
+    let mut events = Events::with_capacity(4);
+    let mut ticks = event_sync.tick_source().unwrap();
+
+    poll
+      .registry()
+      .register(&mut ticks, TICK, Interest::READABLE)
+      .unwrap();
+    event_sync.change_tickrate_millis(TEST_TICKRATE * 2);
+
+    let mut seen_ticks = Vec::new();
+
+    while seen_ticks.len() < 2 {
+      poll
+        .poll(&mut events, Some(Duration::from_secs(1)))
+        .unwrap();
+      assert!(!events.is_empty());
+
+      for event in &events {
+        assert_eq!(event.token(), TICK);
+        seen_ticks.extend(ticks.acknowledge().unwrap());
+      }
+    }
+
+    assert!(seen_ticks[0] < seen_ticks[1]);
+    assert_eq!(event_sync.get_tickrate_millis(), TEST_TICKRATE * 2);
+
+    poll.registry().deregister(&mut ticks).unwrap();
+  }
+}
@@ -0,0 +1,273 @@
+use crate::keyframes::Keyframes;
+use crate::{EventSync, Immutable};
+use std::sync::{Mutex, MutexGuard};
+
+/// A clock running at a changing speed relative to an EventSync, such as for slow-motion effects.
+///
+/// The speed is how many of its ticks pass for every tick of the EventSync, and can be set outright, ramped to over
+/// a few ticks, or scheduled along a curve of [`Keyframes`](Keyframes). Speeds are blended smoothly between keys,
+/// and the time that passes at each speed is added up as it goes, so the dilated tick never jumps or goes back.
+///
+/// It starts on the same tick as the EventSync, and at a speed of 1.0. While the EventSync is paused, no time
+/// passes on it either. Moving the EventSync back, such as by restarting, holds the dilated tick where it was until
+/// the EventSync moves forward again.
+///
+/// # Example
+///
+/// ```
+/// use event_sync::*;
+/// use std::time::Duration;
+///
+/// let tickrate = 10; // 10ms between every tick.
+/// let mut event_sync = EventSync::new_paused_millis(tickrate);
+/// let dilation = TimeDilation::new(event_sync.clone_immutable());
+///
+/// // Ramp from 1.0x down to 0.1x over 30 ticks, then back up over the next 30.
+/// dilation.schedule(
+///   Keyframes::new()
+///     .with_key(0, 1.0)
+///     .with_key(30, 0.1)
+///     .with_key(60, 1.0),
+/// );
+///
+/// event_sync.sync_to(Duration::from_millis(300)).unwrap();
+///
+/// assert_eq!(dilation.speed(), 0.1);
+/// // The average speed over the ramp was 0.55x.
+/// assert_eq!(dilation.ticks_since_started(), 16);
+/// ```
+#[derive(Debug)]
+pub struct TimeDilation {
+  event_sync: EventSync<Immutable>,
+  state: Mutex<DilationState>,
+}
+
+#[derive(Debug)]
+struct DilationState {
+  /// The speed at each tick of the curve, counted from its origin.
+  curve: Keyframes<f64>,
+  /// The fractional EventSync tick the curve starts on.
+  curve_origin: f64,
+  /// The fractional EventSync tick the dilated tick was last brought up to date on.
+  source_tick: f64,
+  dilated_tick: f64,
+}
+
+impl DilationState {
+  /// Adds the time that passed at the curve's speeds up to the EventSync tick.
+  fn advance(&mut self, source_tick: f64) {
+    if source_tick < self.source_tick {
+      // Moved back, so the curve carries on from where it was instead of being replayed.
+      self.curve_origin -= self.source_tick - source_tick;
+    } else {
+      self.dilated_tick += integrate(
+        &self.curve,
+        self.source_tick - self.curve_origin,
+        source_tick - self.curve_origin,
+      );
+    }
+
+    self.source_tick = source_tick;
+  }
+
+  fn speed(&self) -> f64 {
+    speed_at(&self.curve, self.source_tick - self.curve_origin)
+  }
+
+  /// Starts the curve from the current tick.
+  fn restart_curve(&mut self, curve: Keyframes<f64>) {
+    self.curve = curve;
+    self.curve_origin = self.source_tick;
+  }
+}
+
+impl TimeDilation {
+  /// Creates a clock on the same tick as the EventSync, running at a speed of 1.0.
+  pub fn new(event_sync: EventSync<Immutable>) -> Self {
+    let source_tick = fractional_tick(&event_sync);
+
+    Self {
+      event_sync,
+      state: Mutex::new(DilationState {
+        curve: constant(1.0),
+        curve_origin: source_tick,
+        source_tick,
+        dilated_tick: source_tick,
+      }),
+    }
+  }
+
+  /// Sets the speed from now on.
+  ///
+  /// A speed of 0.0 stops time on the clock. Speeds below 0, or that aren't finite, are set to 1.0.
+  pub fn set_speed(&self, speed: f64) {
+    self.lock_advanced().restart_curve(constant(speed));
+  }
+
+  /// Changes the speed steadily from the current one to the given one over the given amount of EventSync ticks,
+  /// then holds it there.
+  ///
+  /// Speeds below 0, or that aren't finite, are set to 1.0.
+  pub fn ramp_to(&self, speed: f64, over_ticks: u64) {
+    let mut state = self.lock_advanced();
+    let curve = Keyframes::new()
+      .with_key(0, state.speed())
+      .with_key(over_ticks, valid_speed(speed));
+
+    state.restart_curve(curve);
+  }
+
+  /// Follows the curve of speeds from now on, with the keys placed at the amount of EventSync ticks from now.
+  ///
+  /// Speeds are blended between the keys. Before the first key, its speed is used, and after the last key, its
+  /// speed is held. Starting the curve at the current [`speed()`](TimeDilation::speed) keeps the change smooth.
+  /// Speeds below 0, or that aren't finite, are set to 1.0, and a curve without any keys runs at 1.0.
+  pub fn schedule(&self, curve: Keyframes<f64>) {
+    let curve = if curve.keys().is_empty() {
+      constant(1.0)
+    } else {
+      curve
+        .keys()
+        .iter()
+        .fold(Keyframes::new(), |valid, (tick, speed)| {
+          valid.with_key(*tick, valid_speed(*speed))
+        })
+    };
+
+    self.lock_advanced().restart_curve(curve);
+  }
+
+  /// Returns how many ticks of the clock pass for every tick of the EventSync at the moment.
+  pub fn speed(&self) -> f64 {
+    self.lock_advanced().speed()
+  }
+
+  /// Returns the tick the clock is on, including how far through it is.
+  pub fn fractional_tick(&self) -> f64 {
+    self.lock_advanced().dilated_tick
+  }
+
+  /// Returns the amount of whole ticks that have passed on the clock.
+  pub fn ticks_since_started(&self) -> u64 {
+    self.fractional_tick() as u64
+  }
+
+  /// Locks the state, first bringing it up to the EventSync's current tick.
+  fn lock_advanced(&self) -> MutexGuard<'_, DilationState> {
+    let source_tick = fractional_tick(&self.event_sync);
+    let mut state = self
+      .state
+      .lock()
+      .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    state.advance(source_tick);
+
+    state
+  }
+}
+
+fn fractional_tick(event_sync: &EventSync<Immutable>) -> f64 {
+  let view = event_sync.view();
+
+  view.tick as f64 + view.tick_progress()
+}
+
+fn valid_speed(speed: f64) -> f64 {
+  if speed >= 0.0 && speed.is_finite() {
+    speed
+  } else {
+    1.0
+  }
+}
+
+fn constant(speed: f64) -> Keyframes<f64> {
+  Keyframes::new().with_key(0, valid_speed(speed))
+}
+
+fn speed_at(curve: &Keyframes<f64>, tick: f64) -> f64 {
+  curve.sample(tick).unwrap_or(1.0)
+}
+
+/// Returns the amount of dilated ticks that pass between the two ticks of the curve.
+///
+/// Speeds are linear between keys, so adding up the area under each piece between them is exact.
+fn integrate(curve: &Keyframes<f64>, from: f64, to: f64) -> f64 {
+  let keys_between = curve
+    .keys()
+    .iter()
+    .map(|(tick, _)| *tick as f64)
+    .filter(|tick| *tick > from && *tick < to);
+  let mut total = 0.0;
+  let mut start = from;
+
+  for end in keys_between.chain([to]) {
+    total += (speed_at(curve, start) + speed_at(curve, end)) / 2.0 * (end - start);
+    start = end;
+  }
+
+  total
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::Mutable;
+  use std::time::Duration;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  fn move_to_tick(event_sync: &mut EventSync<Mutable>, tick: u64) {
+    event_sync
+      .sync_to(Duration::from_millis(tick * TEST_TICKRATE))
+      .unwrap();
+  }
+
+  #[test]
+  fn speeds_add_up_along_the_curve() {
+    let mut event_sync = EventSync::from_starting_tick_millis(TEST_TICKRATE, 10, true);
+    let dilation = TimeDilation::new(event_sync.clone_immutable());
+
+    dilation.set_speed(2.0);
+    move_to_tick(&mut event_sync, 13);
+
+    assert_eq!(dilation.fractional_tick(), 16.0);
+
+    // From 2.0 down to 0.0 over 4 ticks, averaging 1.0.
+    dilation.ramp_to(0.0, 4);
+    move_to_tick(&mut event_sync, 15);
+
+    assert_eq!(dilation.speed(), 1.0);
+    assert_eq!(dilation.fractional_tick(), 19.0);
+
+    move_to_tick(&mut event_sync, 30);
+
+    assert_eq!(dilation.fractional_tick(), 20.0);
+
+    dilation.schedule(Keyframes::new().with_key(2, f64::NAN));
+
+    assert_eq!(dilation.speed(), 1.0);
+  }
+
+  #[test]
+  fn moving_back_never_moves_the_dilated_tick_back() {
+    let mut event_sync = EventSync::from_starting_tick_millis(TEST_TICKRATE, 20, true);
+    let dilation = TimeDilation::new(event_sync.clone_immutable());
+
+    dilation.schedule(Keyframes::new().with_key(0, 1.0).with_key(10, 3.0));
+    move_to_tick(&mut event_sync, 25);
+
+    // The speed went from 1.0 to 2.0.
+    assert_eq!(dilation.fractional_tick(), 27.5);
+
+    event_sync.restart_paused();
+
+    assert_eq!(dilation.fractional_tick(), 27.5);
+    assert_eq!(dilation.speed(), 2.0);
+
+    move_to_tick(&mut event_sync, 5);
+
+    // Carried on from the middle of the curve, going from 2.0 to 3.0.
+    assert_eq!(dilation.fractional_tick(), 40.0);
+    assert_eq!(dilation.speed(), 3.0);
+  }
+}
@@ -0,0 +1,484 @@
+use crate::tick_loop::LoopControl;
+use crate::waiting::WaitTarget;
+use crate::{EventSync, Immutable};
+use serde::{Deserialize, Serialize};
+use std::sync::mpsc::Sender;
+use std::thread::JoinHandle;
+
+/// A named event placed at a tick of a [`Timeline`](Timeline), optionally carrying a payload.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimelineEvent<P> {
+  /// The tick the event happens on, counted from the start of the timeline.
+  pub tick: u64,
+  /// The name of the event.
+  pub name: String,
+  /// The data the event carries, if any.
+  pub payload: Option<P>,
+}
+
+/// A track of named events placed at ticks, such as a cutscene or a recorded demo.
+///
+/// Timelines serialize as their events, so they can be stored as data and played back later with a
+/// [`TimelinePlayer`](TimelinePlayer).
+///
+/// # Example
+///
+/// ```
+/// use event_sync::*;
+///
+/// let timeline = Timeline::new()
+///   .with_event(0, "fade_in")
+///   .with_event_payload(20, "say", "Hello.".to_string())
+///   .with_event(50, "fade_out");
+///
+/// let serialized = serde_json::to_string(&timeline).unwrap();
+/// let deserialized: Timeline<String> = serde_json::from_str(&serialized).unwrap();
+///
+/// assert_eq!(deserialized, timeline);
+/// assert_eq!(timeline.length(), 51);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "SerializedTimeline<P>")]
+#[serde(bound(deserialize = "P: Deserialize<'de>"))]
+pub struct Timeline<P = ()> {
+  events: Vec<TimelineEvent<P>>,
+  length: Option<u64>,
+}
+
+/// A timeline as it's serialized, which may be out of order.
+#[derive(Deserialize)]
+struct SerializedTimeline<P> {
+  events: Vec<TimelineEvent<P>>,
+  length: Option<u64>,
+}
+
+impl<P> From<SerializedTimeline<P>> for Timeline<P> {
+  fn from(mut serialized: SerializedTimeline<P>) -> Self {
+    // Stable, so events on the same tick keep their order.
+    serialized.events.sort_by_key(|event| event.tick);
+
+    Self {
+      events: serialized.events,
+      length: serialized.length,
+    }
+  }
+}
+
+impl<P> Default for Timeline<P> {
+  fn default() -> Self {
+    Self {
+      events: Vec::new(),
+      length: None,
+    }
+  }
+}
+
+impl<P> Timeline<P> {
+  /// Creates a timeline with no events.
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Places an event without a payload at the tick.
+  ///
+  /// Events on the same tick happen in the order they were added.
+  pub fn with_event(self, tick: u64, name: &str) -> Self {
+    self.with(tick, name, None)
+  }
+
+  /// Places an event carrying the payload at the tick.
+  pub fn with_event_payload(self, tick: u64, name: &str, payload: P) -> Self {
+    self.with(tick, name, Some(payload))
+  }
+
+  /// Sets how many ticks the timeline lasts, which is where looping playback starts over.
+  ///
+  /// Events at or after the length are never played.
+  /// Defaults to just after the last event.
+  pub fn with_length(mut self, ticks: u64) -> Self {
+    self.length = Some(ticks);
+
+    self
+  }
+
+  fn with(mut self, tick: u64, name: &str, payload: Option<P>) -> Self {
+    let index = self.events.partition_point(|event| event.tick <= tick);
+
+    self.events.insert(
+      index,
+      TimelineEvent {
+        tick,
+        name: name.to_string(),
+        payload,
+      },
+    );
+
+    self
+  }
+
+  /// Returns every event, in the order they happen.
+  pub fn events(&self) -> &[TimelineEvent<P>] {
+    &self.events
+  }
+
+  /// Returns how many ticks the timeline lasts.
+  pub fn length(&self) -> u64 {
+    self
+      .length
+      .unwrap_or_else(|| self.events.last().map_or(0, |event| event.tick + 1))
+  }
+}
+
+/// Plays a [`Timeline`](Timeline) back against an EventSync, firing each event once its tick arrives.
+///
+/// Playback starts from the tick the player was created on, and can be moved with
+/// [`seek()`](TimelinePlayer::seek), sped up or slowed down with [`set_speed()`](TimelinePlayer::set_speed), or set
+/// to start over at the end with [`with_looping()`](TimelinePlayer::with_looping).
+///
+/// # Example
+///
+/// ```
+/// use event_sync::*;
+///
+/// let tickrate = 10; // 10ms between every tick.
+/// let event_sync = EventSync::new_millis(tickrate);
+/// let timeline = Timeline::<()>::new()
+///   .with_event(1, "open_door")
+///   .with_event(3, "enter");
+///
+/// let mut player = TimelinePlayer::new(event_sync.clone_immutable(), timeline);
+/// let mut fired = Vec::new();
+///
+/// player.run(|event| {
+///   fired.push(event.name.clone());
+///
+///   LoopControl::Continue
+/// });
+///
+/// assert_eq!(fired, ["open_door", "enter"]);
+/// ```
+pub struct TimelinePlayer<P> {
+  event_sync: EventSync<Immutable>,
+  timeline: Timeline<P>,
+  is_looping: bool,
+  speed: f64,
+  /// The EventSync tick the first play through started on, which is negative after seeking past the current tick.
+  origin: f64,
+  /// The amount of times the timeline has looped since the first play through.
+  play_through: u64,
+  next_event: usize,
+}
+
+impl<P> TimelinePlayer<P> {
+  /// Creates a player starting the timeline on the current tick.
+  pub fn new(event_sync: EventSync<Immutable>, timeline: Timeline<P>) -> Self {
+    let origin = event_sync.ticks_since_started() as f64;
+
+    Self {
+      event_sync,
+      timeline,
+      is_looping: false,
+      speed: 1.0,
+      origin,
+      play_through: 0,
+      next_event: 0,
+    }
+  }
+
+  /// Sets whether the timeline starts over after its [`length()`](Timeline::length).
+  ///
+  /// A timeline without any events before its length never loops.
+  pub fn with_looping(mut self, is_looping: bool) -> Self {
+    self.is_looping = is_looping;
+
+    self
+  }
+
+  /// Sets how many ticks of the timeline pass for every tick of the EventSync, see
+  /// [`set_speed()`](TimelinePlayer::set_speed).
+  pub fn with_speed(mut self, speed: f64) -> Self {
+    self.set_speed(speed);
+
+    self
+  }
+
+  /// Sets how many ticks of the timeline pass for every tick of the EventSync, continuing from the current
+  /// position.
+  ///
+  /// A speed of 2.0 plays the timeline twice as fast, and 0.25 at a quarter of the speed, keeping the spacing
+  /// between events. Speeds that aren't above 0 are set to 1.0.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use event_sync::*;
+  ///
+  /// let tickrate = 10; // 10ms between every tick.
+  /// let event_sync = EventSync::new_millis(tickrate);
+  /// let timeline = Timeline::<()>::new().with_event(8, "end");
+  ///
+  /// let mut player = TimelinePlayer::new(event_sync.clone_immutable(), timeline).with_speed(4.0);
+  /// let started_on = event_sync.ticks_since_started();
+  ///
+  /// player.run(|_| LoopControl::Continue);
+  ///
+  /// // 8 ticks of the timeline at 4x speed take 2 ticks.
+  /// assert_eq!(event_sync.ticks_since_started() - started_on, 2);
+  /// ```
+  pub fn set_speed(&mut self, speed: f64) {
+    let speed = if speed > 0.0 && speed.is_finite() {
+      speed
+    } else {
+      1.0
+    };
+    let now = self.event_sync.ticks_since_started() as f64;
+    let elapsed = (now - self.origin) * self.speed;
+
+    self.origin = now - elapsed / speed;
+    self.speed = speed;
+  }
+
+  /// Returns how many ticks of the timeline pass for every tick of the EventSync.
+  pub fn speed(&self) -> f64 {
+    self.speed
+  }
+
+  /// Returns the timeline being played.
+  pub fn timeline(&self) -> &Timeline<P> {
+    &self.timeline
+  }
+
+  /// Returns the tick of the timeline playback is on.
+  pub fn position(&self) -> u64 {
+    let now = self.event_sync.ticks_since_started() as f64;
+    let position = ((now - self.origin) * self.speed).max(0.0) as u64;
+    let length = self.timeline.length();
+
+    if self.is_looping && length > 0 {
+      position % length
+    } else {
+      position
+    }
+  }
+
+  /// Moves playback to the given tick of the timeline, so events from that tick on are fired next.
+  ///
+  /// When looping, ticks past the length wrap around.
+  pub fn seek(&mut self, tick: u64) {
+    let length = self.timeline.length();
+    let tick = if self.is_looping && length > 0 {
+      tick % length
+    } else {
+      tick
+    };
+
+    self.origin = self.event_sync.ticks_since_started() as f64 - tick as f64 / self.speed;
+    self.play_through = 0;
+    self.next_event = self
+      .timeline
+      .events
+      .partition_point(|event| event.tick < tick);
+  }
+
+  /// Returns true once every event has been fired, which never happens while looping.
+  pub fn is_finished(&self) -> bool {
+    self.next_event_in_play_through().is_none() && !self.loops_again()
+  }
+
+  /// Fires every event whose tick has arrived since the last update, without waiting.
+  ///
+  /// Returns the amount of events fired.
+  pub fn update<F: FnMut(&TimelineEvent<P>)>(&mut self, mut fire: F) -> usize {
+    let now = self.event_sync.ticks_since_started() as i128;
+    let mut fired = 0;
+
+    while let Some(tick) = self.next_event_tick() {
+      if tick > now {
+        break;
+      }
+
+      self.fire_next(|event| {
+        fire(event);
+
+        LoopControl::Continue
+      });
+      fired += 1;
+    }
+
+    fired
+  }
+
+  /// Fires every event as its tick arrives, until the timeline is finished or the closure returns
+  /// [`LoopControl::Break`](LoopControl::Break).
+  ///
+  /// While the EventSync is paused, playback waits for it to be unpaused.
+  pub fn run<F: FnMut(&TimelineEvent<P>) -> LoopControl>(&mut self, mut fire: F) {
+    while let Some(tick) = self.next_event_tick() {
+      let tick = tick.max(0) as u64;
+
+      if self.event_sync.ticks_since_started() < tick {
+        self
+          .event_sync
+          .sleep_until_tick_in_chunks(tick, WaitTarget::Absolute, || false);
+      }
+
+      if self.fire_next(&mut fire) == LoopControl::Break {
+        return;
+      }
+    }
+  }
+
+  /// Returns the EventSync tick the next event is fired on, starting the next play through if looping.
+  fn next_event_tick(&mut self) -> Option<i128> {
+    if self.next_event_in_play_through().is_none() && self.loops_again() {
+      self.play_through += 1;
+      self.next_event = 0;
+    }
+
+    let event = self.next_event_in_play_through()?;
+    let timeline_tick =
+      self.play_through as f64 * self.timeline.length() as f64 + event.tick as f64;
+
+    // The first tick of the EventSync at or after the event.
+    Some((self.origin + timeline_tick / self.speed).ceil() as i128)
+  }
+
+  /// Returns the next event in the current play through of the timeline.
+  fn next_event_in_play_through(&self) -> Option<&TimelineEvent<P>> {
+    let length = self.timeline.length();
+
+    self
+      .timeline
+      .events
+      .get(self.next_event)
+      .filter(|event| event.tick < length)
+  }
+
+  /// Returns true if the timeline starts over once the current play through is done, and has events to play.
+  fn loops_again(&self) -> bool {
+    let length = self.timeline.length();
+
+    self.is_looping
+      && self
+        .timeline
+        .events
+        .first()
+        .is_some_and(|event| event.tick < length)
+  }
+
+  fn fire_next<F: FnMut(&TimelineEvent<P>) -> LoopControl>(&mut self, mut fire: F) -> LoopControl {
+    let control = fire(&self.timeline.events[self.next_event]);
+    self.next_event += 1;
+
+    control
+  }
+}
+
+impl<P: Clone + Send + 'static> TimelinePlayer<P> {
+  /// Plays the timeline on a separate thread, sending every event over the channel as its tick arrives.
+  ///
+  /// Playback ends once the timeline is finished, or the receiver is dropped.
+  pub fn spawn(mut self, sender: Sender<TimelineEvent<P>>) -> JoinHandle<()> {
+    std::thread::spawn(move || {
+      self.run(|event| match sender.send(event.clone()) {
+        Ok(()) => LoopControl::Continue,
+        Err(_) => LoopControl::Break,
+      })
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::time::Duration;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  fn test_timeline() -> Timeline<u32> {
+    Timeline::new()
+      .with_event_payload(4, "b", 2)
+      .with_event_payload(0, "a", 1)
+      .with_event(4, "c")
+      .with_length(6)
+  }
+
+  #[test]
+  fn events_on_the_same_tick_keep_their_order() {
+    let timeline = test_timeline();
+    let names: Vec<&str> = timeline
+      .events()
+      .iter()
+      .map(|event| event.name.as_str())
+      .collect();
+
+    assert_eq!(names, ["a", "b", "c"]);
+  }
+
+  #[test]
+  fn seeking_and_looping_wrap_around() {
+    let mut event_sync = EventSync::from_starting_tick_millis(TEST_TICKRATE, 100, true);
+    let mut player =
+      TimelinePlayer::new(event_sync.clone_immutable(), test_timeline()).with_looping(true);
+    let mut fired = Vec::new();
+
+    player.seek(9);
+    assert_eq!(player.position(), 3);
+
+    player.update(|event| fired.push(event.name.clone()));
+    assert!(fired.is_empty());
+
+    event_sync
+      .sync_to(Duration::from_millis(TEST_TICKRATE * 104))
+      .unwrap();
+    player.update(|event| fired.push(event.name.clone()));
+
+    // Tick 104 is tick 1 of the next play through, after the events on tick 4 of the seeked one.
+    assert_eq!(fired, ["b", "c", "a"]);
+    assert!(!player.is_finished());
+  }
+
+  #[test]
+  fn speed_changes_keep_the_position() {
+    let mut event_sync = EventSync::from_starting_tick_millis(TEST_TICKRATE, 10, true);
+    let mut player = TimelinePlayer::new(event_sync.clone_immutable(), test_timeline());
+    let mut fired = Vec::new();
+
+    player.set_speed(0.25);
+    event_sync
+      .sync_to(Duration::from_millis(TEST_TICKRATE * 14))
+      .unwrap();
+    assert_eq!(player.position(), 1);
+
+    player.set_speed(2.0);
+    event_sync
+      .sync_to(Duration::from_millis(TEST_TICKRATE * 15))
+      .unwrap();
+    assert_eq!(player.position(), 3);
+
+    player.update(|event| fired.push(event.name.clone()));
+    assert_eq!(fired, ["a"]);
+
+    // Tick 4 of the timeline is half a tick later, so on the next one.
+    event_sync
+      .sync_to(Duration::from_millis(TEST_TICKRATE * 16))
+      .unwrap();
+    player.update(|event| fired.push(event.name.clone()));
+    assert_eq!(fired, ["a", "b", "c"]);
+  }
+
+  #[test]
+  fn spawned_players_send_every_event() {
+    let event_sync = EventSync::new_millis(TEST_TICKRATE);
+    let (sender, receiver) = std::sync::mpsc::channel();
+
+    TimelinePlayer::new(event_sync.clone_immutable(), test_timeline())
+      .spawn(sender)
+      .join()
+      .unwrap();
+
+    let payloads: Vec<Option<u32>> = receiver.iter().map(|event| event.payload).collect();
+
+    assert_eq!(payloads, [Some(1), Some(2), None]);
+  }
+}
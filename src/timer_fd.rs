@@ -0,0 +1,295 @@
+//! Waking event loops for ticks through a file descriptor, instead of a sleeping thread.
+//!
+//! Enabled with the `timerfd` feature, on Linux with a `timerfd`, and on macOS and FreeBSD with a kqueue timer.
+
+use crate::{EventSync, Immutable};
+use std::io;
+use std::os::fd::{AsFd, AsRawFd, BorrowedFd, FromRawFd, OwnedFd, RawFd};
+use std::time::Duration;
+
+/// A file descriptor that becomes readable once the next tick of an EventSync occurs.
+///
+/// It can be registered with epoll, kqueue, mio, or any other event loop alongside sockets, so ticks are
+/// multiplexed with everything else the loop waits on. Once it's readable,
+/// [`acknowledge()`](TickTimer::acknowledge) returns the tick that occurred and arms it for the next one.
+///
+/// While the EventSync is paused, the timer still fires once every tick's worth of time, but acknowledging it
+/// returns None until a tick occurs, so unpausing is noticed without anything else waking the loop.
+///
+/// Created with [`EventSync::tick_timer()`](EventSync::tick_timer).
+///
+/// # Example
+///
+/// ```
+/// use event_sync::*;
+/// use std::os::fd::AsRawFd;
+///
+/// let tickrate = 10; // 10ms between every tick.
+/// let event_sync = EventSync::new_millis(tickrate);
+/// let mut timer = event_sync.tick_timer().unwrap();
+///
+/// // Register `timer.as_raw_fd()` with the event loop, then once it's readable:
+/// # let mut poll_fd = [libc::pollfd { fd: timer.as_raw_fd(), events: libc::POLLIN, revents: 0 }];
+/// # unsafe { libc::poll(poll_fd.as_mut_ptr(), 1, 1_000) };
+/// if let Some(tick) = timer.acknowledge().unwrap() {
+///   println!("Tick {tick} occurred.");
+/// }
+/// ```
+#[derive(Debug)]
+pub struct TickTimer {
+  event_sync: EventSync<Immutable>,
+  fd: OwnedFd,
+  last_tick: u64,
+}
+
+impl TickTimer {
+  fn new(event_sync: EventSync<Immutable>) -> io::Result<Self> {
+    let last_tick = event_sync.ticks_since_started();
+    let timer = Self {
+      fd: platform::create()?,
+      event_sync,
+      last_tick,
+    };
+
+    timer.rearm()?;
+
+    Ok(timer)
+  }
+
+  /// Clears the timer's readiness and arms it for the next tick, returning the tick the EventSync is on if it has
+  /// changed since the last time it was acknowledged.
+  ///
+  /// The returned tick can be more than one past the last one, if ticks occurred before the timer was acknowledged.
+  ///
+  /// # Errors
+  ///
+  /// - If the OS fails to read or arm the timer.
+  pub fn acknowledge(&mut self) -> io::Result<Option<u64>> {
+    platform::drain(&self.fd)?;
+    self.rearm()?;
+
+    let current_tick = self.event_sync.ticks_since_started();
+
+    if current_tick == self.last_tick {
+      return Ok(None);
+    }
+
+    self.last_tick = current_tick;
+
+    Ok(Some(current_tick))
+  }
+
+  /// Arms the timer for the next tick, replacing when it was set to fire.
+  ///
+  /// Only needed after changing the EventSync, such as unpausing it or changing its tickrate, to be woken for the
+  /// new next tick sooner than the timer was set for.
+  ///
+  /// # Errors
+  ///
+  /// - If the OS fails to arm the timer.
+  pub fn rearm(&self) -> io::Result<()> {
+    let delay = {
+      let inner = self.event_sync.read_inner();
+
+      if inner.is_paused() {
+        Duration::from_millis(inner.get_tickrate())
+      } else {
+        inner.time_until_next_tick()
+      }
+    };
+
+    // A delay of 0 would disarm the timer instead.
+    platform::arm(&self.fd, delay.max(Duration::from_nanos(1)))
+  }
+}
+
+impl AsRawFd for TickTimer {
+  fn as_raw_fd(&self) -> RawFd {
+    self.fd.as_raw_fd()
+  }
+}
+
+impl AsFd for TickTimer {
+  fn as_fd(&self) -> BorrowedFd<'_> {
+    self.fd.as_fd()
+  }
+}
+
+impl<T> EventSync<T> {
+  /// Creates a [`TickTimer`](TickTimer), whose file descriptor becomes readable once the next tick occurs.
+  ///
+  /// # Errors
+  ///
+  /// - If the OS fails to create or arm the timer, such as when the process is out of file descriptors.
+  pub fn tick_timer(&self) -> io::Result<TickTimer> {
+    TickTimer::new(self.to_immutable())
+  }
+}
+
+/// Returns the result of a libc call, or the error it set if it returned -1.
+fn check(result: libc::c_int) -> io::Result<libc::c_int> {
+  if result == -1 {
+    Err(io::Error::last_os_error())
+  } else {
+    Ok(result)
+  }
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+  use super::*;
+
+  pub(super) fn create() -> io::Result<OwnedFd> {
+    // Safety: Creating a timer has no preconditions, and the fd is owned by nothing else.
+    unsafe {
+      let fd = check(libc::timerfd_create(
+        libc::CLOCK_MONOTONIC,
+        libc::TFD_NONBLOCK | libc::TFD_CLOEXEC,
+      ))?;
+
+      Ok(OwnedFd::from_raw_fd(fd))
+    }
+  }
+
+  pub(super) fn arm(fd: &OwnedFd, delay: Duration) -> io::Result<()> {
+    let timer = libc::itimerspec {
+      it_interval: libc::timespec {
+        tv_sec: 0,
+        tv_nsec: 0,
+      },
+      it_value: libc::timespec {
+        tv_sec: delay.as_secs() as libc::time_t,
+        tv_nsec: delay.subsec_nanos() as libc::c_long,
+      },
+    };
+
+    // Safety: The fd is an open timerfd, and the timer is a valid itimerspec.
+    check(unsafe { libc::timerfd_settime(fd.as_raw_fd(), 0, &timer, std::ptr::null_mut()) })?;
+
+    Ok(())
+  }
+
+  pub(super) fn drain(fd: &OwnedFd) -> io::Result<()> {
+    let mut expirations = 0_u64;
+
+    // Safety: The buffer is 8 bytes long, which is what a timerfd reads.
+    let result = unsafe {
+      libc::read(
+        fd.as_raw_fd(),
+        (&mut expirations as *mut u64).cast(),
+        std::mem::size_of::<u64>(),
+      )
+    };
+
+    match check(result as libc::c_int) {
+      Err(error) if error.kind() != io::ErrorKind::WouldBlock => Err(error),
+      _ => Ok(()),
+    }
+  }
+}
+
+#[cfg(any(target_os = "macos", target_os = "freebsd"))]
+mod platform {
+  use super::*;
+
+  /// The identifier of the one timer on each kqueue.
+  const TIMER_ID: libc::uintptr_t = 1;
+
+  pub(super) fn create() -> io::Result<OwnedFd> {
+    // Safety: Creating a kqueue has no preconditions, and the fd is owned by nothing else.
+    unsafe {
+      let fd = check(libc::kqueue())?;
+      let owned = OwnedFd::from_raw_fd(fd);
+
+      check(libc::fcntl(fd, libc::F_SETFD, libc::FD_CLOEXEC))?;
+
+      Ok(owned)
+    }
+  }
+
+  pub(super) fn arm(fd: &OwnedFd, delay: Duration) -> io::Result<()> {
+    // Safety: A zeroed kevent is valid, and only the fields for a timer are set.
+    let mut event: libc::kevent = unsafe { std::mem::zeroed() };
+    event.ident = TIMER_ID;
+    event.filter = libc::EVFILT_TIMER;
+    event.flags = libc::EV_ADD | libc::EV_ONESHOT;
+    event.fflags = libc::NOTE_NSECONDS;
+    event.data = delay.as_nanos().min(i64::MAX as u128) as _;
+
+    // Safety: The fd is an open kqueue, and one change is passed with no room for events.
+    check(unsafe {
+      libc::kevent(
+        fd.as_raw_fd(),
+        &event,
+        1,
+        std::ptr::null_mut(),
+        0,
+        std::ptr::null(),
+      )
+    })?;
+
+    Ok(())
+  }
+
+  pub(super) fn drain(fd: &OwnedFd) -> io::Result<()> {
+    // Safety: A zeroed kevent is valid to be written over.
+    let mut event: libc::kevent = unsafe { std::mem::zeroed() };
+    let no_wait = libc::timespec {
+      tv_sec: 0,
+      tv_nsec: 0,
+    };
+
+    // Safety: The fd is an open kqueue, and there's room for the one event it can have.
+    check(unsafe { libc::kevent(fd.as_raw_fd(), std::ptr::null(), 0, &mut event, 1, &no_wait) })?;
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  /// Waits for the fd to become readable, returning false if it didn't within the timeout.
+  fn wait_readable(timer: &TickTimer, timeout: Duration) -> bool {
+    let mut poll_fd = [libc::pollfd {
+      fd: timer.as_raw_fd(),
+      events: libc::POLLIN,
+      revents: 0,
+    }];
+
+    // Safety: The slice holds the one pollfd passed in.
+    let ready = unsafe { libc::poll(poll_fd.as_mut_ptr(), 1, timeout.as_millis() as libc::c_int) };
+
+    ready == 1
+  }
+
+  #[test]
+  fn the_timer_fires_for_each_tick() {
+    let event_sync = EventSync::new_millis(TEST_TICKRATE);
+    let mut timer = event_sync.tick_timer().unwrap();
+    let started_on = event_sync.ticks_since_started();
+
+    for _ in 0..3 {
+      assert!(wait_readable(&timer, Duration::from_secs(1)));
+
+      while timer.acknowledge().unwrap().is_none() {
+        assert!(wait_readable(&timer, Duration::from_secs(1)));
+      }
+    }
+
+    assert!(event_sync.ticks_since_started() >= started_on + 3);
+    assert_eq!(timer.acknowledge().unwrap(), None);
+  }
+
+  #[test]
+  fn paused_timers_fire_without_a_tick() {
+    let event_sync = EventSync::new_paused_millis(TEST_TICKRATE);
+    let mut timer = event_sync.tick_timer().unwrap();
+
+    assert!(wait_readable(&timer, Duration::from_secs(1)));
+    assert_eq!(timer.acknowledge().unwrap(), None);
+  }
+}
@@ -0,0 +1,94 @@
+use crate::inner::InnerEventSync;
+use crate::tick_math;
+use core::time::Duration;
+use serde::{Deserialize, Serialize};
+
+/// Every reading of an EventSync, taken at the same instant.
+///
+/// Reading the tick, the progress through it, and the tickrate separately can give values from different
+/// moments, such as a tick from before a tickrate change.
+/// A view is taken at once, so its values always agree with each other.
+///
+/// Created with [`EventSync::view()`](crate::EventSync::view) or [`EventSyncCore::view()`](crate::EventSyncCore::view).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct EventSyncView {
+  /// The amount of ticks that had occurred.
+  pub tick: u64,
+  /// The time that had passed since the EventSync started.
+  pub time_since_started: Duration,
+  /// The tickrate in milliseconds.
+  pub tickrate: u64,
+  /// Whether the EventSync was paused.
+  pub is_paused: bool,
+  /// The amount of times the timeline had been reset.
+  pub generation: u64,
+}
+
+impl EventSyncView {
+  pub(crate) fn from_inner(inner: &InnerEventSync) -> Self {
+    let time_since_started = inner.time_since_started();
+    let tickrate = inner.get_tickrate();
+
+    Self {
+      tick: tick_math::ticks_in(time_since_started, tickrate),
+      time_since_started,
+      tickrate,
+      is_paused: inner.is_paused(),
+      generation: inner.generation(),
+    }
+  }
+
+  /// Returns the time that had passed since the last tick.
+  pub fn time_since_last_tick(&self) -> Duration {
+    tick_math::time_since_last_tick(self.time_since_started, self.tickrate)
+  }
+
+  /// Returns the time that was left until the next tick.
+  pub fn time_until_next_tick(&self) -> Duration {
+    tick_math::time_until_next_tick(self.time_since_started, self.tickrate)
+  }
+
+  /// Returns how far through the current tick the EventSync was, from 0.0 up to but not including 1.0.
+  pub fn tick_progress(&self) -> f64 {
+    tick_math::tick_progress(self.time_since_started, self.tickrate)
+  }
+}
+
+/// Formats as `tick 423 @ 10ms, running`.
+impl core::fmt::Display for EventSyncView {
+  fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    let state = if self.is_paused { "paused" } else { "running" };
+
+    write!(
+      formatter,
+      "tick {} @ {}ms, {}",
+      self.tick, self.tickrate, state
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::ClockSource;
+
+  #[test]
+  fn view_readings_agree() {
+    let inner = InnerEventSync::new(
+      10,
+      Duration::from_millis(25),
+      true,
+      ClockSource::Custom(|| Duration::ZERO),
+    );
+    let view = EventSyncView::from_inner(&inner);
+
+    assert_eq!(view.tick, 2);
+    assert_eq!(view.time_since_last_tick(), Duration::from_millis(5));
+    assert_eq!(view.time_until_next_tick(), Duration::from_millis(5));
+    assert_eq!(view.tick_progress(), 0.5);
+    assert!(view.is_paused);
+    // to_string() needs an allocator, which is only there with std.
+    #[cfg(feature = "std")]
+    assert_eq!(view.to_string(), "tick 2 @ 10ms, paused");
+  }
+}
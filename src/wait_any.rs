@@ -0,0 +1,109 @@
+use crate::errors::TimeError;
+use crate::EventSync;
+use std::time::Duration;
+
+/// Waits until the first of several EventSyncs reaches its target tick, returning the position of that EventSync.
+///
+/// Each EventSync is paired with the target tick at the same position.
+/// Paused EventSyncs can't fire, but are still watched in case they're unpaused while waiting.
+///
+/// # Errors
+///
+/// - An error is returned if every EventSync is paused.
+///
+/// # Panics
+///
+/// - If the amount of EventSyncs and target ticks differ, or no EventSyncs are given.
+///
+/// # Example
+///
+/// ```
+/// use event_sync::*;
+///
+/// let physics = EventSync::new_millis(10);
+/// let rendering = EventSync::new_millis(16);
+///
+/// // Physics reaches tick 2 at 20ms, before rendering reaches tick 2 at 32ms.
+/// let fired = wait_any(&[&physics, &rendering], &[2, 2]).unwrap();
+///
+/// assert_eq!(fired, 0);
+/// ```
+pub fn wait_any<T>(
+  event_syncs: &[&EventSync<T>],
+  target_ticks: &[u64],
+) -> Result<usize, TimeError> {
+  assert_eq!(
+    event_syncs.len(),
+    target_ticks.len(),
+    "Every EventSync needs a target tick."
+  );
+  assert!(!event_syncs.is_empty(), "No EventSyncs to wait on.");
+
+  loop {
+    let mut shortest_wait: Option<Duration> = None;
+
+    for (index, (event_sync, target_tick)) in event_syncs.iter().zip(target_ticks).enumerate() {
+      let inner = event_sync.read_inner();
+
+      if inner.ticks_since_started() >= *target_tick {
+        return Ok(index);
+      }
+
+      if inner.is_paused() {
+        continue;
+      }
+
+      // The wait is capped at a tick, so changes to the tickrate or pause state are picked up.
+      let wait_time = match inner.time_until_tick_occurs(*target_tick) {
+        Err(TimeError::ThatTimeHasAlreadyHappened) => return Ok(index),
+        Err(error) => return Err(error),
+        Ok(wait_time) => wait_time.min(Duration::from_millis(inner.get_tickrate())),
+      };
+
+      shortest_wait = Some(shortest_wait.map_or(wait_time, |shortest| shortest.min(wait_time)));
+    }
+
+    match shortest_wait {
+      Some(wait_time) => std::thread::sleep(wait_time),
+      None => return Err(TimeError::EventSyncPaused),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  #[test]
+  fn paused_event_syncs_are_skipped() {
+    let mut paused = EventSync::new_millis(TEST_TICKRATE);
+    paused.pause();
+    let running = EventSync::new_millis(TEST_TICKRATE);
+
+    assert_eq!(wait_any(&[&paused, &running], &[1, 1]), Ok(1));
+  }
+
+  #[test]
+  fn every_event_sync_paused_is_an_error() {
+    let mut event_sync = EventSync::new_millis(TEST_TICKRATE);
+    event_sync.pause();
+
+    assert_eq!(
+      wait_any(&[&event_sync], &[5]),
+      Err(TimeError::EventSyncPaused)
+    );
+  }
+
+  #[test]
+  fn reached_targets_fire_immediately() {
+    let event_sync = EventSync::from_starting_tick_millis(TEST_TICKRATE, 3, true);
+    let other_event_sync = EventSync::new_millis(TEST_TICKRATE);
+
+    assert_eq!(
+      wait_any(&[&other_event_sync, &event_sync], &[100, 2]),
+      Ok(1)
+    );
+  }
+}
@@ -0,0 +1,131 @@
+use crate::errors::TimeError;
+use crate::waiting::WaitOutcome;
+use crate::{EventSync, Immutable};
+
+/// How the wait for one of the targets of [`WaitUntilEach`](WaitUntilEach) ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TargetOutcome {
+  /// The thread slept until the target occurred.
+  Waited,
+
+  /// The EventSync was already on the target, so nothing was waited for.
+  NoWait,
+
+  /// The target occurred before it was reached in the list, so it was skipped without waiting.
+  AlreadyPassed,
+}
+
+impl From<WaitOutcome> for TargetOutcome {
+  fn from(outcome: WaitOutcome) -> Self {
+    match outcome {
+      WaitOutcome::Waited => Self::Waited,
+      WaitOutcome::NoWait => Self::NoWait,
+    }
+  }
+}
+
+/// Waits until each tick of a list in order, yielded as `(tick, outcome)` once the wait for it ends.
+///
+/// Targets that already occurred are yielded as [`AlreadyPassed`](TargetOutcome::AlreadyPassed) straight away, so
+/// falling behind a scripted sequence never stops it. The list is expected to be sorted, as any tick that comes
+/// before the one ahead of it has already passed by the time it's reached.
+///
+/// Created with [`EventSync::wait_until_each()`](EventSync::wait_until_each).
+///
+/// # Errors
+///
+/// - An error is yielded if the EventSync is paused, without moving past the target.
+///
+/// # Example
+///
+/// ```
+/// use event_sync::*;
+///
+/// let tickrate = 10; // 10ms between every tick.
+/// let event_sync = EventSync::from_starting_tick_millis(tickrate, 3, false);
+///
+/// let outcomes: Vec<(u64, TargetOutcome)> = event_sync
+///   .wait_until_each([1, 5, 8])
+///   .collect::<Result<_, _>>()
+///   .unwrap();
+///
+/// assert_eq!(
+///   outcomes,
+///   [
+///     (1, TargetOutcome::AlreadyPassed),
+///     (5, TargetOutcome::Waited),
+///     (8, TargetOutcome::Waited),
+///   ]
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct WaitUntilEach<I: Iterator<Item = u64>> {
+  event_sync: EventSync<Immutable>,
+  targets: std::iter::Peekable<I>,
+}
+
+impl<I: Iterator<Item = u64>> WaitUntilEach<I> {
+  /// Returns the target that will be waited for next, without waiting for it.
+  pub fn peek(&mut self) -> Option<u64> {
+    self.targets.peek().copied()
+  }
+}
+
+impl<I: Iterator<Item = u64>> Iterator for WaitUntilEach<I> {
+  type Item = Result<(u64, TargetOutcome), TimeError>;
+
+  /// Waits until the next target, then returns how the wait ended.
+  fn next(&mut self) -> Option<Self::Item> {
+    let target = self.peek()?;
+
+    let outcome = match self.event_sync.wait_until(target) {
+      Ok(outcome) => outcome.into(),
+      Err(TimeError::ThatTimeHasAlreadyHappened) => TargetOutcome::AlreadyPassed,
+      Err(error) => return Some(Err(error)),
+    };
+
+    self.targets.next();
+
+    Some(Ok((target, outcome)))
+  }
+}
+
+impl<T> EventSync<T> {
+  /// Returns an iterator waiting until each of the ticks in order, yielding how each wait ended.
+  ///
+  /// See [`WaitUntilEach`](WaitUntilEach) for more details.
+  pub fn wait_until_each<I: IntoIterator<Item = u64>>(
+    &self,
+    ticks: I,
+  ) -> WaitUntilEach<I::IntoIter> {
+    WaitUntilEach {
+      event_sync: self.to_immutable(),
+      targets: ticks.into_iter().peekable(),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  #[test]
+  fn pauses_hold_the_target() {
+    let mut event_sync = EventSync::from_starting_tick_millis(TEST_TICKRATE, 4, false);
+    let mut waits = event_sync.wait_until_each([2, 6]);
+
+    assert_eq!(waits.next(), Some(Ok((2, TargetOutcome::AlreadyPassed))));
+
+    event_sync.pause();
+
+    assert_eq!(waits.next(), Some(Err(TimeError::EventSyncPaused)));
+    assert_eq!(waits.peek(), Some(6));
+
+    event_sync.unpause().unwrap();
+
+    assert_eq!(waits.next(), Some(Ok((6, TargetOutcome::Waited))));
+    assert_eq!(waits.next(), None);
+  }
+}
@@ -0,0 +1,311 @@
+use crate::driver::ListenerId;
+use crate::errors::TimeError;
+use crate::waiting::WaitOutcome;
+use crate::{EventSync, Immutable};
+use std::future::{Future, IntoFuture};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+/// A wait until an absolute tick, created with [`EventSync::until()`](EventSync::until) to be awaited.
+///
+/// Checked the same as [`wait_until()`](EventSync::wait_until) when it's awaited, or turned into a
+/// [`TickFuture`](TickFuture) to be composed with other futures first.
+#[derive(Debug, Clone)]
+#[must_use = "waits do nothing unless awaited"]
+pub struct WaitUntil {
+  event_sync: EventSync<Immutable>,
+  tick: u64,
+}
+
+/// A wait for an amount of ticks, created with [`EventSync::for_ticks()`](EventSync::for_ticks) to be awaited.
+///
+/// The ticks are counted from when it's awaited, or turned into a [`TickFuture`](TickFuture), not from when it
+/// was created. Checked the same as [`wait_for_x_ticks()`](EventSync::wait_for_x_ticks).
+#[derive(Debug, Clone)]
+#[must_use = "waits do nothing unless awaited"]
+pub struct WaitTicks {
+  event_sync: EventSync<Immutable>,
+  ticks: u32,
+}
+
+/// A future resolving once a tick occurs, woken by the EventSync's driver thread.
+///
+/// It doesn't depend on any async runtime, so it can be awaited anywhere, and raced against other futures with
+/// `select!` or wrapped in a timeout.
+/// Like the blocking waits, pausing holds the wait until unpaused.
+///
+/// While pending, it's a [`listener`](EventSync::add_listener) of the EventSync, which is removed once it
+/// resolves or is dropped.
+#[derive(Debug)]
+#[must_use = "futures do nothing unless awaited"]
+pub struct TickFuture {
+  event_sync: EventSync<Immutable>,
+  tick: u64,
+  /// The result, when it was known before waiting.
+  finished: Option<Result<WaitOutcome, TimeError>>,
+  waker: Arc<Mutex<Option<Waker>>>,
+  listener: Option<ListenerId>,
+}
+
+impl TickFuture {
+  fn new(event_sync: EventSync<Immutable>, tick: u64) -> Self {
+    Self {
+      event_sync,
+      tick,
+      finished: None,
+      waker: Arc::default(),
+      listener: None,
+    }
+  }
+
+  fn finished(event_sync: EventSync<Immutable>, result: Result<WaitOutcome, TimeError>) -> Self {
+    let mut future = Self::new(event_sync, 0);
+    future.finished = Some(result);
+
+    future
+  }
+
+  /// Returns the tick the future resolves on.
+  pub fn tick(&self) -> u64 {
+    self.tick
+  }
+
+  fn remove_listener(&mut self) {
+    if let Some(listener) = self.listener.take() {
+      self.event_sync.remove_listener(listener);
+    }
+  }
+}
+
+impl Future for TickFuture {
+  type Output = Result<WaitOutcome, TimeError>;
+
+  fn poll(mut self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Self::Output> {
+    if let Some(result) = self.finished.take() {
+      return Poll::Ready(result);
+    }
+
+    if self.event_sync.ticks_since_started() >= self.tick {
+      self.remove_listener();
+
+      return Poll::Ready(Ok(WaitOutcome::Waited));
+    }
+
+    *self.waker.lock().unwrap() = Some(context.waker().clone());
+
+    if self.listener.is_none() {
+      let tick = self.tick;
+      let waker = self.waker.clone();
+
+      self.listener = Some(self.event_sync.add_listener(Box::new(move |current_tick| {
+        if current_tick >= tick {
+          if let Some(waker) = waker.lock().unwrap().take() {
+            waker.wake();
+          }
+        }
+      })));
+
+      // The tick may have occurred before the listener was added.
+      if self.event_sync.ticks_since_started() >= self.tick {
+        self.remove_listener();
+
+        return Poll::Ready(Ok(WaitOutcome::Waited));
+      }
+    }
+
+    Poll::Pending
+  }
+}
+
+impl Drop for TickFuture {
+  fn drop(&mut self) {
+    self.remove_listener();
+  }
+}
+
+impl IntoFuture for WaitUntil {
+  type Output = Result<WaitOutcome, TimeError>;
+  type IntoFuture = TickFuture;
+
+  fn into_future(self) -> TickFuture {
+    let checked = {
+      let inner = self.event_sync.read_inner();
+
+      inner
+        .is_current_tick(self.tick)
+        .and_then(|is_current_tick| {
+          if is_current_tick {
+            return Ok(Some(WaitOutcome::NoWait));
+          }
+
+          inner.time_until_tick_occurs(self.tick).map(|_| None)
+        })
+    };
+
+    match checked.transpose() {
+      Some(result) => TickFuture::finished(self.event_sync, result),
+      None => TickFuture::new(self.event_sync, self.tick),
+    }
+  }
+}
+
+impl IntoFuture for WaitTicks {
+  type Output = Result<WaitOutcome, TimeError>;
+  type IntoFuture = TickFuture;
+
+  fn into_future(self) -> TickFuture {
+    let tick = {
+      let inner = self.event_sync.read_inner();
+
+      inner
+        .err_if_paused()
+        .map(|()| inner.ticks_since_started() + self.ticks as u64)
+    };
+
+    match tick {
+      Ok(_) if self.ticks == 0 => TickFuture::finished(self.event_sync, Ok(WaitOutcome::NoWait)),
+      Ok(tick) => TickFuture::new(self.event_sync, tick),
+      Err(error) => TickFuture::finished(self.event_sync, Err(error)),
+    }
+  }
+}
+
+impl<T> EventSync<T> {
+  /// Returns a wait until the given absolute tick, to be awaited.
+  ///
+  /// The async version of [`wait_until()`](EventSync::wait_until), resolving the same way.
+  ///
+  /// # Errors
+  ///
+  /// - The same as [`wait_until()`](EventSync::wait_until), when awaited.
+  ///
+  /// # Examples
+  ///
+  /// ```no_run
+  /// use event_sync::*;
+  ///
+  /// async fn spawn_wave(event_sync: EventSync) -> Result<(), TimeError> {
+  ///   event_sync.until(100).await?;
+  ///
+  ///   // Spawn the wave.
+  ///
+  ///   Ok(())
+  /// }
+  /// ```
+  pub fn until(&self, tick: u64) -> WaitUntil {
+    WaitUntil {
+      event_sync: self.to_immutable(),
+      tick,
+    }
+  }
+
+  /// Returns a wait for the given amount of ticks, to be awaited.
+  ///
+  /// The async version of [`wait_for_x_ticks()`](EventSync::wait_for_x_ticks), resolving the same way.
+  ///
+  /// # Errors
+  ///
+  /// - The same as [`wait_for_x_ticks()`](EventSync::wait_for_x_ticks), when awaited.
+  pub fn for_ticks(&self, ticks: u32) -> WaitTicks {
+    WaitTicks {
+      event_sync: self.to_immutable(),
+      ticks,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::select::TickOrMessage;
+  use std::task::Wake;
+  use std::thread::{self, Thread};
+
+  const TEST_TICKRATE: u64 = 10;
+
+  struct ThreadWaker(Thread);
+
+  impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+      self.0.unpark();
+    }
+  }
+
+  /// Runs the future on the current thread, parking between polls.
+  fn block_on<F: IntoFuture>(future: F) -> F::Output {
+    let mut future = std::pin::pin!(future.into_future());
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut context = Context::from_waker(&waker);
+
+    loop {
+      if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+        return output;
+      }
+
+      thread::park();
+    }
+  }
+
+  #[test]
+  fn futures_resolve_like_the_blocking_waits() {
+    let event_sync = EventSync::new_millis(TEST_TICKRATE);
+
+    assert_eq!(block_on(event_sync.until(3)), Ok(WaitOutcome::Waited));
+    assert!(event_sync.ticks_since_started() >= 3);
+    assert_eq!(
+      block_on(event_sync.until(1)),
+      Err(TimeError::ThatTimeHasAlreadyHappened)
+    );
+    assert_eq!(block_on(event_sync.for_ticks(0)), Ok(WaitOutcome::NoWait));
+
+    let tick = event_sync.ticks_since_started();
+
+    assert_eq!(block_on(event_sync.for_ticks(2)), Ok(WaitOutcome::Waited));
+    assert!(event_sync.ticks_since_started() >= tick + 2);
+    assert_eq!(event_sync.listener_count(), 0);
+  }
+
+  #[test]
+  fn tick_or_resolves_on_whichever_comes_first() {
+    let event_sync = EventSync::new_millis(TEST_TICKRATE);
+
+    assert_eq!(
+      block_on(event_sync.tick_or(std::future::pending::<Option<()>>())),
+      Ok(TickOrMessage::Tick)
+    );
+    assert_eq!(event_sync.listener_count(), 0);
+
+    let event_sync = EventSync::new_millis(TEST_TICKRATE * 100);
+
+    assert_eq!(
+      block_on(event_sync.tick_or(std::future::ready(Some(5)))),
+      Ok(TickOrMessage::Message(5))
+    );
+    assert_eq!(
+      block_on(event_sync.tick_or(std::future::ready(None::<()>))),
+      Ok(TickOrMessage::Disconnected)
+    );
+  }
+
+  #[test]
+  fn dropped_futures_stop_listening() {
+    let mut event_sync = EventSync::new_millis(TEST_TICKRATE);
+    let mut future = event_sync.until(1_000).into_future();
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+
+    assert!(Pin::new(&mut future)
+      .poll(&mut Context::from_waker(&waker))
+      .is_pending());
+    assert_eq!(event_sync.listener_count(), 1);
+
+    drop(future);
+    event_sync.pause();
+
+    assert_eq!(event_sync.listener_count(), 0);
+    assert_eq!(
+      block_on(event_sync.for_ticks(1)),
+      Err(TimeError::EventSyncPaused)
+    );
+  }
+}
@@ -0,0 +1,210 @@
+//! Sleeping in chunks of at most a tick, so waits follow changes made to the EventSync while they're sleeping.
+
+use crate::errors::TimeError;
+use crate::EventSync;
+use std::time::Duration;
+
+/// What a wait for a tick does when the timeline changes under it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum WaitTarget {
+  /// Waits for the tick with that number, wherever the timeline is moved to.
+  Absolute,
+
+  /// Waits for the amount of ticks that were left, counted again from wherever the timeline is moved to, or
+  /// however long ticks are changed to last.
+  Relative,
+}
+
+/// How a wait for a tick ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WaitOutcome {
+  /// The thread slept until the tick occurred.
+  Waited,
+
+  /// The wait returned without sleeping, as it was for the tick the EventSync is already on, such as when waiting
+  /// for 0 ticks.
+  NoWait,
+}
+
+impl<T> EventSync<T> {
+  /// Sleeps until the given tick has occurred, returning the tick that was waited for and the tick it's on.
+  ///
+  /// The EventSync is re-checked at least once every tick, so pausing, restarting, or changing the tickrate is
+  /// followed, with the target deciding what changes to the timeline mean for the tick waited for.
+  /// While paused, the wait carries on until the EventSync is unpaused.
+  ///
+  /// None is returned as soon as `should_stop` returns true, which is checked at least once every tick.
+  pub(crate) fn sleep_until_tick_in_chunks<F: FnMut() -> bool>(
+    &self,
+    tick: u64,
+    target: WaitTarget,
+    mut should_stop: F,
+  ) -> Option<(u64, u64)> {
+    if should_stop() {
+      return None;
+    }
+
+    self.wait_until_tick_in_chunks(tick, target, |wait_time| {
+      std::thread::sleep(wait_time);
+
+      should_stop()
+    })
+  }
+
+  /// Like [`sleep_until_tick_in_chunks()`](EventSync::sleep_until_tick_in_chunks), but every chunk is waited with
+  /// `wait`, which returns true to stop waiting early.
+  ///
+  /// `wait` is never given more than a tick, and may return before the time it's given has passed.
+  pub(crate) fn wait_until_tick_in_chunks<F: FnMut(Duration) -> bool>(
+    &self,
+    mut tick: u64,
+    target: WaitTarget,
+    mut wait: F,
+  ) -> Option<(u64, u64)> {
+    let mut last_seen = None;
+
+    loop {
+      let wait_time = {
+        let inner = self.read_inner();
+        let current_tick = inner.ticks_since_started();
+        let tickrate = inner.get_tickrate();
+        let generation = inner.generation();
+
+        if let Some((last_tick, last_tickrate, last_generation)) = last_seen {
+          let was_moved =
+            tickrate != last_tickrate || generation != last_generation || current_tick < last_tick;
+
+          if target == WaitTarget::Relative && was_moved {
+            let ticks_left = tick.saturating_sub(last_tick).max(1);
+
+            tick = current_tick + ticks_left;
+          }
+        }
+
+        if current_tick >= tick {
+          return Some((tick, current_tick));
+        }
+
+        last_seen = Some((current_tick, tickrate, generation));
+        let tickrate = Duration::from_millis(tickrate);
+
+        if inner.is_paused() {
+          tickrate
+        } else {
+          inner
+            .time_until_tick_occurs(tick)
+            .map_or(Duration::ZERO, |wait_time| wait_time.min(tickrate))
+        }
+      };
+
+      if wait(wait_time) {
+        return None;
+      }
+    }
+  }
+
+  /// Sleeps until the given amount of time has passed, re-checking the EventSync at least once every tick.
+  ///
+  /// While paused, the wait carries on until the EventSync is unpaused.
+  ///
+  /// False is returned as soon as `should_stop` returns true, which is checked at least once every tick.
+  pub(crate) fn sleep_until_elapsed_in_chunks<F: FnMut() -> bool>(
+    &self,
+    time_since_started: Duration,
+    mut should_stop: F,
+  ) -> bool {
+    loop {
+      if should_stop() {
+        return false;
+      }
+
+      let wait_time = {
+        let inner = self.read_inner();
+        let tickrate = Duration::from_millis(inner.get_tickrate());
+
+        match inner.time_until_elapsed(time_since_started) {
+          Ok(wait_time) => wait_time.min(tickrate),
+          Err(TimeError::EventSyncPaused) => tickrate,
+          Err(_) => return true,
+        }
+      };
+
+      std::thread::sleep(wait_time);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::clock::Instant;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  #[test]
+  fn waits_follow_tickrate_changes() {
+    let mut event_sync = EventSync::new_millis(TEST_TICKRATE);
+
+    std::thread::scope(|scope| {
+      let waiter = event_sync.clone_immutable();
+      let started_at = Instant::now();
+
+      let waiting = scope.spawn(move || {
+        waiter.wait_until(50).unwrap();
+
+        started_at.elapsed()
+      });
+
+      std::thread::sleep(Duration::from_millis(TEST_TICKRATE * 2));
+      // Tick 50 is now at 100ms rather than 500ms.
+      event_sync.change_tickrate_millis(2);
+
+      let waited = waiting.join().unwrap();
+
+      assert!(waited < Duration::from_millis(TEST_TICKRATE * 20));
+      assert!(event_sync.ticks_since_started() >= 50);
+    });
+  }
+
+  #[test]
+  fn relative_waits_count_the_ticks_left_at_the_new_tickrate() {
+    let mut event_sync = EventSync::new_millis(TEST_TICKRATE);
+
+    std::thread::scope(|scope| {
+      let waiter = event_sync.clone_immutable();
+
+      let waiting = scope.spawn(move || waiter.wait_for_x_ticks(20));
+
+      std::thread::sleep(Duration::from_millis(TEST_TICKRATE * 5));
+      let tick_at_change = event_sync.ticks_since_started();
+      event_sync.change_tickrate_millis(TEST_TICKRATE / 2);
+
+      waiting.join().unwrap().unwrap();
+
+      // The tick number doubles with the change, then the ticks that were left are waited for.
+      let ticks_left = event_sync.ticks_since_started() - tick_at_change * 2;
+
+      assert!((12..20).contains(&ticks_left));
+    });
+  }
+
+  #[test]
+  fn waits_carry_on_through_pauses() {
+    let mut event_sync = EventSync::new_millis(TEST_TICKRATE);
+
+    std::thread::scope(|scope| {
+      let waiter = event_sync.clone_immutable();
+      let waiting = scope.spawn(move || waiter.wait_until(3));
+
+      std::thread::sleep(Duration::from_millis(TEST_TICKRATE / 2));
+      event_sync.pause();
+      std::thread::sleep(Duration::from_millis(TEST_TICKRATE * 5));
+      assert!(!waiting.is_finished());
+
+      event_sync.unpause().unwrap();
+
+      assert!(waiting.join().unwrap().is_ok());
+      assert!(event_sync.ticks_since_started() >= 3);
+    });
+  }
+}
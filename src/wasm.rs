@@ -0,0 +1,173 @@
+//! Waiting for ticks in the browser.
+//!
+//! Enabled with the `wasm` feature.
+//!
+//! Blocking the main thread isn't possible on wasm32-unknown-unknown, so methods like
+//! [`wait_for_tick()`](EventSync::wait_for_tick) will panic there. [`AsyncWaiting`](AsyncWaiting) provides
+//! the same waits as futures backed by the browser's timers instead.
+
+use crate::{EventSync, TickOrMessage, TimeError};
+use futures_util::future::{select, Either};
+use gloo_timers::future::sleep;
+use std::future::Future;
+use std::time::Duration;
+
+/// Waiting for ticks without blocking the current thread.
+///
+/// Unlike their blocking counterparts, these waits re-check the EventSync at least once every tick.
+/// Changes made while waiting, like changing the tickrate, are followed, and pausing ends the wait with an error.
+///
+/// # Example
+///
+/// ```no_run
+/// use event_sync::*;
+///
+/// async fn game_loop(event_sync: EventSync) -> Result<(), TimeError> {
+///   loop {
+///     // update the game
+///
+///     event_sync.wait_for_tick_async().await?;
+///   }
+/// }
+/// ```
+pub trait AsyncWaiting {
+  /// Waits until an absolute tick has occurred since EventSync creation.
+  ///
+  /// See [`EventSync::wait_until()`](EventSync::wait_until) for more details.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned when the given time to wait for has already occurred.
+  /// - An error is returned if the EventSync is paused, or becomes paused while waiting.
+  fn wait_until_async(&self, tick_to_wait_for: u64) -> impl Future<Output = Result<(), TimeError>>;
+
+  /// Waits until the next tick relative to where now is between ticks.
+  ///
+  /// See [`EventSync::wait_for_tick()`](EventSync::wait_for_tick) for more details.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if the EventSync is paused, or becomes paused while waiting.
+  fn wait_for_tick_async(&self) -> impl Future<Output = Result<(), TimeError>>;
+
+  /// Waits for the passed in amount of ticks relative to where now is between ticks.
+  ///
+  /// See [`EventSync::wait_for_x_ticks()`](EventSync::wait_for_x_ticks) for more details.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if the EventSync is paused, or becomes paused while waiting.
+  fn wait_for_x_ticks_async(
+    &self,
+    ticks_to_wait: u32,
+  ) -> impl Future<Output = Result<(), TimeError>>;
+
+  /// Waits for either the next tick or the given message, returning whichever came first.
+  ///
+  /// The message is any future resolving to an Option, such as receiving from a channel,
+  /// where None means the channel was closed.
+  ///
+  /// # Errors
+  ///
+  /// - An error is returned if the EventSync is paused, or becomes paused while waiting.
+  ///
+  /// # Example
+  ///
+  /// ```no_run
+  /// use event_sync::*;
+  /// use futures_util::StreamExt;
+  ///
+  /// async fn worker<S: futures_util::Stream<Item = u32> + Unpin>(event_sync: EventSync, mut commands: S) {
+  ///   loop {
+  ///     match event_sync.wait_for_tick_or_async(commands.next()).await {
+  ///       Ok(TickOrMessage::Tick) => (), // Do this tick's work.
+  ///       Ok(TickOrMessage::Message(command)) => (), // Handle the command.
+  ///       Ok(TickOrMessage::Disconnected) | Err(_) => break,
+  ///     }
+  ///   }
+  /// }
+  /// ```
+  fn wait_for_tick_or_async<M, F: Future<Output = Option<M>>>(
+    &self,
+    message: F,
+  ) -> impl Future<Output = Result<TickOrMessage<M>, TimeError>>;
+}
+
+impl<T> AsyncWaiting for EventSync<T> {
+  fn wait_until_async(&self, tick_to_wait_for: u64) -> impl Future<Output = Result<(), TimeError>> {
+    let first_check = self
+      .read_inner()
+      .time_until_tick_occurs(tick_to_wait_for)
+      .map(|_| ());
+
+    async move {
+      first_check?;
+
+      wait_for_tick_to_occur(self, tick_to_wait_for).await
+    }
+  }
+
+  fn wait_for_tick_async(&self) -> impl Future<Output = Result<(), TimeError>> {
+    self.wait_for_x_ticks_async(1)
+  }
+
+  fn wait_for_x_ticks_async(
+    &self,
+    ticks_to_wait: u32,
+  ) -> impl Future<Output = Result<(), TimeError>> {
+    let target_tick = {
+      let inner = self.read_inner();
+
+      inner
+        .err_if_paused()
+        .map(|_| inner.ticks_since_started() + ticks_to_wait as u64)
+    };
+
+    async move { wait_for_tick_to_occur(self, target_tick?).await }
+  }
+
+  fn wait_for_tick_or_async<M, F: Future<Output = Option<M>>>(
+    &self,
+    message: F,
+  ) -> impl Future<Output = Result<TickOrMessage<M>, TimeError>> {
+    let tick = self.wait_for_tick_async();
+
+    async move {
+      let tick = std::pin::pin!(tick);
+      let message = std::pin::pin!(message);
+
+      match select(tick, message).await {
+        Either::Left((result, _)) => result.map(|_| TickOrMessage::Tick),
+        Either::Right((Some(message), _)) => Ok(TickOrMessage::Message(message)),
+        Either::Right((None, _)) => Ok(TickOrMessage::Disconnected),
+      }
+    }
+  }
+}
+
+/// Sleeps until the given tick has occurred, re-checking the EventSync at least once every tick.
+///
+/// Re-checking lets the wait follow any changes made while waiting, such as the tickrate changing or the EventSync
+/// being restarted. It also covers the timers rounding their duration down to the millisecond.
+///
+/// # Errors
+///
+/// - An error is returned if the EventSync is paused while waiting.
+pub(crate) async fn wait_for_tick_to_occur<T>(
+  event_sync: &EventSync<T>,
+  tick_to_wait_for: u64,
+) -> Result<(), TimeError> {
+  loop {
+    let wait_time = {
+      let inner = event_sync.read_inner();
+
+      match inner.time_until_tick_occurs(tick_to_wait_for) {
+        Ok(wait_time) => wait_time.min(Duration::from_millis(inner.get_tickrate())),
+        Err(TimeError::ThatTimeHasAlreadyHappened) => return Ok(()),
+        Err(error) => return Err(error),
+      }
+    };
+
+    sleep(wait_time).await;
+  }
+}
@@ -0,0 +1,151 @@
+use crate::errors::TimeError;
+use crate::{EventSync, Immutable};
+
+/// Fixed length windows of ticks, yielded as `(window_index, start_tick, end_tick)` as each one closes.
+///
+/// Windows are counted from tick 0, so window `n` covers the ticks from `n * window_len` up to, but not including,
+/// `(n + 1) * window_len`, and every thread windowing the same timeline agrees on where they fall.
+/// A window closes once its end tick occurs.
+///
+/// Created with [`EventSync::windows()`](EventSync::windows), starting from the window that's open at the time.
+/// Windows that closed while the iterator wasn't being polled are still yielded, one after another.
+///
+/// # Errors
+///
+/// - An error is yielded if the EventSync is paused, without moving past the window.
+///
+/// # Example
+///
+/// ```
+/// use event_sync::*;
+///
+/// let tickrate = 10; // 10ms between every tick.
+/// let event_sync = EventSync::from_starting_tick_millis(tickrate, 7, false);
+///
+/// let mut windows = event_sync.windows(5);
+///
+/// assert_eq!(windows.next(), Some(Ok((1, 5, 10))));
+/// assert_eq!(windows.next(), Some(Ok((2, 10, 15))));
+/// assert!(event_sync.ticks_since_started() >= 15);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TickWindows {
+  event_sync: EventSync<Immutable>,
+  window_len: u64,
+  next_index: u64,
+}
+
+impl TickWindows {
+  /// Returns the length of every window in ticks.
+  pub fn window_len(&self) -> u64 {
+    self.window_len
+  }
+
+  /// Returns the window that will be yielded next, without waiting for it to close.
+  ///
+  /// None is returned once the windows go past the last tick that can be counted.
+  pub fn peek(&self) -> Option<(u64, u64, u64)> {
+    let start_tick = self.next_index.checked_mul(self.window_len)?;
+    let end_tick = start_tick.checked_add(self.window_len)?;
+
+    Some((self.next_index, start_tick, end_tick))
+  }
+
+  /// Yields each window as it closes, from a [`Stream`](futures_util::Stream) rather than blocking.
+  ///
+  /// Like [`AsyncWaiting`](crate::AsyncWaiting), the waits are backed by the browser's timers.
+  ///
+  /// # Example
+  ///
+  /// ```no_run
+  /// use event_sync::*;
+  /// use futures_util::StreamExt;
+  ///
+  /// async fn report(event_sync: EventSync) -> Result<(), TimeError> {
+  ///   let mut windows = Box::pin(event_sync.windows(60).into_stream());
+  ///
+  ///   while let Some(window) = windows.next().await {
+  ///     let (window_index, start_tick, end_tick) = window?;
+  ///
+  ///     // Emit the metrics gathered from start_tick to end_tick.
+  ///   }
+  ///
+  ///   Ok(())
+  /// }
+  /// ```
+  #[cfg(feature = "wasm")]
+  pub fn into_stream(self) -> impl futures_util::Stream<Item = Result<(u64, u64, u64), TimeError>> {
+    futures_util::stream::unfold(self, |mut windows| async move {
+      let window = windows.peek()?;
+      let closed = crate::wasm::wait_for_tick_to_occur(&windows.event_sync, window.2).await;
+
+      if closed.is_ok() {
+        windows.next_index += 1;
+      }
+
+      Some((closed.map(|()| window), windows))
+    })
+  }
+}
+
+impl Iterator for TickWindows {
+  type Item = Result<(u64, u64, u64), TimeError>;
+
+  /// Waits for the next window to close, then returns it.
+  fn next(&mut self) -> Option<Self::Item> {
+    let window = self.peek()?;
+
+    match self.event_sync.wait_until(window.2) {
+      Ok(_) | Err(TimeError::ThatTimeHasAlreadyHappened) => (),
+      Err(error) => return Some(Err(error)),
+    }
+
+    self.next_index += 1;
+
+    Some(Ok(window))
+  }
+}
+
+impl<T> EventSync<T> {
+  /// Returns an iterator over fixed length windows of ticks, blocking until each one closes.
+  ///
+  /// The window length cannot go below 1. If 0 is passed in, 1 is used.
+  ///
+  /// See [`TickWindows`](TickWindows) for more details.
+  pub fn windows(&self, window_len_ticks: u64) -> TickWindows {
+    let window_len = window_len_ticks.max(1);
+
+    TickWindows {
+      event_sync: self.to_immutable(),
+      window_len,
+      next_index: self.ticks_since_started() / window_len,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::time::Duration;
+
+  const TEST_TICKRATE: u64 = 10;
+
+  #[test]
+  fn closed_windows_are_yielded_in_order() {
+    let mut event_sync = EventSync::from_starting_tick_millis(TEST_TICKRATE, 3, true);
+    let mut windows = event_sync.windows(2);
+
+    assert_eq!(windows.peek(), Some((1, 2, 4)));
+    assert_eq!(windows.next(), Some(Err(TimeError::EventSyncPaused)));
+
+    event_sync
+      .sync_to(Duration::from_millis(TEST_TICKRATE * 9))
+      .unwrap();
+    event_sync.unpause().unwrap();
+
+    let closed: Vec<_> = windows.by_ref().take(4).map(Result::unwrap).collect();
+
+    assert_eq!(closed, [(1, 2, 4), (2, 4, 6), (3, 6, 8), (4, 8, 10)]);
+    assert_eq!(event_sync.windows(0).window_len(), 1);
+  }
+}